@@ -1,38 +1,184 @@
+use crate::markdown;
 use convert_case::*;
+use std::collections::*;
 use std::fmt::*;
 
 /// Generates C# code for a type.
-pub struct DisplayCs<'a, T: DisplayBindings>(pub &'a T);
+pub struct DisplayCs<'a, T: DisplayBindings>(pub &'a T, pub &'a RenderContext);
 
 impl<'a, T: DisplayBindings> Display for DisplayCs<'a, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        self.0.write_cs(f)
+        self.0.write_cs(f, self.1)
     }
 }
 
 /// Generates Rust code for a type.
-pub struct DisplayRs<'a, T: DisplayBindings>(pub &'a T);
+pub struct DisplayRs<'a, T: DisplayBindings>(pub &'a T, pub &'a RenderContext);
 
 impl<'a, T: DisplayBindings> Display for DisplayRs<'a, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        self.0.write_rs(f)
+        self.0.write_rs(f, self.1)
     }
 }
 
 /// A binding type that can generate either Rust or C# code.
 pub trait DisplayBindings {
     /// Generates the C#-side code for this binding.
-    fn write_cs(&self, f: &mut Formatter) -> Result;
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result;
 
     /// Generates the Rust-side code for this binding.
-    fn write_rs(&self, f: &mut Formatter) -> Result;
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result;
+}
+
+/// The settings shared by every binding generated in a single run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderContext {
+    /// The names given to the generated types and exported functions.
+    pub naming: NamingConfig,
+    /// The C# name of every generated type, keyed by the canonical Rust path of the type.
+    pub type_names: BTreeMap<String, String>,
+    /// The pointer width of the target in bits, against which struct layouts are checked.
+    pub pointer_width: usize,
+    /// Whether `bool` fields are declared as `[MarshalAs(UnmanagedType.U1)] bool`, rather than as a
+    /// `byte` exposed through a `bool` property.
+    pub marshal_bools: bool,
+    /// The integer type which each generated enum is passed as, keyed by the name of its Rust mirror.
+    pub enum_reprs: BTreeMap<String, PrimitiveType>,
+    /// The Rust type which each generated enum and struct converts from, keyed by the name of its mirror. Views
+    /// and instantiations without conversions are left out.
+    pub mirrors: BTreeMap<String, String>,
+    /// The tag which identifies the objects of each class, keyed by the Rust name of the class. This
+    /// includes user-provided types which are referred to by handle.
+    pub class_tags: BTreeMap<String, u32>,
+    /// The C# name of every generated class, keyed by the Rust name of the class.
+    pub class_names: BTreeMap<String, String>,
+    /// The parameters and return type of each delegate, keyed by the name of its Rust function pointer type.
+    pub delegates: BTreeMap<String, (Vec<Parameter>, Option<TypeReference>)>,
+    /// The Rust type which each view converts from, keyed by the name of its mirror.
+    pub views: BTreeMap<String, String>,
+    /// Whether each item and exported function is preceded by a comment naming the Rust item it was generated from.
+    pub source_comments: bool,
+    /// Whether items are rendered on several threads, if the `parallel` feature is enabled. The output is the same either way.
+    pub parallel: bool
+}
+
+// Items are rendered on several threads when the `parallel` feature is enabled.
+const _: fn() = || {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<RenderContext>();
+    assert_sync::<Item>();
+};
+
+impl RenderContext {
+    /// Creates a context for generating bindings for the given items, on a 64-bit target.
+    pub fn new(items: &[Item], naming: NamingConfig) -> Self {
+        let type_names = items.iter()
+            .filter(|x| matches!(x, Item::Enum { .. } | Item::Union { .. } | Item::Class { .. } | Item::Struct { .. }))
+            .map(|x| (format!("{}::{}", x.module(), x.name()), x.cs_reference_name()))
+            .collect();
+        let enum_reprs = items.iter()
+            .filter_map(|x| match x {
+                Item::Enum { repr, .. } => Some((x.rs_name(), repr.clone().unwrap_or(PrimitiveType::I32))),
+                _ => None
+            })
+            .collect();
+        let mirrors = items.iter()
+            .filter(|x| matches!(x, Item::Enum { .. } | Item::Struct { conversions: true, view: false, .. }))
+            .map(|x| (x.rs_name(), x.rs_original().to_string()))
+            .collect();
+
+        let mut classes = BTreeSet::new();
+        let mut class_names = BTreeMap::new();
+        for item in items {
+            if let Item::Class { .. } = item {
+                classes.insert(item.rs_class());
+                class_names.insert(item.rs_class(), item.cs_name());
+            }
+
+            item.visit_types(&mut |x| if let TypeReference::Handle { class, .. } | TypeReference::HandleMut { class } = x {
+                classes.insert(class.clone());
+            });
+        }
+        // Zero is never a tag, so that zeroed memory is not mistaken for an object.
+        let class_tags = classes.into_iter().zip(1..).collect();
+        let delegates = items.iter()
+            .filter_map(|x| match x {
+                Item::Delegate { params, ret, .. } => Some((x.rs_name(), (params.clone(), ret.clone()))),
+                _ => None
+            })
+            .collect();
+        let views = items.iter()
+            .filter(|x| matches!(x, Item::Struct { view: true, .. } | Item::Union { view: true, .. }))
+            .map(|x| (x.rs_name(), x.rs_original().to_string()))
+            .collect();
+        Self { naming, type_names, pointer_width: 64, marshal_bools: false, enum_reprs, mirrors, class_tags, class_names, delegates, views, source_comments: true, parallel: true }
+    }
+
+    /// Gets the C# name of the class with the given Rust name. User-provided classes keep their Rust name.
+    pub fn class_name<'a>(&'a self, class: &'a str) -> &'a str {
+        self.class_names.get(class).map_or(class, String::as_str)
+    }
+}
+
+impl Default for RenderContext {
+    fn default() -> Self {
+        Self::new(&[], NamingConfig::default())
+    }
+}
+
+/// Generates a C header declaration for a type.
+pub struct DisplayHeader<'a, T: HeaderBindings>(pub &'a T);
+
+impl<'a, T: HeaderBindings> Display for DisplayHeader<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.0.write_c_header(f)
+    }
+}
+
+/// A binding type that can be declared in a C header, for consumers of the Rust bindings other than C#.
+pub trait HeaderBindings {
+    /// Generates the C declaration for this binding.
+    fn write_c_header(&self, f: &mut Formatter) -> Result;
+}
+
+/// The names given to the generated types and exported functions, so that they do not collide
+/// with other native code in the same library or program. The shared support types, such as
+/// `VxString` and `VxObject`, keep their names.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+pub struct NamingConfig {
+    /// The prefix of each Rust mirror type, such as `Vx` in `VxPos2`.
+    pub type_prefix: String,
+    /// The prefix of each exported function, such as `vx_` in `vx_pos2_default`.
+    pub fn_prefix: String,
+    /// The name of the C# class which declares the exported functions.
+    pub library_class_name: String
+}
+
+impl NamingConfig {
+    /// Gets the exported symbol for a function with the given unprefixed name.
+    pub fn native_fn(&self, name: &str) -> String {
+        format!("{}{name}", self.fn_prefix)
+    }
+
+    /// Gets the C# expression which refers to the exported function with the given symbol.
+    pub fn cs_native_fn(&self, symbol: &str) -> String {
+        format!("{}.{symbol}", self.library_class_name)
+    }
+}
+
+impl Default for NamingConfig {
+    fn default() -> Self {
+        Self { type_prefix: "Vx".to_string(), fn_prefix: "vx_".to_string(), library_class_name: "Vx".to_string() }
+    }
 }
 
 /// A primitive type that can be shared between C# and Rust.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
 pub enum PrimitiveType {
     /// The [`bool`] type.
     Bool,
+    /// The [`char`] type, which crosses the FFI boundary as its `u32` codepoint.
+    Char,
     /// The [`u8`] type.
     U8,
     /// The [`u16`] type.
@@ -41,6 +187,10 @@ pub enum PrimitiveType {
     U32,
     /// The [`u64`] type.
     U64,
+    /// The [`usize`] type.
+    Usize,
+    /// A [`usize`] which crosses the FFI boundary as a [`u64`], whatever the pointer width of the target.
+    FixedUsize,
     /// The [`i8`] type.
     I8,
     /// The [`i16`] type.
@@ -49,6 +199,10 @@ pub enum PrimitiveType {
     I32,
     /// The [`i64`] type.
     I64,
+    /// The [`isize`] type.
+    Isize,
+    /// An [`isize`] which crosses the FFI boundary as an [`i64`], whatever the pointer width of the target.
+    FixedIsize,
     /// The [`f32`] type.
     F32,
     /// The [`f64`] type.
@@ -57,79 +211,471 @@ pub enum PrimitiveType {
     String
 }
 
-impl DisplayBindings for PrimitiveType {
-    fn write_cs(&self, f: &mut Formatter) -> Result {
-        f.write_str(match self {
+impl PrimitiveType {
+    /// Whether this is an integer type.
+    pub fn is_integer(&self) -> bool {
+        !matches!(self, PrimitiveType::Bool | PrimitiveType::Char | PrimitiveType::F32 | PrimitiveType::F64 | PrimitiveType::String)
+    }
+
+    /// Whether this is an integer or floating-point type.
+    pub fn is_numeric(&self) -> bool {
+        !matches!(self, PrimitiveType::Bool | PrimitiveType::Char | PrimitiveType::String)
+    }
+
+    /// Whether the given value is representable by this integer type.
+    pub fn contains(&self, value: i128) -> bool {
+        let (min, max) = match self {
+            PrimitiveType::U8 => (0, u8::MAX as i128),
+            PrimitiveType::U16 => (0, u16::MAX as i128),
+            PrimitiveType::U32 => (0, u32::MAX as i128),
+            PrimitiveType::U64 => (0, u64::MAX as i128),
+            // Only values which fit on every target are accepted.
+            PrimitiveType::Usize | PrimitiveType::FixedUsize => (0, u32::MAX as i128),
+            PrimitiveType::I8 => (i8::MIN as i128, i8::MAX as i128),
+            PrimitiveType::I16 => (i16::MIN as i128, i16::MAX as i128),
+            PrimitiveType::I32 => (i32::MIN as i128, i32::MAX as i128),
+            PrimitiveType::I64 => (i64::MIN as i128, i64::MAX as i128),
+            PrimitiveType::Isize | PrimitiveType::FixedIsize => (i32::MIN as i128, i32::MAX as i128),
+            _ => return false
+        };
+        (min..=max).contains(&value)
+    }
+
+    /// Gets the name of this type in C#.
+    pub fn cs_name(&self) -> &'static str {
+        match self {
             PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "System.Text.Rune",
             PrimitiveType::U8 => "byte",
             PrimitiveType::U16 => "ushort",
             PrimitiveType::U32 => "uint",
             PrimitiveType::U64 => "ulong",
+            PrimitiveType::Usize => "nuint",
+            PrimitiveType::FixedUsize => "ulong",
             PrimitiveType::I8 => "sbyte",
             PrimitiveType::I16 => "short",
             PrimitiveType::I32 => "int",
             PrimitiveType::I64 => "long",
+            PrimitiveType::Isize => "nint",
+            PrimitiveType::FixedIsize => "long",
             PrimitiveType::F32 => "float",
             PrimitiveType::F64 => "double",
-            PrimitiveType::String => "string",
-        })
+            PrimitiveType::String => "string"
+        }
     }
 
-    fn write_rs(&self, f: &mut Formatter) -> Result {
-        f.write_str(match self {
+    /// Gets the name of this type in Rust.
+    pub fn rs_name(&self) -> &'static str {
+        match self {
             PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "VxChar",
             PrimitiveType::U8 => "u8",
             PrimitiveType::U16 => "u16",
             PrimitiveType::U32 => "u32",
             PrimitiveType::U64 => "u64",
+            PrimitiveType::Usize => "usize",
+            PrimitiveType::FixedUsize => "VxUsize",
             PrimitiveType::I8 => "i8",
             PrimitiveType::I16 => "i16",
             PrimitiveType::I32 => "i32",
             PrimitiveType::I64 => "i64",
+            PrimitiveType::Isize => "isize",
+            PrimitiveType::FixedIsize => "VxIsize",
             PrimitiveType::F32 => "f32",
             PrimitiveType::F64 => "f64",
-            PrimitiveType::String => "VxString",
+            PrimitiveType::String => "VxString"
+        }
+    }
+}
+
+impl DisplayBindings for PrimitiveType {
+    fn write_cs(&self, f: &mut Formatter, _: &RenderContext) -> Result {
+        f.write_str(self.cs_name())
+    }
+
+    fn write_rs(&self, f: &mut Formatter, _: &RenderContext) -> Result {
+        f.write_str(self.rs_name())
+    }
+}
+
+impl HeaderBindings for PrimitiveType {
+    fn write_c_header(&self, f: &mut Formatter) -> Result {
+        f.write_str(match self {
+            PrimitiveType::Bool => "bool",
+            PrimitiveType::Char => "uint32_t",
+            PrimitiveType::U8 => "uint8_t",
+            PrimitiveType::U16 => "uint16_t",
+            PrimitiveType::U32 => "uint32_t",
+            PrimitiveType::U64 => "uint64_t",
+            PrimitiveType::Usize => "size_t",
+            PrimitiveType::FixedUsize => "uint64_t",
+            PrimitiveType::I8 => "int8_t",
+            PrimitiveType::I16 => "int16_t",
+            PrimitiveType::I32 => "int32_t",
+            PrimitiveType::I64 => "int64_t",
+            PrimitiveType::Isize => "ptrdiff_t",
+            PrimitiveType::FixedIsize => "int64_t",
+            PrimitiveType::F32 => "float",
+            PrimitiveType::F64 => "double",
+            PrimitiveType::String => "VxString"
         })
     }
 }
 
 /// Defines the data necessary to use or marshal another type.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
 pub enum TypeReference {
     /// The type is externally-provided.
-    Primitive(PrimitiveType)
+    Primitive(PrimitiveType),
+    /// The type is another generated item.
+    Named {
+        /// The name of the item's FFI mirror in Rust.
+        rs_name: String,
+        /// The name of the item in C#.
+        cs_name: String
+    },
+    /// A fixed-size array of another type.
+    Array {
+        /// The type of each element.
+        element: Box<TypeReference>,
+        /// The number of elements.
+        len: usize
+    },
+    /// A raw pointer to another type.
+    Pointer {
+        /// The type being pointed to.
+        target: Box<TypeReference>,
+        /// Whether the pointee may be modified.
+        mutable: bool
+    },
+    /// A pointer to a heap-allocated object of a generated class.
+    Handle {
+        /// The name of the class.
+        class: String,
+        /// Whether the object may be modified. Parameters which may not are borrowed for the call, rather
+        /// than moved out of C#.
+        mutable: bool
+    },
+    /// A pointer to an object of a generated class which is mutably borrowed for the call.
+    HandleMut {
+        /// The name of the class.
+        class: String
+    },
+    /// An optional value, lowered to a flag followed by the value.
+    Optional(Box<TypeReference>),
+    /// A contiguous sequence of values, lowered to a pointer and length.
+    Slice {
+        /// The type of each element.
+        element: Box<TypeReference>,
+        /// Who owns the elements, and so how long C# may read them.
+        ownership: SliceOwnership
+    },
+    /// A string owned by Rust which the callee may modify, such as the text of a text edit, lowered to a
+    /// pointer to a `VxStringBuffer`. C# passes an `EguiStringBuffer`, which may be reused across frames without
+    /// converting the text at each call.
+    StringBuffer,
+    /// A value which the callee may modify, such as `&mut f32`, which C# passes by `ref`.
+    InOut(Box<TypeReference>),
+    /// A callback of a generated [`Item::Delegate`] type, lowered to a function pointer and user-data pointer.
+    Delegate {
+        /// The name of the delegate's Rust function pointer type.
+        rs_name: String,
+        /// The name of the delegate in C#.
+        cs_name: String
+    }
+}
+
+/// Who owns the elements of a [`TypeReference::Slice`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+pub enum SliceOwnership {
+    /// The elements are borrowed for the duration of a call.
+    Borrowed,
+    /// The slice owns its allocation (like a [`Vec`]), which must be freed.
+    Owned,
+    /// The elements belong to a vector which Rust keeps until the bindings next return one, such as at the
+    /// next frame. C# checks that the vector still exists before reading them.
+    Frame,
+    /// The elements were converted from a vector within a view, and the copies are kept along with the value
+    /// which the view borrows from.
+    Converted
+}
+
+impl TypeReference {
+    /// Calls the given function on this type and every type nested within it.
+    pub fn visit(&self, f: &mut impl FnMut(&TypeReference)) {
+        f(self);
+        match self {
+            TypeReference::Array { element: inner, .. }
+            | TypeReference::Pointer { target: inner, .. }
+            | TypeReference::Optional(inner)
+            | TypeReference::Slice { element: inner, .. }
+            | TypeReference::InOut(inner) => inner.visit(f),
+            _ => {}
+        }
+    }
+
+    /// Calls the given function on this type and every type nested within it, allowing them to be modified.
+    pub fn visit_mut(&mut self, f: &mut impl FnMut(&mut TypeReference)) {
+        f(self);
+        match self {
+            TypeReference::Array { element: inner, .. }
+            | TypeReference::Pointer { target: inner, .. }
+            | TypeReference::Optional(inner)
+            | TypeReference::Slice { element: inner, .. }
+            | TypeReference::InOut(inner) => inner.visit_mut(f),
+            _ => {}
+        }
+    }
+
+    /// Gets the suffix which identifies this type in the names of generic instantiations, such as the
+    /// `f32` in `VxOption_f32`, since C has no generics.
+    pub fn c_generic_name(&self) -> String {
+        match self {
+            TypeReference::Primitive(primitive) => primitive.rs_name().to_string(),
+            TypeReference::Named { rs_name, .. } => rs_name.clone(),
+            TypeReference::Array { element, len } => format!("{}_{len}", element.c_generic_name()),
+            TypeReference::Pointer { target, .. } => format!("ptr_{}", target.c_generic_name()),
+            TypeReference::Handle { .. } | TypeReference::HandleMut { .. } => "ptr_VxObject".to_string(),
+            TypeReference::Optional(inner) => format!("option_{}", inner.c_generic_name()),
+            TypeReference::Slice { element, .. } => format!("slice_{}", element.c_generic_name()),
+            TypeReference::InOut(inner) => format!("ptr_{}", inner.c_generic_name()),
+            TypeReference::StringBuffer => "ptr_VxStringBuffer".to_string(),
+            TypeReference::Delegate { rs_name, .. } => rs_name.clone()
+        }
+    }
+
+    /// Gets the C# type used to pass this type across the FFI boundary. This differs from the public
+    /// C# type for characters, strings, and handles, which are converted at each call site.
+    pub fn cs_native_type(&self, ctx: &RenderContext) -> String {
+        match self {
+            TypeReference::Primitive(PrimitiveType::Char) => "uint".to_string(),
+            TypeReference::Primitive(PrimitiveType::String) => "VxString".to_string(),
+            TypeReference::Handle { .. } | TypeReference::HandleMut { .. } => "VxObject*".to_string(),
+            TypeReference::Array { element, .. } => format!("{}*", element.cs_native_type(ctx)),
+            TypeReference::Pointer { target, .. } => format!("{}*", target.cs_native_type(ctx)),
+            TypeReference::Optional(inner) => format!("VxOption<{}>", inner.cs_native_type(ctx)),
+            TypeReference::Slice { element, .. } => format!("VxSlice<{}>", element.cs_native_type(ctx)),
+            TypeReference::InOut(inner) => format!("{}*", inner.cs_native_type(ctx)),
+            TypeReference::StringBuffer => "VxStringBuffer*".to_string(),
+            TypeReference::Delegate { .. } => "nint".to_string(),
+            other => DisplayCs(other, ctx).to_string()
+        }
+    }
+
+    /// Converts a C# expression of this type into the value passed across the FFI boundary.
+    pub fn cs_to_native(&self, value: &str) -> String {
+        match self {
+            TypeReference::Primitive(PrimitiveType::Char) => format!("(uint){value}.Value"),
+            TypeReference::Primitive(PrimitiveType::String) => format!("VxString.FromString({value})"),
+            TypeReference::Handle { mutable: false, .. } | TypeReference::HandleMut { .. } => format!("({value} ?? throw new ArgumentNullException(nameof({value}))).Pointer"),
+            TypeReference::Handle { .. } => format!("({value} ?? throw new ArgumentNullException(nameof({value}))).TakePointer()"),
+            // Like callbacks, values passed by `ref` are copied to a local named after the parameter by `write_cs_wrapper_body`.
+            TypeReference::InOut(_) => format!("&{value}Native"),
+            // Slices are pinned to a pointer named after the parameter by `write_cs_wrapper_body`.
+            TypeReference::Slice { .. } => format!("new({value}Ptr, {value}.Length)"),
+            TypeReference::StringBuffer => format!("({value} ?? throw new ArgumentNullException(nameof({value}))).Pointer"),
+            // Callbacks are wrapped in a native delegate named after the parameter by `write_cs_wrapper_body`. The
+            // user data is only for Rust callers, since C# delegates may capture state themselves.
+            TypeReference::Delegate { .. } => format!("Marshal.GetFunctionPointerForDelegate({value}Callback), null"),
+            _ => value.to_string()
+        }
+    }
+
+    /// Wraps a C# call into Rust which returns this type, so that a panic during the call is thrown as an
+    /// `EguiException` before the returned value is used.
+    pub fn cs_checked(&self, call: &str, ctx: &RenderContext) -> String {
+        match self {
+            // Pointers cannot be generic arguments, so they are checked as `void*` and cast back.
+            TypeReference::Array { .. } | TypeReference::Pointer { .. } | TypeReference::Handle { .. } => format!("({})EguiException.Check({call})", self.cs_native_type(ctx)),
+            _ => format!("EguiException.Check({call})")
+        }
+    }
+
+    /// Converts a value returned across the FFI boundary into the public C# type, taking ownership of it.
+    pub fn cs_from_native(&self, value: &str, ctx: &RenderContext) -> String {
+        match self {
+            // Rust characters are always valid scalar values, so the conversion cannot throw.
+            TypeReference::Primitive(PrimitiveType::Char) => format!("new System.Text.Rune({value})"),
+            TypeReference::Primitive(PrimitiveType::String) => format!("VxString.Take({value})"),
+            TypeReference::Handle { class, .. } => format!("new {}({value})", ctx.class_name(class)),
+            _ => value.to_string()
+        }
+    }
+
+    /// Converts a Rust expression of the original type into the value returned across the FFI boundary.
+    /// Objects are moved to the heap, and C# takes ownership of them. Views and vectors of views are kept
+    /// by Rust until the next value of the same type, and C# borrows from them.
+    pub fn rs_to_native(&self, value: &str, ctx: &RenderContext) -> String {
+        match self {
+            TypeReference::Handle { .. } => format!("VxHandle::into_heap({value})"),
+            TypeReference::Named { rs_name, .. } if ctx.views.contains_key(rs_name) => format!("vx_frame_keep({value})"),
+            TypeReference::Slice { ownership: SliceOwnership::Frame, .. } => format!("vx_frame_store({value})"),
+            TypeReference::Optional(inner) if matches!(**inner, TypeReference::Named { .. }) => format!("{value}.map(Into::into).into()"),
+            _ => format!("{value}.into()")
+        }
+    }
+}
+
+impl HeaderBindings for TypeReference {
+    fn write_c_header(&self, f: &mut Formatter) -> Result {
+        match self {
+            TypeReference::Primitive(primitive_type) => primitive_type.write_c_header(f),
+            TypeReference::Named { rs_name, .. } => f.write_str(rs_name),
+            // Outside of a struct field, arrays decay to a pointer to their first element.
+            TypeReference::Array { element, .. } => f.write_fmt(format_args!("{}*", DisplayHeader(&**element))),
+            TypeReference::Pointer { target, mutable } => f.write_fmt(format_args!("{}{}*", if *mutable { "" } else { "const " }, DisplayHeader(&**target))),
+            TypeReference::Handle { mutable, .. } => f.write_str(if *mutable { "VxObject*" } else { "const VxObject*" }),
+            TypeReference::HandleMut { .. } => f.write_str("VxObject*"),
+            TypeReference::Optional(inner) => f.write_fmt(format_args!("VxOption_{}", inner.c_generic_name())),
+            TypeReference::Slice { element, .. } => f.write_fmt(format_args!("VxSlice_{}", element.c_generic_name())),
+            TypeReference::InOut(inner) => f.write_fmt(format_args!("{}*", DisplayHeader(&**inner))),
+            TypeReference::StringBuffer => f.write_str("VxStringBuffer*"),
+            TypeReference::Delegate { rs_name, .. } => f.write_str(rs_name)
+        }
+    }
 }
 
 impl DisplayBindings for TypeReference {
-    fn write_cs(&self, f: &mut Formatter) -> Result {
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
         match self {
-            TypeReference::Primitive(primitive_type) => primitive_type.write_cs(f),
+            TypeReference::Primitive(primitive_type) => primitive_type.write_cs(f, ctx),
+            TypeReference::Named { cs_name, .. } => f.write_str(cs_name),
+            // Outside of a struct field, arrays decay to a pointer to their first element.
+            TypeReference::Array { element, .. } => f.write_fmt(format_args!("{}*", DisplayCs(&**element, ctx))),
+            TypeReference::Pointer { target, .. } => f.write_fmt(format_args!("{}*", DisplayCs(&**target, ctx))),
+            TypeReference::Handle { class, .. } | TypeReference::HandleMut { class } => f.write_str(ctx.class_name(class)),
+            TypeReference::Optional(inner) => f.write_fmt(format_args!("VxOption<{}>", DisplayCs(&**inner, ctx))),
+            TypeReference::Slice { element, .. } => f.write_fmt(format_args!("VxSlice<{}>", DisplayCs(&**element, ctx))),
+            TypeReference::InOut(inner) => f.write_fmt(format_args!("ref {}", DisplayCs(&**inner, ctx))),
+            TypeReference::StringBuffer => f.write_str("EguiStringBuffer"),
+            // Callbacks are exposed as the standard delegate types, and wrapped in the native delegate at each call.
+            TypeReference::Delegate { rs_name, .. } => {
+                let (params, ret) = &ctx.delegates[rs_name];
+                let arguments = params.iter().map(|x| DisplayCs(&x.ty, ctx).to_string()).chain(ret.iter().map(|x| DisplayCs(x, ctx).to_string())).collect::<Vec<_>>();
+                match (ret, arguments.is_empty()) {
+                    (None, true) => f.write_str("Action"),
+                    (None, false) => f.write_fmt(format_args!("Action<{}>", arguments.join(", "))),
+                    (Some(_), _) => f.write_fmt(format_args!("Func<{}>", arguments.join(", ")))
+                }
+            }
         }
     }
 
-    fn write_rs(&self, f: &mut Formatter) -> Result {
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
         match self {
-            TypeReference::Primitive(primitive_type) => primitive_type.write_rs(f),
+            TypeReference::Primitive(primitive_type) => primitive_type.write_rs(f, ctx),
+            TypeReference::Named { rs_name, .. } => f.write_str(rs_name),
+            TypeReference::Array { element, len } => f.write_fmt(format_args!("[{}; {len}]", DisplayRs(&**element, ctx))),
+            TypeReference::Pointer { target, mutable } => f.write_fmt(format_args!("*{} {}", if *mutable { "mut" } else { "const" }, DisplayRs(&**target, ctx))),
+            TypeReference::Handle { class, mutable } => f.write_fmt(format_args!("*{} VxObject<{class}>", if *mutable { "mut" } else { "const" })),
+            TypeReference::HandleMut { class } => f.write_fmt(format_args!("*mut VxObject<{class}>")),
+            TypeReference::Optional(inner) => f.write_fmt(format_args!("VxOption<{}>", DisplayRs(&**inner, ctx))),
+            TypeReference::Slice { element, .. } => f.write_fmt(format_args!("VxSlice<{}>", DisplayRs(&**element, ctx))),
+            TypeReference::InOut(inner) => f.write_fmt(format_args!("*mut {}", DisplayRs(&**inner, ctx))),
+            TypeReference::StringBuffer => f.write_str("*mut VxStringBuffer"),
+            TypeReference::Delegate { rs_name, .. } => f.write_str(rs_name)
         }
     }
 }
 
 /// A top-level type definition.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
 pub enum Item {
     /// A simple enum without any payload data.
     Enum {
         /// The name of the type.
         name: String,
+        /// The Rust module which defines the item, like `egui::containers::frame`.
+        module: String,
+        /// The name to use in the public C# API instead of the original name, if any.
+        rename: Option<String>,
+        /// The name under which the original type is imported into the generated Rust, if another generated
+        /// type has the same name.
+        alias: Option<String>,
         /// The possible enum values.
         variants: Vec<EnumVariant>,
+        /// The underlying integer type of the enum, or [`None`] to use the default (`int`).
+        repr: Option<PrimitiveType>,
+        /// Whether the variants are bit flags, so that the C# enum has `[Flags]`.
+        flags: bool,
+        /// Whether the Rust enum is `#[non_exhaustive]`.
+        non_exhaustive: bool,
+        /// The discriminant of the `Unknown` variant which stands in for variants added to a non-exhaustive
+        /// enum after the bindings were generated, if one was requested.
+        unknown: Option<i128>,
+        /// The names given to the generated bindings.
+        naming: NamingConfig,
+        /// Whether the item is deprecated, and why.
+        deprecation: Option<Deprecation>,
+        /// The Rust item which the bindings were generated from, if any.
+        origin: Option<Origin>,
         /// The doc-comment to include.
         docs: String,
     },
+    /// An enum whose variants carry data, copied as a tag followed by a union of the data of each variant.
+    Union {
+        /// The name of the type.
+        name: String,
+        /// The Rust module which defines the item, like `egui::containers::frame`.
+        module: String,
+        /// The name to use in the public C# API instead of the original name, if any.
+        rename: Option<String>,
+        /// The name under which the original type is imported into the generated Rust, if another generated
+        /// type has the same name.
+        alias: Option<String>,
+        /// The variants whose data can be copied across the FFI boundary.
+        variants: Vec<UnionVariant>,
+        /// Whether the Rust enum is `#[non_exhaustive]`, so that conversions from it must handle variants
+        /// added in later versions.
+        non_exhaustive: bool,
+        /// Whether an `Unknown` variant stands in for the variants which were left out, or which were added
+        /// to a non-exhaustive enum after the bindings were generated.
+        unknown: bool,
+        /// Whether the mirror is a view which borrows vectors from the original enum, as for [`Item::Struct`].
+        /// Views are only converted from Rust, so C# cannot create them.
+        view: bool,
+        /// Whether the mirror holds strings, which C# frees as for [`Item::Struct`].
+        owns_strings: bool,
+        /// The names given to the generated bindings.
+        naming: NamingConfig,
+        /// Whether the item is deprecated, and why.
+        deprecation: Option<Deprecation>,
+        /// The Rust item which the bindings were generated from, if any.
+        origin: Option<Origin>,
+        /// The doc-comment to include.
+        docs: String
+    },
     /// A heap-allocated object backed by a handle.
     Class {
         /// The name of the type.
         name: String,
+        /// The Rust module which defines the item, like `egui::containers::frame`.
+        module: String,
+        /// The name to use in the public C# API instead of the original name, if any.
+        rename: Option<String>,
+        /// The name under which the original type is imported into the generated Rust, if another generated
+        /// type has the same name.
+        alias: Option<String>,
+        /// The associated functions which create new objects.
+        constructors: Vec<Constructor>,
+        /// The methods callable on the object.
+        methods: Vec<Method>,
+        /// Whether the class implements [`Default`] on the Rust side.
+        has_default: bool,
+        /// Whether the class implements [`Clone`] on the Rust side.
+        cloneable: bool,
+        /// The formatting trait used to convert the object to a string, if any.
+        format: Option<FormatTrait>,
+        /// Whether the object is owned by a generated `SafeHandle`, rather than by deriving from `VxHandle`.
+        safe_handle: bool,
+        /// The number of lifetime parameters of the type, which are `'static` for objects owned by C#.
+        lifetimes: usize,
+        /// The names given to the generated bindings.
+        naming: NamingConfig,
+        /// Whether the item is deprecated, and why.
+        deprecation: Option<Deprecation>,
+        /// The Rust item which the bindings were generated from, if any.
+        origin: Option<Origin>,
         /// The doc-comment to include.
         docs: String,
     },
@@ -137,22 +683,287 @@ pub enum Item {
     Struct {
         /// The name of the type.
         name: String,
+        /// The Rust module which defines the item, like `egui::containers::frame`.
+        module: String,
+        /// The name to use in the public C# API instead of the original name, if any.
+        rename: Option<String>,
+        /// The name under which the original type is imported into the generated Rust, if another generated
+        /// type has the same name.
+        alias: Option<String>,
         /// The possible struct fields.
         fields: Vec<StructField>,
         /// Whether the struct implements [`Default`] on the Rust side.
         has_default: bool,
+        /// Whether the struct implements [`PartialEq`] on the Rust side.
+        has_equality: bool,
+        /// The arithmetic operators that the struct implements on the Rust side.
+        operators: Vec<OperatorKind>,
+        /// The formatting trait used to convert the struct to a string, if any.
+        format: Option<FormatTrait>,
+        /// The existing C# type to use in place of this struct, if any.
+        external: Option<ExternalType>,
+        /// The alignment that the fields are packed to, if the Rust struct is `#[repr(packed)]`.
+        packing: Option<u32>,
+        /// Whether the struct is a newtype which C# marshals as its only field, so that the mirror is
+        /// `#[repr(transparent)]` and passed across the FFI boundary exactly like the field.
+        transparent: bool,
+        /// Whether `From` conversions between the Rust struct and its mirror are generated.
+        conversions: bool,
+        /// Whether the mirror is a view which borrows the vectors of the original struct for the frame.
+        view: bool,
+        /// The tuple or array type whose elements are the fields of the struct, like `(TextureId, ImageDelta)`.
+        positional: Option<String>,
+        /// Whether the mirror holds strings, directly or within other mirrors, which C# must free.
+        owns_strings: bool,
+        /// Whether fields which cannot be copied were left out of the mirror, and filled from `Default`.
+        defaulted: bool,
+        /// The names given to the generated bindings.
+        naming: NamingConfig,
+        /// Whether the item is deprecated, and why.
+        deprecation: Option<Deprecation>,
+        /// The Rust item which the bindings were generated from, if any.
+        origin: Option<Origin>,
+        /// The doc-comment to include.
+        docs: String
+    },
+    /// A constant value, read once through a generated accessor.
+    Constant {
+        /// The name of the constant.
+        name: String,
+        /// The Rust module which defines the item, like `egui::containers::frame`.
+        module: String,
+        /// The C# name of the struct that the constant is associated with, if any.
+        owner: Option<String>,
+        /// The Rust path used to read the constant.
+        path: String,
+        /// The type of the constant.
+        ty: TypeReference,
+        /// The names given to the generated bindings.
+        naming: NamingConfig,
+        /// Whether the item is deprecated, and why.
+        deprecation: Option<Deprecation>,
+        /// The Rust item which the bindings were generated from, if any.
+        origin: Option<Origin>,
+        /// The doc-comment to include.
+        docs: String
+    },
+    /// An immutable static, read through a generated accessor every time it is accessed.
+    Static {
+        /// The name of the static.
+        name: String,
+        /// The Rust module which defines the item, like `egui::containers::frame`.
+        module: String,
+        /// The Rust path used to read the static.
+        path: String,
+        /// The type of the static.
+        ty: TypeReference,
+        /// The names given to the generated bindings.
+        naming: NamingConfig,
+        /// Whether the item is deprecated, and why.
+        deprecation: Option<Deprecation>,
+        /// The Rust item which the bindings were generated from, if any.
+        origin: Option<Origin>,
+        /// The doc-comment to include.
+        docs: String
+    },
+    /// A free function, called through a generated shim.
+    Function {
+        /// The name of the function.
+        name: String,
+        /// The Rust module which defines the item, like `egui::containers::frame`.
+        module: String,
+        /// The Rust path used to call the function.
+        path: String,
+        /// The parameters of the function.
+        params: Vec<Parameter>,
+        /// The return type of the function, or [`None`] if it returns nothing.
+        ret: Option<TypeReference>,
+        /// The names given to the generated bindings.
+        naming: NamingConfig,
+        /// Whether the item is deprecated, and why.
+        deprecation: Option<Deprecation>,
+        /// The Rust item which the bindings were generated from, if any.
+        origin: Option<Origin>,
+        /// The doc-comment to include.
+        docs: String
+    },
+    /// A callback type, which Rust calls through a function pointer along with an opaque user-data pointer.
+    Delegate {
+        /// The name of the delegate.
+        name: String,
+        /// The Rust module which defines the item, like `egui::containers::frame`.
+        module: String,
+        /// The parameters of the callback, which precede the user data.
+        params: Vec<Parameter>,
+        /// The return type of the callback, or [`None`] if it returns nothing.
+        ret: Option<TypeReference>,
+        /// The names given to the generated bindings.
+        naming: NamingConfig,
+        /// Whether the item is deprecated, and why.
+        deprecation: Option<Deprecation>,
+        /// The Rust item which the bindings were generated from, if any.
+        origin: Option<Origin>,
         /// The doc-comment to include.
         docs: String
     }
 }
 
 impl Item {
+    /// Calls the given function on every type referenced by this item.
+    pub fn visit_types(&self, f: &mut impl FnMut(&TypeReference)) {
+        // String conversions return a string, even though no field or parameter mentions it.
+        if let Item::Struct { format: Some(_), .. } | Item::Class { format: Some(_), .. } = self {
+            f(&TypeReference::Primitive(PrimitiveType::String));
+        }
+
+        match self {
+            Item::Struct { fields, .. } => {
+                for field in fields {
+                    field.ty.visit(f);
+                }
+            },
+            Item::Union { variants, .. } => {
+                for field in variants.iter().flat_map(|x| &x.fields) {
+                    field.ty.visit(f);
+                }
+            },
+            Item::Constant { ty, .. } | Item::Static { ty, .. } => ty.visit(f),
+            Item::Function { params, ret, .. } | Item::Delegate { params, ret, .. } => {
+                for param in params {
+                    param.ty.visit(f);
+                }
+                if let Some(ret) = ret {
+                    ret.visit(f);
+                }
+            },
+            Item::Class { constructors, methods, .. } => {
+                for constructor in constructors {
+                    for param in &constructor.params {
+                        param.ty.visit(f);
+                    }
+                }
+
+                for method in methods {
+                    for param in &method.params {
+                        param.ty.visit(f);
+                    }
+                    if let Some(ret) = &method.ret {
+                        ret.visit(f);
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+
+    /// Calls the given function on every type referenced by this item, allowing them to be modified.
+    pub fn visit_types_mut(&mut self, f: &mut impl FnMut(&mut TypeReference)) {
+        match self {
+            Item::Struct { fields, .. } => {
+                for field in fields {
+                    field.ty.visit_mut(f);
+                }
+            },
+            Item::Union { variants, .. } => {
+                for field in variants.iter_mut().flat_map(|x| &mut x.fields) {
+                    field.ty.visit_mut(f);
+                }
+            },
+            Item::Constant { ty, .. } | Item::Static { ty, .. } => ty.visit_mut(f),
+            Item::Function { params, ret, .. } | Item::Delegate { params, ret, .. } => {
+                for param in params {
+                    param.ty.visit_mut(f);
+                }
+                if let Some(ret) = ret {
+                    ret.visit_mut(f);
+                }
+            },
+            Item::Class { constructors, methods, .. } => {
+                for constructor in constructors {
+                    for param in &mut constructor.params {
+                        param.ty.visit_mut(f);
+                    }
+                }
+
+                for method in methods {
+                    for param in &mut method.params {
+                        param.ty.visit_mut(f);
+                    }
+                    if let Some(ret) = &mut method.ret {
+                        ret.visit_mut(f);
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+
     /// Gets the doc-comment associated with this item.
     pub fn docs(&self) -> &str {
         match self {
             Item::Enum { docs, .. } => docs,
+            Item::Union { docs, .. } => docs,
             Item::Class { docs, .. } => docs,
-            Item::Struct { docs, .. } => docs
+            Item::Struct { docs, .. } => docs,
+            Item::Constant { docs, .. } => docs,
+            Item::Static { docs, .. } => docs,
+            Item::Function { docs, .. } => docs,
+            Item::Delegate { docs, .. } => docs
+        }
+    }
+
+    /// Gets the Rust item which this item was generated from.
+    pub fn origin(&self) -> Option<&Origin> {
+        match self {
+            Item::Enum { origin, .. } => origin.as_ref(),
+            Item::Union { origin, .. } => origin.as_ref(),
+            Item::Class { origin, .. } => origin.as_ref(),
+            Item::Struct { origin, .. } => origin.as_ref(),
+            Item::Constant { origin, .. } => origin.as_ref(),
+            Item::Static { origin, .. } => origin.as_ref(),
+            Item::Function { origin, .. } => origin.as_ref(),
+            Item::Delegate { origin, .. } => origin.as_ref()
+        }
+    }
+
+    /// Gets the deprecation status of this item.
+    pub fn deprecation(&self) -> Option<&Deprecation> {
+        match self {
+            Item::Enum { deprecation, .. } => deprecation.as_ref(),
+            Item::Union { deprecation, .. } => deprecation.as_ref(),
+            Item::Class { deprecation, .. } => deprecation.as_ref(),
+            Item::Struct { deprecation, .. } => deprecation.as_ref(),
+            Item::Constant { deprecation, .. } => deprecation.as_ref(),
+            Item::Static { deprecation, .. } => deprecation.as_ref(),
+            Item::Function { deprecation, .. } => deprecation.as_ref(),
+            Item::Delegate { deprecation, .. } => deprecation.as_ref()
+        }
+    }
+
+    /// Calls the given function on the doc-comment of this item and every member within it.
+    pub fn visit_docs_mut(&mut self, f: &mut impl FnMut(&mut String)) {
+        match self {
+            Item::Enum { variants, docs, .. } => {
+                variants.iter_mut().for_each(|x| f(&mut x.docs));
+                f(docs);
+            },
+            Item::Union { variants, docs, .. } => {
+                for variant in variants {
+                    variant.fields.iter_mut().for_each(|x| f(&mut x.docs));
+                    f(&mut variant.docs);
+                }
+                f(docs);
+            },
+            Item::Class { constructors, methods, docs, .. } => {
+                constructors.iter_mut().for_each(|x| f(&mut x.docs));
+                methods.iter_mut().for_each(|x| f(&mut x.docs));
+                f(docs);
+            },
+            Item::Struct { fields, docs, .. } => {
+                fields.iter_mut().for_each(|x| f(&mut x.docs));
+                f(docs);
+            },
+            Item::Constant { docs, .. } | Item::Static { docs, .. } | Item::Function { docs, .. } | Item::Delegate { docs, .. } => f(docs)
         }
     }
 
@@ -160,158 +971,3174 @@ impl Item {
     pub fn name(&self) -> &str {
         match self {
             Item::Enum { name, .. } => name,
+            Item::Union { name, .. } => name,
             Item::Class { name, .. } => name,
-            Item::Struct { name, .. } => name
+            Item::Struct { name, .. } => name,
+            Item::Constant { name, .. } => name,
+            Item::Static { name, .. } => name,
+            Item::Function { name, .. } => name,
+            Item::Delegate { name, .. } => name
+        }
+    }
+
+    /// Gets the Rust module which defines the item.
+    pub fn module(&self) -> &str {
+        match self {
+            Item::Enum { module, .. } => module,
+            Item::Union { module, .. } => module,
+            Item::Class { module, .. } => module,
+            Item::Struct { module, .. } => module,
+            Item::Constant { module, .. } => module,
+            Item::Static { module, .. } => module,
+            Item::Function { module, .. } => module,
+            Item::Delegate { module, .. } => module
         }
     }
 
+    /// Whether any C# is generated for the item. External types already exist in C#, so nothing is
+    /// generated for them unless conversions were requested.
+    pub fn generates_cs(&self) -> bool {
+        !matches!(self, Item::Struct { external: Some(ExternalType { conversions: false, .. }), .. })
+    }
+
     /// Gets the modified type name for the public C# API.
     pub fn cs_name(&self) -> String {
-        self.name().to_string()
+        match self {
+            Item::Constant { name, .. } | Item::Static { name, .. } | Item::Function { name, .. } => cs_identifier(name, Some(Case::Pascal)),
+            Item::Enum { rename: Some(rename), .. } | Item::Union { rename: Some(rename), .. } | Item::Class { rename: Some(rename), .. } | Item::Struct { rename: Some(rename), .. } => cs_identifier(rename, None),
+            _ => cs_identifier(self.name(), None)
+        }
+    }
+
+    /// Gets the name by which other generated C# code refers to this type. This is the
+    /// external type if one replaces this item, or the generated name otherwise.
+    pub fn cs_reference_name(&self) -> String {
+        match self {
+            Item::Struct { external: Some(external), .. } => external.cs_name.clone(),
+            _ => self.cs_name()
+        }
+    }
+
+    /// Gets the names given to the generated bindings for this item.
+    pub fn naming(&self) -> &NamingConfig {
+        match self {
+            Item::Enum { naming, .. } => naming,
+            Item::Union { naming, .. } => naming,
+            Item::Class { naming, .. } => naming,
+            Item::Struct { naming, .. } => naming,
+            Item::Constant { naming, .. } => naming,
+            Item::Static { naming, .. } => naming,
+            Item::Function { naming, .. } => naming,
+            Item::Delegate { naming, .. } => naming
+        }
+    }
+
+    /// Whether this is a flags enum without a zero variant, to which C# adds `None = 0` so that
+    /// an empty set of flags can be written.
+    pub fn has_implicit_none(&self) -> bool {
+        match self {
+            Item::Enum { variants, flags: true, .. } => variants.iter().all(|x| x.index != Some(0)),
+            _ => false
+        }
+    }
+
+    /// Gets the variant which stands in for unknown values of a non-exhaustive enum, or for the variants of
+    /// a union which were left out, if it has one.
+    pub fn unknown_variant(&self) -> Option<EnumVariant> {
+        let (index, docs) = match self {
+            Item::Enum { unknown: Some(index), .. } => (Some(*index), "A variant which was added to the Rust enum after these bindings were generated."),
+            Item::Union { unknown: true, .. } => (None, "A variant whose data cannot be passed across the FFI boundary, or which was added to the Rust enum after these bindings were generated."),
+            _ => return None
+        };
+        Some(EnumVariant {
+            name: "Unknown".to_string(),
+            rename: None,
+            index,
+            deprecation: None,
+            docs: docs.to_string()
+        })
+    }
+
+    /// Gets the name by which the generated Rust refers to the original type, which is its alias if it has one.
+    pub fn rs_type(&self) -> &str {
+        match self {
+            Item::Enum { alias: Some(alias), .. } | Item::Union { alias: Some(alias), .. } | Item::Class { alias: Some(alias), .. } | Item::Struct { alias: Some(alias), .. } => alias,
+            _ => self.name()
+        }
     }
 
     /// Gets the modified type name for C FFI.
     pub fn rs_name(&self) -> String {
-        "Vx".to_string() + self.name()
+        self.naming().type_prefix.clone() + self.rs_type()
+    }
+
+    /// Gets the Rust type which the mirror of this item converts from: the tuple or array type of a positional
+    /// struct, or the original name otherwise.
+    pub fn rs_original(&self) -> &str {
+        match self {
+            Item::Struct { positional: Some(positional), .. } => positional,
+            _ => self.rs_type()
+        }
+    }
+
+    /// Gets the Rust type of the objects of a class, whose lifetimes are all `'static`.
+    pub fn rs_class(&self) -> String {
+        match self {
+            Item::Class { lifetimes, .. } if *lifetimes > 0 => format!("{}<{}>", self.rs_type(), vec!["'static"; *lifetimes].join(", ")),
+            _ => self.rs_type().to_string()
+        }
     }
 
     /// Gets the modified type name that will be inserted before C FFI functions.
     pub fn rs_fn_name(&self) -> String {
-        self.name().to_case(Case::Snake)
+        match self {
+            Item::Constant { name, owner: Some(owner), .. } => format!("{}_{}", owner.to_case(Case::Snake), raw_identifier(name).to_case(Case::Snake)),
+            _ => raw_identifier(self.rs_type()).to_case(Case::Snake)
+        }
     }
 
-    /// Creates the default field for a struct type in C#.
-    fn write_cs_struct_default(&self, f: &mut Formatter) -> Result {
-        write_cs_docs(f, "Returns the \"default value\" for a type.")?;
-        f.write_fmt(format_args!("public static readonly {} Default = ({})Vx.{}_default();\n", self.cs_name(), self.cs_name(), self.rs_fn_name()))?;
-        Ok(())
+    /// Gets the exported symbol of a function belonging to this type, such as its `drop` or `default` function.
+    /// Every function of a type is named `{fn_prefix}{type}_{member}`.
+    pub fn native_fn(&self, member: &str) -> String {
+        self.naming().native_fn(&format!("{}_{member}", self.rs_fn_name()))
     }
 
-    /// Creates the C#-side destructor for this type, assuming that it is a handle.
-    fn write_cs_destructor(&self, f: &mut Formatter) -> Result {
-        f.write_str("/// <inheritdoc/>\n")?;
-        f.write_str("protected override void Free(VxObject* pointer) {\n")?;
-        f.write_fmt(format_args!("    Vx.gui_{}_drop(pointer);\n", self.rs_fn_name()))?;
-        f.write_str("}\n")?;
-        Ok(())
+    /// Gets the C# expression which refers to a function belonging to this type.
+    fn cs_native_fn(&self, member: &str) -> String {
+        self.naming().cs_native_fn(&self.native_fn(member))
     }
 
-    /// Creates the default field initializer for a struct type in Rust.
-    fn write_rs_struct_default(&self, f: &mut Formatter) -> Result {
-        write_rs_docs(f, "Returns the \"default value\" for a type.")?;
-        f.write_str("#[no_mangle]\n")?;
-        f.write_fmt(format_args!("pub fn vx_{}_default() -> {} {{\n", self.rs_fn_name(), self.rs_name()))?;
-        f.write_fmt(format_args!("    let value = {}::default();\n", self.name()))?;
-        f.write_fmt(format_args!("    {} {{\n", self.rs_name()))?;
-
-        let Self::Struct { fields, .. } = self else { panic!("Item was not struct") };
-        for field in fields {
-            f.write_fmt(format_args!("        {}: value.{}.into(),\n", field.rs_name(), field.name))?;
+    /// Gets the exported symbol of the shim for a constant, static, or function.
+    pub fn free_native_fn(&self) -> String {
+        match self {
+            Item::Constant { .. } => self.naming().native_fn(&format!("const_{}", self.rs_fn_name())),
+            Item::Static { .. } => self.naming().native_fn(&format!("static_{}", self.rs_fn_name())),
+            _ => self.naming().native_fn(&self.rs_fn_name())
         }
-
-        f.write_str("    }\n")?;
-        f.write_str("}\n")?;
-        Ok(())
     }
 
-    /// Creates the Rust-side destructor for this type, assuming that it is a handle.
-    fn write_rs_destructor(&self, f: &mut Formatter) -> Result {
-        f.write_str("/// Frees the provided object.\n")?;
-        f.write_str("///\n")?;
-        f.write_str("/// # Safety\n")?;
-        f.write_str("///\n")?;
-        f.write_str("/// For this call to be sound, the pointer must refer to a live object of the corret type.\n");
-        f.write_str("#[no_mangle]\n")?;
-        f.write_fmt(format_args!("pub unsafe extern \"C\" fn vx_gui_{}_drop(value: *mut VxObject<{}>) {{\n)",
-            self.rs_fn_name(), self.name()))?;
-        f.write_str("    VxHandle::from_heap(value);\n")?;
-        f.write_str("}}\n")?;
+    /// Creates the default field for a struct type in C#.
+    fn write_cs_struct_default(&self, f: &mut Formatter) -> Result {
+        write_cs_docs(f, "Returns the \"default value\" for a type.")?;
+        f.write_fmt(format_args!("public static readonly {} Default = ({})EguiException.Check({}());\n", self.cs_name(), self.cs_name(), self.cs_native_fn("default")))?;
         Ok(())
     }
-}
 
-impl DisplayBindings for Item {
-    fn write_cs(&self, f: &mut Formatter) -> Result {
-        write_cs_docs(f, self.docs())?;
+    /// Gets the Rust functions which the C# bindings for this item call, with their C# signatures.
+    pub fn native_functions(&self) -> Vec<NativeFunction> {
+        let object = || TypeReference::Handle { class: self.rs_class(), mutable: true };
+        let string = || TypeReference::Primitive(PrimitiveType::String);
+        let mut result = Vec::new();
         match self {
-            Item::Enum { variants, .. } => {
-                f.write_fmt(format_args!("public enum {} {{\n", self.cs_name()))?;
+            Item::Enum { .. } => result.push(NativeFunction::new(self.native_fn("is_valid"), [("value".to_string(), TypeReference::Primitive(PrimitiveType::I64))], Some(TypeReference::Primitive(PrimitiveType::Bool)))),
+            Item::Class { constructors, methods, has_default, cloneable, format, .. } => {
+                result.push(NativeFunction::new(self.native_fn("drop"), [("value".to_string(), object())], None));
+                if *cloneable {
+                    result.push(NativeFunction::new(self.native_fn("clone"), [("self".to_string(), object())], Some(object())));
+                }
 
-                let mut members = String::new();
-                for variant in variants {
-                    write!(&mut members, "{}\n", DisplayCs(variant))?;
+                if format.is_some() {
+                    result.push(NativeFunction::new(self.native_fn("to_string"), [("self".to_string(), object())], Some(string())));
+                }
+
+                if *has_default {
+                    result.push(NativeFunction::new(self.native_fn("default"), [], Some(object())));
+                }
+
+                for constructor in constructors {
+                    let params = constructor.params.iter().flat_map(Parameter::native_params);
+                    result.push(NativeFunction::new(self.native_fn(&constructor.rs_fn_name()), params, Some(object())));
+                }
+
+                for method in methods {
+                    let params = std::iter::once(("self".to_string(), object())).chain(method.params.iter().flat_map(Parameter::native_params));
+                    result.push(NativeFunction::new(self.native_fn(&method.rs_fn_name()), params, method.ret.clone()));
+                }
+            },
+            Item::Struct { has_default, operators, format, owns_strings, .. } => {
+                let this = TypeReference::Named { rs_name: self.rs_name(), cs_name: self.cs_reference_name() };
+                if *has_default {
+                    result.push(NativeFunction::new(self.native_fn("default"), [], Some(this.clone())));
+                }
+
+                if !self.is_field_wise() {
+                    for operator in operators {
+                        let params = match operator {
+                            OperatorKind::Neg => vec![("value".to_string(), this.clone())],
+                            OperatorKind::MulScalar(scalar) | OperatorKind::DivScalar(scalar) => vec![("left".to_string(), this.clone()), ("right".to_string(), TypeReference::Primitive(scalar.clone()))],
+                            _ => vec![("left".to_string(), this.clone()), ("right".to_string(), this.clone())]
+                        };
+                        result.push(NativeFunction::new(self.native_fn(operator.rs_fn_name()), params, Some(this.clone())));
+                    }
+                }
+
+                if format.is_some() {
+                    result.push(NativeFunction::new(self.native_fn("to_string"), [("value".to_string(), this.clone())], Some(string())));
+                }
+
+                if *owns_strings {
+                    result.push(NativeFunction::new(self.native_fn("drop"), [("value".to_string(), this)], None));
+                }
+            },
+            Item::Union { owns_strings: true, .. } => {
+                let this = TypeReference::Named { rs_name: self.rs_name(), cs_name: self.cs_reference_name() };
+                result.push(NativeFunction::new(self.native_fn("drop"), [("value".to_string(), this)], None));
+            },
+            Item::Constant { ty, .. } | Item::Static { ty, .. } => result.push(NativeFunction::new(self.free_native_fn(), [], Some(ty.clone()))),
+            Item::Function { params, ret, .. } => {
+                let params = params.iter().flat_map(Parameter::native_params);
+                result.push(NativeFunction::new(self.free_native_fn(), params, ret.clone()));
+            },
+            Item::Union { .. } | Item::Delegate { .. } => {}
+        }
+        result
+    }
+
+    /// Creates the field-wise equality members for a struct type in C#, given its fields.
+    fn write_cs_struct_equality(&self, f: &mut Formatter, fields: &[StructField]) -> Result {
+        let this = fields.iter().flat_map(|x| x.cs_values("")).collect::<Vec<_>>();
+        let other = fields.iter().flat_map(|x| x.cs_values("other.")).collect::<Vec<_>>();
+        let comparison = this.iter().zip(&other).map(|(a, b)| format!("{a}.Equals({b})")).collect::<Vec<_>>().join(" && ");
+
+        f.write_str("/// <inheritdoc/>\n")?;
+        f.write_fmt(format_args!("public bool Equals({} other) => {comparison};\n\n", self.cs_name()))?;
+        f.write_str("/// <inheritdoc/>\n")?;
+        f.write_fmt(format_args!("public override bool Equals(object obj) => obj is {} other && Equals(other);\n\n", self.cs_name()))?;
+        f.write_str("/// <inheritdoc/>\n")?;
+        f.write_str("public override int GetHashCode() {\n")?;
+        f.write_str("    var hash = new HashCode();\n")?;
+        for value in &this {
+            f.write_fmt(format_args!("    hash.Add({value});\n"))?;
+        }
+        f.write_str("    return hash.ToHashCode();\n")?;
+        f.write_str("}\n\n")?;
+        f.write_fmt(format_args!("public static bool operator ==({} left, {} right) => left.Equals(right);\n\n", self.cs_name(), self.cs_name()))?;
+        f.write_fmt(format_args!("public static bool operator !=({} left, {} right) => !left.Equals(right);\n", self.cs_name(), self.cs_name()))?;
+        Ok(())
+    }
+
+    /// Whether this is a struct made only of numeric primitives, such that arithmetic operators can be
+    /// assumed to apply to each field independently.
+    fn is_field_wise(&self) -> bool {
+        let Self::Struct { fields, .. } = self else { return false };
+        fields.iter().all(|x| matches!(&x.ty, TypeReference::Primitive(primitive) if primitive.is_numeric()))
+    }
+
+    /// Creates an operator overload for a struct type in C#. Operators on purely numeric structs are
+    /// computed field-wise, while others call into Rust.
+    fn write_cs_operator(&self, f: &mut Formatter, fields: &[StructField], operator: &OperatorKind, ctx: &RenderContext) -> Result {
+        let name = self.cs_name();
+        let signature = match operator {
+            OperatorKind::Neg => format!("{name} value"),
+            OperatorKind::MulScalar(scalar) | OperatorKind::DivScalar(scalar) => format!("{name} left, {} right", DisplayCs(scalar, ctx)),
+            _ => format!("{name} left, {name} right")
+        };
+
+        f.write_fmt(format_args!("public static {name} operator {}({signature}) => ", operator.cs_symbol()))?;
+        if self.is_field_wise() {
+            let values = fields.iter().map(|field| {
+                let field_name = field.cs_name();
+                let value = match operator {
+                    OperatorKind::Neg => format!("-value.{field_name}"),
+                    OperatorKind::MulScalar(_) | OperatorKind::DivScalar(_) => format!("left.{field_name} {} right", operator.cs_symbol()),
+                    _ => format!("left.{field_name} {} right.{field_name}", operator.cs_symbol())
+                };
+                format!("{field_name} = ({})({value})", DisplayCs(&field.ty, ctx))
+            }).collect::<Vec<_>>();
+            f.write_fmt(format_args!("new {name} {{ {} }};\n", values.join(", ")))?;
+        }
+        else if *operator == OperatorKind::Neg {
+            f.write_fmt(format_args!("EguiException.Check({}(value));\n", self.cs_native_fn(operator.rs_fn_name())))?;
+        }
+        else {
+            f.write_fmt(format_args!("EguiException.Check({}(left, right));\n", self.cs_native_fn(operator.rs_fn_name())))?;
+        }
+        Ok(())
+    }
+
+    /// Creates the Rust-side shim for an operator of a struct type.
+    fn write_rs_operator(&self, f: &mut Formatter, operator: &OperatorKind, ctx: &RenderContext) -> Result {
+        f.write_str("#[no_mangle]\n")?;
+        match operator {
+            OperatorKind::Neg => {
+                f.write_fmt(format_args!("pub extern \"C\" fn {}(value: {}) -> MaybeUninit<{}> {{\n", self.native_fn(operator.rs_fn_name()), self.rs_name(), self.rs_name()))?;
+                write_rs_shim_body(f, &[format!("let value: {} = value.into();", self.rs_type()), "(-value).into()".to_string()], true)?;
+            },
+            OperatorKind::MulScalar(scalar) | OperatorKind::DivScalar(scalar) => {
+                f.write_fmt(format_args!("pub extern \"C\" fn {}(left: {}, right: {}) -> MaybeUninit<{}> {{\n", self.native_fn(operator.rs_fn_name()), self.rs_name(), DisplayRs(scalar, ctx), self.rs_name()))?;
+                write_rs_shim_body(f, &[format!("let left: {} = left.into();", self.rs_type()), format!("(left {} right).into()", operator.cs_symbol())], true)?;
+            },
+            _ => {
+                f.write_fmt(format_args!("pub extern \"C\" fn {}(left: {}, right: {}) -> MaybeUninit<{}> {{\n", self.native_fn(operator.rs_fn_name()), self.rs_name(), self.rs_name(), self.rs_name()))?;
+                write_rs_shim_body(f, &[
+                    format!("let left: {} = left.into();", self.rs_type()),
+                    format!("let right: {} = right.into();", self.rs_type()),
+                    format!("(left {} right).into()", operator.cs_symbol())
+                ], true)?;
+            }
+        }
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Creates the C#-side string conversion, which frees the Rust string once it has been copied.
+    /// The `value` is the expression passed to Rust, such as `this` for structs or `Pointer` for classes.
+    fn write_cs_to_string(&self, f: &mut Formatter, value: &str) -> Result {
+        f.write_str("/// <inheritdoc/>\n")?;
+        f.write_fmt(format_args!("public override string ToString() => VxString.Take(EguiException.Check({}({value})));\n", self.cs_native_fn("to_string")))?;
+        Ok(())
+    }
+
+    /// Creates the Rust-side string conversion for a struct or class.
+    fn write_rs_to_string(&self, f: &mut Formatter, format: &FormatTrait) -> Result {
+        write_rs_docs(f, &format!("Formats the value using its [`{}`] implementation.", format.rs_name()))?;
+        if let Self::Class { .. } = self {
+            f.write_str("///\n")?;
+            f.write_str("/// # Safety\n")?;
+            f.write_str("///\n")?;
+            f.write_str("/// For this call to be sound, the pointer must be null or refer to a live object of a generated class.\n")?;
+            f.write_str("#[no_mangle]\n")?;
+            f.write_fmt(format_args!("pub unsafe extern \"C\" fn {}(this: *const VxObject<{}>) -> MaybeUninit<VxString> {{\n", self.native_fn("to_string"), self.rs_class()))?;
+            write_rs_shim_body(f, &[format!("format!(\"{}\", *VxObject::get(this)).into()", format.rs_specifier())], true)?;
+        }
+        else {
+            f.write_str("#[no_mangle]\n")?;
+            f.write_fmt(format_args!("pub extern \"C\" fn {}(value: {}) -> MaybeUninit<VxString> {{\n", self.native_fn("to_string"), self.rs_name()))?;
+            write_rs_shim_body(f, &[format!("let value: {} = value.into();", self.rs_type()), format!("format!(\"{}\", value).into()", format.rs_specifier())], true)?;
+        }
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Creates the C#-side function which frees the strings held by a struct or union returned from Rust.
+    fn write_cs_free(&self, f: &mut Formatter) -> Result {
+        write_cs_docs(f, "Frees the strings held by a value which was returned from Rust. The value must not be used afterwards.")?;
+        f.write_fmt(format_args!("public static void Free({} value) {{ {}(value); EguiException.ThrowIfPanicked(); }}\n", self.cs_name(), self.cs_native_fn("drop")))?;
+        Ok(())
+    }
+
+    /// Creates the Rust-side function which frees the strings held by a struct or union, by converting it back.
+    fn write_rs_free(&self, f: &mut Formatter) -> Result {
+        write_rs_docs(f, &format!("Frees a `{}` returned to C#, along with the strings it holds.", self.rs_name()))?;
+        f.write_str("///\n")?;
+        f.write_str("/// # Safety\n")?;
+        f.write_str("///\n")?;
+        f.write_str("/// For this call to be sound, the strings must have been allocated by Rust and not already freed.\n")?;
+        f.write_str("#[no_mangle]\n")?;
+        f.write_fmt(format_args!("pub unsafe extern \"C\" fn {}(value: {}) {{\n", self.native_fn("drop"), self.rs_name()))?;
+        write_rs_shim_body(f, &[format!("drop({}::from(value))", self.rs_original())], false)?;
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Creates the C#-side method which duplicates an object, assuming that this is a class.
+    fn write_cs_clone(&self, f: &mut Formatter) -> Result {
+        write_cs_docs(f, "Creates a new object with a copy of this object's contents.")?;
+        f.write_fmt(format_args!("public {} Clone() => new {}((VxObject*)EguiException.Check({}(Pointer)));\n", self.cs_name(), self.cs_name(), self.cs_native_fn("clone")))?;
+        Ok(())
+    }
+
+    /// Creates the Rust-side function which duplicates an object, assuming that this is a class.
+    fn write_rs_clone(&self, f: &mut Formatter) -> Result {
+        f.write_str("/// Creates a new object with a copy of the provided object's contents.\n")?;
+        f.write_str("///\n")?;
+        f.write_str("/// # Safety\n")?;
+        f.write_str("///\n")?;
+        f.write_str("/// For this call to be sound, the pointer must be null or refer to a live object of a generated class.\n")?;
+        f.write_str("#[no_mangle]\n")?;
+        f.write_fmt(format_args!("pub unsafe extern \"C\" fn {}(this: *const VxObject<{}>) -> MaybeUninit<*mut VxObject<{}>> {{\n", self.native_fn("clone"), self.rs_class(), self.rs_class()))?;
+        write_rs_shim_body(f, &["VxHandle::into_heap(VxObject::get(this).clone())".to_string()], true)?;
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Creates the default property for a class type in C#.
+    fn write_cs_class_default(&self, f: &mut Formatter) -> Result {
+        write_cs_docs(f, "Creates a new object with the \"default value\" for the type.")?;
+        f.write_fmt(format_args!("public static {} Default => new {}((VxObject*)EguiException.Check({}()));\n", self.cs_name(), self.cs_name(), self.cs_native_fn("default")))?;
+        Ok(())
+    }
+
+    /// Creates the C#-side destructor for this type, assuming that it is a handle.
+    fn write_cs_destructor(&self, f: &mut Formatter) -> Result {
+        f.write_str("/// <inheritdoc/>\n")?;
+        f.write_str("protected override void Free(VxObject* pointer) {\n")?;
+        f.write_fmt(format_args!("    {}(pointer);\n", self.cs_native_fn("drop")))?;
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Creates the default field initializer for a struct type in Rust, given its fields.
+    fn write_rs_struct_default(&self, f: &mut Formatter) -> Result {
+        write_rs_docs(f, "Returns the \"default value\" for a type.")?;
+        f.write_str("#[no_mangle]\n")?;
+        f.write_fmt(format_args!("pub extern \"C\" fn {}() -> MaybeUninit<{}> {{\n", self.native_fn("default"), self.rs_name()))?;
+        write_rs_shim_body(f, &[format!("{}::default().into()", self.rs_type())], true)?;
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Creates the conversions between a Rust struct and its mirror, which convert each field in turn.
+    /// Arrays and options are converted element-wise, and positional structs are built from and into their
+    /// tuple or array.
+    fn write_rs_struct_conversions(&self, f: &mut Formatter, fields: &[StructField], ctx: &RenderContext) -> Result {
+        let name = self.rs_original();
+        let rs_name = self.rs_name();
+        let into_mirror = |field: &StructField, value: &str| match &field.ty {
+            TypeReference::Array { .. } => format!("{value}.map(Into::into)"),
+            TypeReference::Optional(_) => format!("{value}.map(Into::into).into()"),
+            _ => format!("{value}.into()")
+        };
+        let from_mirror = |field: &StructField, value: &str| match &field.ty {
+            TypeReference::Array { .. } => format!("{value}.map(Into::into)"),
+            TypeReference::Optional(inner) => format!("Option::<{}>::from({value}).map(Into::into)", DisplayRs(&**inner, ctx)),
+            TypeReference::Primitive(PrimitiveType::String) => format!("unsafe {{ {value}.into_string() }}"),
+            _ => format!("{value}.into()")
+        };
+
+        f.write_fmt(format_args!("impl From<{name}> for {rs_name} {{\n"))?;
+        f.write_fmt(format_args!("    fn from(value: {name}) -> Self {{\n"))?;
+        f.write_str("        Self {\n")?;
+        for (index, field) in fields.iter().enumerate() {
+            f.write_fmt(format_args!("            {}: {},\n", field.rs_name(), into_mirror(field, &self.rs_original_field(index, field))))?;
+        }
+        f.write_str("        }\n")?;
+        f.write_str("    }\n")?;
+        f.write_str("}\n\n")?;
+
+        f.write_fmt(format_args!("impl From<{rs_name}> for {name} {{\n"))?;
+        f.write_fmt(format_args!("    fn from(value: {rs_name}) -> Self {{\n"))?;
+        if name.starts_with('(') || name.starts_with('[') {
+            let elements = fields.iter().map(|x| from_mirror(x, &format!("value.{}", x.rs_name()))).collect::<Vec<_>>().join(", ");
+            if name.starts_with('[') {
+                f.write_fmt(format_args!("        [{elements}]\n"))?;
+            }
+            else {
+                f.write_fmt(format_args!("        ({elements},)\n"))?;
+            }
+        }
+        else {
+            f.write_str("        Self {\n")?;
+            for field in fields {
+                f.write_fmt(format_args!("            {}: {},\n", field.name, from_mirror(field, &format!("value.{}", field.rs_name()))))?;
+            }
+            if let Item::Struct { defaulted: true, .. } = self {
+                f.write_str("            ..Default::default()\n")?;
+            }
+            f.write_str("        }\n")?;
+        }
+        f.write_str("    }\n")?;
+        f.write_str("}\n")
+    }
+
+    /// Creates the conversion from a Rust struct to its mirror when the mirror is a view, which borrows the
+    /// vectors of the struct rather than taking them. Views cannot be converted back.
+    fn write_rs_view_conversion(&self, f: &mut Formatter, fields: &[StructField], ctx: &RenderContext) -> Result {
+        f.write_fmt(format_args!("impl From<&{}> for {} {{\n", self.rs_original(), self.rs_name()))?;
+        f.write_fmt(format_args!("    fn from(value: &{}) -> Self {{\n", self.rs_original()))?;
+        f.write_str("        Self {\n")?;
+        for (index, field) in fields.iter().enumerate() {
+            f.write_fmt(format_args!("            {}: {},\n", field.rs_name(), field.rs_view_value(&self.rs_original_field(index, field), ctx)))?;
+        }
+        f.write_str("        }\n")?;
+        f.write_str("    }\n")?;
+        f.write_str("}\n")
+    }
+
+    /// Gets the Rust expression which refers to the field at the given index of the original struct, called
+    /// `value`. The fields of positional structs are the elements of their tuple or array.
+    fn rs_original_field(&self, index: usize, field: &StructField) -> String {
+        match self {
+            Item::Struct { positional: Some(positional), .. } if positional.starts_with('[') => format!("value[{index}]"),
+            Item::Struct { positional: Some(_), .. } => format!("value.{index}"),
+            _ => format!("value.{}", field.name)
+        }
+    }
+
+    /// Creates the function which allocates a default object for a class type in Rust.
+    fn write_rs_class_default(&self, f: &mut Formatter) -> Result {
+        write_rs_docs(f, "Creates a new object with the \"default value\" for the type.")?;
+        f.write_str("#[no_mangle]\n")?;
+        f.write_fmt(format_args!("pub extern \"C\" fn {}() -> MaybeUninit<*mut VxObject<{}>> {{\n", self.native_fn("default"), self.rs_class()))?;
+        write_rs_shim_body(f, &[format!("VxHandle::into_heap({}::default())", self.rs_type())], true)?;
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Creates the conversions between a Rust enum and its mirror, which map each variant to the one of the
+    /// same name. Variants added to a non-exhaustive enum after the bindings were generated become the
+    /// `Unknown` variant if there is one, and panic otherwise.
+    fn write_rs_enum_conversions(&self, f: &mut Formatter, variants: &[EnumVariant], non_exhaustive: bool) -> Result {
+        let name = self.rs_type();
+        let rs_name = self.rs_name();
+        f.write_fmt(format_args!("impl From<{name}> for {rs_name} {{\n"))?;
+        f.write_fmt(format_args!("    fn from(value: {name}) -> Self {{\n"))?;
+        f.write_str("        match value {\n")?;
+        for variant in variants {
+            f.write_fmt(format_args!("            {name}::{0} => Self::{0},\n", variant.name))?;
+        }
+        if self.unknown_variant().is_some() {
+            f.write_str("            _ => Self::Unknown,\n")?;
+        }
+        else if non_exhaustive {
+            f.write_fmt(format_args!("            _ => panic!(\"`{name}` has a variant which did not exist when the bindings were generated\"),\n"))?;
+        }
+        f.write_str("        }\n")?;
+        f.write_str("    }\n")?;
+        f.write_str("}\n\n")?;
+
+        f.write_fmt(format_args!("impl From<{rs_name}> for {name} {{\n"))?;
+        f.write_fmt(format_args!("    fn from(value: {rs_name}) -> Self {{\n"))?;
+        f.write_str("        match value {\n")?;
+        for variant in variants {
+            f.write_fmt(format_args!("            {rs_name}::{0} => Self::{0},\n", variant.name))?;
+        }
+        if self.unknown_variant().is_some() {
+            f.write_fmt(format_args!("            {rs_name}::Unknown => panic!(\"An unknown variant cannot be converted to `{name}`\"),\n"))?;
+        }
+        f.write_str("        }\n")?;
+        f.write_str("    }\n")?;
+        f.write_str("}\n")
+    }
+
+    /// Creates the conversions between a Rust enum which carries data and its mirror, which map each variant
+    /// to the one of the same name and convert its fields in turn. Variants which were left out become the
+    /// `Unknown` variant, which cannot be converted back. Views are converted from a reference, and never back.
+    fn write_rs_union_conversions(&self, f: &mut Formatter, variants: &[UnionVariant], non_exhaustive: bool, view: bool, ctx: &RenderContext) -> Result {
+        let name = self.rs_type();
+        let rs_name = self.rs_name();
+        let into_mirror = |field: &StructField| match &field.ty {
+            _ if view => field.rs_view_value(&format!("(*{})", field.rs_name()), ctx),
+            TypeReference::Array { .. } => format!("{}.map(Into::into)", field.rs_name()),
+            TypeReference::Optional(_) => format!("{}.map(Into::into).into()", field.rs_name()),
+            _ => format!("{}.into()", field.rs_name())
+        };
+        let from_mirror = |field: &StructField| match &field.ty {
+            TypeReference::Array { .. } => format!("{}.map(Into::into)", field.rs_name()),
+            TypeReference::Optional(inner) => format!("Option::<{}>::from({}).map(Into::into)", DisplayRs(&**inner, ctx), field.rs_name()),
+            // The mirror owns its strings, which are taken rather than copied.
+            TypeReference::Primitive(PrimitiveType::String) => format!("unsafe {{ {}.into_string() }}", field.rs_name()),
+            _ => format!("{}.into()", field.rs_name())
+        };
+
+        let borrow = if view { "&" } else { "" };
+        f.write_fmt(format_args!("impl From<{borrow}{name}> for {rs_name} {{\n"))?;
+        f.write_fmt(format_args!("    fn from(value: {borrow}{name}) -> Self {{\n"))?;
+        f.write_str("        match value {\n")?;
+        for variant in variants {
+            f.write_fmt(format_args!("            {} => {},\n", variant.rs_pattern(name, StructField::rs_name), variant.rs_pattern("Self", into_mirror)))?;
+        }
+        if self.unknown_variant().is_some() {
+            f.write_str("            _ => Self::Unknown,\n")?;
+        }
+        else if non_exhaustive {
+            f.write_fmt(format_args!("            _ => panic!(\"`{name}` has a variant which did not exist when the bindings were generated\"),\n"))?;
+        }
+        f.write_str("        }\n")?;
+        f.write_str("    }\n")?;
+        if view {
+            return f.write_str("}\n");
+        }
+        f.write_str("}\n\n")?;
+
+        f.write_fmt(format_args!("impl From<{rs_name}> for {name} {{\n"))?;
+        f.write_fmt(format_args!("    fn from(value: {rs_name}) -> Self {{\n"))?;
+        f.write_str("        match value {\n")?;
+        for variant in variants {
+            f.write_fmt(format_args!("            {} => {},\n", variant.rs_pattern(&rs_name, StructField::rs_name), variant.rs_pattern("Self", from_mirror)))?;
+        }
+        if self.unknown_variant().is_some() {
+            f.write_fmt(format_args!("            {rs_name}::Unknown => panic!(\"An unknown variant cannot be converted to `{name}`\"),\n"))?;
+        }
+        f.write_str("        }\n")?;
+        f.write_str("    }\n")?;
+        f.write_str("}\n")
+    }
+
+    /// Creates the C# types which make up a union besides the union itself: the enum of its tags, the
+    /// overlapping fields of its payload, and a struct holding the data of each variant.
+    fn write_cs_union_types(&self, f: &mut Formatter, variants: &[UnionVariant], ctx: &RenderContext) -> Result {
+        let base = self.cs_name().trim_start_matches('@').to_string();
+        write_cs_docs(f, &format!("The variants of `{base}`."))?;
+        f.write_fmt(format_args!("public enum {base}Tag : uint {{\n"))?;
+        write_indented(f, |f| {
+            for variant in variants {
+                f.write_fmt(format_args!("{}\n", DisplayCs(variant, ctx)))?;
+            }
+            if let Some(variant) = self.unknown_variant() {
+                f.write_fmt(format_args!("{}\n", DisplayCs(&variant, ctx)))?;
+            }
+            Ok(())
+        })?;
+        f.write_str("}\n\n")?;
+
+        let variants = variants.iter().filter(|x| !x.fields.is_empty()).collect::<Vec<_>>();
+        write_cs_docs(f, &format!("The data of each variant of `{base}`, of which only the one named by its tag is set."))?;
+        f.write_str("[StructLayout(LayoutKind.Explicit)]\n")?;
+        f.write_fmt(format_args!("public unsafe struct {base}Payload {{\n"))?;
+        write_indented(f, |f| {
+            for variant in &variants {
+                f.write_fmt(format_args!("[FieldOffset(0)]\npublic {base}{} {};\n", variant.cs_name().trim_start_matches('@'), variant.cs_name()))?;
+            }
+            Ok(())
+        })?;
+        f.write_str("}\n")?;
+
+        for variant in variants {
+            let variant_name = format!("{base}{}", variant.cs_name().trim_start_matches('@'));
+            f.write_str("\n")?;
+            write_cs_docs(f, &format!("The data of the `{}` variant of `{base}`.", variant.cs_name()))?;
+            f.write_str("[StructLayout(LayoutKind.Sequential)]\n")?;
+            f.write_fmt(format_args!("public unsafe struct {variant_name} {{\n"))?;
+            write_indented(f, |f| {
+                for field in &variant.fields {
+                    f.write_fmt(format_args!("{}\n", DisplayCs(field, ctx)))?;
+                }
+
+                // Arrays cannot be passed as a single value, so variants which hold them have no constructor.
+                let params = variant.fields.iter().map(|x| Some(format!("{} {}", x.cs_param_type(ctx)?, x.cs_param_name()))).collect::<Option<Vec<_>>>();
+                if let Some(params) = params.filter(|_| !matches!(self, Item::Union { view: true, .. })) {
+                    f.write_str("\n")?;
+                    write_cs_docs(f, "Creates the data of the variant from its fields.")?;
+                    f.write_fmt(format_args!("public {variant_name}({}) {{\n    this = default;\n", params.join(", ")))?;
+                    for field in &variant.fields {
+                        f.write_fmt(format_args!("    {}\n", field.cs_initializer(ctx)))?;
+                    }
+                    f.write_str("}\n")?;
+                }
+                Ok(())
+            })?;
+            f.write_str("}\n")?;
+        }
+        Ok(())
+    }
+
+    /// Creates the static factory which constructs the given variant of a union in C#.
+    fn write_cs_union_factory(&self, f: &mut Formatter, variant: &UnionVariant, ctx: &RenderContext) -> Result {
+        let cs_name = self.cs_name();
+        let base = cs_name.trim_start_matches('@');
+        write_cs_docs(f, &variant.docs)?;
+        write_cs_deprecation(f, variant.deprecation.as_ref(), ctx)?;
+        if variant.fields.is_empty() {
+            return f.write_fmt(format_args!("public static {cs_name} {} => new {cs_name} {{ Tag = {base}Tag.{} }};\n", variant.cs_name(), variant.cs_name()));
+        }
+
+        let params = variant.fields.iter().filter_map(|x| Some(format!("{} {}", x.cs_param_type(ctx)?, x.cs_param_name()))).collect::<Vec<_>>();
+        let arguments = variant.fields.iter().map(StructField::cs_param_name).collect::<Vec<_>>();
+        let payload = format!("new {base}Payload {{ {} = new {base}{}({}) }}", variant.cs_name(), variant.cs_name().trim_start_matches('@'), arguments.join(", "));
+        f.write_fmt(format_args!("public static {cs_name} {}({}) => new {cs_name} {{ Tag = {base}Tag.{}, Payload = {payload} }};\n", variant.cs_name(), params.join(", "), variant.cs_name()))
+    }
+
+    /// Creates the conversion from a raw discriminant to the Rust mirror of this enum, and the exported
+    /// function which checks a discriminant with it.
+    fn write_rs_enum_validation(&self, f: &mut Formatter, variants: &[EnumVariant]) -> Result {
+        let rs_name = self.rs_name();
+        f.write_fmt(format_args!("impl TryFrom<i64> for {rs_name} {{\n"))?;
+        f.write_str("    type Error = i64;\n\n")?;
+        f.write_str("    fn try_from(value: i64) -> Result<Self, i64> {\n")?;
+        f.write_str("        match value {\n")?;
+        let mut next = 0;
+        for variant in variants.iter().chain(&self.unknown_variant()) {
+            // Implicit discriminants are one greater than the previous variant.
+            let value = variant.index.unwrap_or(next);
+            next = value + 1;
+
+            // Discriminants of `u64` enums wrap, just as the cast of the parameter does.
+            f.write_fmt(format_args!("            {} => Ok(Self::{}),\n", value as i64, variant.name))?;
+        }
+        f.write_str("            _ => Err(value)\n")?;
+        f.write_str("        }\n")?;
+        f.write_str("    }\n")?;
+        f.write_str("}\n\n")?;
+
+        f.write_fmt(format_args!("/// Whether the value is the discriminant of a variant of [`{rs_name}`].\n"))?;
+        f.write_str("#[no_mangle]\n")?;
+        f.write_fmt(format_args!("pub extern \"C\" fn {}(value: i64) -> bool {{\n", self.native_fn("is_valid")))?;
+        f.write_fmt(format_args!("    {rs_name}::try_from(value).is_ok()\n"))?;
+        f.write_str("}\n")
+    }
+
+    /// Creates the C# extension class which checks whether values of this enum may be passed to Rust.
+    fn write_cs_enum_extensions(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        f.write_str("/// <summary>\n")?;
+        f.write_fmt(format_args!("/// Checks values of <see cref=\"{}\"/> before they are passed to Rust.\n", self.cs_name()))?;
+        f.write_str("/// </summary>\n")?;
+        write_cs_deprecation(f, self.deprecation(), ctx)?;
+        f.write_fmt(format_args!("public static class {}Extensions {{\n", self.cs_name().trim_start_matches('@')))?;
+        f.write_str("    /// <summary>\n")?;
+        f.write_str("    /// Whether the value is a variant of the Rust enum. Passing any other value to Rust panics.\n")?;
+        f.write_str("    /// </summary>\n")?;
+        f.write_fmt(format_args!("    public static bool IsDefined(this {} value) => {}((long)value);\n", self.cs_name(), self.cs_native_fn("is_valid")))?;
+        f.write_str("}\n")
+    }
+
+    /// Creates the Rust-side destructor for this type, assuming that it is a handle.
+    fn write_rs_destructor(&self, f: &mut Formatter) -> Result {
+        f.write_str("/// Frees the provided object.\n")?;
+        f.write_str("///\n")?;
+        f.write_str("/// # Safety\n")?;
+        f.write_str("///\n")?;
+        f.write_str("/// For this call to be sound, the pointer must be null or refer to a live object of a generated class.\n")?;
+        f.write_str("#[no_mangle]\n")?;
+        f.write_fmt(format_args!("pub unsafe extern \"C\" fn {}(value: *mut VxObject<{}>) {{\n", self.native_fn("drop"), self.rs_class()))?;
+        f.write_str("    vx_discard_panic(|| drop(VxHandle::from_heap(value)));\n")?;
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Creates the `SafeHandle` which owns the objects of this class, assuming that it uses one.
+    fn write_cs_safe_handle(&self, f: &mut Formatter) -> Result {
+        let handle = format!("{}Handle", self.cs_name().trim_start_matches('@'));
+        write_cs_docs(f, &format!("Owns a heap-allocated `{}`, freeing it when released.", self.name()))?;
+        f.write_fmt(format_args!("public unsafe sealed class {handle} : Microsoft.Win32.SafeHandles.SafeHandleZeroOrMinusOneIsInvalid {{\n"))?;
+        f.write_str("    /// <summary>\n")?;
+        f.write_str("    /// Creates an invalid handle, to be filled in by marshaling.\n")?;
+        f.write_str("    /// </summary>\n")?;
+        f.write_fmt(format_args!("    public {handle}() : base(true) {{ }}\n\n"))?;
+        f.write_str("    /// <summary>\n")?;
+        f.write_str("    /// Takes ownership of the provided object, or borrows it for the duration of a callback.\n")?;
+        f.write_str("    /// </summary>\n")?;
+        f.write_fmt(format_args!("    internal {handle}(VxObject* pointer, bool owned = true) : base(owned) {{\n"))?;
+        f.write_str("        SetHandle((IntPtr)pointer);\n")?;
+        f.write_str("    }\n\n")?;
+        f.write_str("    /// <inheritdoc/>\n")?;
+        f.write_str("    protected override bool ReleaseHandle() {\n")?;
+        f.write_fmt(format_args!("        {}((VxObject*)handle);\n", self.cs_native_fn("drop")))?;
+        f.write_str("        return true;\n")?;
+        f.write_str("    }\n")?;
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Creates the members of a class which forward ownership to its `SafeHandle`, in place of `VxHandle`.
+    fn write_cs_safe_handle_members(&self, f: &mut Formatter) -> Result {
+        let handle = format!("{}Handle", self.cs_name().trim_start_matches('@'));
+        write_cs_docs(f, "The handle which owns the object.")?;
+        f.write_fmt(format_args!("internal {handle} Handle;\n\n"))?;
+        write_cs_docs(f, "Takes ownership of the provided object, or borrows it for the duration of a callback.")?;
+        f.write_fmt(format_args!("internal {}(VxObject* pointer, bool owned = true) {{\n", self.cs_name()))?;
+        f.write_fmt(format_args!("    Handle = new {handle}(pointer, owned);\n"))?;
+        f.write_str("}\n\n")?;
+        write_cs_docs(f, "Gets the owned object, throwing if it has already been freed or moved.")?;
+        f.write_str("internal VxObject* Pointer => Handle.IsClosed\n")?;
+        f.write_fmt(format_args!("    ? throw new ObjectDisposedException(nameof({}))\n", self.cs_name()))?;
+        f.write_str("    : (VxObject*)Handle.DangerousGetHandle();\n\n")?;
+        write_cs_docs(f, "Gives up ownership of the object, which is being moved into Rust. The handle is closed without freeing the object.")?;
+        f.write_str("internal VxObject* TakePointer() {\n")?;
+        f.write_str("    var pointer = Pointer;\n")?;
+        f.write_str("    Handle.SetHandleAsInvalid();\n")?;
+        f.write_str("    return pointer;\n")?;
+        f.write_str("}\n\n")?;
+        write_cs_docs(f, "Takes ownership of the object returned by a builder method, which consumed the previous one.")?;
+        f.write_str("internal void ReplacePointer(VxObject* pointer) {\n")?;
+        f.write_fmt(format_args!("    Handle = new {handle}(pointer);\n"))?;
+        f.write_str("}\n\n")?;
+        write_cs_docs(f, "Frees the object. Calling this more than once has no effect.")?;
+        f.write_str("public void Dispose() => Handle.Dispose();\n")?;
+        Ok(())
+    }
+
+    /// Creates the C#-side constructor which wraps an existing object, assuming that this is a class.
+    fn write_cs_pointer_constructor(&self, f: &mut Formatter) -> Result {
+        write_cs_docs(f, "Takes ownership of the provided object, or borrows it for the duration of a callback.")?;
+        f.write_fmt(format_args!("internal {}(VxObject* pointer, bool owned = true) : base(pointer, owned) {{ }}\n", self.cs_name()))?;
+        Ok(())
+    }
+
+    /// Creates the C#-side implicit conversions between this struct and the external type which
+    /// replaces it. The two types have the same layout, so values are reinterpreted in place.
+    fn write_cs_external_conversions(&self, f: &mut Formatter, external: &ExternalType) -> Result {
+        write_cs_docs(f, &format!("Reinterprets the value as a `{}`.", external.cs_name))?;
+        f.write_fmt(format_args!("public static implicit operator {}({} value) => *({}*)&value;\n", external.cs_name, self.cs_name(), external.cs_name))?;
+        f.write_str("\n")?;
+        write_cs_docs(f, &format!("Reinterprets a `{}` as this type.", external.cs_name))?;
+        f.write_fmt(format_args!("public static implicit operator {}({} value) => *({}*)&value;\n", self.cs_name(), external.cs_name, self.cs_name()))?;
+        Ok(())
+    }
+
+    /// Creates the C#-side wrapper for a constructor of this class. Functions named `new` become
+    /// C# constructors, while others become static factory methods.
+    fn write_cs_constructor(&self, f: &mut Formatter, constructor: &Constructor, ctx: &RenderContext) -> Result {
+        let signature = constructor.params.iter().map(|x| x.cs_declaration(ctx)).collect::<Vec<_>>().join(", ");
+        let arguments = constructor.params.iter().map(|x| x.ty.cs_to_native(&x.cs_name())).collect::<Vec<_>>().join(", ");
+        let call = format!("(VxObject*)EguiException.Check({}({arguments}))", self.cs_native_fn(&constructor.rs_fn_name()));
+
+        write_origin(f, constructor.origin.as_ref(), ctx)?;
+        write_cs_function_docs(f, &constructor.docs, &constructor.params, false)?;
+        write_cs_deprecation(f, constructor.deprecation.as_ref(), ctx)?;
+        if constructor.name == "new" {
+            f.write_fmt(format_args!("public {}({signature}) : this({call}) {{ }}\n", self.cs_name()))?;
+        }
+        else {
+            f.write_fmt(format_args!("public static {} {}({signature}) => new {}({call});\n", self.cs_name(), constructor.cs_name(), self.cs_name()))?;
+        }
+        Ok(())
+    }
+
+    /// Creates the Rust-side shim for a constructor of this class, which moves the new object to the heap.
+    fn write_rs_constructor(&self, f: &mut Formatter, constructor: &Constructor, ctx: &RenderContext) -> Result {
+        let signature = constructor.params.iter().map(|x| x.rs_declaration(ctx)).collect::<Vec<_>>().join(", ");
+        let arguments = constructor.params.iter().map(|x| x.rs_bound_argument(ctx)).collect::<Vec<_>>().join(", ");
+        let call = match &constructor.call {
+            Some(call) if constructor.params.is_empty() => call.clone(),
+            Some(call) if constructor.params.len() == 1 => format!("match {} {{ {} => {call} }}", constructor.params[0].rs_bound_argument(ctx), constructor.params[0].rs_name()),
+            Some(call) => format!("match ({}) {{ ({}) => {call} }}",
+                constructor.params.iter().map(|x| x.rs_bound_argument(ctx)).collect::<Vec<_>>().join(", "),
+                constructor.params.iter().map(|x| x.rs_name()).collect::<Vec<_>>().join(", ")),
+            None => format!("{}::{}({arguments})", self.rs_type(), constructor.name)
+        };
+
+        write_origin(f, constructor.origin.as_ref(), ctx)?;
+        write_rs_docs(f, &constructor.docs)?;
+        let qualifier = write_rs_handle_safety(f, &constructor.docs, &constructor.params)?;
+        write_rs_deprecation(f, constructor.deprecation.as_ref(), ctx)?;
+        f.write_str("#[no_mangle]\n")?;
+        f.write_fmt(format_args!("pub {qualifier}extern \"C\" fn {}({signature}) -> MaybeUninit<*mut VxObject<{}>> {{\n", self.native_fn(&constructor.rs_fn_name()), self.rs_class()))?;
+        write_rs_shim_body(f, &[format!("VxHandle::into_heap({call})")], true)?;
+        f.write_str("}\n")?;
+        Ok(())
+    }
+
+    /// Whether a method of this class is a builder method, which consumes the object and returns a new one of the
+    /// same class. C# calls to these are chained on the same handle.
+    fn chained(&self, method: &Method) -> bool {
+        method.receiver == Receiver::Value && matches!(&method.ret, Some(TypeReference::Handle { class, .. }) if *class == self.rs_class())
+    }
+
+    /// Creates the C#-side wrapper for a method of this class. Consuming methods take
+    /// ownership of the pointer, which invalidates the handle.
+    fn write_cs_method(&self, f: &mut Formatter, method: &Method, ctx: &RenderContext) -> Result {
+        let ret = method.ret.as_ref().map_or("void".to_string(), |x| DisplayCs(x, ctx).to_string());
+        let signature = method.params.iter().map(|x| x.cs_declaration(ctx)).collect::<Vec<_>>().join(", ");
+        let this = if method.receiver == Receiver::Value { "TakePointer()" } else { "Pointer" };
+        let arguments = std::iter::once(this.to_string()).chain(method.params.iter().map(|x| x.ty.cs_to_native(&x.cs_name()))).collect::<Vec<_>>().join(", ");
+        let call = format!("{}({arguments})", self.cs_native_fn(&method.rs_fn_name()));
+
+        write_origin(f, method.origin.as_ref(), ctx)?;
+        write_cs_function_docs(f, &method.docs, &method.params, method.ret.is_some() && !method.property)?;
+        write_cs_deprecation(f, method.deprecation.as_ref(), ctx)?;
+        if method.property {
+            f.write_fmt(format_args!("public {ret} {}", method.cs_name()))?;
+        }
+        else {
+            f.write_fmt(format_args!("public {ret} {}({signature})", method.cs_name()))?;
+        }
+        write_cs_wrapper_body(f, &method.params, &call, method.ret.as_ref(), self.chained(method), ctx)?;
+        f.write_str("\n")
+    }
+
+    /// Creates the Rust-side shim for a method of this class.
+    fn write_rs_method(&self, f: &mut Formatter, method: &Method, ctx: &RenderContext) -> Result {
+        let this = TypeReference::Handle { class: self.rs_class(), mutable: method.receiver != Receiver::Ref };
+        let signature = std::iter::once(format!("this: {}", DisplayRs(&this, ctx)))
+            .chain(method.params.iter().map(|x| x.rs_declaration(ctx)))
+            .collect::<Vec<_>>().join(", ");
+        let arguments = method.params.iter().map(|x| x.rs_bound_argument(ctx)).collect::<Vec<_>>().join(", ");
+        let receiver = match method.receiver {
+            Receiver::Ref => "VxObject::get(this)",
+            Receiver::RefMut => "VxObject::get_mut(this)",
+            Receiver::Value => "VxHandle::from_heap(this).into_inner()"
+        };
+        let call = match &method.call {
+            // The arguments are bound by a `match`, so that guards like the borrow of the object live until the expression is evaluated.
+            Some(call) => {
+                let receiver = match method.receiver {
+                    Receiver::Ref => format!("&*{receiver}"),
+                    Receiver::RefMut => format!("&mut *{receiver}"),
+                    Receiver::Value => receiver.to_string()
+                };
+                if method.params.is_empty() {
+                    format!("match {receiver} {{ this => {call} }}")
+                }
+                else {
+                    let arguments = method.params.iter().map(|x| x.rs_bound_argument(ctx));
+                    let names = method.params.iter().map(|x| x.rs_name());
+                    format!("match ({}) {{ ({}) => {call} }}",
+                        std::iter::once(receiver).chain(arguments).collect::<Vec<_>>().join(", "),
+                        std::iter::once("this".to_string()).chain(names).collect::<Vec<_>>().join(", "))
+                }
+            },
+            None => format!("{receiver}.{}({arguments})", method.name)
+        };
+
+        write_origin(f, method.origin.as_ref(), ctx)?;
+        write_rs_docs(f, &method.docs)?;
+        if !method.docs.is_empty() {
+            f.write_str("///\n")?;
+        }
+        f.write_str("/// # Safety\n")?;
+        f.write_str("///\n")?;
+        f.write_str("/// For this call to be sound, the pointer must be null or refer to a live object of a generated class.\n")?;
+        write_rs_deprecation(f, method.deprecation.as_ref(), ctx)?;
+        f.write_str("#[no_mangle]\n")?;
+        if let Some(ret) = &method.ret {
+            f.write_fmt(format_args!("pub unsafe extern \"C\" fn {}({signature}) -> MaybeUninit<{}> {{\n", self.native_fn(&method.rs_fn_name()), DisplayRs(ret, ctx)))?;
+            write_rs_shim_body(f, &[ret.rs_to_native(&call, ctx)], true)?;
+        }
+        else {
+            f.write_fmt(format_args!("pub unsafe extern \"C\" fn {}({signature}) {{\n", self.native_fn(&method.rs_fn_name())))?;
+            write_rs_shim_body(f, &[call], false)?;
+        }
+        f.write_str("}\n")?;
+        Ok(())
+    }
+}
+
+impl DisplayBindings for Item {
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        if !self.generates_cs() {
+            return Ok(());
+        }
+
+        // Free functions, constants, and statics are members of a shared class, so they note their origin within it.
+        if !matches!(self, Item::Function { .. } | Item::Constant { .. } | Item::Static { .. }) {
+            write_origin(f, self.origin(), ctx)?;
+        }
+        write_cs_docs(f, self.docs())?;
+        match self {
+            Item::Enum { variants, repr, flags, .. } => {
+                write_cs_deprecation(f, self.deprecation(), ctx)?;
+                if *flags {
+                    f.write_str("[Flags]\n")?;
+                }
+
+                if let Some(repr) = repr {
+                    f.write_fmt(format_args!("public enum {} : {} {{\n", self.cs_name(), DisplayCs(repr, ctx)))?;
+                }
+                else if *flags {
+                    f.write_fmt(format_args!("public enum {} : int {{\n", self.cs_name()))?;
+                }
+                else {
+                    f.write_fmt(format_args!("public enum {} {{\n", self.cs_name()))?;
+                }
+
+                write_indented(f, |f| {
+                    if self.has_implicit_none() {
+                        f.write_str("/// <summary>\n/// No flags are set.\n/// </summary>\nNone = 0,\n")?;
+                    }
+                    for variant in variants.iter().chain(&self.unknown_variant()) {
+                        f.write_fmt(format_args!("{}\n", DisplayCs(variant, ctx)))?;
+                    }
+                    Ok(())
+                })?;
+
+                f.write_str("}\n\n")?;
+                self.write_cs_enum_extensions(f, ctx)?;
+            },
+            Item::Union { variants, view, owns_strings, .. } => {
+                let base = self.cs_name().trim_start_matches('@').to_string();
+                write_cs_deprecation(f, self.deprecation(), ctx)?;
+                f.write_str("[StructLayout(LayoutKind.Sequential)]\n")?;
+                f.write_fmt(format_args!("public unsafe partial struct {} {{\n", self.cs_name()))?;
+
+                write_indented(f, |f| {
+                    write_cs_docs(f, "The variant which this value holds, and so which field of the payload is set.")?;
+                    f.write_fmt(format_args!("public {base}Tag Tag;\n\n"))?;
+                    write_cs_docs(f, "The data of the variant.")?;
+                    f.write_fmt(format_args!("public {base}Payload Payload;\n"))?;
+
+                    // Arrays cannot be passed as a single value, so variants which hold them have no factory. Nor do
+                    // views, which only Rust creates.
+                    for variant in variants.iter().filter(|x| !*view && x.fields.iter().all(|x| x.cs_param_type(ctx).is_some())) {
+                        f.write_str("\n")?;
+                        self.write_cs_union_factory(f, variant, ctx)?;
+                    }
+                    if *owns_strings {
+                        f.write_str("\n")?;
+                        self.write_cs_free(f)?;
+                    }
+                    Ok(())
+                })?;
+                f.write_str("}\n\n")?;
+                self.write_cs_union_types(f, variants, ctx)?;
+            },
+            Item::Class { constructors, methods, has_default, cloneable, format, safe_handle, .. } => {
+                write_cs_deprecation(f, self.deprecation(), ctx)?;
+                if *safe_handle {
+                    f.write_fmt(format_args!("public unsafe sealed class {} : IDisposable {{\n", self.cs_name()))?;
+                    write_indented(f, |f| self.write_cs_safe_handle_members(f))?;
+                }
+                else {
+                    f.write_fmt(format_args!("public unsafe sealed class {} : VxHandle {{\n", self.cs_name()))?;
+
+                    write_indented(f, |f| self.write_cs_pointer_constructor(f))?;
+                    f.write_str("\n")?;
+
+                    write_indented(f, |f| self.write_cs_destructor(f))?;
+                }
+
+                if *has_default {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_class_default(f))?;
+                }
+
+                for constructor in constructors {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_constructor(f, constructor, ctx))?;
+                }
+
+                if *cloneable {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_clone(f))?;
+                }
+
+                for method in methods {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_method(f, method, ctx))?;
+                }
+
+                if format.is_some() {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_to_string(f, "Pointer"))?;
                 }
-                f.write_str(&indent(&members))?;
 
                 f.write_str("}\n")?;
+
+                if *safe_handle {
+                    f.write_str("\n")?;
+                    self.write_cs_safe_handle(f)?;
+                }
+            },
+            Item::Struct { fields, has_default, has_equality, operators, format, external, packing, owns_strings, .. } => {
+                write_cs_deprecation(f, self.deprecation(), ctx)?;
+                if let Some(packing) = packing {
+                    f.write_fmt(format_args!("[StructLayout(LayoutKind.Sequential, Pack = {packing})]\n"))?;
+                }
+                else {
+                    f.write_str("[StructLayout(LayoutKind.Sequential)]\n")?;
+                }
+                if *has_equality {
+                    f.write_fmt(format_args!("public unsafe partial struct {} : IEquatable<{}> {{\n", self.cs_name(), self.cs_name()))?;
+                }
+                else {
+                    f.write_fmt(format_args!("public unsafe partial struct {} {{\n", self.cs_name()))?;
+                }
+                
+                if *has_default {
+                    write_indented(f, |f| self.write_cs_struct_default(f))?;
+                    f.write_str("\n")?;
+                }
+
+                write_indented(f, |f| {
+                    for field in fields {
+                        f.write_fmt(format_args!("{}\n", DisplayCs(field, ctx)))?;
+                    }
+                    Ok(())
+                })?;
+
+                if *has_equality {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_struct_equality(f, fields))?;
+                }
+
+                for operator in operators {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_operator(f, fields, operator, ctx))?;
+                }
+
+                if format.is_some() {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_to_string(f, "this"))?;
+                }
+
+                if let Some(external) = external {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_external_conversions(f, external))?;
+                }
+
+                if *owns_strings {
+                    f.write_str("\n")?;
+                    write_indented(f, |f| self.write_cs_free(f))?;
+                }
+
+                f.write_str("}\n")?;
+            },
+            Item::Constant { owner, ty, .. } => {
+                // Constants are emitted into a partial declaration of their owner, or a shared class if they are free.
+                match owner {
+                    Some(owner) => f.write_fmt(format_args!("public unsafe partial struct {owner} {{\n"))?,
+                    None => f.write_str("public static partial class Constants {\n")?
+                }
+
+                write_indented(f, |f| {
+                    write_origin(f, self.origin(), ctx)?;
+                    write_cs_docs(f, self.docs())?;
+                    write_cs_deprecation(f, self.deprecation(), ctx)?;
+                    let value = ty.cs_from_native(&ty.cs_checked(&format!("{}()", self.naming().cs_native_fn(&self.free_native_fn())), ctx), ctx);
+                    f.write_fmt(format_args!("public static readonly {} {} = {value};", DisplayCs(ty, ctx), self.cs_name()))
+                })?;
+
+                f.write_str("}\n")?;
+            },
+            Item::Function { params, ret, .. } => {
+                f.write_str("public static unsafe partial class Functions {\n")?;
+
+                let ret_ty = ret.as_ref().map_or("void".to_string(), |x| DisplayCs(x, ctx).to_string());
+                let signature = params.iter().map(|x| x.cs_declaration(ctx)).collect::<Vec<_>>().join(", ");
+                let arguments = params.iter().map(|x| x.ty.cs_to_native(&x.cs_name())).collect::<Vec<_>>().join(", ");
+                let call = format!("{}({arguments})", self.naming().cs_native_fn(&self.free_native_fn()));
+
+                write_indented(f, |f| {
+                    write_origin(f, self.origin(), ctx)?;
+                    write_cs_function_docs(f, self.docs(), params, ret.is_some())?;
+                    write_cs_deprecation(f, self.deprecation(), ctx)?;
+                    f.write_fmt(format_args!("public static {ret_ty} {}({signature})", self.cs_name()))?;
+                    write_cs_wrapper_body(f, params, &call, ret.as_ref(), false, ctx)
+                })?;
+
+                f.write_str("}\n")?;
+            },
+            Item::Delegate { params, ret, .. } => {
+                let ret = ret.as_ref().map_or("void".to_string(), |x| x.cs_native_type(ctx));
+                let params = params.iter().map(|x| format!("{} {}", x.ty.cs_native_type(ctx), x.cs_name()))
+                    .chain(std::iter::once("void* userData".to_string()))
+                    .collect::<Vec<_>>().join(", ");
+
+                write_cs_deprecation(f, self.deprecation(), ctx)?;
+                f.write_str("[UnmanagedFunctionPointer(CallingConvention.Cdecl)]\n")?;
+                f.write_fmt(format_args!("public unsafe delegate {ret} {}({params});\n", self.cs_name()))?;
+            },
+            Item::Static { ty, .. } => {
+                f.write_str("public static partial class Statics {\n")?;
+
+                write_indented(f, |f| {
+                    write_origin(f, self.origin(), ctx)?;
+                    write_cs_docs(f, self.docs())?;
+                    write_cs_deprecation(f, self.deprecation(), ctx)?;
+                    let value = ty.cs_from_native(&ty.cs_checked(&format!("{}()", self.naming().cs_native_fn(&self.free_native_fn())), ctx), ctx);
+                    f.write_fmt(format_args!("public static {} {} => {value};", DisplayCs(ty, ctx), self.cs_name()))
+                })?;
+
+                f.write_str("}\n")?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        write_origin(f, self.origin(), ctx)?;
+        if self.rs_type() != self.name() {
+            f.write_fmt(format_args!("use {}::{} as {};\n", self.module(), self.name(), self.rs_type()))?;
+        }
+        match self {
+            Item::Enum { variants, repr, non_exhaustive, .. } => {
+                write_rs_docs(f, self.docs())?;
+                write_rs_deprecation(f, self.deprecation(), ctx)?;
+                f.write_str("#[derive(Copy, Clone)]\n")?;
+                if let Some(repr) = repr {
+                    f.write_fmt(format_args!("#[repr({})]\n", DisplayRs(repr, ctx)))?;
+                }
+                else {
+                    f.write_str("#[repr(C)]\n")?;
+                }
+                f.write_fmt(format_args!("pub enum {} {{\n", self.rs_name()))?;
+                
+                write_indented(f, |f| {
+                    for variant in variants.iter().chain(&self.unknown_variant()) {
+                        f.write_fmt(format_args!("{}\n", DisplayRs(variant, ctx)))?;
+                    }
+                    Ok(())
+                })?;
+
+                f.write_str("}\n\n")?;
+                self.write_rs_enum_conversions(f, variants, *non_exhaustive)?;
+                f.write_str("\n")?;
+                self.write_rs_enum_validation(f, variants)?;
+            },
+            Item::Union { variants, non_exhaustive, view, owns_strings, .. } => {
+                write_rs_docs(f, self.docs())?;
+                write_rs_deprecation(f, self.deprecation(), ctx)?;
+                f.write_str("#[derive(Copy, Clone)]\n")?;
+                f.write_str("#[repr(C, u32)]\n")?;
+                f.write_fmt(format_args!("pub enum {} {{\n", self.rs_name()))?;
+
+                write_indented(f, |f| {
+                    for variant in variants {
+                        f.write_fmt(format_args!("{}\n", DisplayRs(variant, ctx)))?;
+                    }
+                    if let Some(variant) = self.unknown_variant() {
+                        f.write_fmt(format_args!("{}\n", DisplayRs(&variant, ctx)))?;
+                    }
+                    Ok(())
+                })?;
+
+                f.write_str("}\n\n")?;
+                self.write_rs_union_conversions(f, variants, *non_exhaustive, *view, ctx)?;
+                if *owns_strings {
+                    f.write_str("\n")?;
+                    self.write_rs_free(f)?;
+                }
+            },
+            Item::Class { constructors, methods, has_default, cloneable, format, .. } => {
+                self.write_rs_destructor(f)?;
+                if *cloneable {
+                    f.write_str("\n")?;
+                    self.write_rs_clone(f)?;
+                }
+
+                if let Some(format) = format {
+                    f.write_str("\n")?;
+                    self.write_rs_to_string(f, format)?;
+                }
+
+                if *has_default {
+                    f.write_str("\n")?;
+                    self.write_rs_class_default(f)?;
+                }
+
+                for constructor in constructors {
+                    f.write_str("\n")?;
+                    self.write_rs_constructor(f, constructor, ctx)?;
+                }
+
+                for method in methods {
+                    f.write_str("\n")?;
+                    self.write_rs_method(f, method, ctx)?;
+                }
+            },
+            Item::Struct { fields, has_default, operators, format, packing, transparent, conversions, view, owns_strings, .. } => {
+                write_rs_docs(f, self.docs())?;
+                write_rs_deprecation(f, self.deprecation(), ctx)?;
+                f.write_str("#[derive(Copy, Clone)]\n")?;
+                match packing {
+                    _ if *transparent => f.write_str("#[repr(transparent)]\n")?,
+                    Some(1) => f.write_str("#[repr(C, packed)]\n")?,
+                    Some(packing) => f.write_fmt(format_args!("#[repr(C, packed({packing}))]\n"))?,
+                    None => f.write_str("#[repr(C)]\n")?
+                }
+                f.write_fmt(format_args!("pub struct {} {{\n", self.rs_name()))?;
+                
+                write_indented(f, |f| {
+                    for field in fields {
+                        f.write_fmt(format_args!("{}\n", DisplayRs(field, ctx)))?;
+                    }
+                    Ok(())
+                })?;
+
+                f.write_str("}\n\n")?;
+
+                if *view {
+                    self.write_rs_view_conversion(f, fields, ctx)?;
+                    f.write_str("\n")?;
+                }
+                else if *conversions {
+                    self.write_rs_struct_conversions(f, fields, ctx)?;
+                    f.write_str("\n")?;
+                }
+
+                if *has_default {
+                    self.write_rs_struct_default(f)?;
+                    f.write_str("\n")?;
+                }
+
+                if !self.is_field_wise() {
+                    for operator in operators {
+                        self.write_rs_operator(f, operator, ctx)?;
+                        f.write_str("\n")?;
+                    }
+                }
+
+                if let Some(format) = format {
+                    self.write_rs_to_string(f, format)?;
+                    f.write_str("\n")?;
+                }
+
+                if *owns_strings {
+                    self.write_rs_free(f)?;
+                    f.write_str("\n")?;
+                }
+            },
+            Item::Constant { path, ty, .. } => {
+                write_rs_docs(f, self.docs())?;
+                write_rs_deprecation(f, self.deprecation(), ctx)?;
+                f.write_str("#[no_mangle]\n")?;
+                f.write_fmt(format_args!("pub extern \"C\" fn {}() -> MaybeUninit<{}> {{\n", self.free_native_fn(), DisplayRs(ty, ctx)))?;
+                write_rs_shim_body(f, &[format!("{path}.into()")], true)?;
+                f.write_str("}\n")?;
+            },
+            Item::Function { path, params, ret, .. } => {
+                let signature = params.iter().map(|x| x.rs_declaration(ctx)).collect::<Vec<_>>().join(", ");
+                let arguments = params.iter().map(|x| x.rs_argument(ctx)).collect::<Vec<_>>().join(", ");
+
+                write_rs_docs(f, self.docs())?;
+                let qualifier = write_rs_handle_safety(f, self.docs(), params)?;
+                write_rs_deprecation(f, self.deprecation(), ctx)?;
+                f.write_str("#[no_mangle]\n")?;
+                if let Some(ret) = ret {
+                    f.write_fmt(format_args!("pub {qualifier}extern \"C\" fn {}({signature}) -> MaybeUninit<{}> {{\n", self.free_native_fn(), DisplayRs(ret, ctx)))?;
+                    write_rs_shim_body(f, &[ret.rs_to_native(&format!("{path}({arguments})"), ctx)], true)?;
+                }
+                else {
+                    f.write_fmt(format_args!("pub {qualifier}extern \"C\" fn {}({signature}) {{\n", self.free_native_fn()))?;
+                    write_rs_shim_body(f, &[format!("{path}({arguments})")], false)?;
+                }
+                f.write_str("}\n")?;
+            },
+            Item::Static { path, ty, .. } => {
+                write_rs_docs(f, self.docs())?;
+                write_rs_deprecation(f, self.deprecation(), ctx)?;
+                f.write_str("#[no_mangle]\n")?;
+                f.write_fmt(format_args!("pub extern \"C\" fn {}() -> MaybeUninit<{}> {{\n", self.free_native_fn(), DisplayRs(ty, ctx)))?;
+                write_rs_shim_body(f, &[format!("{path}.into()")], true)?;
+                f.write_str("}\n")?;
+            },
+            Item::Delegate { params, ret, .. } => {
+                let params = params.iter().map(|x| x.rs_declaration(ctx))
+                    .chain(std::iter::once("user_data: *mut std::ffi::c_void".to_string()))
+                    .collect::<Vec<_>>().join(", ");
+                let ret = ret.as_ref().map_or(String::new(), |x| format!(" -> {}", DisplayRs(x, ctx)));
+
+                write_rs_docs(f, self.docs())?;
+                write_rs_deprecation(f, self.deprecation(), ctx)?;
+                f.write_fmt(format_args!("pub type {} = unsafe extern \"C\" fn({params}){ret};\n", self.rs_name()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl HeaderBindings for Item {
+    /// Declares the type of an enum, union, struct, or delegate. Other items have no types, only functions.
+    fn write_c_header(&self, f: &mut Formatter) -> Result {
+        match self {
+            Item::Enum { variants, repr, .. } => {
+                write_c_docs(f, self.docs())?;
+                // The size of a C enum is implementation-defined, so explicitly-sized enums use a typedef instead.
+                if let Some(repr) = repr {
+                    f.write_fmt(format_args!("typedef {} {};\n", DisplayHeader(repr), self.rs_name()))?;
+                    f.write_str("enum {\n")?;
+                }
+                else {
+                    f.write_fmt(format_args!("typedef enum {} {{\n", self.rs_name()))?;
+                }
+
+                // Enumerators share a single namespace in C, so they are prefixed with the name of their enum.
+                write_indented(f, |f| {
+                    for variant in variants.iter().chain(&self.unknown_variant()) {
+                        let variant = EnumVariant { name: format!("{}_{}", self.rs_name(), variant.name), ..variant.clone() };
+                        f.write_fmt(format_args!("{}\n", DisplayHeader(&variant)))?;
+                    }
+                    Ok(())
+                })?;
+
+                if repr.is_some() {
+                    f.write_str("};\n")?;
+                }
+                else {
+                    f.write_fmt(format_args!("}} {};\n", self.rs_name()))?;
+                }
+            },
+            Item::Union { variants, .. } => {
+                // The tag is a `u32`, so its values are declared apart from the field which holds it.
+                f.write_str("enum {\n")?;
+                write_indented(f, |f| {
+                    for variant in variants.iter().map(UnionVariant::tag).chain(self.unknown_variant()) {
+                        let variant = EnumVariant { name: format!("{}_{}", self.rs_name(), variant.name), ..variant };
+                        f.write_fmt(format_args!("{}\n", DisplayHeader(&variant)))?;
+                    }
+                    Ok(())
+                })?;
+                f.write_str("};\n\n")?;
+
+                write_c_docs(f, self.docs())?;
+                f.write_fmt(format_args!("typedef struct {} {{\n", self.rs_name()))?;
+                f.write_str("    uint32_t tag;\n")?;
+                f.write_str("    union {\n")?;
+                write_indented(f, |f| write_indented(f, |f| {
+                    for variant in variants.iter().filter(|x| !x.fields.is_empty()) {
+                        f.write_str("struct {\n")?;
+                        write_indented(f, |f| {
+                            for field in &variant.fields {
+                                f.write_fmt(format_args!("{}\n", DisplayHeader(field)))?;
+                            }
+                            Ok(())
+                        })?;
+                        f.write_fmt(format_args!("}} {};\n", variant.name))?;
+                    }
+                    Ok(())
+                }))?;
+                f.write_str("    } payload;\n")?;
+                f.write_fmt(format_args!("}} {};\n", self.rs_name()))?;
+            },
+            Item::Struct { fields, packing, .. } => {
+                write_c_docs(f, self.docs())?;
+                if let Some(packing) = packing {
+                    f.write_fmt(format_args!("#pragma pack(push, {packing})\n"))?;
+                }
+                f.write_fmt(format_args!("typedef struct {} {{\n", self.rs_name()))?;
+
+                write_indented(f, |f| {
+                    for field in fields {
+                        f.write_fmt(format_args!("{}\n", DisplayHeader(field)))?;
+                    }
+                    Ok(())
+                })?;
+
+                f.write_fmt(format_args!("}} {};\n", self.rs_name()))?;
+                if packing.is_some() {
+                    f.write_str("#pragma pack(pop)\n")?;
+                }
+            },
+            Item::Delegate { params, ret, .. } => {
+                let ret = ret.as_ref().map_or("void".to_string(), |x| DisplayHeader(x).to_string());
+                let params = params.iter().map(|x| format!("{} {}", DisplayHeader(&x.ty), x.rs_name()))
+                    .chain(std::iter::once("void* user_data".to_string()))
+                    .collect::<Vec<_>>().join(", ");
+
+                write_c_docs(f, self.docs())?;
+                f.write_fmt(format_args!("typedef {ret} (*{})({params});\n", self.rs_name()))?;
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// An enum variant.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct EnumVariant {
+    /// The name of the variant.
+    pub name: String,
+    /// The name to use in the public C# API instead of the original name, if any.
+    pub rename: Option<String>,
+    /// The explicit discriminant of the variant, if any.
+    pub index: Option<i128>,
+    /// Whether the member is deprecated, and why.
+    pub deprecation: Option<Deprecation>,
+    /// The doc-comment to include.
+    pub docs: String
+}
+
+impl EnumVariant {
+    /// Gets the modified variant name for the public C# API.
+    pub fn cs_name(&self) -> String {
+        cs_identifier(self.rename.as_ref().unwrap_or(&self.name), None)
+    }
+}
+
+impl DisplayBindings for EnumVariant {
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        write_cs_docs(f, &self.docs)?;
+        write_cs_deprecation(f, self.deprecation.as_ref(), ctx)?;
+        if let Some(index) = self.index {
+            f.write_fmt(format_args!("{} = {index},", self.cs_name()))?;
+        }
+        else {
+            f.write_fmt(format_args!("{},", self.cs_name()))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        write_rs_docs(f, &self.docs)?;
+        write_rs_deprecation(f, self.deprecation.as_ref(), ctx)?;
+        if let Some(index) = self.index {
+            f.write_fmt(format_args!("{} = {index},", self.name))?;
+        }
+        else {
+            f.write_fmt(format_args!("{},", self.name))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl HeaderBindings for EnumVariant {
+    fn write_c_header(&self, f: &mut Formatter) -> Result {
+        write_c_docs(f, &self.docs)?;
+        if let Some(index) = self.index {
+            f.write_fmt(format_args!("{} = {index},", self.name))
+        }
+        else {
+            f.write_fmt(format_args!("{},", self.name))
+        }
+    }
+}
+
+/// A variant of an enum which carries data.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct UnionVariant {
+    /// The name of the variant.
+    pub name: String,
+    /// The name to use in the public C# API instead of the original name, if any.
+    pub rename: Option<String>,
+    /// The data carried by the variant, which are positional for tuple variants.
+    pub fields: Vec<StructField>,
+    /// Whether the member is deprecated, and why.
+    pub deprecation: Option<Deprecation>,
+    /// The doc-comment to include.
+    pub docs: String
+}
+
+impl UnionVariant {
+    /// Gets the modified variant name for the public C# API.
+    pub fn cs_name(&self) -> String {
+        cs_identifier(self.rename.as_ref().unwrap_or(&self.name), None)
+    }
+
+    /// Gets the member of the C# tag enum which names this variant.
+    pub fn tag(&self) -> EnumVariant {
+        EnumVariant { name: self.name.clone(), rename: self.rename.clone(), index: None, deprecation: self.deprecation.clone(), docs: self.docs.clone() }
+    }
+
+    /// Gets a Rust pattern or expression for this variant of the given type, with the given value for each field.
+    pub fn rs_pattern(&self, ty: &str, value: impl Fn(&StructField) -> String) -> String {
+        match self.fields.first() {
+            None => format!("{ty}::{}", self.name),
+            Some(x) if x.is_positional() => format!("{ty}::{}({})", self.name, self.fields.iter().map(value).collect::<Vec<_>>().join(", ")),
+            Some(_) => {
+                let fields = self.fields.iter()
+                    .map(|x| match value(x) {
+                        value if value == x.name => value,
+                        value => format!("{}: {value}", x.name)
+                    })
+                    .collect::<Vec<_>>();
+                format!("{ty}::{} {{ {} }}", self.name, fields.join(", "))
+            }
+        }
+    }
+}
+
+impl DisplayBindings for UnionVariant {
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        self.tag().write_cs(f, ctx)
+    }
+
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        write_rs_docs(f, &self.docs)?;
+        write_rs_deprecation(f, self.deprecation.as_ref(), ctx)?;
+        match self.fields.first() {
+            None => f.write_fmt(format_args!("{},", self.name)),
+            Some(x) if x.is_positional() => {
+                let types = self.fields.iter().map(|x| DisplayRs(&x.ty, ctx).to_string()).collect::<Vec<_>>();
+                f.write_fmt(format_args!("{}({}),", self.name, types.join(", ")))
+            },
+            Some(_) => {
+                f.write_fmt(format_args!("{} {{\n", self.name))?;
+                write_indented(f, |f| {
+                    for field in &self.fields {
+                        write_rs_docs(f, &field.docs)?;
+                        write_rs_deprecation(f, field.deprecation.as_ref(), ctx)?;
+                        f.write_fmt(format_args!("{}: {},\n", field.name, DisplayRs(&field.ty, ctx)))?;
+                    }
+                    Ok(())
+                })?;
+                f.write_str("},")
+            }
+        }
+    }
+}
+
+/// A field of a struct.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct StructField {
+    /// The name of the field, or its index for tuple structs.
+    pub name: String,
+    /// The name to use in the public C# API instead of the original name, if any.
+    pub rename: Option<String>,
+    /// The type of the field.
+    pub ty: TypeReference,
+    /// Whether the member is deprecated, and why.
+    pub deprecation: Option<Deprecation>,
+    /// The doc-comment to include.
+    pub docs: String
+}
+
+impl StructField {
+    /// Whether this is an unnamed field of a tuple struct, like `.0`.
+    pub fn is_positional(&self) -> bool {
+        self.name.parse::<usize>().is_ok()
+    }
+
+    /// Gets the modified type name for the public C# API.
+    pub fn cs_name(&self) -> String {
+        if let Some(rename) = &self.rename {
+            cs_identifier(rename, None)
+        }
+        else if self.is_positional() {
+            format!("Item{}", self.name)
+        }
+        else {
+            cs_identifier(&self.name, Some(Case::Pascal))
+        }
+    }
+
+    /// Gets expressions for each of the C# values making up this field, accessed through the given prefix.
+    /// Arrays contribute one value per element, and handles are compared by address.
+    pub fn cs_values(&self, prefix: &str) -> Vec<String> {
+        match &self.ty {
+            TypeReference::Array { element, len } => match &**element {
+                TypeReference::Primitive(_) => (0..*len).map(|i| format!("{prefix}{}[{i}]", self.cs_name())).collect(),
+                _ => (0..*len).map(|i| format!("{prefix}{}{i}", self.cs_name())).collect()
             },
-            Item::Class { .. } => {
-                f.write_fmt(format_args!("public unsafe sealed class {} : VxHandle {{\n", self.cs_name()))?;
-                
-                let mut destructor = String::new();
-                self.write_cs_destructor(&mut Formatter::new(&mut destructor, f.options()))?;
-                f.write_str(&indent(&destructor))?;
+            TypeReference::Handle { .. } => vec![format!("((nint){prefix}{})", self.cs_name())],
+            _ => vec![format!("{prefix}{}", self.cs_name())]
+        }
+    }
+
+    /// Gets the modified type name for C FFI.
+    pub fn rs_name(&self) -> String {
+        if self.is_positional() {
+            format!("item{}", self.name)
+        }
+        else {
+            self.name.to_string()
+        }
+    }
+
+    /// Gets the name of the C# constructor parameter which initializes this field.
+    pub fn cs_param_name(&self) -> String {
+        cs_identifier(self.cs_name().trim_start_matches('@'), Some(Case::Camel))
+    }
+
+    /// Gets the public C# type of this field as a constructor parameter, or [`None`] for arrays, which
+    /// cannot be passed as a single value.
+    pub fn cs_param_type(&self, ctx: &RenderContext) -> Option<String> {
+        match &self.ty {
+            TypeReference::Array { .. } => None,
+            TypeReference::Handle { .. } => Some("VxObject*".to_string()),
+            TypeReference::Primitive(PrimitiveType::String) => Some("string".to_string()),
+            TypeReference::Primitive(PrimitiveType::Char) => Some("System.Text.Rune".to_string()),
+            other => Some(DisplayCs(other, ctx).to_string())
+        }
+    }
+
+    /// Gets the C# statement which initializes this field from its constructor parameter. Fields stored
+    /// behind a property are written directly, since the setter of a string would free the previous value.
+    pub fn cs_initializer(&self, ctx: &RenderContext) -> String {
+        let field_name = format!("_{}", self.cs_name().to_case(Case::Camel));
+        let param = self.cs_param_name();
+        match &self.ty {
+            TypeReference::Primitive(PrimitiveType::String) => format!("{field_name} = VxString.FromString({param});"),
+            TypeReference::Primitive(PrimitiveType::Char) => format!("{field_name} = (uint){param}.Value;"),
+            TypeReference::Primitive(PrimitiveType::Bool) if !ctx.marshal_bools => format!("{field_name} = {param} ? (byte)1 : (byte)0;"),
+            _ => format!("{} = {param};", self.cs_name())
+        }
+    }
+
+    /// Gets the Rust expression which converts this field of a view from the given place in the original value.
+    /// Vectors and other views are borrowed, while the remaining fields are copied. The conversions of views name
+    /// their original type, so that views within an `Arc` are borrowed through it.
+    pub fn rs_view_value(&self, place: &str, ctx: &RenderContext) -> String {
+        match &self.ty {
+            TypeReference::Slice { element, ownership: SliceOwnership::Converted } => match &**element {
+                TypeReference::Named { rs_name, .. } if ctx.views.contains_key(rs_name) => format!("VxSlice::frame_map({place}.as_slice(), |x| <{rs_name} as From<&{}>>::from(x))", ctx.views[rs_name]),
+                _ => format!("VxSlice::frame_map({place}.as_slice(), |x| x.clone().into())")
+            },
+            TypeReference::Slice { .. } => format!("VxSlice::frame({place}.as_slice())"),
+            TypeReference::Named { rs_name, .. } if ctx.views.contains_key(rs_name) => format!("<{rs_name} as From<&{}>>::from(&{place})", ctx.views[rs_name]),
+            TypeReference::Primitive(_) => format!("{place}.into()"),
+            TypeReference::Array { .. } => format!("{place}.clone().map(Into::into)"),
+            TypeReference::Optional(_) => format!("{place}.clone().map(Into::into).into()"),
+            _ => format!("{place}.clone().into()")
+        }
+    }
+}
+
+impl DisplayBindings for StructField {
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        let byte_bool = self.ty == TypeReference::Primitive(PrimitiveType::Bool) && !ctx.marshal_bools;
+        if !matches!(self.ty, TypeReference::Primitive(PrimitiveType::Char | PrimitiveType::String)) && !byte_bool {
+            write_cs_docs(f, &self.docs)?;
+            write_cs_deprecation(f, self.deprecation.as_ref(), ctx)?;
+        }
+
+        match &self.ty {
+            TypeReference::Array { element, len } => match &**element {
+                TypeReference::Primitive(PrimitiveType::Bool) if !ctx.marshal_bools => f.write_fmt(format_args!("public fixed byte {}[{len}];\n", self.cs_name())),
+                TypeReference::Primitive(primitive) => f.write_fmt(format_args!("public fixed {} {}[{len}];\n", DisplayCs(primitive, ctx), self.cs_name())),
+                // Fixed buffers only support primitives, so other arrays are expanded into one field per element.
+                other => {
+                    for i in 0..*len {
+                        f.write_fmt(format_args!("public {} {}{i};\n", DisplayCs(other, ctx), self.cs_name()))?;
+                    }
+                    Ok(())
+                }
+            },
+            // Managed class references cannot live in a blittable struct, so handles are stored raw.
+            TypeReference::Handle { .. } => f.write_fmt(format_args!("public VxObject* {};\n", self.cs_name())),
+            // Strings are exposed as managed strings, converting to and from UTF-8 on access.
+            TypeReference::Primitive(PrimitiveType::String) => {
+                let field_name = format!("_{}", self.cs_name().to_case(Case::Camel));
+                f.write_fmt(format_args!("private VxString {field_name};\n\n"))?;
+                write_cs_docs(f, &self.docs)?;
+                write_cs_deprecation(f, self.deprecation.as_ref(), ctx)?;
+                f.write_fmt(format_args!("public string {} {{\n", self.cs_name()))?;
+                f.write_fmt(format_args!("    get => {field_name}.ToString();\n"))?;
+                f.write_str("    set {\n")?;
+                f.write_fmt(format_args!("        VxString.Free({field_name});\n"))?;
+                f.write_fmt(format_args!("        {field_name} = VxString.FromString(value);\n"))?;
+                f.write_str("    }\n")?;
+                f.write_str("}\n")
+            },
+            // Rust booleans are a single byte, whereas the default marshaling of a C# bool is four bytes. Storing
+            // a byte keeps the struct blittable, so that it has the same layout however it is marshaled.
+            TypeReference::Primitive(PrimitiveType::Bool) if byte_bool => {
+                let field_name = format!("_{}", self.cs_name().to_case(Case::Camel));
+                f.write_fmt(format_args!("private byte {field_name};\n\n"))?;
+                write_cs_docs(f, &self.docs)?;
+                write_cs_deprecation(f, self.deprecation.as_ref(), ctx)?;
+                f.write_fmt(format_args!("public bool {} {{\n", self.cs_name()))?;
+                f.write_fmt(format_args!("    get => {field_name} != 0;\n"))?;
+                f.write_fmt(format_args!("    set => {field_name} = value ? (byte)1 : (byte)0;\n"))?;
+                f.write_str("}\n")
+            },
+            // Characters are stored as their codepoint, since a C# char is a UTF-16 code unit. Codepoints which
+            // are not valid scalar values read as the replacement character.
+            TypeReference::Primitive(PrimitiveType::Char) => {
+                let field_name = format!("_{}", self.cs_name().to_case(Case::Camel));
+                f.write_fmt(format_args!("private uint {field_name};\n\n"))?;
+                write_cs_docs(f, &self.docs)?;
+                write_cs_deprecation(f, self.deprecation.as_ref(), ctx)?;
+                f.write_fmt(format_args!("public System.Text.Rune {} {{\n", self.cs_name()))?;
+                f.write_fmt(format_args!("    get => System.Text.Rune.IsValid({field_name}) ? new System.Text.Rune({field_name}) : System.Text.Rune.ReplacementChar;\n"))?;
+                f.write_fmt(format_args!("    set => {field_name} = (uint)value.Value;\n"))?;
+                f.write_str("}\n")
+            },
+            TypeReference::Primitive(PrimitiveType::Bool) => f.write_fmt(format_args!("[MarshalAs(UnmanagedType.U1)]\npublic bool {};\n", self.cs_name())),
+            _ => f.write_fmt(format_args!("public {} {};\n", DisplayCs(&self.ty, ctx), self.cs_name()))
+        }
+    }
+
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        write_rs_docs(f, &self.docs)?;
+        write_rs_deprecation(f, self.deprecation.as_ref(), ctx)?;
+        f.write_fmt(format_args!("pub {}: {},", self.rs_name(), DisplayRs(&self.ty, ctx)))
+    }
+}
+
+impl HeaderBindings for StructField {
+    fn write_c_header(&self, f: &mut Formatter) -> Result {
+        write_c_docs(f, &self.docs)?;
+        let name = self.rs_name();
+        match &self.ty {
+            TypeReference::Array { element, len } => f.write_fmt(format_args!("{} {name}[{len}];", DisplayHeader(&**element))),
+            other => f.write_fmt(format_args!("{} {name};", DisplayHeader(other)))
+        }
+    }
+}
+
+/// A method of a class, called through a generated shim.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct Method {
+    /// The name of the method.
+    pub name: String,
+    /// The name to use in the generated bindings instead of the original name, if any.
+    pub rename: Option<String>,
+    /// How the method receives the object.
+    pub receiver: Receiver,
+    /// The parameters of the method, excluding the receiver.
+    pub params: Vec<Parameter>,
+    /// The return type of the method, or [`None`] if it returns nothing.
+    pub ret: Option<TypeReference>,
+    /// The Rust expression to evaluate instead of calling the method, in which `this` is the object and
+    /// each parameter is bound by name.
+    pub call: Option<String>,
+    /// The type substituted into the signature, if this is one of several instantiations of a configured method.
+    pub instance: Option<String>,
+    /// Whether the method is exposed as a read-only C# property.
+    pub property: bool,
+    /// Whether the member is deprecated, and why.
+    pub deprecation: Option<Deprecation>,
+    /// The Rust item which the bindings were generated from, if any.
+    pub origin: Option<Origin>,
+    /// The doc-comment to include.
+    pub docs: String
+}
+
+impl Method {
+    /// Gets the modified method name for the public C# API.
+    pub fn cs_name(&self) -> String {
+        cs_identifier(self.rename.as_ref().unwrap_or(&self.name), Some(Case::Pascal))
+    }
+
+    /// Gets the modified method name that will be inserted into C FFI functions.
+    pub fn rs_fn_name(&self) -> String {
+        let name = raw_identifier(self.rename.as_ref().unwrap_or(&self.name)).to_case(Case::Snake);
+        match &self.instance {
+            Some(instance) => format!("{name}_{}", instance.rsplit("::").next().unwrap_or(instance).to_lowercase()),
+            None => name
+        }
+    }
+}
+
+/// An arithmetic operator implemented by a struct.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub enum OperatorKind {
+    /// Addition of two values ([`std::ops::Add`]).
+    Add,
+    /// Subtraction of two values ([`std::ops::Sub`]).
+    Sub,
+    /// Negation of a value ([`std::ops::Neg`]).
+    Neg,
+    /// Multiplication by a scalar ([`std::ops::Mul`]).
+    MulScalar(PrimitiveType),
+    /// Division by a scalar ([`std::ops::Div`]).
+    DivScalar(PrimitiveType)
+}
+
+impl OperatorKind {
+    /// Gets the symbol used for this operator in both C# and Rust.
+    pub fn cs_symbol(&self) -> &'static str {
+        match self {
+            OperatorKind::Add => "+",
+            OperatorKind::Sub | OperatorKind::Neg => "-",
+            OperatorKind::MulScalar(_) => "*",
+            OperatorKind::DivScalar(_) => "/"
+        }
+    }
+
+    /// Gets the name that will be inserted into C FFI functions.
+    pub fn rs_fn_name(&self) -> &'static str {
+        match self {
+            OperatorKind::Add => "add",
+            OperatorKind::Sub => "sub",
+            OperatorKind::Neg => "neg",
+            OperatorKind::MulScalar(_) => "mul",
+            OperatorKind::DivScalar(_) => "div"
+        }
+    }
+}
+
+/// A trait which can be used to format a value as a string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub enum FormatTrait {
+    /// The user-facing [`std::fmt::Display`] trait.
+    Display,
+    /// The programmer-facing [`std::fmt::Debug`] trait.
+    Debug
+}
+
+impl FormatTrait {
+    /// Gets the name of the trait in Rust.
+    pub fn rs_name(&self) -> &'static str {
+        match self {
+            FormatTrait::Display => "Display",
+            FormatTrait::Debug => "Debug"
+        }
+    }
+
+    /// Gets the format specifier which invokes the trait.
+    pub fn rs_specifier(&self) -> &'static str {
+        match self {
+            FormatTrait::Display => "{}",
+            FormatTrait::Debug => "{:?}"
+        }
+    }
+}
+
+/// An associated function of a class which returns a new object.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct Constructor {
+    /// The name of the function.
+    pub name: String,
+    /// The name to use in the generated bindings instead of the original name, if any.
+    pub rename: Option<String>,
+    /// The parameters of the function.
+    pub params: Vec<Parameter>,
+    /// The Rust expression to evaluate instead of calling the function, in which each parameter is bound by name.
+    pub call: Option<String>,
+    /// Whether the member is deprecated, and why.
+    pub deprecation: Option<Deprecation>,
+    /// The Rust item which the bindings were generated from, if any.
+    pub origin: Option<Origin>,
+    /// The doc-comment to include.
+    pub docs: String
+}
+
+impl Constructor {
+    /// Gets the name of the static factory method for the public C# API.
+    pub fn cs_name(&self) -> String {
+        cs_identifier(&format!("Create_{}", raw_identifier(self.rename.as_ref().unwrap_or(&self.name))), Some(Case::Pascal))
+    }
+
+    /// Gets the modified function name that will be inserted into C FFI functions.
+    pub fn rs_fn_name(&self) -> String {
+        raw_identifier(self.rename.as_ref().unwrap_or(&self.name)).to_case(Case::Snake)
+    }
+}
+
+/// An existing C# type which is used in place of a generated struct with the same layout.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct ExternalType {
+    /// The name of the existing C# type.
+    pub cs_name: String,
+    /// Whether to generate the C# struct anyway, with implicit conversions to and from the existing type.
+    pub conversions: bool
+}
+
+/// The Rust item from which a binding was generated, noted in a comment above the binding so that misbehaving
+/// output can be traced back to its source.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct Origin {
+    /// The canonical path of the item, such as `egui::ui::Ui::button`.
+    pub path: String,
+    /// The file which defines the item, and the line on which it begins, if the rustdoc JSON records them.
+    pub span: Option<(String, usize)>
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match &self.span {
+            Some((file, line)) => write!(f, "generated from {file}:{line} ({})", self.path),
+            None => write!(f, "generated from {}", self.path)
+        }
+    }
+}
+
+/// Marks an item or member as deprecated.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct Deprecation {
+    /// The version in which the item was deprecated, if known.
+    pub since: Option<String>,
+    /// The reason for the deprecation, or the alternative to use.
+    pub note: Option<String>
+}
+
+impl Deprecation {
+    /// Gets the message shown when the item is used from C#.
+    pub fn message(&self) -> String {
+        match (&self.since, &self.note) {
+            (Some(since), Some(note)) => format!("Deprecated since {since}: {note}"),
+            (Some(since), None) => format!("Deprecated since {since}"),
+            (None, Some(note)) => note.clone(),
+            (None, None) => "Deprecated".to_string()
+        }
+    }
+}
+
+impl DisplayBindings for Deprecation {
+    fn write_cs(&self, f: &mut Formatter, _: &RenderContext) -> Result {
+        // Notes may span several lines, which a regular string literal cannot.
+        let message = self.message().replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n").replace('\r', "\\r").replace('\t', "\\t");
+        f.write_fmt(format_args!("[Obsolete(\"{message}\")]\n"))
+    }
+
+    fn write_rs(&self, f: &mut Formatter, _: &RenderContext) -> Result {
+        let arguments = [("since", &self.since), ("note", &self.note)].into_iter()
+            .filter_map(|(key, value)| Some(format!("{key} = {:?}", value.as_ref()?)))
+            .collect::<Vec<_>>();
+
+        if arguments.is_empty() {
+            f.write_str("#[deprecated]\n")
+        }
+        else {
+            f.write_fmt(format_args!("#[deprecated({})]\n", arguments.join(", ")))
+        }
+    }
+}
+
+/// How a method receives the object it is called on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub enum Receiver {
+    /// The method borrows the object immutably (`&self`).
+    Ref,
+    /// The method borrows the object mutably (`&mut self`).
+    RefMut,
+    /// The method consumes the object (`self`).
+    Value
+}
+
+/// A parameter of a function.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct Parameter {
+    /// The name of the parameter.
+    pub name: String,
+    /// The type of the parameter.
+    pub ty: TypeReference
+}
+
+impl Parameter {
+    /// Gets the modified parameter name for the public C# API.
+    pub fn cs_name(&self) -> String {
+        cs_identifier(&self.name, Some(Case::Camel))
+    }
+
+    /// Gets the modified parameter name for C FFI.
+    pub fn rs_name(&self) -> String {
+        match self.name.strip_prefix("r#") {
+            Some(name) => format!("r#{}", name.to_case(Case::Snake)),
+            None => self.name.to_case(Case::Snake)
+        }
+    }
+
+    /// Gets the names and C# types with which this parameter crosses the FFI boundary. Delegates are
+    /// followed by their user data.
+    pub fn native_params(&self) -> Vec<(String, TypeReference)> {
+        let mut result = vec![(self.cs_name(), self.ty.clone())];
+        if let TypeReference::Delegate { .. } = self.ty {
+            result.push((format!("{}UserData", self.cs_name()), TypeReference::Pointer { target: Box::new(TypeReference::Primitive(PrimitiveType::U8)), mutable: true }));
+        }
+        result
+    }
+
+    /// Gets the declaration of this parameter in the public C# API. Slices are taken as spans, which may
+    /// view arrays, stack memory, or native buffers alike.
+    pub fn cs_declaration(&self, ctx: &RenderContext) -> String {
+        match &self.ty {
+            TypeReference::Slice { element, .. } => format!("ReadOnlySpan<{}> {}", element.cs_native_type(ctx), self.cs_name()),
+            other => format!("{} {}", DisplayCs(other, ctx), self.cs_name())
+        }
+    }
+
+    /// Gets the declaration of this parameter in a Rust shim. Enums are received as their underlying
+    /// integer, or a pointer to it, since C# may pass any value, and are checked by [`Parameter::rs_argument`].
+    pub fn rs_declaration(&self, ctx: &RenderContext) -> String {
+        match &self.ty {
+            TypeReference::Named { rs_name, .. } if ctx.enum_reprs.contains_key(rs_name) => format!("{}: {}", self.rs_name(), DisplayRs(&ctx.enum_reprs[rs_name], ctx)),
+            TypeReference::InOut(inner) => match &**inner {
+                TypeReference::Named { rs_name, .. } if ctx.enum_reprs.contains_key(rs_name) => format!("{}: *mut {}", self.rs_name(), DisplayRs(&ctx.enum_reprs[rs_name], ctx)),
+                other => format!("{}: *mut {}", self.rs_name(), DisplayRs(other, ctx))
+            },
+            TypeReference::Delegate { rs_name, .. } => format!("{0}: {rs_name}, {0}_user_data: *mut std::ffi::c_void", self.rs_name()),
+            other => format!("{}: {}", self.rs_name(), DisplayRs(other, ctx))
+        }
+    }
+
+    /// Gets the expression which converts this parameter of a Rust shim into the argument of the wrapped function.
+    pub fn rs_argument(&self, ctx: &RenderContext) -> String {
+        match &self.ty {
+            TypeReference::Named { rs_name, .. } if ctx.enum_reprs.contains_key(rs_name) => {
+                format!("{rs_name}::try_from({} as i64).expect(\"Invalid discriminant for `{rs_name}`\").into()", self.rs_name())
+            },
+            // Callbacks become closures which pass the user data back, converting each argument to its mirror.
+            TypeReference::Delegate { rs_name, .. } => {
+                let (params, ret) = &ctx.delegates[rs_name];
+                let names = params.iter().map(Parameter::rs_name).collect::<Vec<_>>();
+                // Objects are only borrowed by the callback, so they are wrapped for its duration rather than moved to the heap.
+                let arguments = params.iter().map(|x| match x.ty {
+                    TypeReference::Handle { .. } => format!("&mut VxObject::borrowed({}), ", x.rs_name()),
+                    _ => format!("{}.into(), ", x.rs_name())
+                }).collect::<String>();
+                let call = format!("unsafe {{ {0}({arguments}{0}_user_data) }}", self.rs_name());
+                format!("move |{}| {call}{}", names.join(", "), if ret.is_some() { ".into()" } else { "" })
+            },
+            // Objects passed by value are moved out of C#, which no longer owns them.
+            TypeReference::Handle { mutable: false, .. } => format!("&*VxObject::get({})", self.rs_name()),
+            TypeReference::HandleMut { .. } => format!("&mut *VxObject::get_mut({})", self.rs_name()),
+            TypeReference::Handle { .. } => format!("VxHandle::from_heap({}).into_inner()", self.rs_name()),
+            // Strings are allocated by Rust when C# copies them, so they are freed once the call returns.
+            TypeReference::Primitive(PrimitiveType::String) => format!("unsafe {{ {}.into_string() }}.as_str()", self.rs_name()),
+            TypeReference::InOut(inner) if **inner == TypeReference::Primitive(PrimitiveType::String) => format!("&mut *unsafe {{ VxStringMut::new({}) }}", self.rs_name()),
+            // Mirrors are converted for the call and written back. Their original types are named explicitly, since
+            // generic functions like `selectable_value` cannot infer them.
+            TypeReference::InOut(inner) => match &**inner {
+                TypeReference::Named { rs_name, .. } if ctx.enum_reprs.contains_key(rs_name) => {
+                    let check = format!("|x| {rs_name}::try_from(x as i64).expect(\"Invalid discriminant for `{rs_name}`\")");
+                    format!("&mut *unsafe {{ VxMirrorMut::<_, {}>::checked({}, {check}) }}", ctx.mirrors[rs_name], self.rs_name())
+                },
+                TypeReference::Named { rs_name, .. } if ctx.mirrors.contains_key(rs_name) => {
+                    format!("&mut *unsafe {{ VxMirrorMut::<_, {}>::new({}) }}", ctx.mirrors[rs_name], self.rs_name())
+                },
+                _ => format!("unsafe {{ &mut *{} }}", self.rs_name())
+            },
+            TypeReference::StringBuffer => format!("unsafe {{ VxStringBuffer::get_mut({}) }}", self.rs_name()),
+            // Slices are borrowed as their original element type, which mirrors of blittable structs share the layout of.
+            TypeReference::Slice { element, .. } => match &**element {
+                TypeReference::Named { rs_name, .. } if ctx.mirrors.contains_key(rs_name) => format!("unsafe {{ {}.as_slice::<{}>() }}", self.rs_name(), ctx.mirrors[rs_name]),
+                other => format!("unsafe {{ {}.as_slice::<{}>() }}", self.rs_name(), DisplayRs(other, ctx))
+            },
+            TypeReference::Optional(inner) if matches!(**inner, TypeReference::Named { .. }) => format!("Option::<{}>::from({}).map(Into::into)", DisplayRs(&**inner, ctx), self.rs_name()),
+            _ => format!("{}.into()", self.rs_name())
+        }
+    }
+
+    /// Gets the argument for this parameter which is bound by name within a configured call, or passed to
+    /// a method directly. Unlike [`Self::rs_argument`], primitives which cross the boundary unchanged are not
+    /// converted, so that their types are known wherever the call uses them, even by generic parameters like
+    /// the `impl Into<Vec2b>` of `ScrollArea::auto_shrink`.
+    pub fn rs_bound_argument(&self, ctx: &RenderContext) -> String {
+        match &self.ty {
+            TypeReference::Primitive(PrimitiveType::Char | PrimitiveType::String | PrimitiveType::FixedUsize | PrimitiveType::FixedIsize) => self.rs_argument(ctx),
+            TypeReference::Primitive(_) => self.rs_name(),
+            _ => self.rs_argument(ctx)
+        }
+    }
+}
+
+/// A function exported from Rust, which C# declares in the [`NativeMethods`] class.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NativeFunction {
+    /// The exported symbol.
+    pub name: String,
+    /// The names and types of the parameters.
+    pub params: Vec<(String, TypeReference)>,
+    /// The return type, or [`None`] if the function returns nothing.
+    pub ret: Option<TypeReference>
+}
+
+impl NativeFunction {
+    /// Creates a new function with the given signature.
+    pub fn new(name: String, params: impl IntoIterator<Item = (String, TypeReference)>, ret: Option<TypeReference>) -> Self {
+        Self { name, params: params.into_iter().collect(), ret }
+    }
+}
+
+impl HeaderBindings for NativeFunction {
+    fn write_c_header(&self, f: &mut Formatter) -> Result {
+        let ret = self.ret.as_ref().map_or("void".to_string(), |x| DisplayHeader(x).to_string());
+        let params = self.params.iter()
+            .map(|(name, ty)| {
+                let name = name.trim_start_matches('@');
+                if C_KEYWORDS.contains(&name) {
+                    format!("{} {name}_", DisplayHeader(ty))
+                }
+                else {
+                    format!("{} {name}", DisplayHeader(ty))
+                }
+            })
+            .collect::<Vec<_>>();
+        let params = if params.is_empty() { "void".to_string() } else { params.join(", ") };
+        f.write_fmt(format_args!("{ret} {}({params});", self.name))
+    }
+}
+
+/// The class which declares every function exported from Rust for use by the C# bindings.
+pub struct NativeMethods<'a> {
+    /// The settings shared by the generated bindings, including the name of the class.
+    pub ctx: &'a RenderContext,
+    /// The name of the native library which exports the functions.
+    pub library: &'a str,
+    /// Whether to use `[DllImport]` rather than source-generated `[LibraryImport]` declarations.
+    pub dll_import: bool,
+    /// The functions to declare.
+    pub functions: &'a [NativeFunction]
+}
+
+impl Display for NativeMethods<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("/// <summary>\n")?;
+        f.write_str("/// The functions exported by the native library.\n")?;
+        f.write_str("/// </summary>\n")?;
+        f.write_fmt(format_args!("internal static unsafe partial class {} {{\n", self.ctx.naming.library_class_name))?;
+        f.write_fmt(format_args!("    private const string Library = \"{}\";\n", self.library.escape_default()))?;
+        for function in self.functions {
+            // Rust booleans are a single byte, whereas the default marshaling of a C# bool is four bytes.
+            let bool_marshaling = |ty: &TypeReference| if *ty == TypeReference::Primitive(PrimitiveType::Bool) { "[MarshalAs(UnmanagedType.U1)] " } else { "" };
+            let ret = function.ret.as_ref().map_or("void".to_string(), |x| x.cs_native_type(self.ctx));
+            let params = function.params.iter()
+                .map(|(name, ty)| format!("{}{} {name}", bool_marshaling(ty), ty.cs_native_type(self.ctx)))
+                .collect::<Vec<_>>().join(", ");
+
+            f.write_str("\n")?;
+            if self.dll_import {
+                f.write_str("    [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]\n")?;
+            }
+            else {
+                f.write_str("    [LibraryImport(Library)]\n")?;
+            }
+
+            if let Some(ret) = &function.ret && !bool_marshaling(ret).is_empty() {
+                f.write_str("    [return: MarshalAs(UnmanagedType.U1)]\n")?;
+            }
+
+            let modifier = if self.dll_import { "extern" } else { "partial" };
+            f.write_fmt(format_args!("    internal static {modifier} {ret} {}({params});\n", function.name))?;
+        }
+        f.write_str("}\n")
+    }
+}
+
+/// The C# definition of the opaque objects referred to by [`TypeReference::Handle`].
+pub const CS_OBJECT_SUPPORT: &str = r#"/// <summary>
+/// An opaque Rust object, which is only ever referred to by pointer.
+/// </summary>
+public struct VxObject { }
+"#;
+
+/// The C# base class of every [`Item::Class`] that does not use a `SafeHandle`.
+pub const CS_HANDLE_SUPPORT: &str = r#"/// <summary>
+/// Owns a heap-allocated Rust object, which is freed when the handle is disposed or finalized.
+/// </summary>
+public abstract unsafe class VxHandle : IDisposable {
+    private VxObject* _pointer;
+    private readonly bool _owned;
+    private bool _disposed;
+
+    /// <summary>
+    /// Takes ownership of the provided object, or borrows it for the duration of a callback if
+    /// <paramref name="owned"/> is false. Borrowed objects are never freed.
+    /// </summary>
+    protected VxHandle(VxObject* pointer, bool owned = true) {
+        _pointer = pointer;
+        _owned = owned;
+        if (!owned) {
+            GC.SuppressFinalize(this);
+        }
+    }
+
+    /// <summary>
+    /// Frees the object if the handle was never disposed.
+    /// </summary>
+    ~VxHandle() {
+        Dispose(false);
+    }
+
+    /// <summary>
+    /// Gets the owned object, throwing if it has already been freed or moved.
+    /// </summary>
+    internal VxObject* Pointer {
+        get {
+            ThrowIfDisposed();
+            return _pointer;
+        }
+    }
+
+    /// <summary>
+    /// Frees the object. Calling this more than once has no effect.
+    /// </summary>
+    public void Dispose() {
+        Dispose(true);
+        GC.SuppressFinalize(this);
+    }
+
+    /// <summary>
+    /// Gives up ownership of the object, which is being moved into Rust. The handle is
+    /// marked as disposed without freeing the object.
+    /// </summary>
+    internal VxObject* TakePointer() {
+        if (!_owned) {
+            throw new InvalidOperationException($"A borrowed {GetType().Name} cannot be moved into Rust.");
+        }
+
+        var pointer = Pointer;
+        _pointer = null;
+        _disposed = true;
+        GC.SuppressFinalize(this);
+        return pointer;
+    }
+
+    /// <summary>
+    /// Takes ownership of the object returned by a builder method, which consumed the previous one,
+    /// so that calls can be chained on the same handle.
+    /// </summary>
+    internal void ReplacePointer(VxObject* pointer) {
+        _pointer = pointer;
+        _disposed = false;
+        GC.ReRegisterForFinalize(this);
+    }
+
+    /// <summary>
+    /// Throws an exception if the object has already been freed or moved.
+    /// </summary>
+    protected void ThrowIfDisposed() {
+        if (_disposed) {
+            throw new ObjectDisposedException(GetType().Name);
+        }
+    }
+
+    /// <summary>
+    /// Frees the object, unless it has already been freed or moved or is only borrowed.
+    /// </summary>
+    protected virtual void Dispose(bool disposing) {
+        if (!_disposed) {
+            _disposed = true;
+            if (_owned) {
+                Free(_pointer);
+            }
+            _pointer = null;
+        }
+    }
+
+    /// <summary>
+    /// Frees the provided object with the destructor for its type.
+    /// </summary>
+    protected abstract void Free(VxObject* pointer);
+}
+"#;
+
+/// The C# definition backing [`TypeReference::Optional`].
+pub const CS_OPTION_SUPPORT: &str = r#"/// <summary>
+/// An optional value which may be passed across the FFI boundary.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public struct VxOption<T> where T : unmanaged {
+    private byte _hasValue;
+    private T _value;
+
+    /// <summary>
+    /// Creates an optional value that is present.
+    /// </summary>
+    public VxOption(T value) {
+        _hasValue = 1;
+        _value = value;
+    }
+
+    /// <summary>
+    /// Whether the value is present.
+    /// </summary>
+    public bool HasValue => _hasValue != 0;
+
+    /// <summary>
+    /// Gets the value, throwing if it is not present.
+    /// </summary>
+    public T Value => HasValue ? _value : throw new InvalidOperationException("Optional value was not present.");
+}
+"#;
+
+/// The C# definition of `ecolor::Color32`, which has the same layout as the Rust type.
+pub const CS_COLOR32_SUPPORT: &str = r#"/// <summary>
+/// A 32-bit color, with 0-255 gamma space sRGBA channels and <i>premultiplied alpha</i>. The alpha channel
+/// is in linear space, and an alpha of zero means that the color is additive.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public struct Color32 {
+    /// <summary>
+    /// The red channel, premultiplied by alpha.
+    /// </summary>
+    public byte R;
+    /// <summary>
+    /// The green channel, premultiplied by alpha.
+    /// </summary>
+    public byte G;
+    /// <summary>
+    /// The blue channel, premultiplied by alpha.
+    /// </summary>
+    public byte B;
+    /// <summary>
+    /// The alpha channel.
+    /// </summary>
+    public byte A;
+
+    /// <summary>
+    /// Creates a color from channels which are already premultiplied by alpha.
+    /// </summary>
+    public Color32(byte r, byte g, byte b, byte a) {
+        R = r;
+        G = g;
+        B = b;
+        A = a;
+    }
+
+    /// <summary>
+    /// Creates an opaque color.
+    /// </summary>
+    public static Color32 FromRgb(byte r, byte g, byte b) => new Color32(r, g, b, 255);
+
+    /// <summary>
+    /// Packs the channels into an integer, with red in the lowest byte and alpha in the highest.
+    /// </summary>
+    public uint ToUint() => (uint)R | (uint)G << 8 | (uint)B << 16 | (uint)A << 24;
+}
+"#;
+
+/// The C# definition of `ecolor::Rgba`, which has the same layout as the Rust type.
+pub const CS_RGBA_SUPPORT: &str = r#"/// <summary>
+/// A color with 0-1 linear space RGBA channels and <i>premultiplied alpha</i>.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public struct Rgba {
+    /// <summary>
+    /// The red channel, premultiplied by alpha.
+    /// </summary>
+    public float R;
+    /// <summary>
+    /// The green channel, premultiplied by alpha.
+    /// </summary>
+    public float G;
+    /// <summary>
+    /// The blue channel, premultiplied by alpha.
+    /// </summary>
+    public float B;
+    /// <summary>
+    /// The alpha channel.
+    /// </summary>
+    public float A;
+
+    /// <summary>
+    /// Creates a color from channels which are already premultiplied by alpha.
+    /// </summary>
+    public Rgba(float r, float g, float b, float a) {
+        R = r;
+        G = g;
+        B = b;
+        A = a;
+    }
+}
+"#;
+
+/// The C# definition of `epaint::TextureId`.
+pub const CS_TEXTURE_ID_SUPPORT: &str = r#"/// <summary>
+/// Identifies a texture, which is either managed by egui or allocated by the user.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public struct TextureId {
+    private ulong _user;
+    private ulong _id;
+
+    private TextureId(ulong user, ulong id) {
+        _user = user;
+        _id = id;
+    }
+
+    /// <summary>
+    /// Identifies a texture managed by egui, such as the font atlas or one loaded through the context.
+    /// </summary>
+    public static TextureId Managed(ulong id) => new TextureId(0, id);
+
+    /// <summary>
+    /// Identifies a texture allocated by the user, which the renderer maps to its own texture.
+    /// </summary>
+    public static TextureId User(ulong id) => new TextureId(1, id);
+
+    /// <summary>
+    /// Whether the texture was allocated by the user.
+    /// </summary>
+    public bool IsUser => _user != 0;
+
+    /// <summary>
+    /// The number of the texture.
+    /// </summary>
+    public ulong Id => _id;
+}
+"#;
+
+/// The Rust definition backing `epaint::TextureId`.
+pub const RS_TEXTURE_ID_SUPPORT: &str = include_str!("support/texture_id.rs");
+
+/// The Rust definition backing [`TypeReference::Optional`].
+pub const RS_OPTION_SUPPORT: &str = r#"/// An optional value which may be passed across the FFI boundary.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxOption<T: Copy> {
+    /// Whether the value is present. This is `0` or `1`.
+    pub has_value: u8,
+    /// The value, which is only initialized if `has_value` is set.
+    pub value: std::mem::MaybeUninit<T>
+}
+
+impl<T: Copy> From<Option<T>> for VxOption<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(x) => Self { has_value: 1, value: std::mem::MaybeUninit::new(x) },
+            None => Self { has_value: 0, value: std::mem::MaybeUninit::zeroed() }
+        }
+    }
+}
+
+impl<T: Copy> From<VxOption<T>> for Option<T> {
+    fn from(value: VxOption<T>) -> Self {
+        // Safety: the value is always initialized when the flag is set.
+        (value.has_value != 0).then(|| unsafe { value.value.assume_init() })
+    }
+}
+"#;
+
+/// The Rust definitions backing [`PrimitiveType::FixedUsize`] and [`PrimitiveType::FixedIsize`]. Values
+/// which do not fit in the target's pointer width cause a panic when converted back.
+pub const RS_FIXED_SIZE_SUPPORT: &str = r#"/// A `usize` which is passed across the FFI boundary as a `u64`.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct VxUsize(pub u64);
+
+impl From<usize> for VxUsize {
+    fn from(value: usize) -> Self {
+        Self(value as u64)
+    }
+}
 
-                f.write_str("}\n")?;
-            },
-            Item::Struct { fields, has_default, .. } => {
-                f.write_fmt(format_args!("public unsafe struct {} {{\n", self.cs_name()))?;
-                
-                if *has_default {
-                    let mut default = String::new();
-                    self.write_cs_struct_default(&mut Formatter::new(&mut default, f.options()))?;
-                    f.write_str(&indent(&default))?;
-                    f.write_str("\n");
-                }
+impl From<VxUsize> for usize {
+    fn from(value: VxUsize) -> Self {
+        usize::try_from(value.0).expect("Value does not fit in a usize on this target")
+    }
+}
 
-                let mut members = String::new();
-                for field in fields {
-                    write!(&mut members, "{}\n", DisplayCs(field))?;
-                }
-                f.write_str(&indent(&members))?;
+/// An `isize` which is passed across the FFI boundary as an `i64`.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct VxIsize(pub i64);
 
-                f.write_str("}\n")?;
-            }
+impl From<isize> for VxIsize {
+    fn from(value: isize) -> Self {
+        Self(value as i64)
+    }
+}
+
+impl From<VxIsize> for isize {
+    fn from(value: VxIsize) -> Self {
+        isize::try_from(value.0).expect("Value does not fit in an isize on this target")
+    }
+}
+"#;
+
+/// The Rust definition backing [`PrimitiveType::Char`].
+pub const RS_CHAR_SUPPORT: &str = include_str!("support/char.rs");
+
+/// The Rust definition backing enums and structs which C# passes by `ref`.
+pub const RS_MIRROR_MUT_SUPPORT: &str = include_str!("support/mirror_mut.rs");
+
+/// The C# and Rust definitions backing [`PrimitiveType::String`], which free and create strings
+/// through exported functions.
+pub struct StringSupport;
+
+impl StringSupport {
+    /// Gets the Rust functions which the C# definition calls, with their C# signatures.
+    pub fn native_functions(&self, ctx: &RenderContext) -> Vec<NativeFunction> {
+        let string = TypeReference::Primitive(PrimitiveType::String);
+        let bytes = TypeReference::Pointer { target: Box::new(TypeReference::Primitive(PrimitiveType::U8)), mutable: false };
+        vec![
+            NativeFunction::new(ctx.naming.native_fn("string_drop"), [("value".to_string(), string.clone())], None),
+            NativeFunction::new(ctx.naming.native_fn("string_from_utf8"), [("ptr".to_string(), bytes), ("len".to_string(), TypeReference::Primitive(PrimitiveType::Usize))], Some(string))
+        ]
+    }
+}
+
+impl DisplayBindings for StringSupport {
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        let drop = ctx.naming.cs_native_fn(&ctx.naming.native_fn("string_drop"));
+        let from_utf8 = ctx.naming.cs_native_fn(&ctx.naming.native_fn("string_from_utf8"));
+        write!(f, r#"/// <summary>
+/// An owned UTF-8 string which may be passed across the FFI boundary.
+/// The string is allocated by Rust, and must be freed with <c>{drop}</c>.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe struct VxString {{
+    private byte* _ptr;
+    private nuint _len;
+    private nuint _capacity;
+
+    /// <summary>
+    /// Copies a managed string into a new Rust-allocated string.
+    /// </summary>
+    public static VxString FromString(string value) {{
+        var bytes = System.Text.Encoding.UTF8.GetBytes(value);
+        fixed (byte* ptr = bytes) {{
+            return {from_utf8}(ptr, (nuint)bytes.Length);
+        }}
+    }}
+
+    /// <summary>
+    /// Copies a Rust-allocated string into a managed string, and then frees it.
+    /// </summary>
+    public static string Take(VxString value) {{
+        try {{
+            return value.ToString();
+        }}
+        finally {{
+            Free(value);
+        }}
+    }}
+
+    /// <summary>
+    /// Frees a Rust-allocated string.
+    /// </summary>
+    public static void Free(VxString value) => {drop}(value);
+
+    /// <summary>
+    /// Whether this is a null string, which Rust returns in place of a string that does not exist.
+    /// </summary>
+    internal bool IsNull => _ptr == null;
+
+    /// <summary>
+    /// Copies the contents of this string into a managed string.
+    /// </summary>
+    public override string ToString() => System.Text.Encoding.UTF8.GetString(_ptr, checked((int)_len));
+}}
+"#)
+    }
+
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        let drop = ctx.naming.native_fn("string_drop");
+        let from_utf8 = ctx.naming.native_fn("string_from_utf8");
+        write!(f, r#"/// An owned UTF-8 string which may be passed across the FFI boundary.
+/// The string must be freed with [`{drop}`].
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxString {{
+    /// A pointer to the first byte.
+    pub ptr: *mut u8,
+    /// The length of the string in bytes.
+    pub len: usize,
+    /// The capacity of the allocation.
+    pub capacity: usize
+}}
+
+impl From<String> for VxString {{
+    fn from(value: String) -> Self {{
+        let mut value = std::mem::ManuallyDrop::new(value);
+        Self {{ ptr: value.as_mut_ptr(), len: value.len(), capacity: value.capacity() }}
+    }}
+}}
+
+impl VxString {{
+    /// Takes ownership of the string.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the string must have been allocated by Rust and not already freed.
+    pub unsafe fn into_string(self) -> String {{
+        String::from_raw_parts(self.ptr, self.len, self.capacity)
+    }}
+}}
+
+/// A string which C# passes by `ref`. The string is taken for the duration of a call, and written back
+/// when this is dropped, even if the call panics.
+pub struct VxStringMut {{
+    /// The string to write back to.
+    target: *mut VxString,
+    /// The string while it is in use.
+    value: String
+}}
+
+impl VxStringMut {{
+    /// Takes the string which the pointer refers to.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must refer to a string allocated by Rust, which is not
+    /// used again until this is dropped.
+    pub unsafe fn new(target: *mut VxString) -> Self {{
+        Self {{ value: (*target).into_string(), target }}
+    }}
+}}
+
+impl std::ops::Deref for VxStringMut {{
+    type Target = String;
+
+    fn deref(&self) -> &String {{
+        &self.value
+    }}
+}}
+
+impl std::ops::DerefMut for VxStringMut {{
+    fn deref_mut(&mut self) -> &mut String {{
+        &mut self.value
+    }}
+}}
+
+impl Drop for VxStringMut {{
+    fn drop(&mut self) {{
+        unsafe {{ *self.target = std::mem::take(&mut self.value).into() }};
+    }}
+}}
+
+/// Frees the provided string.
+///
+/// # Safety
+///
+/// For this call to be sound, the string must have been allocated by Rust and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn {drop}(value: VxString) {{
+    drop(value.into_string());
+}}
+
+/// Copies the provided UTF-8 bytes into a new string, replacing invalid sequences.
+///
+/// # Safety
+///
+/// For this call to be sound, the pointer must refer to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn {from_utf8}(ptr: *const u8, len: usize) -> VxString {{
+    String::from_utf8_lossy(std::slice::from_raw_parts(ptr, len)).into_owned().into()
+}}
+"#)
+    }
+}
+
+/// The C# and Rust definitions backing [`TypeReference::StringBuffer`].
+pub struct StringBufferSupport;
+
+impl StringBufferSupport {
+    /// Gets the Rust functions which the C# definition calls, with their C# signatures.
+    pub fn native_functions(&self, ctx: &RenderContext) -> Vec<NativeFunction> {
+        let buffer = || ("buffer".to_string(), TypeReference::StringBuffer);
+        let bytes = TypeReference::Pointer { target: Box::new(TypeReference::Primitive(PrimitiveType::U8)), mutable: false };
+        let len = TypeReference::Primitive(PrimitiveType::Usize);
+        vec![
+            NativeFunction::new(ctx.naming.native_fn("string_buffer_new"), [], Some(TypeReference::StringBuffer)),
+            NativeFunction::new(ctx.naming.native_fn("string_buffer_set"), [buffer(), ("ptr".to_string(), bytes.clone()), ("len".to_string(), len.clone())], None),
+            NativeFunction::new(ctx.naming.native_fn("string_buffer_get"), [buffer()], Some(bytes)),
+            NativeFunction::new(ctx.naming.native_fn("string_buffer_len"), [buffer()], Some(len)),
+            NativeFunction::new(ctx.naming.native_fn("string_buffer_drop"), [buffer()], None)
+        ]
+    }
+}
+
+impl DisplayBindings for StringBufferSupport {
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        let [new, set, get, len, drop] = ["new", "set", "get", "len", "drop"].map(|x| ctx.naming.cs_native_fn(&ctx.naming.native_fn(&format!("string_buffer_{x}"))));
+        write!(f, r#"/// <summary>
+/// An opaque string owned by Rust, which is only ever referred to by pointer.
+/// </summary>
+public struct VxStringBuffer {{ }}
+
+/// <summary>
+/// A string owned by Rust, which widgets such as text edits modify in place. The same buffer may be passed
+/// every frame, since its text is only converted when <see cref="Text"/> is read or written.
+/// </summary>
+public unsafe sealed class EguiStringBuffer : IDisposable {{
+    private VxStringBuffer* _pointer;
+
+    /// <summary>
+    /// Creates an empty buffer.
+    /// </summary>
+    public EguiStringBuffer() {{
+        _pointer = {new}();
+    }}
+
+    /// <summary>
+    /// Creates a buffer holding the provided text.
+    /// </summary>
+    public EguiStringBuffer(string text) : this() {{
+        Text = text;
+    }}
+
+    /// <summary>
+    /// Frees the buffer if it was never disposed.
+    /// </summary>
+    ~EguiStringBuffer() {{
+        Free();
+    }}
+
+    /// <summary>
+    /// Gets the buffer, throwing if it has already been freed.
+    /// </summary>
+    internal VxStringBuffer* Pointer => _pointer == null ? throw new ObjectDisposedException(nameof(EguiStringBuffer)) : _pointer;
+
+    /// <summary>
+    /// The text held by the buffer, which is copied each time it is read or written.
+    /// </summary>
+    public string Text {{
+        get => System.Text.Encoding.UTF8.GetString({get}(Pointer), checked((int){len}(Pointer)));
+        set {{
+            var bytes = System.Text.Encoding.UTF8.GetBytes(value ?? throw new ArgumentNullException(nameof(value)));
+            fixed (byte* ptr = bytes) {{
+                {set}(Pointer, ptr, (nuint)bytes.Length);
+            }}
+        }}
+    }}
+
+    /// <summary>
+    /// Frees the buffer. Calling this more than once has no effect.
+    /// </summary>
+    public void Dispose() {{
+        Free();
+        GC.SuppressFinalize(this);
+    }}
+
+    /// <inheritdoc/>
+    public override string ToString() => Text;
+
+    /// <summary>
+    /// Frees the buffer, unless it has already been freed.
+    /// </summary>
+    private void Free() {{
+        if (_pointer != null) {{
+            {drop}(_pointer);
+            _pointer = null;
+        }}
+    }}
+}}
+"#)
+    }
+
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        let [new, set, get, len, drop] = ["new", "set", "get", "len", "drop"].map(|x| ctx.naming.native_fn(&format!("string_buffer_{x}")));
+        write!(f, r#"/// A string owned by Rust on behalf of C#, which widgets such as text edits modify in place.
+/// The buffer must be freed with [`{drop}`].
+pub struct VxStringBuffer(String);
+
+impl VxStringBuffer {{
+    /// Borrows the string which the pointer refers to, panicking if it is null.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must be null or refer to a live buffer, which is not used
+    /// again until the borrow ends.
+    pub unsafe fn get_mut<'a>(this: *mut Self) -> &'a mut String {{
+        assert!(!this.is_null(), "expected string buffer, got null");
+        &mut (*this).0
+    }}
+}}
+
+/// Creates an empty string buffer.
+#[no_mangle]
+pub extern "C" fn {new}() -> *mut VxStringBuffer {{
+    Box::into_raw(Box::new(VxStringBuffer(String::new())))
+}}
+
+/// Replaces the contents of a string buffer with the provided UTF-8 bytes, replacing invalid sequences.
+/// The allocation of the buffer is reused where possible.
+///
+/// # Safety
+///
+/// For this call to be sound, the buffer must be live, and the pointer must refer to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn {set}(buffer: *mut VxStringBuffer, ptr: *const u8, len: usize) {{
+    // C# pins empty arrays as null, which a slice may not be made from.
+    let bytes = if len == 0 {{ &[][..] }} else {{ std::slice::from_raw_parts(ptr, len) }};
+    let value = &mut (*buffer).0;
+    value.clear();
+    value.push_str(&String::from_utf8_lossy(bytes));
+}}
+
+/// Gets a pointer to the UTF-8 contents of a string buffer, which is valid until the buffer is next modified.
+///
+/// # Safety
+///
+/// For this call to be sound, the buffer must be live.
+#[no_mangle]
+pub unsafe extern "C" fn {get}(buffer: *const VxStringBuffer) -> *const u8 {{
+    (&(*buffer).0).as_ptr()
+}}
+
+/// Gets the length of the contents of a string buffer in bytes.
+///
+/// # Safety
+///
+/// For this call to be sound, the buffer must be live.
+#[no_mangle]
+pub unsafe extern "C" fn {len}(buffer: *const VxStringBuffer) -> usize {{
+    (&(*buffer).0).len()
+}}
+
+/// Frees a string buffer.
+///
+/// # Safety
+///
+/// For this call to be sound, the buffer must be live, and must not be used again.
+#[no_mangle]
+pub unsafe extern "C" fn {drop}(buffer: *mut VxStringBuffer) {{
+    drop(Box::from_raw(buffer));
+}}
+"#)
+    }
+}
+
+/// The support code for the objects of every class, which C# owns by pointer.
+pub struct ObjectSupport;
+
+impl DisplayBindings for ObjectSupport {
+    fn write_cs(&self, f: &mut Formatter, _: &RenderContext) -> Result {
+        f.write_str(CS_OBJECT_SUPPORT)
+    }
+
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        f.write_str(RS_OBJECT_SUPPORT)?;
+        for (class, tag) in &ctx.class_tags {
+            f.write_fmt(format_args!("\nimpl VxClass for {class} {{\n    const TAG: u32 = {tag};\n}}\n"))?;
+        }
+
+        f.write_str("\n/// Gets the name of the class with the given tag, for reporting mismatched handles.\n")?;
+        f.write_str("fn vx_class_name(tag: u32) -> &'static str {\n")?;
+        f.write_str("    match tag {\n")?;
+        for (class, tag) in &ctx.class_tags {
+            f.write_fmt(format_args!("        {tag} => \"{class}\",\n"))?;
+        }
+        f.write_str("        _ => \"an unknown class\"\n")?;
+        f.write_str("    }\n")?;
+        f.write_str("}\n")
+    }
+}
+
+/// The Rust definitions of the objects referred to by [`TypeReference::Handle`].
+pub const RS_OBJECT_SUPPORT: &str = include_str!("support/object.rs");
+
+/// The support code which reports panics caught by the Rust shims as C# exceptions.
+pub struct ErrorSupport;
+
+impl ErrorSupport {
+    /// Gets the Rust functions which the C# definition calls, with their C# signatures.
+    pub fn native_functions(&self, ctx: &RenderContext) -> Vec<NativeFunction> {
+        vec![NativeFunction::new(ctx.naming.native_fn("last_error_message"), [], Some(TypeReference::Primitive(PrimitiveType::String)))]
+    }
+}
+
+impl DisplayBindings for ErrorSupport {
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        let last_error_message = ctx.naming.cs_native_fn(&ctx.naming.native_fn("last_error_message"));
+        write!(f, r#"/// <summary>
+/// A panic in the Rust code called by the bindings. Any Rust objects involved may be left in an
+/// inconsistent state.
+/// </summary>
+public sealed class EguiException : Exception {{
+    /// <summary>
+    /// Creates an exception with the message of the panic.
+    /// </summary>
+    public EguiException(string message) : base(message) {{ }}
+
+    /// <summary>
+    /// Throws the panic caught during the last call into Rust on this thread, if there was one.
+    /// </summary>
+    internal static unsafe void ThrowIfPanicked() {{
+        var message = {last_error_message}();
+        if (!message.IsNull) {{
+            throw new EguiException(VxString.Take(message));
+        }}
+    }}
+
+    /// <summary>
+    /// Returns the value from a call into Rust, unless the call panicked.
+    /// </summary>
+    internal static T Check<T>(T value) {{
+        ThrowIfPanicked();
+        return value;
+    }}
+
+    /// <summary>
+    /// Returns the pointer from a call into Rust, unless the call panicked.
+    /// </summary>
+    internal static unsafe void* Check(void* value) {{
+        ThrowIfPanicked();
+        return value;
+    }}
+}}
+"#)
+    }
+
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        f.write_str(RS_PANIC_SUPPORT)?;
+        let last_error_message = ctx.naming.native_fn("last_error_message");
+        write!(f, r#"
+/// Takes the message of the last panic caught by a shim on this thread, or returns a null string if there was none.
+#[no_mangle]
+pub extern "C" fn {last_error_message}() -> VxString {{
+    match vx_take_error() {{
+        Some(message) => message.into(),
+        None => VxString {{ ptr: std::ptr::null_mut(), len: 0, capacity: 0 }}
+    }}
+}}
+"#)
+    }
+}
+
+/// The Rust definitions with which every shim catches panics.
+pub const RS_PANIC_SUPPORT: &str = include_str!("support/panic.rs");
+
+/// The support code backing [`TypeReference::Slice`].
+pub struct SliceSupport;
+
+impl SliceSupport {
+    /// Gets the Rust functions which the C# definition calls, with their C# signatures.
+    pub fn native_functions(&self, ctx: &RenderContext) -> Vec<NativeFunction> {
+        let params = [("epoch".to_string(), TypeReference::Primitive(PrimitiveType::U64))];
+        vec![NativeFunction::new(ctx.naming.native_fn("frame_kept"), params, Some(TypeReference::Primitive(PrimitiveType::Bool)))]
+    }
+}
+
+impl DisplayBindings for SliceSupport {
+    fn write_cs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        let frame_kept = ctx.naming.cs_native_fn(&ctx.naming.native_fn("frame_kept"));
+        write!(f, r#"/// <summary>
+/// A contiguous sequence of values which may be passed across the FFI boundary.
+/// </summary>
+[StructLayout(LayoutKind.Sequential)]
+public unsafe struct VxSlice<T> where T : unmanaged {{
+    private T* _ptr;
+    private nuint _len;
+    private nuint _capacity;
+    private ulong _epoch;
+
+    /// <summary>
+    /// Creates a slice which borrows elements pinned by C# for the duration of a call.
+    /// </summary>
+    internal VxSlice(T* ptr, int length) {{
+        _ptr = ptr;
+        _len = (nuint)length;
+        _capacity = 0;
+        _epoch = 0;
+    }}
+
+    /// <summary>
+    /// The number of elements in the slice.
+    /// </summary>
+    public int Length => checked((int)_len);
+
+    /// <summary>
+    /// Gets a view of the elements in the slice. Slices which borrow from output kept by Rust, such as the
+    /// meshes of a frame, may only be read until the same output is next returned, after which this throws.
+    /// </summary>
+    public ReadOnlySpan<T> Span {{
+        get {{
+            if (_epoch != 0 && !{frame_kept}(_epoch)) {{
+                throw new InvalidOperationException("The slice borrows from output which has since been freed.");
+            }}
+            return new ReadOnlySpan<T>(_ptr, Length);
+        }}
+    }}
+}}
+"#)
+    }
+
+    fn write_rs(&self, f: &mut Formatter, ctx: &RenderContext) -> Result {
+        f.write_str(RS_SLICE_SUPPORT)?;
+        let frame_kept = ctx.naming.native_fn("frame_kept");
+        write!(f, r#"
+/// Whether the value with the given epoch is still kept, which C# checks before reading a slice.
+#[no_mangle]
+pub extern "C" fn {frame_kept}(epoch: u64) -> bool {{
+    vx_frame_is_kept(epoch)
+}}
+"#)
+    }
+}
+
+/// The Rust definitions backing [`TypeReference::Slice`], including the values kept for C#.
+pub const RS_SLICE_SUPPORT: &str = include_str!("support/slice.rs");
+
+/// The Rust function which frees an owned slice of the given element type.
+pub struct SliceDrop<'a>(pub &'a TypeReference, pub &'a RenderContext);
+
+impl SliceDrop<'_> {
+    /// Gets the exported symbol of the function.
+    fn symbol(&self) -> String {
+        self.1.naming.native_fn(&format!("slice_{}_drop", DisplayRs(self.0, self.1).to_string().to_case(Case::Snake)))
+    }
+
+    /// Gets the signature of the function, for declaration in C#.
+    pub fn native_function(&self) -> NativeFunction {
+        let slice = TypeReference::Slice { element: Box::new(self.0.clone()), ownership: SliceOwnership::Owned };
+        NativeFunction::new(self.symbol(), [("value".to_string(), slice)], None)
+    }
+}
+
+impl Display for SliceDrop<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_rs_slice_drop(f, self.0, &self.symbol(), self.1)
+    }
+}
+
+/// Writes the Rust function which frees an owned slice of the given element type.
+fn write_rs_slice_drop(f: &mut Formatter, element: &TypeReference, symbol: &str, ctx: &RenderContext) -> Result {
+    let element_name = DisplayRs(element, ctx).to_string();
+    f.write_fmt(format_args!("/// Frees an owned slice of `{element_name}`.\n"))?;
+    f.write_str("///\n")?;
+    f.write_str("/// # Safety\n")?;
+    f.write_str("///\n")?;
+    f.write_str("/// For this call to be sound, the slice must have been allocated by Rust and not already freed.\n")?;
+    f.write_str("#[no_mangle]\n")?;
+    f.write_fmt(format_args!("pub unsafe extern \"C\" fn {symbol}(value: VxSlice<{element_name}>) {{\n"))?;
+    f.write_fmt(format_args!("    drop(Vec::from_raw_parts(value.ptr as *mut {element_name}, value.len, value.capacity));\n"))?;
+    f.write_str("}\n")?;
+    Ok(())
+}
+
+/// The reserved keywords of C and C++, which cannot be used as parameter names in a header.
+const C_KEYWORDS: &[&str] = &[
+    "alignas", "alignof", "and", "asm", "auto", "bool", "break", "case", "catch", "char", "class", "const",
+    "constexpr", "continue", "default", "delete", "do", "double", "else", "enum", "explicit", "export", "extern",
+    "false", "float", "for", "friend", "goto", "if", "inline", "int", "long", "mutable", "namespace", "new",
+    "not", "operator", "or", "private", "protected", "public", "register", "restrict", "return", "short",
+    "signed", "sizeof", "static", "struct", "switch", "template", "this", "throw", "true", "try", "typedef",
+    "typename", "union", "unsigned", "using", "virtual", "void", "volatile", "while", "xor"
+];
+
+/// The reserved keywords of C#, which must be escaped to be used as identifiers.
+const CS_KEYWORDS: &[&str] = &[
+    "abstract", "as", "base", "bool", "break", "byte", "case", "catch", "char", "checked", "class", "const",
+    "continue", "decimal", "default", "delegate", "do", "double", "else", "enum", "event", "explicit", "extern",
+    "false", "finally", "fixed", "float", "for", "foreach", "goto", "if", "implicit", "in", "int", "interface",
+    "internal", "is", "lock", "long", "namespace", "new", "null", "object", "operator", "out", "override",
+    "params", "private", "protected", "public", "readonly", "ref", "return", "sbyte", "sealed", "short",
+    "sizeof", "stackalloc", "static", "string", "struct", "switch", "this", "throw", "true", "try", "typeof",
+    "uint", "ulong", "unchecked", "unsafe", "ushort", "using", "virtual", "void", "volatile", "while"
+];
+
+/// Removes the prefix of a raw Rust identifier, like the `r#` of `r#ref`.
+fn raw_identifier(name: &str) -> &str {
+    name.strip_prefix("r#").unwrap_or(name)
+}
+
+/// Converts a Rust identifier into a valid C# identifier, optionally changing its case.
+/// Raw identifier prefixes are removed, and C# keywords are escaped with `@`.
+fn cs_identifier(name: &str, case: Option<Case>) -> String {
+    let name = match case {
+        Some(case) => raw_identifier(name).to_case(case),
+        None => raw_identifier(name).to_string()
+    };
+
+    if CS_KEYWORDS.contains(&name.as_str()) {
+        format!("@{name}")
+    }
+    else {
+        name
+    }
+}
+
+/// Writes generated code, indenting each non-blank line by four spaces per level. Blank lines at
+/// the end of an indented block are dropped, so that blocks never end with empty lines.
+pub struct CodeWriter<'a> {
+    /// The destination of the code.
+    out: &'a mut dyn Write,
+    /// The options with which nested values are formatted.
+    options: FormattingOptions,
+    /// The current level of indentation.
+    level: usize,
+    /// The number of blank lines held back when each level of indentation was pushed.
+    held: Vec<usize>,
+    /// The number of blank lines which have not yet been written, as they may end the block.
+    blank_lines: usize,
+    /// Whether nothing has been written since the last line ended.
+    line_start: bool
+}
+
+impl<'a> CodeWriter<'a> {
+    /// Creates a writer with no indentation.
+    pub fn new(out: &'a mut dyn Write) -> Self {
+        Self { out, options: FormattingOptions::new(), level: 0, held: Vec::new(), blank_lines: 0, line_start: true }
+    }
+
+    /// Creates a writer with no indentation which formats nested values with the options of the given formatter.
+    pub fn from_formatter(f: &'a mut Formatter<'_>) -> Self {
+        let options = f.options();
+        Self { options, ..Self::new(f) }
+    }
+
+    /// Gets a formatter which writes through this writer, for [`DisplayBindings`] implementations.
+    pub fn formatter(&mut self) -> Formatter<'_> {
+        let options = self.options;
+        Formatter::new(self, options)
+    }
+
+    /// Indents the lines which follow by one more level.
+    pub fn push_indent(&mut self) {
+        self.held.push(self.blank_lines);
+        self.level += 1;
+    }
+
+    /// Ends the current level of indentation, finishing the last line and dropping any blank lines after it.
+    pub fn pop_indent(&mut self) -> Result {
+        if !self.line_start {
+            self.out.write_char('\n')?;
+            self.line_start = true;
         }
+        self.level -= 1;
+        self.blank_lines = self.held.pop().expect("Indentation was popped more often than it was pushed");
         Ok(())
     }
+}
 
-    fn write_rs(&self, f: &mut Formatter) -> Result {
-        match self {
-            Item::Enum { variants, .. } => {
-                write_rs_docs(f, self.docs())?;
-                f.write_str("#[derive(Copy, Clone)]]\n")?;
-                f.write_str("#[repr(C)]\n")?;
-                f.write_fmt(format_args!("pub enum {} {{\n", self.rs_name()))?;
-                
-                let mut members = String::new();
-                for variant in variants {
-                    write!(&mut members, "{}\n", DisplayRs(variant))?;
-                }
-                f.write_str(&indent(&members))?;
+impl Write for CodeWriter<'_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        for piece in s.split_inclusive('\n') {
+            let (line, newline) = match piece.strip_suffix('\n') {
+                Some(line) => (line, true),
+                None => (piece, false)
+            };
 
-                f.write_str("}\n")?;
-            },
-            Item::Class { .. } => {
-                self.write_rs_destructor(f);
-            },
-            Item::Struct { fields, has_default, .. } => {
-                write_rs_docs(f, self.docs())?;
-                f.write_str("#[derive(Copy, Clone)]]\n")?;
-                f.write_str("#[repr(C)]\n")?;
-                f.write_fmt(format_args!("pub struct {} {{\n", self.rs_name()))?;
-                
-                let mut members = String::new();
-                for field in fields {
-                    write!(&mut members, "{}\n", DisplayRs(field))?;
+            if !line.is_empty() {
+                if self.line_start {
+                    for _ in 0..self.blank_lines {
+                        self.out.write_char('\n')?;
+                    }
+                    self.blank_lines = 0;
+                    self.held.iter_mut().for_each(|x| *x = 0);
+                    for _ in 0..self.level {
+                        self.out.write_str("    ")?;
+                    }
+                    self.line_start = false;
                 }
-                f.write_str(&indent(&members))?;
-
-                f.write_str("}\n\n")?;
+                self.out.write_str(line)?;
+            }
 
-                if *has_default {
-                    self.write_rs_struct_default(f)?;
-                    f.write_str("\n")?;
+            if newline {
+                if self.line_start && self.level > 0 {
+                    self.blank_lines += 1;
+                }
+                else {
+                    self.out.write_char('\n')?;
+                    self.line_start = true;
                 }
             }
         }
@@ -319,103 +4146,261 @@ impl DisplayBindings for Item {
     }
 }
 
-/// An enum variant.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct EnumVariant {
-    /// The name of the variant.
-    pub name: String,
-    /// The index of the variant, if any.
-    pub index: Option<u64>,
-    /// The doc-comment to include.
-    pub docs: String
+/// Adapts an [`std::io::Write`] to be written with [`Write`], keeping the first error which it returns.
+pub struct IoWriter<'a> {
+    /// The destination of the text.
+    out: &'a mut dyn std::io::Write,
+    /// The first error returned by the destination.
+    error: Option<std::io::Error>
 }
 
-impl DisplayBindings for EnumVariant {
-    fn write_cs(&self, f: &mut Formatter<'_>) -> Result {
-        write_cs_docs(f, &self.docs)?;
-        if let Some(index) = self.index {
-            f.write_fmt(format_args!("{} = {index},", self.name))?;
-        }
-        else {
-            f.write_fmt(format_args!("{},", self.name))?;
-        }
-
-        Ok(())
+impl<'a> IoWriter<'a> {
+    /// Creates a writer into the given destination.
+    pub fn new(out: &'a mut dyn std::io::Write) -> Self {
+        Self { out, error: None }
     }
 
-    fn write_rs(&self, f: &mut Formatter) -> Result {
-        write_rs_docs(f, &self.docs)?;
-        if let Some(index) = self.index {
-            f.write_fmt(format_args!("{} = {index},", self.name))?;
+    /// Converts the result of formatting into this writer to that of the underlying writes.
+    pub fn finish(self, result: Result) -> std::io::Result<()> {
+        match (result, self.error) {
+            (_, Some(error)) => Err(error),
+            (Ok(()), None) => Ok(()),
+            (Err(_), None) => Err(std::io::Error::other("failed to format the bindings"))
         }
-        else {
-            f.write_fmt(format_args!("{},", self.name))?;
+    }
+}
+
+impl Write for IoWriter<'_> {
+    fn write_str(&mut self, s: &str) -> Result {
+        if self.error.is_some() {
+            return Err(Error);
         }
 
-        Ok(())
+        self.out.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            Error
+        })
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct StructField {
-    /// The name of the field.
-    pub name: String,
-    /// The type of the field.
-    pub ty: TypeReference,
-    /// The doc-comment to include.
-    pub docs: String
+/// Writes a block of code into the formatter, indented by one more level than the surrounding code.
+fn write_indented(f: &mut Formatter, body: impl FnOnce(&mut Formatter) -> Result) -> Result {
+    let mut writer = CodeWriter::from_formatter(f);
+    writer.push_indent();
+    body(&mut writer.formatter())?;
+    writer.pop_indent()
 }
 
-impl StructField {
-    /// Gets the modified type name for the public C# API.
-    pub fn cs_name(&self) -> String {
-        self.name.to_case(Case::Pascal)
-    }
+/// Writes a C# doc-comment, converting its markdown into XML documentation tags. The first
+/// paragraph is written as the summary, and everything after it as remarks.
+fn write_cs_docs(f: &mut Formatter, docs: &str) -> Result {
+    write_cs_xml_docs(f, markdown::to_xml_docs(docs))
+}
 
-    /// Gets the modified type name for C FFI.
-    pub fn rs_name(&self) -> String {
-        self.name.to_string()
+/// Writes the doc-comment of a function, method, or constructor into C#. Documented functions also describe
+/// each of their parameters, their return value, and the exception thrown when they panic.
+fn write_cs_function_docs(f: &mut Formatter, docs: &str, params: &[Parameter], returns: bool) -> Result {
+    if docs.trim().is_empty() {
+        return Ok(());
     }
+
+    let params = params.iter().map(|x| (x.name.clone(), x.cs_name().trim_start_matches('@').to_string())).collect::<Vec<_>>();
+    write_cs_xml_docs(f, markdown::to_xml_function_docs(docs, &params, returns))
 }
 
-impl DisplayBindings for StructField {
-    fn write_cs(&self, f: &mut Formatter) -> Result {
-        write_cs_docs(f, &self.docs)?;
-        f.write_fmt(format_args!("public {} {};\n", DisplayCs(&self.ty), self.cs_name()))
-    }
+/// Writes the elements of a C# XML doc-comment. The summary, exception, and remarks are written as blocks,
+/// while parameters and return values are written on a single line where they fit.
+fn write_cs_xml_docs(f: &mut Formatter, docs: markdown::XmlDocs) -> Result {
+    let block = |f: &mut Formatter, tag: &str, body: &str| {
+        if !body.is_empty() {
+            let end = tag.split(' ').next().unwrap_or(tag);
+            f.write_fmt(format_args!("/// <{tag}>\n"))?;
+            f.write_fmt(format_args!("/// {}\n", body.replace("\n", "\n/// ")))?;
+            f.write_fmt(format_args!("/// </{end}>\n"))?;
+        }
+        Ok(())
+    };
 
-    fn write_rs(&self, f: &mut Formatter) -> Result {
-        write_rs_docs(f, &self.docs)?;
-        f.write_fmt(format_args!("pub {}: {},", self.rs_name(), DisplayRs(&self.ty)))
+    block(f, "summary", &docs.summary)?;
+    for (name, body) in &docs.params {
+        f.write_fmt(format_args!("/// <param name=\"{name}\">{}</param>\n", body.replace("\n", "\n/// ")))?;
     }
+    if let Some(returns) = &docs.returns {
+        f.write_fmt(format_args!("/// <returns>{}</returns>\n", returns.replace("\n", "\n/// ")))?;
+    }
+    block(f, "exception cref=\"EguiException\"", &docs.exception)?;
+    block(f, "remarks", &docs.remarks)
+}
+
+/// Writes a C# `[Obsolete]` attribute if the item is deprecated.
+fn write_cs_deprecation(f: &mut Formatter, deprecation: Option<&Deprecation>, ctx: &RenderContext) -> Result {
+    deprecation.map_or(Ok(()), |x| x.write_cs(f, ctx))
 }
 
-/// Adds one level of indentation (four spaces) to every line
-/// of the string.
-fn indent(value: &str) -> String {
-    if value.is_empty() {
-        String::new()
+/// Writes a comment naming the Rust item which a binding was generated from, if it has one and such comments
+/// are enabled. The comment is the same in C# and Rust.
+fn write_origin(f: &mut Formatter, origin: Option<&Origin>, ctx: &RenderContext) -> Result {
+    match origin.filter(|_| ctx.source_comments) {
+        Some(origin) => f.write_fmt(format_args!("// {origin}\n")),
+        None => Ok(())
     }
-    else {
-        "    ".to_string() + &value.trim_end().replace("\n", "\n    ") + "\n"
+}
+
+/// Writes a Rust `#[deprecated]` attribute if the item is deprecated.
+fn write_rs_deprecation(f: &mut Formatter, deprecation: Option<&Deprecation>, ctx: &RenderContext) -> Result {
+    deprecation.map_or(Ok(()), |x| x.write_rs(f, ctx))
+}
+
+/// Writes a doc-comment into a C header. Trailing backslashes are removed, since they would
+/// continue the comment onto the next line.
+fn write_c_docs(f: &mut Formatter, docs: &str) -> Result {
+    for line in markdown::strip_link_destinations(docs.trim_end()).lines() {
+        f.write_fmt(format_args!("/// {}\n", line.trim_end_matches('\\')))?;
     }
+
+    Ok(())
 }
 
-/// Writes a C# summary doc-comment.
-fn write_cs_docs(f: &mut Formatter, docs: &str) -> Result {
+/// Writes the safety section of a Rust shim which takes objects by handle, and gets the qualifier which
+/// makes it `unsafe`. Shims without handles are safe, and have no such section.
+fn write_rs_handle_safety(f: &mut Formatter, docs: &str, params: &[Parameter]) -> std::result::Result<&'static str, Error> {
+    if !params.iter().any(|x| matches!(x.ty, TypeReference::Handle { .. } | TypeReference::HandleMut { .. })) {
+        return Ok("");
+    }
+
     if !docs.is_empty() {
-        f.write_str("/// <summary>\n")?;
-        f.write_fmt(format_args!("/// {}\n", docs.trim_end().replace("\n", "\n/// ")))?;
-        f.write_str("/// </summary>\n")?;
+        f.write_str("///\n")?;
     }
-    Ok(())
+    f.write_str("/// # Safety\n")?;
+    f.write_str("///\n")?;
+    f.write_str("/// For this call to be sound, the pointers must be null or refer to live objects of generated classes.\n")?;
+    Ok("unsafe ")
 }
 
 /// Writes a Rust doc-comment.
 fn write_rs_docs(f: &mut Formatter, docs: &str) -> Result {
     if !docs.is_empty() {
-        f.write_fmt(format_args!("/// {}\n", docs.trim_end().replace("\n", "\n/// ")))?;
+        f.write_fmt(format_args!("/// {}\n", markdown::strip_link_destinations(docs.trim_end()).replace("\n", "\n/// ")))?;
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Writes the body of a C# wrapper which makes the given call into Rust and converts its result. Callbacks
+/// are wrapped in delegates which are kept alive until the call returns, and exceptions thrown by them
+/// are rethrown afterwards, rather than unwinding through Rust. Values passed by `ref` are copied in
+/// before the call and written back after it. Chained builder methods replace the object of their handle with
+/// the one they return, and return the handle itself.
+fn write_cs_wrapper_body(f: &mut Formatter, params: &[Parameter], call: &str, ret: Option<&TypeReference>, chained: bool, ctx: &RenderContext) -> Result {
+    let callbacks = params.iter().filter(|x| matches!(x.ty, TypeReference::Delegate { .. })).collect::<Vec<_>>();
+    let in_outs = params.iter().filter_map(|x| match &x.ty {
+        TypeReference::InOut(inner) => Some((x.cs_name(), inner)),
+        _ => None
+    }).collect::<Vec<_>>();
+    let slices = params.iter().filter_map(|x| match &x.ty {
+        TypeReference::Slice { element, .. } => Some((x.cs_name(), element)),
+        _ => None
+    }).collect::<Vec<_>>();
+    if callbacks.is_empty() && in_outs.is_empty() && slices.is_empty() {
+        return match ret {
+            Some(ty) if chained => f.write_fmt(format_args!(" {{ ReplacePointer({}); return this; }}", ty.cs_checked(call, ctx))),
+            Some(ty) => f.write_fmt(format_args!(" => {};", ty.cs_from_native(&ty.cs_checked(call, ctx), ctx))),
+            None => f.write_fmt(format_args!(" {{ {call}; EguiException.ThrowIfPanicked(); }}"))
+        };
+    }
+
+    let write_body = |f: &mut Formatter| -> Result {
+        for callback in &callbacks {
+            let TypeReference::Delegate { rs_name, cs_name } = &callback.ty else { unreachable!() };
+            let (delegate_params, delegate_ret) = &ctx.delegates[rs_name];
+            let name = callback.cs_name();
+            let native_names = delegate_params.iter().map(|x| format!("native{}", x.cs_name().to_case(Case::Pascal))).collect::<Vec<_>>();
+
+            // Objects are borrowed from Rust for the duration of the callback, so their handles do not free them.
+            let mut arguments = Vec::with_capacity(delegate_params.len());
+            let mut borrows = String::new();
+            for (param, native_name) in delegate_params.iter().zip(&native_names) {
+                match &param.ty {
+                    TypeReference::Handle { class, .. } => {
+                        let borrowed = format!("borrowed{}", param.cs_name().to_case(Case::Pascal));
+                        borrows += &format!("    using var {borrowed} = new {}({native_name}, false);\n", ctx.class_name(class));
+                        arguments.push(borrowed);
+                    },
+                    other => arguments.push(other.cs_from_native(native_name, ctx))
+                }
+            }
+
+            let invoke = format!("{name}({})", arguments.join(", "));
+            f.write_fmt(format_args!("System.Runtime.ExceptionServices.ExceptionDispatchInfo {name}Error = null;\n"))?;
+            f.write_fmt(format_args!("{cs_name} {name}Callback = ({}) => {{\n", native_names.iter().map(String::as_str).chain(["_"]).collect::<Vec<_>>().join(", ")))?;
+            f.write_str(&borrows)?;
+            f.write_str("    try {\n")?;
+            match delegate_ret {
+                Some(ty) => f.write_fmt(format_args!("        return {};\n", ty.cs_to_native(&invoke)))?,
+                None => f.write_fmt(format_args!("        {invoke};\n"))?
+            }
+            f.write_str("    }\n")?;
+            f.write_str("    catch (Exception exception) {\n")?;
+            f.write_fmt(format_args!("        {name}Error ??= System.Runtime.ExceptionServices.ExceptionDispatchInfo.Capture(exception);\n"))?;
+            if delegate_ret.is_some() {
+                f.write_str("        return default;\n")?;
+            }
+            f.write_str("    }\n")?;
+            f.write_str("};\n")?;
+        }
+
+        for (name, inner) in &in_outs {
+            f.write_fmt(format_args!("var {name}Native = {};\n", inner.cs_to_native(name)))?;
+        }
+        match ret {
+            Some(_) => f.write_fmt(format_args!("var result = {call};\n"))?,
+            None => f.write_fmt(format_args!("{call};\n"))?
+        }
+        for (name, inner) in &in_outs {
+            f.write_fmt(format_args!("{name} = {};\n", inner.cs_from_native(&format!("{name}Native"), ctx)))?;
+        }
+        for callback in &callbacks {
+            f.write_fmt(format_args!("GC.KeepAlive({}Callback);\n", callback.cs_name()))?;
+        }
+        for callback in &callbacks {
+            f.write_fmt(format_args!("{}Error?.Throw();\n", callback.cs_name()))?;
+        }
+        match ret {
+            Some(ty) if chained => f.write_fmt(format_args!("ReplacePointer({});\nreturn this;\n", ty.cs_checked("result", ctx)))?,
+            Some(ty) => f.write_fmt(format_args!("return {};\n", ty.cs_from_native(&ty.cs_checked("result", ctx), ctx)))?,
+            None => f.write_str("EguiException.ThrowIfPanicked();\n")?
+        }
+        Ok(())
+    };
+
+    // Slices are pinned around the call, so that Rust may borrow the elements in place.
+    f.write_str(" {\n")?;
+    if slices.is_empty() {
+        write_indented(f, write_body)?;
+    }
+    else {
+        for (index, (name, element)) in slices.iter().enumerate() {
+            let open = if index + 1 == slices.len() { " {" } else { "" };
+            f.write_fmt(format_args!("    fixed ({}* {name}Ptr = {name}){open}\n", element.cs_native_type(ctx)))?;
+        }
+        write_indented(f, |f| write_indented(f, write_body))?;
+        f.write_str("    }\n")?;
+    }
+    f.write_str("}")
+}
+
+/// Writes the body of a Rust shim, which runs the given lines within `vx_catch` so that a panic does not
+/// unwind into C#. The last line is the returned expression, or a statement if nothing is returned.
+fn write_rs_shim_body(f: &mut Formatter, lines: &[String], returns: bool) -> Result {
+    match lines {
+        [line] if returns => f.write_fmt(format_args!("    vx_catch(|| {line})\n")),
+        [line] => f.write_fmt(format_args!("    vx_catch(|| {{ {line}; }});\n")),
+        _ => {
+            f.write_str("    vx_catch(|| {\n")?;
+            for line in lines {
+                f.write_fmt(format_args!("        {line}\n"))?;
+            }
+            f.write_str(if returns { "    })\n" } else { "    });\n" })
+        }
+    }
+}
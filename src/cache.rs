@@ -0,0 +1,107 @@
+use crate::*;
+use serde::*;
+use std::io;
+
+/// Everything that [`Bindgen::generate`] needs from the rustdoc JSON, once the items to generate have been chosen.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Classification {
+    /// The items for which bindings are generated.
+    pub items: Vec<ag::Item>,
+    /// The names given to the generated types and exported functions.
+    pub naming: ag::NamingConfig,
+    /// A hash of the rustdoc JSON from which the items were collected.
+    pub source_hash: u64,
+    /// Which items were generated, and why the others were skipped.
+    pub report: report::Report,
+    /// The methods listed for a class in the configuration which could not be generated.
+    pub unbound_methods: Vec<report::UnboundMethod>,
+    /// The items which the configuration requires, but which could not be generated.
+    pub missing_requirements: Vec<report::MissingRequirement>
+}
+
+impl Classification {
+    /// Takes the collected items from a context.
+    pub fn new(ctx: BindgenContext) -> Self {
+        Self {
+            report: ctx.report(),
+            unbound_methods: ctx.unbound_methods(),
+            missing_requirements: ctx.missing_requirements(),
+            source_hash: ctx.source_hash,
+            naming: ctx.naming,
+            items: ctx.items
+        }
+    }
+}
+
+/// A cached classification, as it is written to disk.
+#[derive(Serialize)]
+struct EntryRef<'a> {
+    /// The version of the tool which wrote the entry.
+    version: &'a str,
+    /// The key under which the entry was stored.
+    key: u64,
+    /// The cached classification.
+    classification: &'a Classification
+}
+
+/// A cached classification, as it is read from disk.
+#[derive(Deserialize)]
+struct Entry {
+    /// The version of the tool which wrote the entry.
+    version: String,
+    /// The key under which the entry was stored.
+    key: u64,
+    /// The cached classification.
+    classification: Classification
+}
+
+/// The version of the tool, which is part of every key so that entries written by other versions are never used.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Gets the path of the entry with the given key.
+pub fn entry_path(dir: &Path, key: u64) -> PathBuf {
+    dir.join(format!("{key:016x}.json"))
+}
+
+/// Loads the classification stored under the given key. Missing, unreadable, or corrupt entries, and those
+/// written by another version of the tool, are ignored so that the items are classified from scratch.
+pub fn load(dir: &Path, key: u64) -> Option<Classification> {
+    let path = entry_path(dir, key);
+    let json = match std::fs::read(&path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            log::debug!("No cached classification at {}", path.display());
+            return None;
+        },
+        Err(e) => {
+            log::warn!("Ignoring cached classification at {}: {e}", path.display());
+            return None;
+        }
+    };
+
+    match serde_json::from_slice::<Entry>(&json) {
+        Ok(entry) if entry.version == VERSION && entry.key == key => {
+            log::debug!("Loaded cached classification from {}", path.display());
+            Some(entry.classification)
+        },
+        Ok(entry) => {
+            log::warn!("Ignoring cached classification at {}, which was written by version {}", path.display(), entry.version);
+            None
+        },
+        Err(e) => {
+            log::warn!("Ignoring corrupt cached classification at {}: {e}", path.display());
+            None
+        }
+    }
+}
+
+/// Stores a classification under the given key. The entry is written to a temporary file first, so that
+/// concurrent runs never read a partially-written entry.
+pub fn store(dir: &Path, key: u64, classification: &Classification) -> io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let path = entry_path(dir, key);
+    let temp = path.with_extension(format!("{}.tmp", std::process::id()));
+    let json = serde_json::to_vec(&EntryRef { version: VERSION, key, classification }).map_err(io::Error::other)?;
+    std::fs::write(&temp, json)?;
+    std::fs::rename(&temp, &path).inspect_err(|_| _ = std::fs::remove_file(&temp))
+}
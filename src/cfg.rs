@@ -0,0 +1,116 @@
+use std::collections::*;
+use std::fmt::*;
+use syn::punctuated::Punctuated;
+
+/// The predicate of a `#[cfg(...)]` attribute, such as `all(unix, feature = "persistence")`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CfgPredicate {
+    /// An option which is either set or unset, such as `debug_assertions`.
+    Name(String),
+    /// An option with a value, such as `target_arch = "wasm32"`.
+    KeyValue(String, String),
+    /// Satisfied if every predicate is.
+    All(Vec<CfgPredicate>),
+    /// Satisfied if any predicate is.
+    Any(Vec<CfgPredicate>),
+    /// Satisfied if the predicate is not.
+    Not(Box<CfgPredicate>)
+}
+
+impl CfgPredicate {
+    /// Parses the predicate of an attribute as recorded in rustdoc JSON, like `#[cfg(unix)]`.
+    /// Returns [`None`] if the attribute is not a `cfg`.
+    pub fn from_attribute(attribute: &str) -> Option<std::result::Result<Self, String>> {
+        let meta = attribute.strip_prefix("#[")?.strip_suffix(']')?;
+        let syn::Meta::List(list) = syn::parse_str::<syn::Meta>(meta).ok()? else { return None };
+        if !list.path.is_ident("cfg") {
+            return None;
+        }
+
+        Some(list.parse_args::<syn::Meta>().map_err(|e| e.to_string()).and_then(|x| Self::from_meta(&x)))
+    }
+
+    /// Converts a parsed predicate.
+    fn from_meta(meta: &syn::Meta) -> std::result::Result<Self, String> {
+        let name = |path: &syn::Path| path.get_ident().map(|x| x.to_string()).ok_or_else(|| "expected an identifier".to_string());
+        match meta {
+            syn::Meta::Path(path) => Ok(Self::Name(name(path)?)),
+            syn::Meta::NameValue(x) => match &x.value {
+                syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(value), .. }) => Ok(Self::KeyValue(name(&x.path)?, value.value())),
+                _ => Err(format!("expected a string value for `{}`", name(&x.path)?))
+            },
+            syn::Meta::List(list) => {
+                let args = list.parse_args_with(Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated).map_err(|e| e.to_string())?;
+                let mut args = args.iter().map(Self::from_meta).collect::<std::result::Result<Vec<_>, _>>()?;
+                match name(&list.path)?.as_str() {
+                    "all" => Ok(Self::All(args)),
+                    "any" => Ok(Self::Any(args)),
+                    "not" if args.len() == 1 => Ok(Self::Not(Box::new(args.remove(0)))),
+                    "not" => Err("expected exactly one predicate in `not`".to_string()),
+                    other => Err(format!("unknown predicate `{other}`"))
+                }
+            }
+        }
+    }
+
+    /// Whether the predicate holds for the given target.
+    pub fn evaluate(&self, target: &TargetCfg) -> bool {
+        match self {
+            Self::Name(name) => target.names.contains(name),
+            Self::KeyValue(key, value) => target.values.contains(&(key.clone(), value.clone())),
+            Self::All(predicates) => predicates.iter().all(|x| x.evaluate(target)),
+            Self::Any(predicates) => predicates.iter().any(|x| x.evaluate(target)),
+            Self::Not(predicate) => !predicate.evaluate(target)
+        }
+    }
+}
+
+impl Display for CfgPredicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let list = |f: &mut Formatter<'_>, name: &str, predicates: &[CfgPredicate]| {
+            f.write_fmt(format_args!("{name}("))?;
+            for (i, predicate) in predicates.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                Display::fmt(predicate, f)?;
+            }
+            f.write_str(")")
+        };
+
+        match self {
+            Self::Name(name) => f.write_str(name),
+            Self::KeyValue(key, value) => write!(f, "{key} = {value:?}"),
+            Self::All(predicates) => list(f, "all", predicates),
+            Self::Any(predicates) => list(f, "any", predicates),
+            Self::Not(predicate) => write!(f, "not({predicate})")
+        }
+    }
+}
+
+/// The configuration options set when building for a particular target, against which
+/// `#[cfg(...)]` attributes are evaluated.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TargetCfg {
+    /// The options which are set without a value, such as `unix`.
+    names: BTreeSet<String>,
+    /// The options which are set with a value, such as `target_os = "linux"`.
+    values: BTreeSet<(String, String)>
+}
+
+impl TargetCfg {
+    /// Sets an option, written as it would be passed to `rustc --cfg`, such as `unix` or `target_arch = "x86_64"`.
+    pub fn set(&mut self, option: &str) -> std::result::Result<(), String> {
+        match CfgPredicate::from_meta(&syn::parse_str::<syn::Meta>(option).map_err(|e| e.to_string())?)? {
+            CfgPredicate::Name(name) => self.names.insert(name),
+            CfgPredicate::KeyValue(key, value) => self.values.insert((key, value)),
+            _ => return Err(format!("expected `name` or `name = \"value\"`, found `{option}`"))
+        };
+        Ok(())
+    }
+
+    /// Enables a feature of the bound crate.
+    pub fn enable_feature(&mut self, feature: impl Into<String>) {
+        self.values.insert(("feature".to_string(), feature.into()));
+    }
+}
@@ -0,0 +1,412 @@
+use crate::ag::*;
+use std::collections::*;
+use std::fmt::*;
+
+/// Two sources which generate the same name, such that the bindings would not compile or link.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Collision {
+    /// The kind of name, such as `C# type` or `exported symbol`.
+    pub kind: &'static str,
+    /// The name which is generated twice.
+    pub name: String,
+    /// The source which generated the name first.
+    pub first: String,
+    /// The source which generated the name again.
+    pub second: String
+}
+
+impl Display for Collision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "Generated {} `{}` comes from both `{}` and `{}`", self.kind, self.name, self.first, self.second)
+    }
+}
+
+/// The members of `System.Object`, which generated members should not hide.
+const CS_OBJECT_MEMBERS: &[&str] = &["Equals", "Finalize", "GetHashCode", "GetType", "MemberwiseClone", "ReferenceEquals", "ToString"];
+
+/// A generated C# member which hides a member of `System.Object`, so that the inherited member can no longer be called by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HiddenMember {
+    /// The hidden member, qualified with the type which contains it.
+    pub name: String,
+    /// The source which generated the member.
+    pub source: String
+}
+
+impl Display for HiddenMember {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "Generated C# member `{}` from `{}` hides a member of System.Object", self.name, self.source)
+    }
+}
+
+/// Finds every generated C# member which hides a member of `System.Object`, like a method named `Equals`.
+pub fn find_hidden_members(items: &[Item], naming: &NamingConfig) -> Vec<HiddenMember> {
+    generated_names(items, naming).into_iter()
+        .filter(|x| x.kind == "C# member" && x.origin.renameable() && x.scope != x.name && CS_OBJECT_MEMBERS.contains(&x.name.as_str()))
+        .map(|x| HiddenMember { name: x.display(), source: x.origin.source(items) })
+        .collect()
+}
+
+/// Finds every generated C# type name, C# member name, and exported Rust symbol which is produced
+/// by more than one source.
+pub fn find_collisions(items: &[Item], naming: &NamingConfig) -> Vec<Collision> {
+    let mut first = HashMap::new();
+    let mut result = Vec::new();
+    for name in generated_names(items, naming) {
+        let display = name.display();
+        match first.entry((name.kind, name.scope, name.name)) {
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(name.origin);
+            },
+            hash_map::Entry::Occupied(entry) => result.push(Collision {
+                kind: name.kind,
+                name: display,
+                first: entry.get().source(items),
+                second: name.origin.source(items)
+            })
+        }
+    }
+    result
+}
+
+/// Renames items and members until no generated names collide, by appending the smallest numeric
+/// suffix that makes each name unique. Sources which come later in the item order are renamed, so
+/// the result is deterministic. Names that cannot be renamed, such as those of support types, are
+/// left in place to be reported by [`find_collisions`].
+pub fn disambiguate(items: &mut [Item], naming: &NamingConfig) {
+    // The names to which suffixes are appended, recorded before the first rename of each source.
+    let mut bases = HashMap::<Origin, (String, u32)>::new();
+    loop {
+        let mut first = HashMap::new();
+        let colliding = generated_names(items, naming).into_iter().find_map(|name| {
+            let origin = name.origin;
+            match first.entry((name.kind, name.scope, name.name)) {
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(origin);
+                    None
+                },
+                hash_map::Entry::Occupied(_) => origin.renameable().then_some(origin)
+            }
+        });
+
+        let Some(origin) = colliding else { break };
+        let (base, suffix) = bases.entry(origin).or_insert_with(|| (origin.base_name(items), 1));
+        *suffix += 1;
+        log::debug!("Renaming `{}` with suffix {suffix} to avoid a collision", origin.source(items));
+        origin.rename(items, base, *suffix);
+    }
+}
+
+/// Where a generated name comes from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum Origin {
+    /// The support code which is shared by all bindings.
+    Support,
+    /// An item, which may be renamed.
+    Item(usize),
+    /// A name derived from an item which cannot be renamed, such as the destructor of a class.
+    Fixed(usize),
+    /// A variant of an enum.
+    Variant(usize, usize),
+    /// A field of a struct.
+    Field(usize, usize),
+    /// A constructor of a class.
+    Constructor(usize, usize),
+    /// A method of a class.
+    Method(usize, usize)
+}
+
+impl Origin {
+    /// Whether the name can be changed by renaming its source.
+    fn renameable(&self) -> bool {
+        !matches!(self, Origin::Support | Origin::Fixed(_))
+    }
+
+    /// Gets a description of the source, such as the path of an item or member.
+    fn source(&self, items: &[Item]) -> String {
+        let path = |index: usize| format!("{}::{}", items[index].module(), items[index].name());
+        match *self {
+            Origin::Support => "generated support code".to_string(),
+            Origin::Item(index) | Origin::Fixed(index) => path(index),
+            Origin::Variant(index, member) => match &items[index] {
+                Item::Enum { variants, .. } => format!("{}::{}", path(index), variants[member].name),
+                Item::Union { variants, .. } => format!("{}::{}", path(index), variants[member].name),
+                _ => unreachable!()
+            },
+            Origin::Field(index, member) => {
+                let Item::Struct { fields, .. } = &items[index] else { unreachable!() };
+                format!("{}::{}", path(index), fields[member].name)
+            },
+            Origin::Constructor(index, member) => {
+                let Item::Class { constructors, .. } = &items[index] else { unreachable!() };
+                format!("{}::{}", path(index), constructors[member].name)
+            },
+            Origin::Method(index, member) => {
+                let Item::Class { methods, .. } = &items[index] else { unreachable!() };
+                format!("{}::{}", path(index), methods[member].name)
+            }
+        }
+    }
+
+    /// Gets the name to which a suffix is appended when renaming the source.
+    fn base_name(&self, items: &[Item]) -> String {
+        match *self {
+            Origin::Support | Origin::Fixed(_) => String::new(),
+            Origin::Item(index) => match &items[index] {
+                Item::Enum { .. } | Item::Union { .. } | Item::Class { .. } | Item::Struct { .. } => items[index].cs_name().trim_start_matches('@').to_string(),
+                item => item.name().to_string()
+            },
+            Origin::Variant(index, member) => match &items[index] {
+                Item::Enum { variants, .. } => variants[member].cs_name().trim_start_matches('@').to_string(),
+                Item::Union { variants, .. } => variants[member].cs_name().trim_start_matches('@').to_string(),
+                _ => unreachable!()
+            },
+            Origin::Field(index, member) => {
+                let Item::Struct { fields, .. } = &items[index] else { unreachable!() };
+                fields[member].cs_name().trim_start_matches('@').to_string()
+            },
+            Origin::Constructor(index, member) => {
+                let Item::Class { constructors, .. } = &items[index] else { unreachable!() };
+                constructors[member].name.clone()
+            },
+            Origin::Method(index, member) => {
+                let Item::Class { methods, .. } = &items[index] else { unreachable!() };
+                methods[member].name.clone()
+            }
+        }
+    }
+
+    /// Renames the source by appending the given suffix to its base name.
+    fn rename(&self, items: &mut [Item], base: &str, suffix: u32) {
+        match *self {
+            Origin::Support | Origin::Fixed(_) => {},
+            Origin::Item(index) => match &mut items[index] {
+                Item::Constant { name, .. } | Item::Static { name, .. } | Item::Function { name, .. } => *name = format!("{base}_{suffix}"),
+                _ => rename_type(items, index, format!("{base}{suffix}"))
+            },
+            Origin::Variant(index, member) => match &mut items[index] {
+                Item::Enum { variants, .. } => variants[member].rename = Some(format!("{base}{suffix}")),
+                Item::Union { variants, .. } => variants[member].rename = Some(format!("{base}{suffix}")),
+                _ => unreachable!()
+            },
+            Origin::Field(index, member) => {
+                let Item::Struct { fields, .. } = &mut items[index] else { unreachable!() };
+                fields[member].rename = Some(format!("{base}{suffix}"));
+            },
+            Origin::Constructor(index, member) => {
+                let Item::Class { constructors, .. } = &mut items[index] else { unreachable!() };
+                constructors[member].rename = Some(format!("{base}_{suffix}"));
+            },
+            Origin::Method(index, member) => {
+                let Item::Class { methods, .. } = &mut items[index] else { unreachable!() };
+                methods[member].rename = Some(format!("{base}_{suffix}"));
+            }
+        }
+    }
+}
+
+/// Renames a generated type in C#, and updates every reference to it.
+fn rename_type(items: &mut [Item], index: usize, new_name: String) {
+    let old_reference = items[index].cs_reference_name();
+    let rs_name = items[index].rs_name();
+    let (Item::Enum { rename, .. } | Item::Union { rename, .. } | Item::Class { rename, .. } | Item::Struct { rename, .. }) = &mut items[index] else { unreachable!() };
+    *rename = Some(new_name);
+
+    let new_reference = items[index].cs_reference_name();
+    if old_reference == new_reference {
+        return;
+    }
+
+    for item in items.iter_mut() {
+        item.visit_types_mut(&mut |x| if let TypeReference::Named { rs_name: x_rs_name, cs_name } = x && *x_rs_name == rs_name {
+            *cs_name = new_reference.clone();
+        });
+
+        if let Item::Constant { owner: Some(owner), .. } = item && *owner == old_reference {
+            *owner = new_reference.clone();
+        }
+    }
+}
+
+/// A name in the generated bindings, which must be unique within its scope.
+struct GeneratedName {
+    /// The kind of name.
+    kind: &'static str,
+    /// The C# type containing a member, or an empty string for names which must be globally unique.
+    scope: String,
+    /// The generated name.
+    name: String,
+    /// Where the name comes from.
+    origin: Origin
+}
+
+impl GeneratedName {
+    /// Creates the name of a C# type.
+    fn cs_type(name: String, origin: Origin) -> Self {
+        Self { kind: "C# type", scope: String::new(), name, origin }
+    }
+
+    /// Creates the name of a member within a C# type.
+    fn cs_member(scope: &str, name: String, origin: Origin) -> Self {
+        Self { kind: "C# member", scope: scope.to_string(), name, origin }
+    }
+
+    /// Creates the name of a function exported from Rust.
+    fn symbol(name: String, origin: Origin) -> Self {
+        Self { kind: "exported symbol", scope: String::new(), name, origin }
+    }
+
+    /// Gets the name as it should be reported, qualified with its scope.
+    fn display(&self) -> String {
+        if self.scope.is_empty() {
+            self.name.clone()
+        }
+        else {
+            format!("{}.{}", self.scope, self.name)
+        }
+    }
+}
+
+/// Gets every name which the bindings for the given items generate, in the order that the items are generated.
+fn generated_names(items: &[Item], naming: &NamingConfig) -> Vec<GeneratedName> {
+    let mut result = Vec::new();
+    let support_types = ["VxObject", "VxHandle", "VxString", "VxStringBuffer", "EguiStringBuffer", "EguiException", "VxOption", "VxSlice", "VxLayoutChecks", "Constants", "Functions", "Statics", &naming.library_class_name];
+    for name in support_types {
+        result.push(GeneratedName::cs_type(name.to_string(), Origin::Support));
+    }
+
+    for name in ["Color32", "Rgba"] {
+        if crate::uses_named_type(items, name) {
+            result.push(GeneratedName::cs_type(name.to_string(), Origin::Support));
+        }
+    }
+
+    let ctx = RenderContext { naming: naming.clone(), ..RenderContext::default() };
+    if crate::uses_strings(items) {
+        for function in StringSupport.native_functions(&ctx) {
+            result.push(GeneratedName::symbol(function.name, Origin::Support));
+        }
+    }
+
+    if crate::has_shims(items) {
+        for function in ErrorSupport.native_functions(&ctx) {
+            result.push(GeneratedName::symbol(function.name, Origin::Support));
+        }
+    }
+
+    if crate::uses_string_buffers(items) {
+        for function in StringBufferSupport.native_functions(&ctx) {
+            result.push(GeneratedName::symbol(function.name, Origin::Support));
+        }
+    }
+
+    if crate::uses_type(items, |x| matches!(x, TypeReference::Slice { .. })) {
+        for function in SliceSupport.native_functions(&ctx) {
+            result.push(GeneratedName::symbol(function.name, Origin::Support));
+        }
+    }
+
+    for (index, item) in items.iter().enumerate() {
+        let cs_name = item.cs_name();
+        match item {
+            Item::Enum { variants, .. } => {
+                result.push(GeneratedName::cs_type(cs_name.clone(), Origin::Item(index)));
+                result.push(GeneratedName::cs_type(format!("{}Extensions", cs_name.trim_start_matches('@')), Origin::Item(index)));
+                result.push(GeneratedName::cs_member(&cs_name, cs_name.clone(), Origin::Item(index)));
+                if item.has_implicit_none() {
+                    result.push(GeneratedName::cs_member(&cs_name, "None".to_string(), Origin::Fixed(index)));
+                }
+                if let Some(variant) = item.unknown_variant() {
+                    result.push(GeneratedName::cs_member(&cs_name, variant.cs_name(), Origin::Fixed(index)));
+                }
+                for (member, variant) in variants.iter().enumerate() {
+                    result.push(GeneratedName::cs_member(&cs_name, variant.cs_name(), Origin::Variant(index, member)));
+                }
+            },
+            Item::Union { variants, owns_strings, .. } => {
+                let base = cs_name.trim_start_matches('@');
+                result.push(GeneratedName::cs_type(cs_name.clone(), Origin::Item(index)));
+                result.push(GeneratedName::cs_type(format!("{base}Tag"), Origin::Item(index)));
+                result.push(GeneratedName::cs_type(format!("{base}Payload"), Origin::Item(index)));
+                result.push(GeneratedName::cs_member(&cs_name, cs_name.clone(), Origin::Item(index)));
+                for name in ["Tag", "Payload"].into_iter().chain(owns_strings.then_some("Free")) {
+                    result.push(GeneratedName::cs_member(&cs_name, name.to_string(), Origin::Support));
+                }
+
+                // Each variant names a factory, and a struct holding its data if it has any.
+                for (member, variant) in variants.iter().enumerate() {
+                    result.push(GeneratedName::cs_member(&cs_name, variant.cs_name(), Origin::Variant(index, member)));
+                    if !variant.fields.is_empty() {
+                        result.push(GeneratedName::cs_type(format!("{base}{}", variant.cs_name().trim_start_matches('@')), Origin::Variant(index, member)));
+                    }
+                }
+            },
+            // External types without conversions generate no C#.
+            Item::Struct { external: Some(ExternalType { conversions: false, .. }), .. } => {},
+            Item::Struct { fields, has_default, owns_strings, .. } => {
+                result.push(GeneratedName::cs_type(cs_name.clone(), Origin::Item(index)));
+                result.push(GeneratedName::cs_member(&cs_name, cs_name.clone(), Origin::Item(index)));
+                for name in has_default.then_some("Default").into_iter().chain(owns_strings.then_some("Free")) {
+                    result.push(GeneratedName::cs_member(&cs_name, name.to_string(), Origin::Support));
+                }
+
+                for (member, field) in fields.iter().enumerate() {
+                    result.push(GeneratedName::cs_member(&cs_name, field.cs_name(), Origin::Field(index, member)));
+                }
+            },
+            Item::Class { constructors, methods, has_default, cloneable, safe_handle, .. } => {
+                result.push(GeneratedName::cs_type(cs_name.clone(), Origin::Item(index)));
+                if *safe_handle {
+                    result.push(GeneratedName::cs_type(format!("{}Handle", cs_name.trim_start_matches('@')), Origin::Item(index)));
+                }
+
+                result.push(GeneratedName::cs_member(&cs_name, cs_name.clone(), Origin::Item(index)));
+                let support_members = ["Pointer", "TakePointer", "Dispose"].into_iter()
+                    .chain(has_default.then_some("Default"))
+                    .chain(cloneable.then_some("Clone"))
+                    .chain(safe_handle.then_some("Handle"));
+                for name in support_members {
+                    result.push(GeneratedName::cs_member(&cs_name, name.to_string(), Origin::Support));
+                }
+
+                // Constructors named `new` become C# constructors, which have no name of their own.
+                for (member, constructor) in constructors.iter().enumerate().filter(|(_, x)| x.name != "new") {
+                    result.push(GeneratedName::cs_member(&cs_name, constructor.cs_name(), Origin::Constructor(index, member)));
+                }
+
+                // Instantiations of the same method are C# overloads, which share a name.
+                let overloads = methods.iter().enumerate().filter(|(member, x)| x.instance.is_none() || !methods[..*member].iter().any(|y| y.instance.is_some() && y.name == x.name));
+                for (member, method) in overloads {
+                    result.push(GeneratedName::cs_member(&cs_name, method.cs_name(), Origin::Method(index, member)));
+                }
+            },
+            Item::Constant { owner, .. } => result.push(GeneratedName::cs_member(owner.as_deref().unwrap_or("Constants"), cs_name, Origin::Item(index))),
+            Item::Static { .. } => result.push(GeneratedName::cs_member("Statics", cs_name, Origin::Item(index))),
+            Item::Function { .. } => result.push(GeneratedName::cs_member("Functions", cs_name, Origin::Item(index))),
+            Item::Delegate { .. } => result.push(GeneratedName::cs_type(cs_name, Origin::Item(index)))
+        }
+
+        for function in item.native_functions() {
+            let origin = match item {
+                Item::Class { constructors, methods, .. } => {
+                    let constructor = constructors.iter().position(|x| item.native_fn(&x.rs_fn_name()) == function.name);
+                    let method = methods.iter().position(|x| item.native_fn(&x.rs_fn_name()) == function.name);
+                    match (constructor, method) {
+                        (Some(member), _) => Origin::Constructor(index, member),
+                        (_, Some(member)) => Origin::Method(index, member),
+                        _ => Origin::Fixed(index)
+                    }
+                },
+                Item::Constant { .. } | Item::Static { .. } | Item::Function { .. } => Origin::Item(index),
+                _ => Origin::Fixed(index)
+            };
+            result.push(GeneratedName::symbol(function.name, origin));
+        }
+    }
+
+    for element in crate::owned_slice_elements(items) {
+        result.push(GeneratedName::symbol(SliceDrop(&element, &ctx).native_function().name, Origin::Support));
+    }
+
+    result
+}
@@ -0,0 +1,490 @@
+use serde::{de, Deserialize, Deserializer};
+use std::collections::*;
+use std::fmt::*;
+use std::path::{Path, PathBuf};
+
+/// Settings loaded from a `bindgen.toml` file, which override the built-in decisions of the generator.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    /// The namespace of the generated C# code, from `csharp.namespace`.
+    pub namespace: Option<String>,
+    /// Whether the C# namespace is file-scoped, from `csharp.file_scoped_namespace`.
+    pub file_scoped_namespace: Option<bool>,
+    /// Whether C# classes are owned by a generated `SafeHandle`, from `csharp.safe_handles`.
+    pub safe_handles: Option<bool>,
+    /// The native library which C# loads the bindings from, from `csharp.library`.
+    pub library: Option<String>,
+    /// Whether to declare native functions with `[DllImport]`, from `csharp.dll_import`.
+    pub dll_import: Option<bool>,
+    /// Whether `usize` and `isize` are passed as `ulong` and `long` rather than `nuint` and `nint`, from `csharp.fixed_width_sizes`.
+    pub fixed_width_sizes: Option<bool>,
+    /// Whether `bool` fields are declared with `[MarshalAs(UnmanagedType.U1)]` rather than backed by a `byte`, from `csharp.marshal_bools`.
+    pub marshal_bools: Option<bool>,
+    /// Whether to generate `#[repr(packed)]` structs with a matching C# `Pack`, from `csharp.allow_packed_structs`.
+    pub allow_packed_structs: bool,
+    /// Whether `#[non_exhaustive]` enums are given an `Unknown` variant for values added later, from `csharp.unknown_variants`.
+    pub unknown_variants: bool,
+    /// The prefix of each generated Rust mirror type, from `naming.type_prefix`.
+    pub type_prefix: Option<String>,
+    /// The prefix of each exported function, from `naming.fn_prefix`.
+    pub fn_prefix: Option<String>,
+    /// The name of the C# class declaring the exported functions, from `naming.library_class_name`.
+    pub library_class_name: Option<String>,
+    /// Whether colliding generated names are renamed apart with numeric suffixes, from `naming.disambiguate`.
+    pub disambiguate: Option<bool>,
+    /// The configuration of the build which the bindings are for, from `cfg.options` and `cfg.features`.
+    pub target_cfg: Option<crate::cfg::TargetCfg>,
+    /// Additional types whose marshaling is known, keyed by canonical Rust path.
+    pub known_types: BTreeMap<String, KnownTypeConfig>,
+    /// The structs to generate for instantiations of generic types, tuples, or arrays, keyed by the instantiated
+    /// type, such as `core::ops::range::RangeInclusive<f32>` or `[usize; 2]`.
+    pub instantiations: BTreeMap<String, InstantiationConfig>,
+    /// The canonical paths of items which should never have bindings generated, from `skip.paths`. These may contain
+    /// `*` wildcards, which are matched by [`path_matches`].
+    pub skip: Vec<String>,
+    /// The canonical paths of items which must have bindings generated, from `require.paths`.
+    pub require: Vec<String>,
+    /// The canonical paths of structs whose unsupported fields are left out of their mirrors, and filled from
+    /// `Default` when converted back, from `defaults.paths`.
+    pub defaults: Vec<String>,
+    /// The C# names to use for generated types, struct fields, and methods, keyed by canonical Rust path.
+    pub renames: BTreeMap<String, String>,
+    /// Methods whose signatures are given explicitly, keyed by canonical Rust path, such as `egui::ui::Ui::button`.
+    pub methods: BTreeMap<String, MethodConfig>,
+    /// The methods to generate for each class, keyed by canonical Rust path, such as `egui::ui::Ui`.
+    pub classes: BTreeMap<String, ClassConfig>,
+    /// The least fraction of items which must be generated, from the `coverage` table. This is keyed by a kind of
+    /// item from [`crate::report::CoverageReport::KINDS`], such as `method`, or by `overall` for all items together.
+    pub min_coverage: BTreeMap<String, f64>
+}
+
+impl Config {
+    /// Reads the configuration file at the given path.
+    pub fn from_path(path: &Path) -> std::result::Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|e| ConfigError::Io(path.to_owned(), e))?;
+        Self::parse(&text).map_err(|(line, message)| ConfigError::Parse { path: path.to_owned(), line, message })
+    }
+
+    /// Gets the settings which affect the choice of items to generate, leaving out those which only affect how
+    /// the items are rendered, such as the namespace, or how the results are checked.
+    pub fn classification(&self) -> Self {
+        Self {
+            namespace: None,
+            file_scoped_namespace: None,
+            library: None,
+            dll_import: None,
+            marshal_bools: None,
+            min_coverage: BTreeMap::new(),
+            ..self.clone()
+        }
+    }
+
+    /// Parses a configuration file, returning the line and description of the first error.
+    ///
+    /// ```
+    /// use egui_inspect::config::*;
+    ///
+    /// // Any TOML syntax may be used, such as inline tables, dotted keys, and escapes.
+    /// let config = Config::parse(r#"
+    ///     csharp.namespace = "Egui\u002EInterop"
+    ///     renames = { "egui::Frame" = "EguiFrame" }
+    ///     known_types."emath::Vec2" = { cs_name = "Vector2", rs_name = "Vec2", marshal = "convert", into_fn = "into", from_fn = "from" }
+    ///
+    ///     [skip]
+    ///     paths = [
+    ///         "egui::util",  # Comments may follow values.
+    ///         'egui::os',
+    ///     ]
+    /// "#).unwrap();
+    /// assert_eq!(config.namespace.as_deref(), Some("Egui.Interop"));
+    /// assert_eq!(config.renames["egui::Frame"], "EguiFrame");
+    /// assert_eq!(config.known_types["emath::Vec2"].marshal, MarshalConfig::Convert { into_fn: "into".to_string(), from_fn: "from".to_string() });
+    /// assert_eq!(config.skip, ["egui::util", "egui::os"]);
+    ///
+    /// // Errors name the line on which they occurred.
+    /// let (line, message) = Config::parse("[csharp]\nnamespace = \"Egui\"\nsafe_handles = \"yes\"").unwrap_err();
+    /// assert_eq!(line, 3);
+    /// assert!(message.contains("invalid type: string \"yes\", expected a boolean"), "{message}");
+    ///
+    /// let (line, message) = Config::parse("[csharp]\n\n[skip]\nitems = []").unwrap_err();
+    /// assert_eq!(line, 4);
+    /// assert!(message.contains("unknown field `items`, expected `paths`"), "{message}");
+    ///
+    /// let (line, message) = Config::parse("[coverage]\nmethods = 0.5").unwrap_err();
+    /// assert_eq!(line, 1);
+    /// assert!(message.contains("unknown item kind `methods`"), "{message}");
+    ///
+    /// let (_, message) = Config::parse("[methods.\"egui::Ui::label\"]\nparams = [\"text\"]").unwrap_err();
+    /// assert!(message.contains("expected `name: type`, found `text`"), "{message}");
+    ///
+    /// let (_, message) = Config::parse("[known_types.\"emath::Vec2\"]\ncs_name = \"Vector2\"\nmarshal = \"convert\"").unwrap_err();
+    /// assert!(message.contains("a known type must set `rs_name` unless it is external"), "{message}");
+    /// ```
+    pub fn parse(text: &str) -> std::result::Result<Self, (usize, String)> {
+        let file = toml::from_str::<ConfigFile>(text).map_err(|e| {
+            let line = e.span().map_or(0, |x| text[..x.start].matches('\n').count() + 1);
+            (line, e.message().to_string())
+        })?;
+
+        for (ty, instantiation) in &file.instantiations {
+            if instantiation.name.is_empty() || instantiation.fields.is_empty() {
+                return Err((0, format!("instantiation `{ty}` must set both `name` and `fields`")));
+            }
+        }
+
+        Ok(Self {
+            namespace: file.csharp.namespace,
+            file_scoped_namespace: file.csharp.file_scoped_namespace,
+            safe_handles: file.csharp.safe_handles,
+            library: file.csharp.library,
+            dll_import: file.csharp.dll_import,
+            fixed_width_sizes: file.csharp.fixed_width_sizes,
+            marshal_bools: file.csharp.marshal_bools,
+            allow_packed_structs: file.csharp.allow_packed_structs,
+            unknown_variants: file.csharp.unknown_variants,
+            type_prefix: file.naming.type_prefix,
+            fn_prefix: file.naming.fn_prefix,
+            library_class_name: file.naming.library_class_name,
+            disambiguate: file.naming.disambiguate,
+            target_cfg: file.cfg,
+            known_types: file.known_types,
+            instantiations: file.instantiations,
+            skip: file.skip.paths,
+            require: file.require.paths,
+            defaults: file.defaults.paths,
+            renames: file.renames,
+            methods: file.methods,
+            classes: file.classes,
+            min_coverage: file.coverage
+        })
+    }
+}
+
+/// Whether a canonical path, or the path of an item containing it, matches a configured pattern. Each `*` in
+/// the pattern matches any part of a single path segment.
+///
+/// ```
+/// use egui_inspect::config::path_matches;
+///
+/// assert!(path_matches("egui::Context", "egui::Context"));
+/// assert!(path_matches("egui::Context", "egui::Context::run"));
+/// assert!(!path_matches("egui::Context", "egui::ContextMenu"));
+///
+/// assert!(path_matches("egui::accesskit::*", "egui::accesskit::Role"));
+/// assert!(path_matches("egui::accesskit::*", "egui::accesskit::Role::Button"));
+/// assert!(!path_matches("egui::accesskit::*", "egui::accesskit"));
+/// assert!(path_matches("egui::*::Persistence*", "egui::memory::PersistenceOptions"));
+/// assert!(!path_matches("egui::*::Persistence*", "egui::Persistence"));
+/// ```
+pub fn path_matches(pattern: &str, path: &str) -> bool {
+    let mut path = path.split("::");
+    pattern.split("::").all(|pattern| path.next().is_some_and(|segment| segment_matches(pattern, segment)))
+}
+
+/// Whether a path segment matches a pattern, in which each `*` matches any part of the segment.
+fn segment_matches(pattern: &str, segment: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, rest)) => segment.strip_prefix(prefix).is_some_and(|segment| {
+            segment.char_indices().map(|(i, _)| i).chain([segment.len()]).any(|i| segment_matches(rest, &segment[i..]))
+        }),
+        None => pattern == segment
+    }
+}
+
+/// A type that the configuration declares to be marshalable.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "KnownTypeTable")]
+pub struct KnownTypeConfig {
+    /// The name of the type in the public C# API.
+    pub cs_name: String,
+    /// The name of the type on the Rust side of the FFI boundary. For external types, this
+    /// defaults to the last segment of the path.
+    pub rs_name: String,
+    /// How values of the type cross the FFI boundary.
+    pub marshal: MarshalConfig,
+    /// Whether the C# type already exists outside of the generated code. The Rust mirror of an
+    /// external type is still generated, but its C# struct is not.
+    pub external: bool,
+    /// Whether to generate the C# struct of an external type anyway, with implicit conversions
+    /// to and from the external type.
+    pub conversions: bool
+}
+
+/// A struct to generate for an instantiation of a generic type, such as `RangeInclusiveF32` for
+/// `core::ops::range::RangeInclusive<f32>`. The Rust mirror is converted to and from the instantiated
+/// type with `From` implementations, which must be provided alongside the generated bindings. The fields
+/// of tuples and arrays are their elements, so their conversions are generated.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InstantiationConfig {
+    /// The name of the generated struct.
+    pub name: String,
+    /// The name and type of each field, in order. Types are primitives or the keys of known types,
+    /// such as `f32` or `emath::vec2::Vec2`. Instantiations holding views are views themselves.
+    #[serde(deserialize_with = "deserialize_typed_names")]
+    pub fields: Vec<(String, String)>
+}
+
+/// The signature of a method, given in place of the one in the rustdoc JSON. This binds methods and constructors
+/// whose parameters are generic, like `impl Into<WidgetText>`, and methods which do not exist at all, such as a
+/// `Ui::slider` which calls `ui.add(Slider::new(...))`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MethodConfig {
+    /// The name and type of each configured parameter. Types are `&str`, primitives, the keys of known
+    /// types, an `Option` of either, slices of blittable values like `&[u8]`, which C# passes as a `ReadOnlySpan`,
+    /// `&` or `&mut` references to classes, `&mut` references to primitives,
+    /// enums, or `String`, which C# passes by `ref`, or `&mut StringBuffer`, which C# passes as an
+    /// `EguiStringBuffer` it reuses.
+    /// The parameters of an existing method which are not listed keep their original types.
+    #[serde(deserialize_with = "deserialize_typed_names")]
+    pub params: Vec<(String, String)>,
+    /// The return type, as a primitive, the key of a known type, an `Option` or `Vec` of either, or a `String`,
+    /// which C# takes ownership of.
+    pub returns: Option<String>,
+    /// The Rust expression to evaluate instead of calling the method, in which `this` is the object and
+    /// each parameter is bound by name. Constructors have no `this`. Methods which do not exist are only generated
+    /// if this is set.
+    pub call: Option<String>,
+    /// The doc-comment of the method, replacing the original.
+    pub docs: String,
+    /// Whether the method is exposed as a read-only C# property, like `Response.Clicked`. Properties take
+    /// the object by shared reference, have no parameters, and return a value.
+    pub property: bool,
+    /// The types substituted for `T` in the parameters and return type of a method generated from `call`,
+    /// such as `["f32", "f64", "i32"]` for a slider over any number. Each type generates a C# overload, whose
+    /// exported symbol ends with the type.
+    pub instantiations: Vec<String>
+}
+
+/// The settings of a generated class.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClassConfig {
+    /// Which of the class's methods and constructors to generate, from `methods`.
+    pub methods: MethodSurface
+}
+
+/// Which methods of a class to generate.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum MethodSurface {
+    /// Every method that can be bound, with the others left to the report. Given as `"*"`.
+    #[default]
+    All,
+    /// Only the methods with the given names, across all inherent `impl` blocks of the class. Listed methods which
+    /// cannot be bound, or do not exist, fail generation.
+    Only(Vec<String>)
+}
+
+impl<'de> Deserialize<'de> for MethodSurface {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        /// A method surface as it is written, before the string is checked.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Surface {
+            All(String),
+            Only(Vec<String>)
+        }
+
+        match Surface::deserialize(deserializer) {
+            Ok(Surface::All(x)) if x == "*" => Ok(MethodSurface::All),
+            Ok(Surface::Only(x)) => Ok(MethodSurface::Only(x)),
+            _ => Err(de::Error::custom("expected `\"*\"` or an array of method names"))
+        }
+    }
+}
+
+/// How values of a configured type cross the FFI boundary.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum MarshalConfig {
+    /// The type has the same layout on both sides, and may be copied directly.
+    #[default]
+    Blittable,
+    /// The type is copied into another type using the given conversion functions.
+    Convert {
+        /// The Rust function which converts the original type into the FFI type.
+        into_fn: String,
+        /// The Rust function which converts the FFI type back into the original type.
+        from_fn: String
+    },
+    /// The type lives on the Rust heap and is referred to by an opaque handle.
+    Handle
+}
+
+/// An error that occurred while loading a configuration file.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file could not be read.
+    Io(PathBuf, std::io::Error),
+    /// The file was not a valid configuration.
+    Parse {
+        /// The file that was loaded.
+        path: PathBuf,
+        /// The line on which the error occurred, or zero if it applies to the whole file.
+        line: usize,
+        /// A description of the problem.
+        message: String
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ConfigError::Io(path, err) => write!(f, "Failed to read {}: {err}", path.display()),
+            ConfigError::Parse { path, line: 0, message } => write!(f, "Invalid configuration in {}: {message}", path.display()),
+            ConfigError::Parse { path, line, message } => write!(f, "Invalid configuration in {} on line {line}: {message}", path.display())
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// The tables of a configuration file, as they are written.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct ConfigFile {
+    csharp: CSharpTable,
+    naming: NamingTable,
+    #[serde(deserialize_with = "deserialize_target_cfg")]
+    cfg: Option<crate::cfg::TargetCfg>,
+    #[serde(deserialize_with = "deserialize_coverage")]
+    coverage: BTreeMap<String, f64>,
+    skip: PathsTable,
+    require: PathsTable,
+    defaults: PathsTable,
+    renames: BTreeMap<String, String>,
+    known_types: BTreeMap<String, KnownTypeConfig>,
+    instantiations: BTreeMap<String, InstantiationConfig>,
+    methods: BTreeMap<String, MethodConfig>,
+    classes: BTreeMap<String, ClassConfig>
+}
+
+/// The `csharp` table, which is described by the fields of [`Config`].
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CSharpTable {
+    namespace: Option<String>,
+    file_scoped_namespace: Option<bool>,
+    safe_handles: Option<bool>,
+    library: Option<String>,
+    dll_import: Option<bool>,
+    fixed_width_sizes: Option<bool>,
+    marshal_bools: Option<bool>,
+    allow_packed_structs: bool,
+    unknown_variants: bool
+}
+
+/// The `naming` table, which is described by the fields of [`Config`].
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct NamingTable {
+    type_prefix: Option<String>,
+    fn_prefix: Option<String>,
+    library_class_name: Option<String>,
+    disambiguate: Option<bool>
+}
+
+/// A table holding a list of canonical paths, such as `skip`.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct PathsTable {
+    paths: Vec<String>
+}
+
+/// The `cfg` table, which sets the options and features of the target build.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct CfgTable {
+    /// The `--cfg` options, such as `unix` or `target_os = "linux"`.
+    options: Vec<String>,
+    /// The enabled features.
+    features: Vec<String>
+}
+
+/// A known type as it is written, before its marshaling is checked.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KnownTypeTable {
+    cs_name: String,
+    #[serde(default)]
+    rs_name: String,
+    marshal: Option<MarshalKind>,
+    into_fn: Option<String>,
+    from_fn: Option<String>,
+    #[serde(default)]
+    external: bool,
+    #[serde(default)]
+    conversions: bool
+}
+
+/// How values of a known type cross the FFI boundary, as it is written.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum MarshalKind {
+    Blittable,
+    Convert,
+    Handle
+}
+
+impl TryFrom<KnownTypeTable> for KnownTypeConfig {
+    type Error = String;
+
+    fn try_from(table: KnownTypeTable) -> std::result::Result<Self, String> {
+        if table.rs_name.is_empty() && !table.external {
+            return Err("a known type must set `rs_name` unless it is external".to_string());
+        }
+
+        if table.conversions && !table.external {
+            return Err("a known type must be external to generate conversions".to_string());
+        }
+
+        let marshal = match (table.marshal, table.into_fn, table.from_fn) {
+            (None | Some(MarshalKind::Blittable), None, None) => MarshalConfig::Blittable,
+            (Some(MarshalKind::Handle), None, None) => MarshalConfig::Handle,
+            (None | Some(MarshalKind::Convert), Some(into_fn), Some(from_fn)) => MarshalConfig::Convert { into_fn, from_fn },
+            (None | Some(MarshalKind::Convert), _, _) => return Err("a converted known type must set both `into_fn` and `from_fn`".to_string()),
+            (Some(_), _, _) => return Err("`into_fn` and `from_fn` only apply to known types marshaled with `convert`".to_string())
+        };
+
+        Ok(Self { cs_name: table.cs_name, rs_name: table.rs_name, marshal, external: table.external, conversions: table.conversions })
+    }
+}
+
+/// Deserializes a list of `name: type` pairs, such as the parameters of a method.
+fn deserialize_typed_names<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Vec<(String, String)>, D::Error> {
+    Vec::<String>::deserialize(deserializer)?.into_iter()
+        .map(|x| match x.split_once(':') {
+            Some((name, ty)) => Ok((name.trim().to_string(), ty.trim().to_string())),
+            None => Err(de::Error::custom(format!("expected `name: type`, found `{x}`")))
+        })
+        .collect()
+}
+
+/// Deserializes the `cfg` table into the configuration of the target build.
+fn deserialize_target_cfg<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Option<crate::cfg::TargetCfg>, D::Error> {
+    let table = CfgTable::deserialize(deserializer)?;
+    let mut target = crate::cfg::TargetCfg::default();
+    for option in &table.options {
+        target.set(option).map_err(de::Error::custom)?;
+    }
+
+    for feature in table.features {
+        target.enable_feature(feature);
+    }
+    Ok(Some(target))
+}
+
+/// Deserializes the `coverage` table, checking that each key is a kind of item and each minimum is a fraction.
+fn deserialize_coverage<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<BTreeMap<String, f64>, D::Error> {
+    let coverage = BTreeMap::<String, f64>::deserialize(deserializer)?;
+    for (kind, minimum) in &coverage {
+        if kind != crate::report::CoverageReport::OVERALL && !crate::report::CoverageReport::KINDS.contains(&kind.as_str()) {
+            return Err(de::Error::custom(format!("unknown item kind `{kind}`")));
+        }
+
+        if !(0.0..=1.0).contains(minimum) {
+            return Err(de::Error::custom(format!("the minimum coverage of `{kind}` must be a number from 0 to 1")));
+        }
+    }
+    Ok(coverage)
+}
@@ -0,0 +1,289 @@
+use std::borrow::*;
+use std::fmt::Write;
+use std::path::*;
+
+/// The number of unchanged lines shown around each change in a diff.
+const CONTEXT: usize = 3;
+
+/// The most lines which may be removed and inserted before the search for the fewest edits gives up, as it takes
+/// memory quadratic in their number. Beyond this, the lines which differ are shown as removed and inserted in full.
+const MAX_EDITS: isize = 2048;
+
+/// How a file on disk differs from the contents that would be generated in its place.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mismatch {
+    /// The file does not exist.
+    Missing,
+    /// The file has different contents, described by a unified diff from the file to the generated contents.
+    Changed(String)
+}
+
+/// Compares a file on disk against the contents that would be generated in its place, returning
+/// [`None`] if they match. If `loose` is set, differences in whitespace and blank lines are ignored.
+///
+/// ```
+/// use egui_inspect::diff::{check_file, Mismatch};
+///
+/// let dir = std::env::temp_dir().join(format!("egui_inspect_check_{}", std::process::id()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("Gui.g.cs");
+/// std::fs::write(&path, "namespace Gui {\n    public enum Align { Min }\n}\n").unwrap();
+///
+/// assert_eq!(check_file(&path, "namespace Gui {\n    public enum Align { Min }\n}\n", false).unwrap(), None);
+/// assert_eq!(check_file(&dir.join("Missing.g.cs"), "", false).unwrap(), Some(Mismatch::Missing));
+///
+/// let Some(Mismatch::Changed(diff)) = check_file(&path, "namespace Gui {\n    public enum Align { Max }\n}\n", false).unwrap()
+/// else { panic!() };
+/// assert!(diff.contains("-    public enum Align { Min }\n+    public enum Align { Max }\n"));
+///
+/// let reindented = "namespace Gui {\n\n  public enum Align  { Min }\n}\n";
+/// assert!(check_file(&path, reindented, false).unwrap().is_some());
+/// assert_eq!(check_file(&path, reindented, true).unwrap(), None);
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+pub fn check_file(path: &Path, expected: &str, loose: bool) -> std::io::Result<Option<Mismatch>> {
+    let actual = match std::fs::read_to_string(path) {
+        Ok(x) => x,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Some(Mismatch::Missing)),
+        Err(e) => return Err(e)
+    };
+
+    let name = path.to_string_lossy();
+    Ok(unified_diff(&actual, expected, &name, &format!("{name} (generated)"), loose).map(Mismatch::Changed))
+}
+
+/// Compares two texts line by line, returning a unified diff which turns `old` into `new`, or [`None`] if
+/// they are the same. If `loose` is set, differences in whitespace and blank lines are ignored.
+///
+/// ```
+/// use egui_inspect::diff::unified_diff;
+///
+/// let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+/// let new = "a\nb\nc\nd\nE\nf\ng\nh\ni\n";
+/// assert_eq!(unified_diff(old, old, "old", "new", false), None);
+/// assert_eq!(unified_diff(old, new, "old", "new", false).unwrap(), concat!(
+///     "--- old\n",
+///     "+++ new\n",
+///     "@@ -2,7 +2,8 @@\n",
+///     " b\n",
+///     " c\n",
+///     " d\n",
+///     "-e\n",
+///     "+E\n",
+///     " f\n",
+///     " g\n",
+///     " h\n",
+///     "+i\n"
+/// ));
+///
+/// assert!(unified_diff("a\r\nb\n", "a\nb\n", "old", "new", false).is_some());
+/// assert_eq!(unified_diff("a\r\nb\n", "a\nb\n", "old", "new", true), None);
+/// ```
+pub fn unified_diff(old: &str, new: &str, old_name: &str, new_name: &str, loose: bool) -> Option<String> {
+    if old == new {
+        return None;
+    }
+
+    let old = lines(old, loose);
+    let new = lines(new, loose);
+    let edits = edits(&old, &new);
+    let changes = edits.iter().enumerate().filter(|(_, x)| !matches!(x, Edit::Keep(..))).map(|(i, _)| i).collect::<Vec<_>>();
+    if changes.is_empty() {
+        return None;
+    }
+
+    let mut result = String::new();
+    let _ = write!(&mut result, "--- {old_name}\n+++ {new_name}\n");
+
+    // Changes separated by no more than twice the context share a hunk.
+    let mut start = 0;
+    while start < changes.len() {
+        let mut end = start + 1;
+        while end < changes.len() && changes[end] - changes[end - 1] <= 2 * CONTEXT + 1 {
+            end += 1;
+        }
+
+        let first = changes[start].saturating_sub(CONTEXT);
+        let last = (changes[end - 1] + CONTEXT + 1).min(edits.len());
+        write_hunk(&mut result, &edits[..last], first, &old, &new);
+        start = end;
+    }
+
+    Some(result)
+}
+
+/// A line of text, along with the key by which it is compared.
+struct Line<'a> {
+    /// The line number within the text, starting from one.
+    number: usize,
+    /// The line, including its terminator if it has one.
+    text: &'a str,
+    /// The contents by which the line is compared.
+    key: Cow<'a, str>
+}
+
+impl Line<'_> {
+    /// Writes the line to a diff, after the given marker.
+    fn write(&self, f: &mut String, marker: char) {
+        let text = self.text.strip_suffix('\n').unwrap_or(self.text);
+        let _ = writeln!(f, "{marker}{text}");
+        if !self.text.ends_with('\n') {
+            let _ = writeln!(f, "\\ No newline at end of file");
+        }
+    }
+}
+
+/// Splits a text into lines. Loose lines are compared with their whitespace collapsed, and blank lines are dropped.
+fn lines(text: &str, loose: bool) -> Vec<Line<'_>> {
+    let lines = text.split_inclusive('\n').enumerate().map(|(i, text)| Line { number: i + 1, text, key: Cow::Borrowed(text) });
+    if loose {
+        lines.map(|x| Line { key: Cow::Owned(x.text.split_whitespace().collect::<Vec<_>>().join(" ")), ..x })
+            .filter(|x| !x.key.is_empty())
+            .collect()
+    }
+    else {
+        lines.collect()
+    }
+}
+
+/// A step in turning one list of lines into another.
+#[derive(Copy, Clone, Debug)]
+enum Edit {
+    /// The old and new lines at the given indices are the same.
+    Keep(usize, usize),
+    /// The old line at the given index is removed.
+    Remove(usize),
+    /// The new line at the given index is inserted.
+    Insert(usize)
+}
+
+impl Edit {
+    /// Moves the edit forward by the given number of lines in both texts.
+    fn offset(self, lines: usize) -> Self {
+        match self {
+            Self::Keep(i, j) => Self::Keep(i + lines, j + lines),
+            Self::Remove(i) => Self::Remove(i + lines),
+            Self::Insert(j) => Self::Insert(j + lines)
+        }
+    }
+}
+
+/// Finds a list of edits which turns `old` into `new`, keeping the lines at their start and end which are the same.
+fn edits(old: &[Line], new: &[Line]) -> Vec<Edit> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a.key == b.key).count();
+    let suffix = old[prefix..].iter().rev().zip(new[prefix..].iter().rev()).take_while(|(a, b)| a.key == b.key).count();
+    let (old_end, new_end) = (old.len() - suffix, new.len() - suffix);
+
+    let mut result = (0..prefix).map(|i| Edit::Keep(i, i)).collect::<Vec<_>>();
+    match shortest_edits(&old[prefix..old_end], &new[prefix..new_end]) {
+        Some(edits) => result.extend(edits.into_iter().map(|x| x.offset(prefix))),
+        None => {
+            result.extend((prefix..old_end).map(Edit::Remove));
+            result.extend((prefix..new_end).map(Edit::Insert));
+        }
+    }
+    result.extend((0..suffix).map(|i| Edit::Keep(old_end + i, new_end + i)));
+    result
+}
+
+/// Finds the shortest list of edits which turns `old` into `new` with Myers' algorithm, or [`None`] if it
+/// would be longer than [`MAX_EDITS`].
+fn shortest_edits(old: &[Line], new: &[Line]) -> Option<Vec<Edit>> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+
+    // The furthest old index reached on each diagonal, offset so that diagonal `-max - 1` is at zero.
+    let mut v = vec![0; 2 * max as usize + 3];
+    let at = |k: isize| (k + max + 1) as usize;
+
+    // The diagonals `-d - 1..=d + 1` of `v` before each round `d`, from which the path is traced back.
+    let mut trace = Vec::new();
+    let mut found = false;
+    'search: for d in 0..=max.min(MAX_EDITS) {
+        trace.push(v[at(-d - 1)..=at(d + 1)].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) { v[at(k + 1)] } else { v[at(k - 1)] + 1 };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize].key == new[y as usize].key {
+                x += 1;
+                y += 1;
+            }
+
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                found = true;
+                break 'search;
+            }
+        }
+    }
+
+    if !found {
+        return None;
+    }
+
+    let mut result = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let at = |k: isize| (k + d + 1) as usize;
+        let k = x - y;
+        let previous_k = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) { k + 1 } else { k - 1 };
+        let previous_x = v[at(previous_k)];
+        let previous_y = previous_x - previous_k;
+        while x > previous_x && y > previous_y {
+            x -= 1;
+            y -= 1;
+            result.push(Edit::Keep(x as usize, y as usize));
+        }
+
+        if d > 0 {
+            result.push(if x == previous_x { Edit::Insert(previous_y as usize) } else { Edit::Remove(previous_x as usize) });
+            x = previous_x;
+            y = previous_y;
+        }
+    }
+
+    result.reverse();
+    Some(result)
+}
+
+/// Writes the hunk of a diff which begins with the edit at index `first`, and ends with the last of `edits`.
+fn write_hunk(f: &mut String, edits: &[Edit], first: usize, old: &[Line], new: &[Line]) {
+    // The number of old and new lines before the hunk, from which it is positioned.
+    let (mut old_before, mut new_before) = (0, 0);
+    for edit in &edits[..first] {
+        match edit {
+            Edit::Keep(..) => {
+                old_before += 1;
+                new_before += 1;
+            },
+            Edit::Remove(_) => old_before += 1,
+            Edit::Insert(_) => new_before += 1
+        }
+    }
+
+    let hunk = &edits[first..];
+    let old_count = hunk.iter().filter(|x| !matches!(x, Edit::Insert(_))).count();
+    let new_count = hunk.iter().filter(|x| !matches!(x, Edit::Remove(_))).count();
+    let _ = writeln!(f, "@@ -{} +{} @@", range(old, old_before, old_count), range(new, new_before, new_count));
+
+    for edit in hunk {
+        match *edit {
+            Edit::Keep(i, _) => old[i].write(f, ' '),
+            Edit::Remove(i) => old[i].write(f, '-'),
+            Edit::Insert(j) => new[j].write(f, '+')
+        }
+    }
+}
+
+/// Describes the lines of one side of a hunk, which begins after the first `before` lines. An empty
+/// range is positioned at the line before it.
+fn range(lines: &[Line], before: usize, count: usize) -> String {
+    let start = if count > 0 { lines[before].number } else { before.checked_sub(1).map_or(0, |x| lines[x].number) };
+    if count == 1 {
+        start.to_string()
+    }
+    else {
+        format!("{start},{count}")
+    }
+}
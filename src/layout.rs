@@ -0,0 +1,229 @@
+use crate::ag::*;
+use std::collections::*;
+use std::fmt::*;
+
+/// The size and alignment of a type, in bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    /// The number of bytes that the type occupies, including trailing padding.
+    pub size: usize,
+    /// The alignment that the type requires.
+    pub align: usize
+}
+
+impl Layout {
+    /// Creates a layout whose size and alignment are the same.
+    pub const fn scalar(size: usize) -> Self {
+        Self { size, align: size }
+    }
+
+    /// Gets the layout of an array with the given number of elements of this type.
+    pub const fn repeat(self, len: usize) -> Self {
+        Self { size: self.size * len, align: self.align }
+    }
+
+    /// Computes the layout of a `#[repr(C)]` struct with the given fields, in order. If the
+    /// struct is packed, the alignment of each field is capped at the packing.
+    ///
+    /// ```
+    /// use egui_inspect::layout::Layout;
+    ///
+    /// // A `u8`, `u32`, and `u16` are padded to the alignment of the `u32`.
+    /// let fields = [Layout::scalar(1), Layout::scalar(4), Layout::scalar(2)];
+    /// assert_eq!(Layout::of_struct(fields, None), Layout { size: 12, align: 4 });
+    /// assert_eq!(Layout::of_struct(fields, Some(2)), Layout { size: 8, align: 2 });
+    /// assert_eq!(Layout::of_struct(fields, Some(1)), Layout { size: 7, align: 1 });
+    /// assert_eq!(Layout::of_struct([], None), Layout { size: 0, align: 1 });
+    /// ```
+    pub fn of_struct(fields: impl IntoIterator<Item = Layout>, packing: Option<usize>) -> Self {
+        let mut size = 0usize;
+        let mut align = 1usize;
+        for field in fields {
+            let field_align = packing.map_or(field.align, |x| field.align.min(x));
+            size = size.next_multiple_of(field_align) + field.size;
+            align = align.max(field_align);
+        }
+        Self { size: size.next_multiple_of(align), align }
+    }
+}
+
+/// Computes the layouts of generated types for a target with a particular pointer size.
+pub struct LayoutModel<'a> {
+    /// The generated enums, unions, and structs, keyed by the name of their Rust mirror.
+    items: HashMap<String, &'a Item>,
+    /// The size of a pointer on the target, in bytes.
+    pointer_size: usize,
+    /// The layouts of the structs computed so far.
+    cache: HashMap<String, Layout>
+}
+
+impl<'a> LayoutModel<'a> {
+    /// Creates a model of the given items for a target with the given pointer size.
+    pub fn new(items: &'a [Item], pointer_size: usize) -> Self {
+        let items = items.iter()
+            .filter(|x| matches!(x, Item::Enum { .. } | Item::Union { .. } | Item::Struct { .. }))
+            .map(|x| (x.rs_name(), x))
+            .collect();
+        Self { items, pointer_size, cache: HashMap::new() }
+    }
+
+    /// Gets the layout of a primitive type.
+    pub fn primitive(&self, primitive: &PrimitiveType) -> Layout {
+        match primitive {
+            PrimitiveType::Bool | PrimitiveType::U8 | PrimitiveType::I8 => Layout::scalar(1),
+            PrimitiveType::U16 | PrimitiveType::I16 => Layout::scalar(2),
+            PrimitiveType::Char | PrimitiveType::U32 | PrimitiveType::I32 | PrimitiveType::F32 => Layout::scalar(4),
+            PrimitiveType::U64 | PrimitiveType::I64 | PrimitiveType::F64 => Layout::scalar(8),
+            PrimitiveType::FixedUsize | PrimitiveType::FixedIsize => Layout::scalar(8),
+            PrimitiveType::Usize | PrimitiveType::Isize => Layout::scalar(self.pointer_size),
+            // A pointer, length, and capacity.
+            PrimitiveType::String => Layout::of_struct([Layout::scalar(self.pointer_size); 3], None)
+        }
+    }
+
+    /// Gets the layout of a type as it is passed across the FFI boundary, or [`None`] if it
+    /// refers to a type that was not generated.
+    pub fn type_layout(&mut self, ty: &TypeReference) -> Option<Layout> {
+        Some(match ty {
+            TypeReference::Primitive(primitive) => self.primitive(primitive),
+            TypeReference::Named { rs_name, .. } => self.item_layout(rs_name)?,
+            TypeReference::Array { element, len } => {
+                self.type_layout(element)?.repeat(*len)
+            },
+            TypeReference::Pointer { .. } | TypeReference::Handle { .. } | TypeReference::HandleMut { .. } | TypeReference::InOut(_) | TypeReference::StringBuffer | TypeReference::Delegate { .. } => Layout::scalar(self.pointer_size),
+            TypeReference::Optional(inner) => Layout::of_struct([Layout::scalar(1), self.type_layout(inner)?], None),
+            // A pointer, length, and capacity, followed by the frame that the slice borrows from.
+            TypeReference::Slice { .. } => Layout::of_struct([Layout::scalar(self.pointer_size), Layout::scalar(self.pointer_size), Layout::scalar(self.pointer_size), Layout::scalar(8)], None)
+        })
+    }
+
+    /// Gets the layout of the generated enum, union, or struct whose Rust mirror has the given name.
+    pub fn item_layout(&mut self, rs_name: &str) -> Option<Layout> {
+        if let Some(layout) = self.cache.get(rs_name) {
+            return Some(*layout);
+        }
+
+        let layout = match rs_name {
+            // Colors and texture ids are not generated, but their C# support structs mirror the Rust layout.
+            "Color32" => return Some(Layout::scalar(1).repeat(4)),
+            "Rgba" => return Some(Layout::scalar(4).repeat(4)),
+            "VxTextureId" => return Some(Layout::scalar(8).repeat(2)),
+            _ => *self.items.get(rs_name)?
+        };
+        let layout = match layout {
+            // Fieldless enums without an explicit representation are `#[repr(C)]`, which is an `int`.
+            Item::Enum { repr, .. } => repr.as_ref().map_or(Layout::scalar(4), |x| self.primitive(x)),
+            Item::Struct { fields, packing, .. } => {
+                let fields = fields.iter().map(|x| self.type_layout(&x.ty)).collect::<Option<Vec<_>>>()?;
+                Layout::of_struct(fields, packing.map(|x| x as usize))
+            },
+            // A `#[repr(C, u32)]` enum is a `u32` tag followed by a union of a `#[repr(C)]` struct for each variant.
+            Item::Union { variants, .. } => {
+                let mut payload = Layout::scalar(1);
+                for variant in variants {
+                    let fields = variant.fields.iter().map(|x| self.type_layout(&x.ty)).collect::<Option<Vec<_>>>()?;
+                    let layout = Layout::of_struct(fields, None);
+                    payload = Layout { size: payload.size.max(layout.size), align: payload.align.max(layout.align) };
+                }
+                Layout::of_struct([Layout::scalar(4), Layout::of_struct([payload], None)], None)
+            },
+            _ => return None
+        };
+
+        self.cache.insert(rs_name.to_string(), layout);
+        Some(layout)
+    }
+}
+
+/// Assertions that each generated struct has the same layout in Rust and C#, so that changes to the
+/// bound types are caught at compile time in Rust, and at startup in C#.
+pub struct LayoutChecks {
+    /// The checked structs, along with their layouts on the target.
+    structs: Vec<LayoutCheck>,
+    /// The pointer width of the target in bits.
+    pointer_width: usize
+}
+
+/// The expected layout of a single struct.
+struct LayoutCheck {
+    /// The name of the struct's Rust mirror.
+    rs_name: String,
+    /// The name of the struct in C#.
+    cs_name: String,
+    /// The layout on the target.
+    layout: Layout,
+    /// Whether the layout depends on the pointer width, so that it only holds on the target.
+    pointer_dependent: bool
+}
+
+impl LayoutChecks {
+    /// Computes the expected layouts of the structs among the given items, for a target with the given
+    /// pointer width in bits.
+    pub fn new(items: &[Item], pointer_width: usize) -> Self {
+        let mut model = LayoutModel::new(items, pointer_width / 8);
+        // A layout depends on the pointer width exactly when it changes along with the pointer size.
+        let mut wide_model = LayoutModel::new(items, pointer_width / 4);
+        let structs = items.iter()
+            .filter(|x| matches!(x, Item::Struct { .. } | Item::Union { .. }))
+            .filter_map(|x| {
+                let layout = model.item_layout(&x.rs_name())?;
+                Some(LayoutCheck {
+                    rs_name: x.rs_name(),
+                    cs_name: x.cs_reference_name(),
+                    layout,
+                    pointer_dependent: wide_model.item_layout(&x.rs_name())? != layout
+                })
+            })
+            .collect();
+        Self { structs, pointer_width }
+    }
+
+    /// Whether there are no structs to check.
+    pub fn is_empty(&self) -> bool {
+        self.structs.is_empty()
+    }
+}
+
+impl DisplayBindings for LayoutChecks {
+    fn write_cs(&self, f: &mut Formatter, _: &RenderContext) -> Result {
+        f.write_str("/// <summary>\n")?;
+        f.write_str("/// Verifies that the generated structs have the same size as their Rust counterparts.\n")?;
+        f.write_str("/// </summary>\n")?;
+        f.write_str("public static class VxLayoutChecks {\n")?;
+        f.write_str("    /// <summary>\n")?;
+        f.write_str("    /// Throws an exception if the size of any generated struct differs from the size in Rust.\n")?;
+        f.write_str("    /// </summary>\n")?;
+        f.write_str("    public static void Verify() {\n")?;
+        for check in &self.structs {
+            if check.pointer_dependent {
+                f.write_fmt(format_args!("        if (IntPtr.Size == {}) Check<{}>({});\n", self.pointer_width / 8, check.cs_name, check.layout.size))?;
+            }
+            else {
+                f.write_fmt(format_args!("        Check<{}>({});\n", check.cs_name, check.layout.size))?;
+            }
+        }
+        f.write_str("    }\n\n")?;
+        f.write_str("    private static void Check<T>(int size) {\n")?;
+        f.write_str("        var actual = Marshal.SizeOf<T>();\n")?;
+        f.write_str("        if (actual != size) {\n")?;
+        f.write_str("            throw new InvalidOperationException($\"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.\");\n")?;
+        f.write_str("        }\n")?;
+        f.write_str("    }\n")?;
+        f.write_str("}\n")
+    }
+
+    fn write_rs(&self, f: &mut Formatter, _: &RenderContext) -> Result {
+        for check in &self.structs {
+            if check.pointer_dependent {
+                f.write_fmt(format_args!("#[cfg(target_pointer_width = \"{}\")]\n", self.pointer_width))?;
+            }
+            write_rs_assertion(f, &check.rs_name, check.layout)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes a compile-time assertion that a Rust type has the given layout.
+fn write_rs_assertion(f: &mut Formatter, rs_name: &str, layout: Layout) -> Result {
+    f.write_fmt(format_args!("const _: () = assert!(size_of::<{rs_name}>() == {} && align_of::<{rs_name}>() == {});\n", layout.size, layout.align))
+}
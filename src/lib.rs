@@ -0,0 +1,4258 @@
+//! Generates C# bindings for egui from rustdoc JSON, along with the Rust functions that they call.
+//!
+//! The [`Bindgen`] builder runs the whole pipeline, so that bindings can be generated from a build script:
+//!
+//! ```
+//! use egui_inspect::Bindgen;
+//!
+//! let output = Bindgen::new()
+//!     .input_json(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/tiny.json"))
+//!     .namespace("Tiny")
+//!     .generate()?;
+//!
+//! assert!(output.cs.contains("namespace Tiny {"));
+//! assert!(output.cs.contains("public enum Direction {"));
+//! assert!(output.cs.contains("public static float Area(Size size)"));
+//! assert!(output.rs.contains("pub extern \"C\" fn vx_area("));
+//! assert_eq!(output.report.generated, 3);
+//! # Ok::<(), egui_inspect::GenerateError>(())
+//! ```
+
+#![feature(formatting_options)]
+
+use std::collections::*;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use convert_case::*;
+use rustdoc_types::*;
+
+/// Defines the C# and Rust members that will be autogenerated.
+pub mod ag;
+/// Converts rustdoc markdown into C# XML documentation.
+mod markdown;
+/// Tracks which items were skipped during generation, and why.
+pub mod report;
+/// Loads user configuration which overrides the generator's defaults.
+pub mod config;
+/// Computes the memory layout of generated structs, to check that Rust and C# agree.
+pub mod layout;
+/// Checks that the generated bindings are syntactically valid.
+pub mod validate;
+/// Detects generated names which collide, and renames them apart.
+pub mod collisions;
+/// Evaluates `#[cfg(...)]` attributes against the configuration of the target build.
+pub mod cfg;
+/// Lists the generated items and exported symbols for build pipelines.
+pub mod manifest;
+/// Compares generated output against the files on disk, to check that they are up to date.
+pub mod diff;
+/// Stores the classified items on disk, so that reruns with the same input and configuration skip straight to rendering.
+pub mod cache;
+
+/// Uniquely identifies an item across all of the loaded crates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ItemId {
+    /// The index of the crate within [`BindgenContext::crates`].
+    pub krate: usize,
+    /// The ID of the item within that crate's index.
+    pub id: Id
+}
+
+impl ItemId {
+    /// Creates a new item ID.
+    pub fn new(krate: usize, id: Id) -> Self {
+        Self { krate, id }
+    }
+}
+
+/// The kinds of item which are collected by separate passes, by which the items waiting to be generated are partitioned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum WorkKind {
+    Enum,
+    Struct,
+    TypeAlias,
+    Constant,
+    Static,
+    Function,
+    Method
+}
+
+pub struct BindgenContext {
+    /// The definitions to create in C# and Rust.
+    items: Vec<ag::Item>,
+    /// The rustdoc documents from which bindings are generated.
+    crates: Vec<Crate>,
+    /// Maps the defining path of every crate-local item to its location.
+    local_paths: HashMap<Vec<String>, ItemId>,
+    /// Maps every item to the module or impl block that contains it.
+    parents: HashMap<ItemId, ItemId>,
+    /// The shortest public path of every item reachable from a crate root, following re-exports.
+    public_paths: HashMap<ItemId, Vec<String>>,
+    /// The types that can be referenced by generated code, keyed by canonical Rust path.
+    known_types: HashMap<String, KnownType>,
+    /// The structs to generate for instantiations of generic types, keyed by the [`Self::type_key`] of the instantiation.
+    instantiations: HashMap<String, config::InstantiationConfig>,
+    /// The canonical paths of the traits implemented by each type, keyed by canonical Rust path.
+    trait_impls: HashMap<String, HashSet<String>>,
+    /// Single-field newtypes that should get their own C# struct rather than being
+    /// marshaled as their inner type.
+    distinct_newtypes: HashSet<String>,
+    /// The names of the Rust mirrors of generated structs which have the same layout as the original, so that
+    /// views may borrow vectors of them.
+    c_layouts: HashSet<String>,
+    /// The crate-local items which may have bindings generated, in path order.
+    relevant_items: Vec<ItemId>,
+    /// The items which have not yet been generated, partitioned by the pass which collects them, each in path order.
+    pending: HashMap<WorkKind, Vec<ItemId>>,
+    /// The items which have been generated.
+    collected: HashSet<ItemId>,
+    /// Why each of the remaining items could not be generated.
+    skip_reasons: HashMap<ItemId, report::SkipReason>,
+    /// The instantiations of generic types with no configured struct, along with the canonical paths of the fields which use them.
+    unknown_instantiations: BTreeMap<String, BTreeSet<String>>,
+    /// The fields which were left out of the mirrors of generated structs, and filled from `Default` instead.
+    defaulted_fields: Vec<report::SkippedItem>,
+    /// The errors in the rustdoc JSON of the remaining items which are malformed.
+    errors: HashMap<ItemId, BindgenError>,
+    total_items: usize,
+    /// The items which were considered for generation, after hidden items were excluded.
+    considered_items: Vec<ItemId>,
+    /// Whether `#[doc(hidden)]` and non-public items should have bindings generated.
+    include_hidden: bool,
+    /// The number of items that were skipped for being hidden or private.
+    hidden_items: usize,
+    /// A hash of the rustdoc JSON from which the crates were parsed.
+    source_hash: u64,
+    /// The canonical paths of items (and the members of items) which should not be generated.
+    forced_skips: HashSet<String>,
+    /// The canonical paths of the items which must be generated.
+    required: BTreeSet<String>,
+    /// The canonical paths of structs whose unsupported fields are filled from `Default`, rather than preventing
+    /// the struct from being generated.
+    defaults: HashSet<String>,
+    /// The C# names to use for generated types, struct fields, and methods, keyed by canonical Rust path.
+    renames: HashMap<String, String>,
+    /// Methods whose signatures are given by the configuration, keyed by canonical Rust path.
+    methods: HashMap<String, config::MethodConfig>,
+    /// The names of the only methods to generate for each class, keyed by canonical Rust path.
+    method_surfaces: HashMap<String, Vec<String>>,
+    /// Existing C# types which replace generated structs, keyed by canonical Rust path.
+    external_types: HashMap<String, ag::ExternalType>,
+    /// Whether `#[repr(packed)]` structs should be generated with matching C# packing.
+    allow_packed_structs: bool,
+    /// Whether `#[non_exhaustive]` enums should be given an `Unknown` variant.
+    unknown_variants: bool,
+    /// Whether classes should be owned by a generated `SafeHandle` in C#.
+    safe_handles: bool,
+    /// Whether colliding generated names should be renamed apart with numeric suffixes.
+    disambiguate: bool,
+    /// Whether `usize` and `isize` cross the FFI boundary as 64-bit integers, rather than at the target's pointer width.
+    fixed_width_sizes: bool,
+    /// The configuration of the build which the bindings are for. If set, items disabled by a `#[cfg(...)]` are skipped.
+    target_cfg: Option<cfg::TargetCfg>,
+    /// The names given to the generated types and exported functions.
+    naming: ag::NamingConfig
+}
+
+impl BindgenContext {
+    /// Creates a context from the rustdoc JSON bundled with this tool.
+    pub fn bundled() -> Result<Self, LoadError> {
+        let krate = Self::parse_crate(Path::new("egui.json"), BUNDLED_JSON, false)?;
+        Ok(Self { source_hash: fnv1a(FNV_OFFSET_BASIS, BUNDLED_JSON.as_bytes()), ..Self::from_crates(vec![krate]) })
+    }
+
+    /// Creates a context from the rustdoc JSON file at the given path. Unless `force` is set,
+    /// files produced with an unsupported rustdoc format version are rejected before parsing.
+    pub fn from_path(path: &Path, force: bool) -> Result<Self, LoadError> {
+        Self::from_paths(&[path], force)
+    }
+
+    /// Creates a context from several rustdoc JSON files, such as `egui`, `epaint`, and `emath`.
+    /// References between the crates are resolved by path.
+    pub fn from_paths(paths: &[impl AsRef<Path>], force: bool) -> Result<Self, LoadError> {
+        let mut crates = Vec::with_capacity(paths.len());
+        let mut source_hash = FNV_OFFSET_BASIS;
+        for path in paths {
+            let path = path.as_ref();
+            let json = std::fs::read_to_string(path).map_err(|e| LoadError::Io(path.to_owned(), e))?;
+            source_hash = fnv1a(source_hash, json.as_bytes());
+            crates.push(Self::parse_crate(path, &json, force)?);
+        }
+
+        Ok(Self { source_hash, ..Self::from_crates(crates) })
+    }
+
+    /// Parses a rustdoc crate, first checking that its format version matches [`FORMAT_VERSION`].
+    fn parse_crate(path: &Path, json: &str, force: bool) -> Result<Crate, LoadError> {
+        let header = serde_json::from_str::<FormatHeader>(json).map_err(|e| LoadError::Parse(path.to_owned(), e))?;
+        if header.format_version != FORMAT_VERSION && !force {
+            return Err(LoadError::FormatVersion {
+                path: path.to_owned(),
+                found: header.format_version,
+                crate_version: header.crate_version
+            });
+        }
+
+        serde_json::from_str::<Crate>(json).map_err(|e| LoadError::Parse(path.to_owned(), e))
+    }
+
+    /// Creates a context for a set of already-parsed rustdoc crates.
+    pub fn from_crates(crates: Vec<Crate>) -> Self {
+        let items = Vec::new();
+        
+        let mut local_paths = HashMap::new();
+        let mut parents = HashMap::new();
+        let mut relevant_items = Vec::new();
+        for (index, krate) in crates.iter().enumerate() {
+            for (id, summary) in &krate.paths {
+                if summary.crate_id == 0 {
+                    local_paths.insert(summary.path.clone(), ItemId::new(index, *id));
+                }
+            }
+
+            for item in krate.index.values() {
+                let children = match &item.inner {
+                    ItemEnum::Module(module) => &module.items,
+                    ItemEnum::Impl(imp) => &imp.items,
+                    _ => continue
+                };
+
+                for child in children {
+                    parents.insert(ItemId::new(index, *child), ItemId::new(index, item.id));
+                }
+            }
+
+            relevant_items.extend(krate.index.values()
+                .filter(|x| x.crate_id == 0)
+                .filter(Self::item_relevant)
+                .map(|x| ItemId::new(index, x.id)));
+        }
+
+        let total_items = relevant_items.len();
+
+        let mut result = Self {
+            items,
+            crates,
+            local_paths,
+            parents,
+            public_paths: HashMap::new(),
+            known_types: Self::default_known_types(),
+            instantiations: HashMap::new(),
+            trait_impls: HashMap::new(),
+            distinct_newtypes: HashSet::new(),
+            c_layouts: HashSet::new(),
+            relevant_items,
+            pending: HashMap::new(),
+            collected: HashSet::new(),
+            skip_reasons: HashMap::new(),
+            unknown_instantiations: BTreeMap::new(),
+            defaulted_fields: Vec::new(),
+            errors: HashMap::new(),
+            total_items,
+            considered_items: Vec::new(),
+            include_hidden: false,
+            hidden_items: 0,
+            source_hash: FNV_OFFSET_BASIS,
+            forced_skips: HashSet::new(),
+            required: BTreeSet::new(),
+            defaults: HashSet::new(),
+            renames: Self::default_renames(),
+            methods: HashMap::new(),
+            method_surfaces: HashMap::new(),
+            external_types: HashMap::new(),
+            allow_packed_structs: false,
+            unknown_variants: false,
+            safe_handles: false,
+            disambiguate: false,
+            fixed_width_sizes: false,
+            target_cfg: None,
+            naming: ag::NamingConfig::default()
+        };
+
+        // The index is a hash map, so items are visited in path order to keep the output stable across runs.
+        let mut relevant_items = std::mem::take(&mut result.relevant_items);
+        relevant_items.sort_by_cached_key(|x| (result.rust_name(*x), x.krate, x.id.0));
+        result.relevant_items = relevant_items;
+        result.public_paths = result.find_public_paths();
+        result.validate_known_types();
+        result
+    }
+
+    /// Finds the shortest public path of every item reachable from a crate root, following `pub use`
+    /// re-exports and glob re-exports. Items which are re-exported by another loaded crate, such as
+    /// `epaint::Color32` as `egui::Color32`, may take their path through that crate instead.
+    fn find_public_paths(&self) -> HashMap<ItemId, Vec<String>> {
+        let mut result = HashMap::<ItemId, Vec<String>>::new();
+        let offer = |result: &mut HashMap<ItemId, Vec<String>>, id: ItemId, path: Vec<String>| {
+            let shorter = result.get(&id).is_none_or(|x| path.len() < x.len());
+            if shorter {
+                result.insert(id, path);
+            }
+            shorter
+        };
+
+        // Each entry is a module whose items are reachable under the given path. A module is only
+        // expanded again if it is reached through a shorter path, so cyclic globs terminate.
+        let mut expanded = HashMap::<ItemId, usize>::new();
+        let mut queue = VecDeque::new();
+        for (krate, data) in self.crates.iter().enumerate() {
+            if let Some(name) = data.index.get(&data.root).and_then(|x| x.name.clone()) {
+                offer(&mut result, ItemId::new(krate, data.root), vec![name.clone()]);
+                queue.push_back((ItemId::new(krate, data.root), vec![name]));
+            }
+        }
+
+        while let Some((module, path)) = queue.pop_front() {
+            if expanded.get(&module).is_some_and(|x| *x <= path.len()) {
+                continue;
+            }
+            expanded.insert(module, path.len());
+
+            let Some(ItemEnum::Module(x)) = self.crates[module.krate].index.get(&module.id).map(|x| &x.inner) else { continue };
+            for child in &x.items {
+                let Some(item) = self.crates[module.krate].index.get(child).filter(|x| x.visibility == Visibility::Public) else { continue };
+                let (target, name) = match &item.inner {
+                    ItemEnum::Use(import) => {
+                        let Some(target) = import.id.and_then(|x| self.resolve(module.krate, x)) else { continue };
+                        if import.is_glob {
+                            queue.push_back((target, path.clone()));
+                            continue;
+                        }
+                        (target, import.name.clone())
+                    },
+                    _ => match &item.name {
+                        Some(name) => (ItemId::new(module.krate, item.id), name.clone()),
+                        None => continue
+                    }
+                };
+
+                let mut target_path = path.clone();
+                target_path.push(name);
+                if offer(&mut result, target, target_path.clone()) {
+                    queue.push_back((target, target_path));
+                }
+            }
+        }
+
+        result
+    }
+    
+    /// Generates every item that can be bound, recording why the others were skipped.
+    pub fn collect(&mut self) {
+        if !self.include_hidden {
+            self.skip_hidden_items();
+        }
+        self.considered_items = self.relevant_items.clone();
+
+        // Items skipped by configuration or disabled for the target are left out of the work queue, so that no pass considers them.
+        let mut pending = HashMap::<WorkKind, Vec<ItemId>>::new();
+        for index in 0..self.relevant_items.len() {
+            let id = self.relevant_items[index];
+            if let Some(predicate) = self.disabled_cfg(id) {
+                self.skip(id, report::SkipReason::Cfg { predicate: predicate.to_string() });
+            }
+            else if self.is_forced_skip(id) {
+                self.skip(id, report::SkipReason::Configured);
+            }
+            else if let Some(kind) = self.work_kind(id) {
+                pending.entry(kind).or_default().push(id);
+            }
+        }
+        self.pending = pending;
+
+        self.collect_trait_impls();
+        self.collect_primitive_enums();
+        self.collect_primitive_structs();
+        self.collect_instantiations();
+        self.collect_classes();
+        self.collect_constants();
+        self.collect_statics();
+        self.collect_functions();
+        self.collect_methods();
+        self.collect_configured_methods();
+        self.resolve_doc_links();
+        self.items.sort_by(|a, b| (a.module(), a.name()).cmp(&(b.module(), b.name())).then_with(|| a.cmp(b)));
+        if self.disambiguate {
+            collisions::disambiguate(&mut self.items, &self.naming);
+        }
+
+        for hidden in collisions::find_hidden_members(&self.items, &self.naming) {
+            log::warn!("{hidden}");
+        }
+
+        for item in &self.items {
+            log::trace!("Collected {item:?}");
+        }
+    }
+
+    /// Summarizes which items were generated, and why the others, or the fields left out of generated structs, were skipped.
+    pub fn report(&self) -> report::Report {
+        let mut skipped = self.remaining_items().filter(|x| !self.errors.contains_key(x)).map(|x| report::SkippedItem {
+            path: self.rust_name(x),
+            kind: self.item_kind(x),
+            reason: self.skip_reasons.get(&x).cloned().unwrap_or(report::SkipReason::UnsupportedKind)
+        }).chain(self.defaulted_fields.iter().cloned()).collect::<Vec<_>>();
+        skipped.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let mut errors = self.errors.values().cloned().collect::<Vec<_>>();
+        errors.sort_by(|a, b| a.path().cmp(b.path()));
+
+        let mut coverage = report::CoverageReport::default();
+        for id in self.considered_items.iter().filter(|x| !self.skip_reasons.get(x).is_some_and(report::SkipReason::is_intentional)) {
+            let kind = coverage.kinds.entry(self.item_kind(*id)).or_default();
+            kind.total += 1;
+            kind.generated += usize::from(self.collected.contains(id));
+        }
+
+        report::Report {
+            generated: self.collected.len(),
+            total: self.total_items,
+            hidden: self.hidden_items,
+            skipped,
+            unknown_instantiations: self.unknown_instantiations.iter()
+                .map(|(ty, fields)| report::UnknownInstantiation { ty: ty.clone(), fields: fields.iter().cloned().collect() })
+                .collect(),
+            errors,
+            coverage
+        }
+    }
+
+    /// Records why an item was not generated. Always returns `false`.
+    fn skip(&mut self, id: ItemId, reason: report::SkipReason) -> bool {
+        log::debug!("Skipping {}: {reason}", self.rust_name(id));
+        self.skip_reasons.insert(id, reason);
+        false
+    }
+
+    /// Records that an item was not generated because its rustdoc JSON is malformed. Always returns `false`.
+    fn fail(&mut self, id: ItemId, error: BindgenError) -> bool {
+        log::warn!("{error}");
+        self.errors.insert(id, error);
+        false
+    }
+
+    /// Gets a human-readable name for the kind of an item, which is one of [`report::CoverageReport::KINDS`].
+    fn item_kind(&self, id: ItemId) -> &'static str {
+        match &self.item(id).inner {
+            ItemEnum::Union(_) => "union",
+            ItemEnum::Struct(_) if self.known_types.get(&self.rust_name(id)).is_some_and(|x| x.strategy == Marshal::Handle) => "class",
+            ItemEnum::Struct(_) => "struct",
+            ItemEnum::Enum(_) => "enum",
+            ItemEnum::Function(_) if self.is_method(id) => "method",
+            ItemEnum::Function(_) => "function",
+            ItemEnum::TypeAlias(_) => "type alias",
+            ItemEnum::Constant { .. } | ItemEnum::AssocConst { .. } => "constant",
+            ItemEnum::Static(_) => "static",
+            ItemEnum::ExternType => "extern type",
+            ItemEnum::Macro(_) | ItemEnum::ProcMacro(_) => "macro",
+            _ => "item"
+        }
+    }
+
+    /// Gets the items collected so far, in path order.
+    pub fn items(&self) -> &[ag::Item] {
+        &self.items
+    }
+
+    /// Gets a hash of the rustdoc JSON from which this context was created.
+    pub fn source_hash(&self) -> u64 {
+        self.source_hash
+    }
+
+    /// Generates a distinct C# struct for the newtype at the given path, rather than
+    /// marshaling it as its inner type.
+    pub fn keep_newtype(&mut self, path: impl Into<String>) {
+        self.distinct_newtypes.insert(path.into());
+    }
+
+    /// Sets whether classes should be owned by a generated `SafeHandle` in C#, rather than deriving from `VxHandle`.
+    pub fn safe_handles(&mut self, enabled: bool) {
+        self.safe_handles = enabled;
+    }
+
+    /// Sets whether `usize` and `isize` cross the FFI boundary as `ulong` and `long`, converted on each side,
+    /// rather than as `nuint` and `nint`.
+    pub fn fixed_width_sizes(&mut self, enabled: bool) {
+        self.fixed_width_sizes = enabled;
+        for (name, primitive) in [("usize", ag::PrimitiveType::Usize), ("isize", ag::PrimitiveType::Isize)] {
+            let known_type = KnownType::primitive(self.ffi_primitive(primitive));
+            let strategy = match enabled {
+                true => Marshal::ConvertOnCopy { into_fn: format!("{}::from", known_type.rs_name), from_fn: format!("{name}::from") },
+                false => Marshal::Blittable
+            };
+            self.known_types.insert(name.to_string(), KnownType { strategy, ..known_type });
+        }
+    }
+
+    /// Sets whether colliding generated names should be renamed apart with numeric suffixes, rather than reported.
+    pub fn disambiguate(&mut self, enabled: bool) {
+        self.disambiguate = enabled;
+    }
+
+    /// Sets the configuration of the build which the bindings are for, so that items disabled by a `#[cfg(...)]`
+    /// attribute are skipped. Without one, `#[cfg(...)]` attributes are ignored.
+    pub fn target_cfg(&mut self, target: cfg::TargetCfg) {
+        self.target_cfg = Some(target);
+    }
+
+    /// Gets the names given to the generated types and exported functions.
+    pub fn naming(&self) -> &ag::NamingConfig {
+        &self.naming
+    }
+
+    /// Sets whether `#[doc(hidden)]` and non-public items should have bindings generated.
+    pub fn include_hidden(&mut self, include: bool) {
+        self.include_hidden = include;
+    }
+
+    /// Merges the given configuration over the built-in defaults. Paths in the configuration
+    /// which do not exist in any loaded crate are reported as warnings.
+    pub fn apply_config(&mut self, config: &config::Config) {
+        let paths = self.crates.iter()
+            .flat_map(|x| x.paths.values().map(|x| x.path.join("::")))
+            .collect::<HashSet<_>>();
+
+        // Items may be configured by their public path, which is translated to the canonical path used internally.
+        let aliases = self.public_paths.iter()
+            .map(|(id, path)| (path.join("::"), self.rust_name(*id)))
+            .collect::<HashMap<_, _>>();
+        // Fields are not items of their own, so they are configured through the path of their struct.
+        let canonical = |path: &String| match aliases.get(path) {
+            Some(path) => path.clone(),
+            None => path.rsplit_once("::")
+                .and_then(|(parent, field)| Some(format!("{}::{field}", aliases.get(parent)?)))
+                .unwrap_or_else(|| path.clone())
+        };
+
+        let known_paths = config.known_types.keys().map(|x| ("known type", x));
+        let skip_paths = config.skip.iter().map(|x| ("skipped item", x));
+        let require_paths = config.require.iter().map(|x| ("required item", x));
+        let default_paths = config.defaults.iter().map(|x| ("defaulted struct", x));
+        let rename_paths = config.renames.keys().map(|x| ("renamed item", x));
+        let method_paths = config.methods.keys().map(|x| ("method", x));
+        let class_paths = config.classes.keys().map(|x| ("class", x));
+        for (kind, path) in known_paths.chain(skip_paths).chain(require_paths).chain(default_paths).chain(rename_paths).chain(method_paths).chain(class_paths) {
+            let path_exists = |path: &String| paths.contains(path) || matches!(kind, "skipped item" | "renamed item" | "method" | "required item") && path.rsplit_once("::").is_some_and(|x| paths.contains(x.0));
+            let exists = if path.contains('*') { paths.iter().any(|x| config::path_matches(path, x)) } else { path_exists(&canonical(path)) };
+            if !exists {
+                log::warn!("Configured {kind} `{path}` does not exist in the loaded crates");
+            }
+        }
+
+        for (path, known_type) in &config.known_types {
+            let path = &canonical(path);
+            // External types still have their Rust mirrors generated, while other configured
+            // types replace the generated bindings entirely.
+            if known_type.external {
+                self.external_types.insert(path.clone(), ag::ExternalType { cs_name: known_type.cs_name.clone(), conversions: known_type.conversions });
+            }
+            else {
+                self.forced_skips.insert(path.clone());
+            }
+
+            let rs_name = match known_type.rs_name.as_str() {
+                "" => path.rsplit("::").next().unwrap_or(path).to_string(),
+                x => x.to_string()
+            };
+            let known_type = config::KnownTypeConfig { rs_name, ..known_type.clone() };
+            let (strategy, reference) = match &known_type.marshal {
+                config::MarshalConfig::Blittable => (Marshal::Blittable, ag::TypeReference::Named { rs_name: known_type.rs_name.clone(), cs_name: known_type.cs_name.clone() }),
+                config::MarshalConfig::Convert { into_fn, from_fn } => (
+                    Marshal::ConvertOnCopy { into_fn: into_fn.clone(), from_fn: from_fn.clone() },
+                    ag::TypeReference::Named { rs_name: known_type.rs_name.clone(), cs_name: known_type.cs_name.clone() }
+                ),
+                config::MarshalConfig::Handle => (Marshal::Handle, ag::TypeReference::Handle { class: known_type.rs_name.clone(), mutable: true })
+            };
+
+            let known_type = KnownType { reference: Some(reference), ..KnownType::new(&known_type.cs_name, &known_type.rs_name, strategy) };
+            self.known_types.insert(path.clone(), known_type);
+        }
+
+        for (ty, instantiation) in &config.instantiations {
+            self.instantiations.insert(Self::normalize_type_key(ty), instantiation.clone());
+        }
+
+        self.allow_packed_structs = config.allow_packed_structs;
+        self.unknown_variants = config.unknown_variants;
+        if let Some(type_prefix) = &config.type_prefix {
+            self.naming.type_prefix = type_prefix.clone();
+        }
+        if let Some(fn_prefix) = &config.fn_prefix {
+            self.naming.fn_prefix = fn_prefix.clone();
+        }
+        if let Some(library_class_name) = &config.library_class_name {
+            self.naming.library_class_name = library_class_name.clone();
+        }
+        // Patterns cannot be translated from public paths, so they must be given as canonical paths.
+        self.forced_skips.extend(config.skip.iter().map(|x| if x.contains('*') { x.clone() } else { canonical(x) }));
+        self.required.extend(config.require.iter().map(canonical));
+        self.defaults.extend(config.defaults.iter().map(canonical));
+        self.renames.extend(config.renames.iter().map(|(a, b)| (canonical(a), b.clone())));
+        self.methods.extend(config.methods.iter().map(|(a, b)| (canonical(a), b.clone())));
+        for (path, class) in &config.classes {
+            match &class.methods {
+                config::MethodSurface::All => self.method_surfaces.remove(&canonical(path)),
+                config::MethodSurface::Only(names) => self.method_surfaces.insert(canonical(path), names.clone())
+            };
+        }
+    }
+
+    /// Gets the items which the configuration requires, but which were not generated, sorted by path.
+    pub fn missing_requirements(&self) -> Vec<report::MissingRequirement> {
+        let mut ids = HashMap::<String, Vec<ItemId>>::new();
+        for id in &self.considered_items {
+            let path = self.rust_name(*id);
+            if self.required.contains(&path) {
+                ids.entry(path).or_default().push(*id);
+            }
+        }
+
+        let mut result = Vec::new();
+        for path in &self.required {
+            // An item may be declared several times, such as under different `#[cfg(...)]` attributes.
+            let ids = ids.get(path).map(Vec::as_slice).unwrap_or_default();
+            if ids.iter().any(|x| self.collected.contains(x)) {
+                continue;
+            }
+
+            let reason = ids.first().map(|x| self.skip_reasons.get(x).cloned().unwrap_or(report::SkipReason::UnsupportedKind));
+            result.push(report::MissingRequirement { path: path.clone(), reason });
+        }
+        result
+    }
+
+    /// Whether the configuration leaves the named method or constructor out of the class at the given canonical path.
+    fn is_unlisted_method(&self, owner: &str, name: &str) -> bool {
+        self.method_surfaces.get(owner).is_some_and(|x| !x.iter().any(|x| x == name))
+    }
+
+    /// Gets the methods which the configuration lists for each class, but which were not generated, sorted by path.
+    pub fn unbound_methods(&self) -> Vec<report::UnboundMethod> {
+        let mut result = Vec::new();
+        for (owner, names) in &self.method_surfaces {
+            let class = self.known_types.get(owner)
+                .filter(|x| x.strategy == Marshal::Handle)
+                .and_then(|x| self.items.iter().find(|item| matches!(item, ag::Item::Class { .. }) && item.cs_name() == x.cs_name));
+            for name in names {
+                let generated = match class {
+                    Some(ag::Item::Class { methods, constructors, .. }) => methods.iter().any(|x| x.name == *name) || constructors.iter().any(|x| x.name == *name),
+                    _ => false
+                };
+                if generated {
+                    continue;
+                }
+
+                // A method may be declared in several `impl` blocks, such as under different `#[cfg(...)]` attributes.
+                let path = format!("{owner}::{name}");
+                let reason = self.remaining_items()
+                    .filter(|x| self.is_method(*x) && self.rust_name(*x) == path)
+                    .find_map(|x| self.skip_reasons.get(&x).cloned());
+                result.push(report::UnboundMethod { path, reason });
+            }
+        }
+
+        result.sort_by(|a, b| a.path.cmp(&b.path));
+        result
+    }
+
+    /// Whether the configuration forbids generating the item, or an item that contains it.
+    fn is_forced_skip(&self, id: ItemId) -> bool {
+        let path = self.rust_name(id);
+        self.forced_skips.iter().any(|x| config::path_matches(x, &path))
+    }
+
+    /// Removes hidden and private items from consideration, so that they count
+    /// neither as generated nor as total items.
+    fn skip_hidden_items(&mut self) {
+        let mut relevant = std::mem::take(&mut self.relevant_items);
+        relevant.retain(|x| !self.is_hidden(*x));
+        self.hidden_items = self.total_items - relevant.len();
+        self.total_items = relevant.len();
+        self.relevant_items = relevant;
+    }
+
+    /// Whether an item is private, or it or any module containing it is `#[doc(hidden)]`.
+    /// The visibility of containing modules is not checked, since their items may be re-exported.
+    fn is_hidden(&self, id: ItemId) -> bool {
+        if self.item(id).visibility != Visibility::Public {
+            return true;
+        }
+
+        let mut current = Some(id);
+        while let Some(x) = current {
+            if self.item(x).attrs.iter().any(|x| x == "#[doc(hidden)]") {
+                return true;
+            }
+            current = self.parents.get(&x).copied();
+        }
+
+        false
+    }
+
+    /// Gets the first `#[cfg(...)]` predicate on an item, or any item containing it, which the target does not satisfy.
+    fn disabled_cfg(&self, id: ItemId) -> Option<cfg::CfgPredicate> {
+        let mut current = Some(id);
+        while let Some(x) = current {
+            if let Some(predicate) = self.unsatisfied_cfg(self.item(x)) {
+                return Some(predicate);
+            }
+            current = self.parents.get(&x).copied();
+        }
+
+        None
+    }
+
+    /// Gets the first `#[cfg(...)]` predicate on an item which the target does not satisfy. Malformed
+    /// predicates are reported and ignored.
+    fn unsatisfied_cfg(&self, item: &Item) -> Option<cfg::CfgPredicate> {
+        let target = self.target_cfg.as_ref()?;
+        item.attrs.iter().filter_map(|x| match cfg::CfgPredicate::from_attribute(x)? {
+            Ok(predicate) => Some(predicate),
+            Err(e) => {
+                log::warn!("Ignoring malformed attribute `{x}` on `{}`: {e}", item.name.as_deref().unwrap_or_default());
+                None
+            }
+        }).find(|x| !x.evaluate(target))
+    }
+
+    /// Gets the item with the given ID.
+    fn item(&self, id: ItemId) -> &Item {
+        &self.crates[id.krate].index[&id.id]
+    }
+
+    /// Gets a member of the given item, such as an enum variant or struct field.
+    fn member(&self, owner: ItemId, id: &Id) -> Result<&Item, BindgenError> {
+        self.crates[owner.krate].index.get(id).ok_or_else(|| BindgenError::MissingItem { path: self.rust_name(owner), id: id.0 })
+    }
+
+    /// Gets the name of a member of the given item, such as an enum variant or struct field.
+    fn member_name(&self, owner: ItemId, member: &Item, kind: &'static str) -> Result<String, BindgenError> {
+        member.name.clone().ok_or_else(|| BindgenError::MissingName { path: self.rust_name(owner), member: Some(kind) })
+    }
+
+    /// Finds the definition of an item referenced from within the given crate.
+    /// If the item was defined in another crate, this looks for it among the other
+    /// loaded crates by path.
+    fn resolve(&self, krate: usize, id: Id) -> Option<ItemId> {
+        let local = &self.crates[krate];
+        if local.index.get(&id).is_some_and(|x| x.crate_id == 0) {
+            Some(ItemId::new(krate, id))
+        }
+        else {
+            let summary = local.paths.get(&id)?;
+            self.local_paths.get(&summary.path).copied()
+        }
+    }
+
+    /// Gets the canonical path of an item, such as `egui::style::Margin`. This is used to
+    /// key [`Self::known_types`] so that same-named items in different modules do not collide.
+    fn rust_name(&self, id: ItemId) -> String {
+        if let Some(summary) = self.crates[id.krate].paths.get(&id.id) {
+            summary.path.join("::")
+        }
+        else {
+            let mut segments = Vec::new();
+            let mut current = Some(id);
+            while let Some(x) = current {
+                let item = self.item(x);
+                if let ItemEnum::Impl(imp) = &item.inner {
+                    segments.push(self.impl_self_name(x.krate, &imp.for_));
+                    break;
+                }
+
+                segments.extend(item.name.clone());
+                current = self.parents.get(&x).copied();
+            }
+            segments.reverse();
+            segments.join("::")
+        }
+    }
+
+    /// Gets the path of the type that an impl block is for, ignoring any generic arguments.
+    fn impl_self_name(&self, krate: usize, ty: &Type) -> String {
+        match ty {
+            Type::ResolvedPath(path) => match self.resolve(krate, path.id) {
+                Some(id) => self.rust_name(id),
+                None => path.path.clone()
+            },
+            other => self.type_key(krate, other).unwrap_or_else(|| "_".to_string())
+        }
+    }
+
+    /// Gets the path by which an item is publicly reachable, such as `egui::Frame` rather than
+    /// `egui::containers::frame::Frame`. Associated items are reached through their owner, and
+    /// items with no public path fall back to their canonical path.
+    fn public_path(&self, id: ItemId) -> String {
+        if let Some(path) = self.public_paths.get(&id) {
+            return path.join("::");
+        }
+
+        let owner = self.parents.get(&id).and_then(|x| match &self.item(*x).inner {
+            ItemEnum::Impl(Impl { for_: Type::ResolvedPath(path), .. }) => self.resolve(x.krate, path.id),
+            _ => None
+        });
+        match (owner, &self.item(id).name) {
+            (Some(owner), Some(name)) => format!("{}::{name}", self.public_path(owner)),
+            _ => self.rust_name(id)
+        }
+    }
+
+    /// Gets the public path of the module which exports an item, such as `egui`.
+    fn module_path(&self, id: ItemId) -> String {
+        let mut path = self.public_path(id);
+        path.truncate(path.rfind("::").unwrap_or(0));
+        path
+    }
+
+    /// Gets the unqualified name of an item, which is used for display.
+    fn short_name(&self, id: ItemId) -> Result<String, BindgenError> {
+        self.item(id).name.clone().ok_or_else(|| BindgenError::MissingName { path: self.rust_name(id), member: None })
+    }
+
+    /// Gets the name under which a type is imported into the generated Rust, if a type generated earlier already has its
+    /// name. The generated Rust refers to the original types by name, so that same-named types in different modules,
+    /// like `a::Id` and `b::Id`, are told apart by numbering the later ones.
+    fn rs_alias(&self, id: ItemId) -> Result<Option<String>, BindgenError> {
+        let name = self.short_name(id)?;
+        let taken = |alias: &str| self.items.iter().any(|x| matches!(x, ag::Item::Enum { .. } | ag::Item::Union { .. } | ag::Item::Class { .. } | ag::Item::Struct { .. }) && x.rs_type() == alias);
+        if !taken(&name) {
+            return Ok(None);
+        }
+        Ok((2..).map(|x| format!("{name}{x}")).find(|x| !taken(x)))
+    }
+
+    /// Gets the doc-comment of an item, with each intra-doc link pointed at the canonical
+    /// path of its target so that it can later be resolved to a generated type.
+    fn docs(&self, krate: usize, item: &Item) -> String {
+        let docs = item.docs.as_deref().unwrap_or_default();
+        markdown::rewrite_links(docs, |key| {
+            let target = item.links.get(key)?;
+            let path = match self.resolve(krate, *target) {
+                Some(id) => self.rust_name(id),
+                None => self.crates[krate].paths.get(target)?.path.join("::")
+            };
+            Some(format!("{}{path}", markdown::RUST_LINK))
+        })
+    }
+
+    /// Gets the deprecation status of an item.
+    fn deprecation(item: &Item) -> Option<ag::Deprecation> {
+        item.deprecation.as_ref().map(|x| ag::Deprecation { since: x.since.clone(), note: x.note.clone() })
+    }
+
+    /// Gets the canonical path of an item, along with the file and line which define it. Files in the Cargo
+    /// registry are given relative to it, like `egui-0.31.0/src/ui.rs`, so that they do not depend on the machine
+    /// which generated the rustdoc JSON.
+    fn origin(&self, id: ItemId) -> ag::Origin {
+        let span = self.item(id).span.as_ref().map(|span| {
+            let file = span.filename.to_string_lossy().replace('\\', "/");
+            let file = match file.rsplit_once("/registry/src/") {
+                Some((_, rest)) => rest.split_once('/').map_or(rest, |x| x.1).to_string(),
+                None => file
+            };
+            (file, span.begin.0)
+        });
+        ag::Origin { path: self.rust_name(id), span }
+    }
+
+    /// Points every intra-doc link whose target received bindings at the generated C# type.
+    /// Links to other items are left alone, and are rendered as plain text.
+    fn resolve_doc_links(&mut self) {
+        let generated = self.items.iter()
+            .filter(|x| matches!(x, ag::Item::Enum { .. } | ag::Item::Union { .. } | ag::Item::Class { .. } | ag::Item::Struct { .. }))
+            .map(|x| x.cs_name())
+            .collect::<HashSet<_>>();
+
+        let known_types = &self.known_types;
+        for item in &mut self.items {
+            item.visit_docs_mut(&mut |docs| {
+                *docs = markdown::rewrite_links(docs, |key| {
+                    let cs_name = &known_types.get(key.strip_prefix(markdown::RUST_LINK)?)?.cs_name;
+                    generated.contains(cs_name).then(|| format!("{}{cs_name}", markdown::CS_LINK))
+                });
+            });
+        }
+    }
+
+    /// Records which traits are implemented for each concrete type, so that optional
+    /// functionality like [`Default`] can be bound where it is available.
+    fn collect_trait_impls(&mut self) {
+        let mut trait_impls = HashMap::<String, HashSet<String>>::new();
+        for (krate, data) in self.crates.iter().enumerate() {
+            for item in data.index.values() {
+                let ItemEnum::Impl(imp) = &item.inner else { continue };
+                let Some(trait_path) = imp.trait_.as_ref().filter(|_| !imp.is_negative) else { continue };
+                let Some(trait_summary) = data.paths.get(&trait_path.id) else { continue };
+                let Some(ty) = self.type_key(krate, &imp.for_) else { continue };
+
+                // Traits with explicit generic arguments, like `Mul<f32>`, are recorded with their arguments.
+                let mut trait_name = trait_summary.path.join("::");
+                if let Some(GenericArgs::AngleBracketed { args, .. }) = trait_path.args.as_deref() {
+                    let args = args.iter()
+                        .filter_map(|x| match x {
+                            GenericArg::Type(ty) => Some(self.type_key(krate, ty).unwrap_or_else(|| "_".to_string())),
+                            _ => None
+                        })
+                        .collect::<Vec<_>>();
+                    if !args.is_empty() {
+                        trait_name += &format!("<{}>", args.join(", "));
+                    }
+                }
+                trait_impls.entry(ty).or_default().insert(trait_name);
+            }
+        }
+        self.trait_impls = trait_impls;
+    }
+
+    /// Whether the type at the given canonical path implements the trait at the given canonical path.
+    fn implements(&self, ty: &str, trait_path: &str) -> bool {
+        self.trait_impls.get(ty).is_some_and(|x| x.contains(trait_path))
+    }
+
+    /// Gets the trait used to convert the type at the given canonical path to a string, preferring [`std::fmt::Display`].
+    fn format_trait(&self, ty: &str) -> Option<ag::FormatTrait> {
+        if self.implements(ty, "core::fmt::Display") {
+            Some(ag::FormatTrait::Display)
+        }
+        else if self.implements(ty, "core::fmt::Debug") {
+            Some(ag::FormatTrait::Debug)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Gets the arithmetic operators implemented by the type at the given canonical path. Binary operators
+    /// must take the type itself on both sides, and multiplication or division must take a float scalar.
+    fn operators(&self, ty: &str) -> Vec<ag::OperatorKind> {
+        let same_type = |name: &str| self.implements(ty, name) || self.implements(ty, &format!("{name}<{ty}>"));
+        let mut result = Vec::new();
+        if same_type("core::ops::arith::Add") {
+            result.push(ag::OperatorKind::Add);
+        }
+
+        if same_type("core::ops::arith::Sub") {
+            result.push(ag::OperatorKind::Sub);
+        }
+
+        if self.implements(ty, "core::ops::arith::Neg") {
+            result.push(ag::OperatorKind::Neg);
+        }
+
+        for scalar in [ag::PrimitiveType::F32, ag::PrimitiveType::F64] {
+            let scalar_name = scalar.rs_name();
+            if self.implements(ty, &format!("core::ops::arith::Mul<{scalar_name}>")) {
+                result.push(ag::OperatorKind::MulScalar(scalar.clone()));
+            }
+
+            if self.implements(ty, &format!("core::ops::arith::Div<{scalar_name}>")) {
+                result.push(ag::OperatorKind::DivScalar(scalar));
+            }
+        }
+
+        result
+    }
+
+    /// Gets the pass which collects an item, or [`None`] if no pass generates items of its kind.
+    fn work_kind(&self, id: ItemId) -> Option<WorkKind> {
+        match self.item(id).inner {
+            ItemEnum::Enum(_) => Some(WorkKind::Enum),
+            ItemEnum::Struct(_) => Some(WorkKind::Struct),
+            ItemEnum::TypeAlias(_) => Some(WorkKind::TypeAlias),
+            ItemEnum::Constant { .. } | ItemEnum::AssocConst { .. } => Some(WorkKind::Constant),
+            ItemEnum::Static(_) => Some(WorkKind::Static),
+            ItemEnum::Function(_) if self.is_method(id) => Some(WorkKind::Method),
+            ItemEnum::Function(_) => Some(WorkKind::Function),
+            _ => None
+        }
+    }
+
+    /// Runs a pass over the items of the given kind which have not yet been generated. Items which the pass
+    /// generates are marked as collected, and the others are left for later passes.
+    fn collect_pending(&mut self, kind: WorkKind, mut collect: impl FnMut(&mut Self, ItemId) -> Result<bool, BindgenError>) {
+        let mut pending = self.pending.remove(&kind).unwrap_or_default();
+        pending.retain(|x| {
+            let collected = collect(self, *x).unwrap_or_else(|e| self.fail(*x, e));
+            if collected {
+                self.collected.insert(*x);
+            }
+            !collected
+        });
+        self.pending.insert(kind, pending);
+    }
+
+    /// Gets the relevant items which were not generated, in path order.
+    fn remaining_items(&self) -> impl Iterator<Item = ItemId> + '_ {
+        self.relevant_items.iter().copied().filter(|x| !self.collected.contains(x))
+    }
+
+    fn collect_primitive_enums(&mut self) {
+        self.collect_pending(WorkKind::Enum, Self::collect_primitive_enum);
+    }
+    
+    fn collect_primitive_enum(&mut self, id: ItemId) -> Result<bool, BindgenError> {
+        let enum_ty = self.item(id);
+        let ItemEnum::Enum(x) = &enum_ty.inner else { unreachable!() };
+        // Enums whose variants carry data are collected along with structs, once the types of their fields are known.
+        if let Some(variant) = self.payload_variant(id, x)? {
+            Ok(self.skip(id, report::SkipReason::PayloadEnum { variant }))
+        }
+        else {
+            let mut variants = Vec::with_capacity(x.variants.len());
+            let mut values = Vec::with_capacity(x.variants.len());
+            for variant_id in &x.variants {
+                let variant = self.member(id, variant_id)?;
+                if self.unsatisfied_cfg(variant).is_some() {
+                    continue;
+                }
+
+                let ItemEnum::Variant(inner) = &variant.inner else {
+                    return Err(BindgenError::UnexpectedKind { path: self.rust_name(id), expected: "variant" });
+                };
+                let index = inner.discriminant.as_ref()
+                    .map(|x| str::parse::<i128>(&x.value).map_err(|_| BindgenError::MalformedDiscriminant { path: self.rust_name(id), value: x.value.clone() }))
+                    .transpose()?;
+                
+                // Implicit discriminants are one greater than the previous variant.
+                values.push(index.unwrap_or_else(|| values.last().map_or(0, |x| x + 1)));
+                variants.push(ag::EnumVariant {
+                    deprecation: Self::deprecation(variant),
+                    docs: self.docs(id.krate, variant),
+                    name: self.member_name(id, variant, "variant")?,
+                    rename: None,
+                    index
+                });
+            }
+
+            // Non-exhaustive enums may gain variants, which are given a discriminant beyond every known one.
+            let non_exhaustive = Self::is_non_exhaustive(enum_ty);
+            let mut unknown = None;
+            if non_exhaustive && self.unknown_variants {
+                if variants.iter().any(|x| x.name == "Unknown") {
+                    log::warn!("Enum `{}` already has an `Unknown` variant, so none was added", self.rust_name(id));
+                }
+                else {
+                    unknown = Some(values.iter().max().map_or(0, |x| x + 1));
+                    values.extend(unknown);
+                }
+            }
+
+            // Discriminants which do not fit belong to a variant, or to the synthesized `Unknown` variant.
+            let variant_name = |variants: &mut Vec<ag::EnumVariant>, position: usize| if position < variants.len() { variants.swap_remove(position).name } else { "Unknown".to_string() };
+
+            let explicit_repr = Self::repr_attributes(enum_ty).iter().find_map(|x| match x.as_str() {
+                "C" => Some(ag::PrimitiveType::I32),
+                other => Self::primitive_type(other).filter(|x| x.is_integer())
+            });
+
+            let fits = |ty: &ag::PrimitiveType| values.iter().all(|x| ty.contains(*x));
+            let repr = match &explicit_repr {
+                Some(repr) => {
+                    if let Some(position) = values.iter().position(|x| !repr.contains(*x)) {
+                        let variant = variant_name(&mut variants, position);
+                        return Ok(self.skip(id, report::SkipReason::InvalidDiscriminant { variant, value: values[position].to_string() }));
+                    }
+                    Some(repr.clone())
+                },
+                None if fits(&ag::PrimitiveType::I32) => None,
+                None => match [ag::PrimitiveType::I64, ag::PrimitiveType::U64].into_iter().find(fits) {
+                    Some(repr) => Some(repr),
+                    None => {
+                        let position = values.iter().position(|x| !ag::PrimitiveType::I64.contains(*x)).expect("Discriminants fit in i64");
+                        let variant = variant_name(&mut variants, position);
+                        return Ok(self.skip(id, report::SkipReason::InvalidDiscriminant { variant, value: values[position].to_string() }));
+                    }
+                }
+            };
+
+            let mut docs = self.docs(id.krate, enum_ty);
+            if non_exhaustive {
+                Self::note_non_exhaustive(&mut docs);
+            }
+
+            let item = ag::Item::Enum {
+                naming: self.naming.clone(),
+                deprecation: Self::deprecation(enum_ty),
+                origin: Some(self.origin(id)),
+                docs,
+                name: self.short_name(id)?,
+                module: self.module_path(id),
+                rename: self.renames.get(&self.rust_name(id)).cloned(),
+                alias: self.rs_alias(id)?,
+                flags: Self::is_flags(&variants),
+                non_exhaustive,
+                unknown,
+                variants,
+                repr
+            };
+
+            // Enums without an explicit repr have no guaranteed layout, and enums with an `Unknown` variant
+            // have no Rust counterpart for it, so both must be converted to the generated enum when copied.
+            let strategy = if explicit_repr.is_some() && unknown.is_none() { Marshal::Blittable } else { Marshal::convert(&item) };
+            self.known_types.insert(self.rust_name(id), KnownType::item(&item, strategy));
+            self.items.push(item);
+
+            Ok(true)
+        }
+    }
+    
+    /// Generates a tagged union for an enum whose variants carry data. Variants whose data cannot be copied
+    /// across the FFI boundary are left out, and become an `Unknown` variant when converted to the mirror.
+    fn collect_payload_enum(&mut self, id: ItemId) -> Result<bool, BindgenError> {
+        let enum_ty = self.item(id);
+        let ItemEnum::Enum(x) = &enum_ty.inner else { unreachable!() };
+        if !x.generics.params.is_empty() {
+            return Ok(self.skip(id, report::SkipReason::Generic));
+        }
+
+        let mut variants = Vec::with_capacity(x.variants.len());
+        let mut strategies = Vec::with_capacity(x.variants.len());
+        let mut omitted = None;
+        for variant_id in &x.variants {
+            let variant = self.member(id, variant_id)?;
+            if self.unsatisfied_cfg(variant).is_some() {
+                continue;
+            }
+
+            let ItemEnum::Variant(inner) = &variant.inner else {
+                return Err(BindgenError::UnexpectedKind { path: self.rust_name(id), expected: "variant" });
+            };
+            let name = self.member_name(id, variant, "variant")?;
+            let fields = match Self::variant_fields(inner) {
+                Some(fields) => self.union_fields(id, &fields)?,
+                None => None
+            };
+
+            match fields {
+                Some((fields, field_strategies)) => {
+                    variants.push(ag::UnionVariant {
+                        name,
+                        rename: None,
+                        fields,
+                        deprecation: Self::deprecation(variant),
+                        docs: self.docs(id.krate, variant)
+                    });
+                    strategies.push(field_strategies);
+                },
+                None => {
+                    log::debug!("Variant `{}::{name}` carries data which cannot be marshaled, so it was left out", self.rust_name(id));
+                    omitted.get_or_insert(name);
+                }
+            }
+        }
+
+        // Enums are views if every variant carrying data holds a vector or another view. Otherwise, as for input
+        // events which C# also creates, the variants which do are left out.
+        let holds_view = variants.iter().zip(&strategies).map(|(x, strategies)| Self::holds_view(&x.fields, strategies)).collect::<Vec<_>>();
+        let view = holds_view.contains(&true) && variants.iter().zip(&holds_view).all(|(x, holds_view)| *holds_view || x.fields.is_empty());
+        if !view && holds_view.contains(&true) {
+            let mut kept = Vec::with_capacity(variants.len());
+            for (variant, holds_view) in variants.into_iter().zip(holds_view) {
+                if holds_view {
+                    log::debug!("Variant `{}::{}` holds a view, so it was left out", self.rust_name(id), variant.name);
+                    omitted.get_or_insert(variant.name);
+                }
+                else {
+                    kept.push(variant);
+                }
+            }
+            variants = kept;
+        }
+        else if view {
+            let mut kept = Vec::with_capacity(variants.len());
+            for (mut variant, strategies) in variants.into_iter().zip(strategies) {
+                let fields = variant.fields.iter().zip(&strategies)
+                    .map(|(x, strategy)| Some(ag::StructField { ty: self.view_field(&x.ty, strategy)?, ..x.clone() }))
+                    .collect::<Option<Vec<_>>>();
+                match fields {
+                    Some(fields) => {
+                        variant.fields = fields;
+                        kept.push(variant);
+                    },
+                    None => {
+                        log::debug!("Variant `{}::{}` carries data which cannot be viewed, so it was left out", self.rust_name(id), variant.name);
+                        omitted.get_or_insert(variant.name);
+                    }
+                }
+            }
+            variants = kept;
+        }
+
+        // Without any data, the union would only be a worse version of the enum of its tags.
+        if let Some(variant) = omitted.clone().filter(|_| variants.iter().all(|x| x.fields.is_empty())) {
+            return Ok(self.skip(id, report::SkipReason::PayloadEnum { variant }));
+        }
+
+        let non_exhaustive = Self::is_non_exhaustive(enum_ty);
+        let mut docs = self.docs(id.krate, enum_ty);
+        if non_exhaustive {
+            Self::note_non_exhaustive(&mut docs);
+        }
+
+        let owns_strings = !view && self.holds_strings(variants.iter().flat_map(|x| &x.fields));
+        let item = ag::Item::Union {
+            naming: self.naming.clone(),
+            deprecation: Self::deprecation(enum_ty),
+            origin: Some(self.origin(id)),
+            docs,
+            name: self.short_name(id)?,
+            module: self.module_path(id),
+            rename: self.renames.get(&self.rust_name(id)).cloned(),
+            alias: self.rs_alias(id)?,
+            variants,
+            non_exhaustive,
+            unknown: omitted.is_some() || (non_exhaustive && self.unknown_variants),
+            owns_strings,
+            view
+        };
+
+        let strategy = if view { Marshal::View } else { Marshal::convert(&item) };
+        self.known_types.insert(self.rust_name(id), KnownType::item(&item, strategy));
+        self.skip_reasons.remove(&id);
+        self.items.push(item);
+        Ok(true)
+    }
+
+    /// Gets the fields of an enum variant, or [`None`] if some of them are hidden.
+    fn variant_fields(variant: &Variant) -> Option<Vec<Id>> {
+        match &variant.kind {
+            VariantKind::Plain => Some(Vec::new()),
+            VariantKind::Tuple(fields) => fields.iter().copied().collect(),
+            VariantKind::Struct { fields, has_stripped_fields } => (!has_stripped_fields).then(|| fields.clone())
+        }
+    }
+
+    /// Gets the fields of a variant of the given enum, along with their strategies, or [`None`] if any of them
+    /// cannot be copied across the FFI boundary.
+    fn union_fields(&self, id: ItemId, fields: &[Id]) -> Result<Option<VariantFields>, BindgenError> {
+        let mut result = Vec::with_capacity(fields.len());
+        let mut strategies = Vec::with_capacity(fields.len());
+        for field in fields {
+            let field_item = self.member(id, field)?;
+            if self.unsatisfied_cfg(field_item).is_some() {
+                continue;
+            }
+
+            let ItemEnum::StructField(ty) = &field_item.inner else {
+                return Err(BindgenError::UnexpectedKind { path: self.rust_name(id), expected: "field" });
+            };
+            let (strategy, field_ty) = match self.view_vector(id.krate, ty) {
+                Some(field_ty) => (Some(Marshal::View), Some(field_ty)),
+                None => {
+                    let strategy = self.marshal_strategy(id.krate, ty).filter(|x| x.is_embeddable() || *x == Marshal::View);
+                    (strategy.clone(), self.type_reference(id.krate, ty).filter(|_| strategy.is_some()))
+                }
+            };
+            let (Some(field_ty), Some(strategy)) = (field_ty, strategy) else {
+                return Ok(None);
+            };
+
+            result.push(ag::StructField {
+                name: self.member_name(id, field_item, "field")?,
+                rename: None,
+                ty: field_ty,
+                deprecation: Self::deprecation(field_item),
+                docs: self.docs(id.krate, field_item)
+            });
+            strategies.push(strategy);
+        }
+
+        Ok(Some((result, strategies)))
+    }
+
+    /// Adds a note to the docs of a non-exhaustive enum.
+    fn note_non_exhaustive(docs: &mut String) {
+        if !docs.is_empty() {
+            *docs += "\n\n";
+        }
+        *docs += "This enum is non-exhaustive, so later versions may add variants. Matches on it should handle unknown values.";
+    }
+
+    /// Whether an item is marked `#[non_exhaustive]`.
+    fn is_non_exhaustive(item: &Item) -> bool {
+        item.attrs.iter().any(|x| x == "#[non_exhaustive]")
+    }
+
+    /// Whether the variants of an enum are bit flags: every discriminant is explicit, distinct, and either
+    /// zero, a single bit, or a union of the single-bit variants. At least three single bits are required,
+    /// and the discriminants must not be consecutive, so that enums numbered like `0, 1, 2, 3, 4` are not
+    /// mistaken for flags.
+    fn is_flags(variants: &[ag::EnumVariant]) -> bool {
+        let Some(mut values) = variants.iter().map(|x| x.index).collect::<Option<Vec<_>>>() else { return false };
+        values.sort();
+        values.dedup();
+        let bits = values.iter().filter(|x| **x > 0 && x.count_ones() == 1).fold(0, |bits, x| bits | x);
+        let consecutive = values.windows(2).all(|x| x[1] == x[0] + 1);
+        values.len() == variants.len() && !consecutive && bits.count_ones() >= 3 && values.iter().all(|x| *x >= 0 && x & !bits == 0)
+    }
+
+    /// Classifies structs whose fields are all blittable or convertible, as described by [`Marshal::is_embeddable`],
+    /// along with enums whose variants carry such fields, and resolves type aliases to their targets. Items are
+    /// visited in dependency order (using Kahn's algorithm) so that every field type or alias target is classified
+    /// before the items that refer to it. Items that participate in a cycle are never visited, and so remain
+    /// unmarshalable.
+    fn collect_primitive_structs(&mut self) {
+        let structs = [WorkKind::Struct, WorkKind::TypeAlias, WorkKind::Enum].iter()
+            .flat_map(|x| self.pending.get(x).into_iter().flatten())
+            .copied()
+            .filter(|x| !matches!(self.item(*x).inner, ItemEnum::Enum(_)) || matches!(self.skip_reasons.get(x), Some(report::SkipReason::PayloadEnum { .. })))
+            .collect::<Vec<_>>();
+
+        let mut in_degree = structs.iter().map(|x| (*x, 0usize)).collect::<HashMap<_, _>>();
+        let mut dependents = HashMap::<ItemId, Vec<ItemId>>::new();
+        for id in &structs {
+            for dependency in self.item_dependencies(*id) {
+                if in_degree.contains_key(&dependency) {
+                    dependents.entry(dependency).or_default().push(*id);
+                    *in_degree.get_mut(id).expect("Struct was not in graph") += 1;
+                }
+            }
+        }
+
+        let mut queue = structs.iter().copied().filter(|x| in_degree[x] == 0).collect::<VecDeque<_>>();
+        while let Some(id) = queue.pop_front() {
+            let collected_item = match self.item(id).inner {
+                ItemEnum::TypeAlias(_) => self.collect_type_alias(id),
+                ItemEnum::Enum(_) => self.collect_payload_enum(id),
+                _ => self.collect_primitive_struct(id)
+            }.unwrap_or_else(|e| self.fail(id, e));
+
+            if collected_item {
+                self.collected.insert(id);
+            }
+
+            for dependent in dependents.get(&id).into_iter().flatten() {
+                let degree = in_degree.get_mut(dependent).expect("Struct was not in graph");
+                *degree -= 1;
+                if *degree == 0 {
+                    queue.push_back(*dependent);
+                }
+            }
+        }
+
+        for (id, degree) in in_degree {
+            if degree > 0 {
+                self.skip(id, report::SkipReason::Cycle);
+            }
+        }
+
+        for kind in [WorkKind::Struct, WorkKind::TypeAlias, WorkKind::Enum] {
+            let mut pending = self.pending.remove(&kind).unwrap_or_default();
+            pending.retain(|x| !self.collected.contains(x));
+            self.pending.insert(kind, pending);
+        }
+    }
+
+    /// Gets the set of items that the fields of a struct or of the variants of an enum, or the target of a
+    /// type alias, refer to.
+    fn item_dependencies(&self, id: ItemId) -> HashSet<ItemId> {
+        let mut result = HashSet::new();
+        let fields = match &self.item(id).inner {
+            ItemEnum::Struct(x) => match &x.kind {
+                StructKind::Unit => Vec::new(),
+                StructKind::Tuple(fields) => fields.iter().flatten().copied().collect(),
+                StructKind::Plain { fields, .. } => fields.clone()
+            },
+            ItemEnum::Enum(x) => x.variants.iter()
+                .filter_map(|x| match &self.crates[id.krate].index.get(x)?.inner {
+                    ItemEnum::Variant(variant) => Some(Self::variant_fields(variant).unwrap_or_default()),
+                    _ => None
+                })
+                .flatten()
+                .collect(),
+            ItemEnum::TypeAlias(x) => {
+                self.type_dependencies(id.krate, &x.type_, &mut result);
+                return result;
+            },
+            _ => unreachable!()
+        };
+
+        for field in &fields {
+            if let Some(Item { inner: ItemEnum::StructField(ty), .. }) = self.crates[id.krate].index.get(field) {
+                self.type_dependencies(id.krate, ty, &mut result);
+            }
+        }
+
+        result
+    }
+
+    /// Adds all items referenced by the given type to the dependency set.
+    fn type_dependencies(&self, krate: usize, ty: &Type, result: &mut HashSet<ItemId>) {
+        match ty {
+            Type::ResolvedPath(path) => {
+                result.extend(self.resolve(krate, path.id));
+                if let Some(GenericArgs::AngleBracketed { args, .. }) = path.args.as_deref() {
+                    for arg in args {
+                        if let GenericArg::Type(ty) = arg {
+                            self.type_dependencies(krate, ty, result);
+                        }
+                    }
+                }
+            },
+            Type::Tuple(elements) => elements.iter().for_each(|x| self.type_dependencies(krate, x, result)),
+            Type::Array { type_, .. } | Type::Slice(type_) => self.type_dependencies(krate, type_, result),
+            _ => {}
+        }
+    }
+
+    /// Registers a type alias as a known type, marshaled in the same way as its target.
+    fn collect_type_alias(&mut self, id: ItemId) -> Result<bool, BindgenError> {
+        let ItemEnum::TypeAlias(x) = &self.item(id).inner else { unreachable!() };
+        if !x.generics.params.is_empty() {
+            return Ok(self.skip(id, report::SkipReason::Generic));
+        }
+
+        let reference = self.type_reference(id.krate, &x.type_);
+        let strategy = self.marshal_strategy(id.krate, &x.type_);
+        let (Some(reference), Some(strategy)) = (reference, strategy) else {
+            let ty = self.type_key(id.krate, &x.type_);
+            return Ok(self.skip(id, report::SkipReason::UnsupportedTarget { ty }));
+        };
+
+        self.known_types.insert(self.rust_name(id), KnownType { strategy, ..KnownType::from_reference(reference) });
+        Ok(true)
+    }
+
+    fn collect_primitive_struct(&mut self, id: ItemId) -> Result<bool, BindgenError> {
+        self.instantiate_fields(id);
+        let struct_ty = self.item(id);
+        let ItemEnum::Struct(x) = &struct_ty.inner else { unreachable!() };
+        if !x.generics.params.is_empty() {
+            return Ok(self.skip(id, report::SkipReason::Generic));
+        }
+        else if Self::is_non_exhaustive(struct_ty) {
+            return Ok(self.skip(id, report::SkipReason::NonExhaustive));
+        }
+
+        // Unit structs are skipped, since C# cannot declare a zero-sized struct.
+        let fields = match &x.kind {
+            StructKind::Plain { fields, has_stripped_fields: false } => fields.clone(),
+            StructKind::Tuple(fields) if !fields.is_empty() => {
+                let Some(fields) = fields.iter().copied().collect::<Option<Vec<_>>>() else {
+                    return Ok(self.skip(id, report::SkipReason::StrippedFields));
+                };
+                fields
+            },
+            StructKind::Plain { has_stripped_fields: true, .. } => return Ok(self.skip(id, report::SkipReason::StrippedFields)),
+            _ => return Ok(self.skip(id, report::SkipReason::UnitStruct))
+        };
+
+        let mut strategies = Vec::with_capacity(fields.len());
+        let mut struct_fields = Vec::with_capacity(fields.len());
+        let mut field_types = Vec::with_capacity(fields.len());
+        let mut defaulted = Vec::new();
+        for field in &fields {
+            let field_item = self.member(id, field)?;
+            if self.unsatisfied_cfg(field_item).is_some() {
+                continue;
+            }
+
+            let ItemEnum::StructField(ty) = &field_item.inner else {
+                return Err(BindgenError::UnexpectedKind { path: self.rust_name(id), expected: "field" });
+            };
+            let field_name = self.member_name(id, field_item, "field")?;
+            let (field_strategy, field_ty) = match self.view_vector(id.krate, ty) {
+                Some(field_ty) => (Some(Marshal::View), Some(field_ty)),
+                None => {
+                    let field_strategy = self.marshal_strategy(id.krate, ty).filter(|x| x.is_embeddable() || *x == Marshal::View);
+                    (field_strategy.clone(), self.type_reference(id.krate, ty).filter(|_| field_strategy.is_some()))
+                }
+            };
+            let Some(field_ty) = field_ty else {
+                let unknown = self.unknown_instantiation(id.krate, ty);
+                let ty = self.type_key(id.krate, ty);
+                // Configured structs leave the field out of their mirror, if they can fill it from `Default`.
+                let rust_name = self.rust_name(id);
+                if self.defaults.contains(&rust_name) && self.implements(&rust_name, "core::default::Default") {
+                    defaulted.push((field_name, ty));
+                    continue;
+                }
+
+                if let Some(unknown) = unknown {
+                    let field_path = format!("{}::{field_name}", self.rust_name(id));
+                    self.unknown_instantiations.entry(unknown).or_default().insert(field_path);
+                }
+
+                return Ok(self.skip(id, report::SkipReason::NonCopyField { field: field_name, ty }));
+            };
+
+            strategies.extend(field_strategy);
+            field_types.push(ty.clone());
+
+            let rename = self.renames.get(&format!("{}::{field_name}", self.rust_name(id))).cloned();
+            struct_fields.push(ag::StructField {
+                name: field_name,
+                rename,
+                ty: field_ty,
+                deprecation: Self::deprecation(field_item),
+                docs: self.docs(id.krate, field_item)
+            });
+        }
+
+        // C# cannot declare a zero-sized struct, so at least one field must be kept.
+        if struct_fields.is_empty() && !defaulted.is_empty() {
+            let (field, ty) = defaulted.swap_remove(0);
+            return Ok(self.skip(id, report::SkipReason::NonCopyField { field, ty }));
+        }
+
+        let rust_name = self.rust_name(id);
+        let repr = Self::repr_attributes(struct_ty);
+        let transparent = matches!(x.kind, StructKind::Tuple(_)) || repr.iter().any(|x| x == "transparent");
+        // Newtypes of plain data are marshaled as their field in C#. Rust still needs a mirror to convert to and from, but
+        // it is passed exactly like the field.
+        let newtype = transparent && struct_fields.len() == 1 && defaulted.is_empty() && !self.distinct_newtypes.contains(&rust_name)
+            && matches!(strategies[0], Marshal::Blittable | Marshal::ConvertOnCopy { .. });
+        let external = match newtype {
+            true => Some(ag::ExternalType { cs_name: ag::DisplayCs(&struct_fields[0].ty, &ag::RenderContext::default()).to_string(), conversions: false }),
+            false => self.external_types.get(&rust_name).cloned()
+        };
+
+        let packing = match self.struct_packing(&repr) {
+            Ok(packing) => packing,
+            Err(repr) => return Ok(self.skip(id, report::SkipReason::UnsupportedLayout { repr }))
+        };
+
+        let view = Self::holds_view(&struct_fields, &strategies);
+        if view {
+            for ((field, strategy), ty) in struct_fields.iter_mut().zip(&strategies).zip(&field_types) {
+                let Some(field_ty) = self.view_field(&field.ty, strategy) else {
+                    let ty = self.type_key(id.krate, ty);
+                    return Ok(self.skip(id, report::SkipReason::NonCopyField { field: field.name.clone(), ty }));
+                };
+                field.ty = field_ty;
+            }
+        }
+
+        // Views hold borrows, so they are never compared, formatted, or created by C#.
+        let owns_strings = !view && self.holds_strings(&struct_fields);
+        let c_layout = !view && defaulted.is_empty() && repr.iter().any(|x| x == "C" || x == "transparent") && struct_fields.iter().all(|x| self.has_c_layout(&x.ty));
+        let item = ag::Item::Struct {
+            name: self.short_name(id)?,
+            module: self.module_path(id),
+            rename: self.renames.get(&self.rust_name(id)).cloned(),
+            alias: self.rs_alias(id)?,
+            fields: struct_fields,
+            has_default: !view && self.implements(&rust_name, "core::default::Default"),
+            has_equality: !view && self.implements(&rust_name, "core::cmp::PartialEq"),
+            operators: if view { Vec::new() } else { self.operators(&rust_name) },
+            format: if view { None } else { self.format_trait(&rust_name) },
+            external,
+            packing,
+            transparent: newtype,
+            conversions: true,
+            owns_strings,
+            view,
+            positional: None,
+            defaulted: !defaulted.is_empty(),
+            naming: self.naming.clone(),
+            deprecation: Self::deprecation(struct_ty),
+            origin: Some(self.origin(id)),
+            docs: self.docs(id.krate, struct_ty)
+        };
+        if c_layout {
+            self.c_layouts.insert(item.rs_name());
+        }
+
+        // Mirrors which leave fields out have a different layout from the original, so they are always converted.
+        let blittable = defaulted.is_empty() && strategies.iter().all(|x| *x == Marshal::Blittable);
+        for (field, ty) in defaulted {
+            self.defaulted_fields.push(report::SkippedItem { path: format!("{rust_name}::{field}"), kind: "field", reason: report::SkipReason::Defaulted { ty } });
+        }
+
+        let strategy = if view { Marshal::View } else if blittable { Marshal::Blittable } else { Marshal::convert(&item) };
+        self.known_types.insert(rust_name, KnownType::item(&item, strategy));
+        self.items.push(item);
+        Ok(true)
+    }
+
+    /// Gets the type of a vector whose elements are converted when viewed, rather than borrowed, since their
+    /// mirrors have a different layout. Only views may hold such vectors, and their copies are kept along with them.
+    fn view_vector(&self, krate: usize, ty: &Type) -> Option<ag::TypeReference> {
+        let element = self.vec_argument(krate, ty)?;
+        match self.marshal_strategy(krate, element)? {
+            Marshal::ConvertOnCopy { .. } | Marshal::View => {},
+            _ => return None
+        }
+
+        match self.type_reference(krate, element)? {
+            element @ ag::TypeReference::Named { .. } => Some(ag::TypeReference::Slice { element: Box::new(element), ownership: ag::SliceOwnership::Converted }),
+            _ => None
+        }
+    }
+
+    /// Whether the fields of a struct or variant, with their strategies, make it a view: it holds a vector of
+    /// blittable elements, or another view.
+    fn holds_view(fields: &[ag::StructField], strategies: &[Marshal]) -> bool {
+        fields.iter().zip(strategies).any(|(field, strategy)| *strategy == Marshal::View
+            || matches!(field.ty, ag::TypeReference::Slice { ownership: ag::SliceOwnership::Owned, .. }))
+    }
+
+    /// Whether a mirror with these fields holds strings, directly or within other mirrors, so that C# must free it.
+    fn holds_strings<'a>(&self, fields: impl IntoIterator<Item = &'a ag::StructField>) -> bool {
+        let mut result = false;
+        for field in fields {
+            field.ty.visit(&mut |x| result |= match x {
+                ag::TypeReference::Primitive(ag::PrimitiveType::String) => true,
+                ag::TypeReference::Named { rs_name, .. } => self.items.iter()
+                    .any(|x| matches!(x, ag::Item::Struct { owns_strings: true, .. } | ag::Item::Union { owns_strings: true, .. }) && x.rs_name() == *rs_name),
+                _ => false
+            });
+        }
+        result
+    }
+
+    /// Gets the type of a field within a view, if it can have one. Vectors are borrowed for the frame, so their
+    /// elements must have the same layout as their mirrors, while other fields must be copied without allocating.
+    fn view_field(&self, reference: &ag::TypeReference, strategy: &Marshal) -> Option<ag::TypeReference> {
+        match reference {
+            ag::TypeReference::Slice { element, ownership: ag::SliceOwnership::Owned } if self.has_c_layout(element) => {
+                Some(ag::TypeReference::Slice { element: element.clone(), ownership: ag::SliceOwnership::Frame })
+            },
+            _ if *strategy == Marshal::View || self.copies_cleanly(reference) => Some(reference.clone()),
+            _ => None
+        }
+    }
+
+    /// Whether a type has the same layout as its mirror: primitives other than strings, known types which are
+    /// not generated, and `#[repr(C)]` structs of such types.
+    fn has_c_layout(&self, reference: &ag::TypeReference) -> bool {
+        match reference {
+            ag::TypeReference::Primitive(primitive) => *primitive != ag::PrimitiveType::String,
+            ag::TypeReference::Named { rs_name, .. } => self.c_layouts.contains(rs_name) || !self.items.iter().any(|x| x.rs_name() == *rs_name),
+            ag::TypeReference::Array { element, .. } => self.has_c_layout(element),
+            _ => false
+        }
+    }
+
+    /// Whether a value may be copied into its mirror without allocating, which is to say that it holds no
+    /// strings or vectors.
+    fn copies_cleanly(&self, reference: &ag::TypeReference) -> bool {
+        let mut clean = true;
+        self.visit_field_types(reference, &mut |x| clean &= !matches!(x, ag::TypeReference::Primitive(ag::PrimitiveType::String) | ag::TypeReference::Slice { .. }));
+        clean
+    }
+
+    /// Visits a type, along with the types of the fields of any generated items it refers to.
+    fn visit_field_types(&self, reference: &ag::TypeReference, f: &mut impl FnMut(&ag::TypeReference)) {
+        f(reference);
+        match reference {
+            ag::TypeReference::Array { element, .. } | ag::TypeReference::Slice { element, .. } => self.visit_field_types(element, f),
+            ag::TypeReference::Optional(inner) => self.visit_field_types(inner, f),
+            ag::TypeReference::Named { rs_name, .. } => match self.items.iter().find(|x| x.rs_name() == *rs_name) {
+                Some(ag::Item::Struct { fields, .. }) => fields.iter().for_each(|x| self.visit_field_types(&x.ty, f)),
+                Some(ag::Item::Union { variants, .. }) => variants.iter().flat_map(|x| &x.fields).for_each(|x| self.visit_field_types(&x.ty, f)),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Generates the structs configured for the instantiations of generic types within the fields of a
+    /// struct. Instantiations which cannot be generated are reported by [`Self::collect_instantiations`].
+    fn instantiate_fields(&mut self, id: ItemId) {
+        let ItemEnum::Struct(x) = &self.item(id).inner else { return };
+        let fields = match &x.kind {
+            StructKind::Unit => Vec::new(),
+            StructKind::Tuple(fields) => fields.iter().flatten().copied().collect(),
+            StructKind::Plain { fields, .. } => fields.clone()
+        };
+
+        let mut keys = Vec::new();
+        for field in &fields {
+            if let Some(Item { inner: ItemEnum::StructField(ty), .. }) = self.crates[id.krate].index.get(field) {
+                self.instantiation_keys(id.krate, ty, &mut keys);
+            }
+        }
+
+        for key in keys {
+            let _ = self.instantiate(&key);
+        }
+    }
+
+    /// Generates the structs of every configured instantiation which no struct field has used, so that
+    /// functions may refer to them. Instantiations with fields that cannot be embedded are reported.
+    fn collect_instantiations(&mut self) {
+        let mut keys = self.instantiations.keys().cloned().collect::<Vec<_>>();
+        keys.sort();
+        for key in keys {
+            if let Err(ty) = self.instantiate(&key) {
+                log::warn!("Instantiation `{key}` has field type `{ty}`, which cannot be embedded in a struct");
+            }
+        }
+    }
+
+    /// Generates the struct configured for an instantiation of a generic type, tuple, or array, unless it already
+    /// exists. Returns the type of the first field that cannot be embedded if the struct cannot be generated.
+    fn instantiate(&mut self, key: &str) -> Result<(), String> {
+        if self.known_types.contains_key(key) {
+            return Ok(());
+        }
+
+        // The fields of tuples and arrays are their elements, in order.
+        let positional = (key.starts_with('(') || key.starts_with('[')).then(|| Self::short_type_name(key));
+        let instantiation = self.instantiations[key].clone();
+        let mut fields = Vec::with_capacity(instantiation.fields.len());
+        let mut strategies = Vec::with_capacity(instantiation.fields.len());
+        for (name, ty) in &instantiation.fields {
+            let ty_key = Self::normalize_type_key(ty);
+            let known_type = match Self::primitive_type(&ty_key) {
+                Some(primitive) => Some(KnownType::primitive(self.ffi_primitive(primitive))),
+                None => self.known_types.get(&ty_key).filter(|x| x.strategy.is_embeddable() || x.strategy == Marshal::View).cloned()
+            };
+            let Some(KnownType { reference: Some(reference), strategy, .. }) = known_type else {
+                return Err(ty.clone());
+            };
+
+            fields.push(ag::StructField {
+                name: name.clone(),
+                rename: None,
+                ty: reference,
+                deprecation: None,
+                docs: String::new()
+            });
+            strategies.push(strategy);
+        }
+
+        let view = Self::holds_view(&fields, &strategies);
+        if view {
+            for ((field, strategy), (_, ty)) in fields.iter_mut().zip(&strategies).zip(&instantiation.fields) {
+                field.ty = self.view_field(&field.ty, strategy).ok_or_else(|| ty.clone())?;
+            }
+        }
+
+        // Strings are only freed by converting them back, which instantiations of generic types cannot do.
+        let owns_strings = positional.is_some() && !view && self.holds_strings(&fields);
+
+        // The generic type has no generated Rust mirror of its own, so traits cannot be forwarded to it. Tuples and
+        // arrays are built from their elements instead, and belong to no module.
+        let generic_path = key.split_once('<').map_or(key, |x| x.0);
+        let item = ag::Item::Struct {
+            name: instantiation.name,
+            module: if positional.is_some() { String::new() } else { generic_path.rsplit_once("::").map_or("", |x| x.0).to_string() },
+            rename: None,
+            alias: None,
+            fields,
+            has_default: false,
+            has_equality: false,
+            operators: Vec::new(),
+            format: None,
+            external: None,
+            packing: None,
+            transparent: false,
+            conversions: positional.is_some(),
+            owns_strings,
+            view,
+            positional,
+            defaulted: false,
+            naming: self.naming.clone(),
+            deprecation: None,
+            origin: Some(ag::Origin { path: key.to_string(), span: None }),
+            docs: format!("An instantiation of `{key}`.")
+        };
+
+        let strategy = if view { Marshal::View } else { Marshal::convert(&item) };
+        self.known_types.insert(key.to_string(), KnownType::item(&item, strategy));
+        self.items.push(item);
+        Ok(())
+    }
+
+    /// Shortens the paths within a type key to the names of the items, like `(TextureId, ImageDelta)`, since
+    /// generated code refers to types by name.
+    fn short_type_name(key: &str) -> String {
+        let mut result = String::new();
+        let mut start = 0;
+        for (index, c) in key.char_indices().filter(|(_, c)| !(c.is_alphanumeric() || *c == '_' || *c == ':')) {
+            result += key[start..index].rsplit("::").next().unwrap_or_default();
+            result.push(c);
+            start = index + c.len_utf8();
+        }
+        result += key[start..].rsplit("::").next().unwrap_or_default();
+        result
+    }
+
+    fn collect_constants(&mut self) {
+        self.collect_pending(WorkKind::Constant, Self::collect_constant);
+    }
+
+    /// Generates an accessor for a free constant, or a constant associated with a generated struct.
+    fn collect_constant(&mut self, id: ItemId) -> Result<bool, BindgenError> {
+        let constant = self.item(id);
+        let (ty, owner) = match &constant.inner {
+            ItemEnum::Constant { type_, .. } => (type_, None),
+            ItemEnum::AssocConst { type_, .. } => {
+                let Some(ItemEnum::Impl(imp)) = self.parents.get(&id).map(|x| &self.item(*x).inner) else {
+                    return Ok(self.skip(id, report::SkipReason::UnsupportedKind));
+                };
+
+                // Associated constants are emitted onto the owner's C# struct, so the owner must have been generated.
+                let owner = self.type_key(id.krate, &imp.for_);
+                let owner_reference = owner.as_ref().and_then(|x| self.known_types.get(x)?.reference.as_ref());
+                let owner_item = self.items.iter().find(|item| matches!(item, ag::Item::Struct { .. })
+                    && matches!(owner_reference, Some(ag::TypeReference::Named { cs_name, .. }) if *cs_name == item.cs_name()));
+                let Some(owner_item) = owner_item else {
+                    return Ok(self.skip(id, report::SkipReason::UnsupportedOwner { owner }));
+                };
+
+                let ty = if matches!(type_, Type::Generic(x) if x == "Self") { &imp.for_ } else { type_ };
+                (ty, Some((owner_item.cs_name(), owner_item.module().to_string())))
+            },
+            _ => unreachable!()
+        };
+
+        let reference = self.type_reference(id.krate, ty).filter(|_| self.marshal_strategy(id.krate, ty).is_some_and(|x| x.is_embeddable()));
+        let Some(ty) = reference else {
+            let ty = self.type_key(id.krate, ty);
+            return Ok(self.skip(id, report::SkipReason::UnsupportedTarget { ty }));
+        };
+
+        // Associated constants share the module of their owner, so that they are laid out alongside it.
+        let (owner, module) = match owner {
+            Some((owner, module)) => (Some(owner), module),
+            None => (None, self.module_path(id))
+        };
+
+        let item = ag::Item::Constant {
+            name: self.short_name(id)?,
+            module,
+            owner,
+            path: self.public_path(id),
+            ty,
+            naming: self.naming.clone(),
+            deprecation: Self::deprecation(constant),
+            origin: Some(self.origin(id)),
+            docs: self.docs(id.krate, constant)
+        };
+        self.items.push(item);
+        Ok(true)
+    }
+
+    fn collect_statics(&mut self) {
+        self.collect_pending(WorkKind::Static, Self::collect_static);
+    }
+
+    /// Generates an accessor for an immutable static. The value is copied out on every access,
+    /// so statics that own buffers are not supported.
+    fn collect_static(&mut self, id: ItemId) -> Result<bool, BindgenError> {
+        let static_item = self.item(id);
+        let ItemEnum::Static(x) = &static_item.inner else { unreachable!() };
+        if x.is_mutable {
+            return Ok(self.skip(id, report::SkipReason::MutableStatic));
+        }
+
+        let strategy = self.marshal_strategy(id.krate, &x.type_);
+        let reference = self.type_reference(id.krate, &x.type_).filter(|_| strategy.is_some_and(|x| x.is_embeddable() && x != Marshal::StringLike));
+        let Some(ty) = reference else {
+            let ty = self.type_key(id.krate, &x.type_);
+            return Ok(self.skip(id, report::SkipReason::UnsupportedTarget { ty }));
+        };
+
+        let item = ag::Item::Static {
+            name: self.short_name(id)?,
+            module: self.module_path(id),
+            path: self.public_path(id),
+            ty,
+            naming: self.naming.clone(),
+            deprecation: Self::deprecation(static_item),
+            origin: Some(self.origin(id)),
+            docs: self.docs(id.krate, static_item)
+        };
+        self.items.push(item);
+        Ok(true)
+    }
+
+    /// Turns structs that could not be copied across the FFI boundary, including those whose fields are
+    /// private, into opaque classes which are instead referred to by handle.
+    fn collect_classes(&mut self) {
+        self.collect_pending(WorkKind::Struct, Self::collect_class);
+    }
+
+    fn collect_class(&mut self, id: ItemId) -> Result<bool, BindgenError> {
+        let struct_ty = self.item(id);
+        let ItemEnum::Struct(x) = &struct_ty.inner else { unreachable!() };
+        // Objects owned by C# may live for any length of time, so their lifetimes are `'static`. Other generic
+        // parameters cannot be chosen.
+        let lifetimes = x.generics.params.iter().filter(|x| matches!(x.kind, GenericParamDefKind::Lifetime { .. })).count();
+        if lifetimes < x.generics.params.len() || !matches!(self.skip_reasons.get(&id), Some(report::SkipReason::NonCopyField { .. } | report::SkipReason::Cycle | report::SkipReason::StrippedFields | report::SkipReason::NonExhaustive | report::SkipReason::Generic)) {
+            return Ok(false);
+        }
+
+        let item = ag::Item::Class {
+            name: self.short_name(id)?,
+            module: self.module_path(id),
+            rename: self.renames.get(&self.rust_name(id)).cloned(),
+            alias: self.rs_alias(id)?,
+            constructors: Vec::new(),
+            methods: Vec::new(),
+            has_default: self.implements(&self.rust_name(id), "core::default::Default"),
+            cloneable: self.implements(&self.rust_name(id), "core::clone::Clone"),
+            format: self.format_trait(&self.rust_name(id)),
+            safe_handle: self.safe_handles,
+            lifetimes,
+            naming: self.naming.clone(),
+            deprecation: Self::deprecation(struct_ty),
+            origin: Some(self.origin(id)),
+            docs: self.docs(id.krate, struct_ty)
+        };
+        self.known_types.insert(self.rust_name(id), KnownType::class(&item));
+        self.skip_reasons.remove(&id);
+        self.items.push(item);
+        Ok(true)
+    }
+
+    fn collect_methods(&mut self) {
+        self.collect_pending(WorkKind::Method, Self::collect_method);
+    }
+
+    /// Adds a method from an inherent `impl` block to the class that it belongs to. Associated
+    /// functions which return the class become constructors, while others are left alone.
+    fn collect_method(&mut self, id: ItemId) -> Result<bool, BindgenError> {
+        let function = self.item(id);
+        let ItemEnum::Function(x) = &function.inner else { unreachable!() };
+        let ItemEnum::Impl(imp) = &self.item(self.parents[&id]).inner else { unreachable!() };
+        if imp.trait_.is_some() {
+            return Ok(false);
+        }
+
+        let owner = self.type_key(id.krate, &imp.for_);
+        let class = owner.as_ref()
+            .and_then(|x| self.known_types.get(x))
+            .filter(|x| x.strategy == Marshal::Handle)
+            .and_then(|x| self.items.iter().position(|item| matches!(item, ag::Item::Class { .. }) && item.cs_name() == x.cs_name));
+        let Some(class) = class else {
+            return Ok(self.skip(id, report::SkipReason::UnsupportedOwner { owner }));
+        };
+        if owner.as_ref().is_some_and(|x| self.is_unlisted_method(x, function.name.as_deref().unwrap_or_default())) {
+            return Ok(self.skip(id, report::SkipReason::Configured));
+        }
+
+        let Some((receiver_name, receiver_ty)) = x.sig.inputs.first().filter(|x| x.0 == "self") else {
+            let returns_self = match &x.sig.output {
+                Some(Type::Generic(x)) => x == "Self",
+                Some(output) => self.type_key(id.krate, output).is_some_and(|x| Some(x) == owner),
+                None => false
+            };
+
+            if !returns_self {
+                return Ok(false);
+            }
+
+            return self.collect_constructor(id, class);
+        };
+
+        let receiver = match receiver_ty {
+            Type::Generic(x) if x == "Self" => ag::Receiver::Value,
+            Type::BorrowedRef { is_mutable, type_, .. } if matches!(&**type_, Type::Generic(x) if x == "Self") => {
+                if *is_mutable { ag::Receiver::RefMut } else { ag::Receiver::Ref }
+            },
+            _ => return Ok(self.skip(id, report::SkipReason::UnsupportedParameter { parameter: receiver_name.clone(), ty: None }))
+        };
+
+        // Configured parameters replace generic ones, so the generics of configured methods are not checked.
+        let path = self.rust_name(id);
+        let configured = self.methods.get(&path);
+        if configured.is_none() && !self.callback_generics(id.krate, &x.generics) {
+            return Ok(self.skip(id, report::SkipReason::Generic));
+        }
+        else if x.header.is_unsafe || x.header.is_async || x.sig.is_c_variadic {
+            return Ok(self.skip(id, report::SkipReason::UnsupportedKind));
+        }
+
+        let inputs = x.sig.inputs[1..].iter().map(|(name, ty)| (name.clone(), Self::replace_self(ty, &imp.for_))).collect::<Vec<_>>();
+        let output = Self::callback_output(&x.generics, &x.sig.output).map(|x| Self::replace_self(&x, &imp.for_));
+
+        // The lifetimes of objects owned by C# are `'static`, which borrows passed in for them would not live for.
+        let lifetimes = imp.generics.params.iter()
+            .filter(|x| matches!(x.kind, GenericParamDefKind::Lifetime { .. }))
+            .map(|x| x.name.as_str())
+            .collect::<Vec<_>>();
+        if let Some((parameter, ty)) = inputs.iter().find(|x| Self::mentions_lifetime(&x.1, &lifetimes)) {
+            let reason = report::SkipReason::UnsupportedParameter { parameter: parameter.clone(), ty: self.type_key(id.krate, ty) };
+            return Ok(self.skip(id, reason));
+        }
+        let overrides = configured.map_or(&[][..], |x| &x.params);
+        let (params, mut ret) = match self.signature(id.krate, &inputs, &output, overrides) {
+            Ok(signature) => signature,
+            Err(reason) => return Ok(self.skip(id, reason))
+        };
+        if let Some(returns) = configured.and_then(|x| x.returns.as_ref()) {
+            let Some(reference) = self.configured_return(returns) else {
+                return Ok(self.skip(id, report::SkipReason::UnsupportedReturn { ty: Some(returns.clone()) }));
+            };
+            ret = Some(reference);
+        }
+        let callbacks = inputs.iter().filter_map(|(_, ty)| self.callback(id.krate, ty)).collect();
+        let property = configured.is_some_and(|x| x.property) && Self::is_property(&path, receiver, &params, &ret);
+
+        let method = ag::Method {
+            name: self.short_name(id)?,
+            rename: self.renames.get(&path).cloned(),
+            receiver,
+            params,
+            ret,
+            call: configured.and_then(|x| x.call.clone()),
+            instance: None,
+            property,
+            deprecation: Self::deprecation(function),
+            origin: Some(self.origin(id)),
+            docs: match configured.filter(|x| !x.docs.is_empty()) {
+                Some(configured) => configured.docs.clone(),
+                None => self.docs(id.krate, function)
+            }
+        };
+
+        let ag::Item::Class { methods, .. } = &mut self.items[class] else { unreachable!() };
+        methods.push(method);
+        self.collect_callbacks(callbacks, self.items[class].module().to_string());
+        Ok(true)
+    }
+
+    /// Adds the configured methods which do not exist in the loaded crates, such as a `Ui::slider` which calls
+    /// `ui.add(Slider::new(...))`. These are bound through their `call` expressions, and take the object mutably.
+    fn collect_configured_methods(&mut self) {
+        let mut paths = self.methods.iter().filter(|x| x.1.call.is_some()).map(|x| x.0.clone()).collect::<Vec<_>>();
+        paths.sort();
+        for path in paths {
+            let configured = &self.methods[&path];
+            let Some((owner, name)) = path.rsplit_once("::") else { continue };
+            let class = self.known_types.get(owner)
+                .filter(|x| x.strategy == Marshal::Handle)
+                .and_then(|x| self.items.iter().position(|item| matches!(item, ag::Item::Class { .. }) && item.cs_name() == x.cs_name));
+            let Some(class) = class else {
+                log::warn!("Configured method `{path}` does not belong to a generated class");
+                continue;
+            };
+            if self.is_unlisted_method(owner, name) {
+                continue;
+            }
+
+            let ag::Item::Class { methods, constructors, .. } = &self.items[class] else { unreachable!() };
+            if methods.iter().any(|x| x.name == name) || constructors.iter().any(|x| x.name == name) {
+                continue;
+            }
+
+            // Each instantiation substitutes its type for `T`, and generates an overload.
+            let instances = if configured.instantiations.is_empty() {
+                vec![None]
+            }
+            else {
+                configured.instantiations.iter().cloned().map(Some).collect()
+            };
+            for instance in instances {
+                let instantiate = |ty: &str| match &instance {
+                    Some(instance) => Self::substitute_generic(ty, "T", instance),
+                    None => ty.to_string()
+                };
+                let params = configured.params.iter()
+                    .map(|(param, ty)| self.configured_type(&instantiate(ty)).filter(|x| !self.returns_only(x)).map(|ty| ag::Parameter { name: param.clone(), ty }).ok_or(instantiate(ty)))
+                    .collect::<Result<Vec<_>, _>>();
+                let ret = configured.returns.as_ref()
+                    .map(|ty| self.configured_return(&instantiate(ty)).ok_or(instantiate(ty)))
+                    .transpose();
+                let (params, ret) = match (params, ret) {
+                    (Ok(params), Ok(ret)) => (params, ret),
+                    (Err(ty), _) | (_, Err(ty)) => {
+                        log::warn!("Configured method `{path}` has unsupported type `{ty}`");
+                        continue;
+                    }
+                };
+
+                // Properties only read the object, while other methods may modify it.
+                let receiver = if configured.property { ag::Receiver::Ref } else { ag::Receiver::RefMut };
+                let method = ag::Method {
+                    name: name.to_string(),
+                    rename: self.renames.get(&path).cloned(),
+                    receiver,
+                    property: configured.property && Self::is_property(&path, receiver, &params, &ret),
+                    params,
+                    ret,
+                    call: configured.call.clone(),
+                    instance,
+                    deprecation: None,
+                    origin: Some(ag::Origin { path: path.clone(), span: None }),
+                    docs: configured.docs.clone()
+                };
+                let ag::Item::Class { methods, .. } = &mut self.items[class] else { unreachable!() };
+                methods.push(method);
+            }
+        }
+    }
+
+    /// Replaces each occurrence of a generic parameter in a configured type, such as `T` in `&mut T`.
+    fn substitute_generic(ty: &str, name: &str, replacement: &str) -> String {
+        let mut result = String::with_capacity(ty.len());
+        let mut rest = ty;
+        while let Some(start) = rest.find(|x: char| x.is_alphanumeric() || x == '_') {
+            result += &rest[..start];
+            rest = &rest[start..];
+            let end = rest.find(|x: char| !(x.is_alphanumeric() || x == '_')).unwrap_or(rest.len());
+            result += if &rest[..end] == name { replacement } else { &rest[..end] };
+            rest = &rest[end..];
+        }
+        result + rest
+    }
+
+    /// Whether a method configured as a property can be one, warning if it cannot.
+    fn is_property(path: &str, receiver: ag::Receiver, params: &[ag::Parameter], ret: &Option<ag::TypeReference>) -> bool {
+        let valid = receiver == ag::Receiver::Ref && params.is_empty() && ret.is_some();
+        if !valid {
+            log::warn!("Configured property `{path}` must take `&self`, have no parameters, and return a value");
+        }
+        valid
+    }
+
+    /// Adds an associated function which returns a new object to the given class.
+    fn collect_constructor(&mut self, id: ItemId, class: usize) -> Result<bool, BindgenError> {
+        let function = self.item(id);
+        let ItemEnum::Function(x) = &function.inner else { unreachable!() };
+        // As for methods, configured parameters replace generic ones.
+        let configured = self.methods.get(&self.rust_name(id));
+        if configured.is_none() && x.generics.params.iter().any(|x| !matches!(x.kind, GenericParamDefKind::Lifetime { .. })) {
+            return Ok(self.skip(id, report::SkipReason::Generic));
+        }
+        else if x.header.is_unsafe || x.header.is_async || x.sig.is_c_variadic {
+            return Ok(self.skip(id, report::SkipReason::UnsupportedKind));
+        }
+
+        let overrides = configured.map_or(&[][..], |x| &x.params);
+        let (params, _) = match self.signature(id.krate, &x.sig.inputs, &None, overrides) {
+            Ok(signature) => signature,
+            Err(reason) => return Ok(self.skip(id, reason))
+        };
+        // C# constructors call into Rust before their bodies run, so they cannot pin slices.
+        if let Some(param) = params.iter().find(|x| matches!(x.ty, ag::TypeReference::Slice { .. })) {
+            return Ok(self.skip(id, report::SkipReason::UnsupportedParameter { parameter: param.name.clone(), ty: None }));
+        }
+
+        let constructor = ag::Constructor {
+            name: self.short_name(id)?,
+            rename: None,
+            params,
+            call: configured.and_then(|x| x.call.clone()),
+            deprecation: Self::deprecation(function),
+            origin: Some(self.origin(id)),
+            docs: self.docs(id.krate, function)
+        };
+
+        let ag::Item::Class { constructors, .. } = &mut self.items[class] else { unreachable!() };
+        constructors.push(constructor);
+        Ok(true)
+    }
+
+    fn collect_functions(&mut self) {
+        self.collect_pending(WorkKind::Function, Self::collect_function);
+    }
+
+    /// Whether the function is declared within an `impl` block.
+    fn is_method(&self, id: ItemId) -> bool {
+        self.parents.get(&id).is_some_and(|x| matches!(self.item(*x).inner, ItemEnum::Impl(_)))
+    }
+
+    /// Generates a shim for a free function.
+    fn collect_function(&mut self, id: ItemId) -> Result<bool, BindgenError> {
+        let function = self.item(id);
+        let ItemEnum::Function(x) = &function.inner else { unreachable!() };
+        if !self.callback_generics(id.krate, &x.generics) {
+            return Ok(self.skip(id, report::SkipReason::Generic));
+        }
+        else if x.header.is_unsafe || x.header.is_async || x.sig.is_c_variadic {
+            return Ok(self.skip(id, report::SkipReason::UnsupportedKind));
+        }
+
+        let output = Self::callback_output(&x.generics, &x.sig.output);
+        let (params, ret) = match self.signature(id.krate, &x.sig.inputs, &output, &[]) {
+            Ok(signature) => signature,
+            Err(reason) => return Ok(self.skip(id, reason))
+        };
+        let callbacks = x.sig.inputs.iter().filter_map(|(_, ty)| self.callback(id.krate, ty)).collect();
+
+        let item = ag::Item::Function {
+            name: self.short_name(id)?,
+            module: self.module_path(id),
+            path: self.public_path(id),
+            params,
+            ret,
+            naming: self.naming.clone(),
+            deprecation: Self::deprecation(function),
+            origin: Some(self.origin(id)),
+            docs: self.docs(id.krate, function)
+        };
+        self.collect_callbacks(callbacks, item.module().to_string());
+        self.items.push(item);
+        Ok(true)
+    }
+
+    /// Converts the parameters and return type of a function. Parameters must be blittable or convertible, borrowed
+    /// strings or slices, objects moved out of C#, or mutable references which C# passes by `ref`. The return value may additionally own a buffer or
+    /// an object, since ownership is passed to the caller. Parameters named by the overrides take the configured
+    /// type instead.
+    fn signature(&self, krate: usize, inputs: &[(String, Type)], output: &Option<Type>, overrides: &[(String, String)]) -> Result<(Vec<ag::Parameter>, Option<ag::TypeReference>), report::SkipReason> {
+        let mut params = Vec::with_capacity(inputs.len());
+        for (index, (name, ty)) in inputs.iter().enumerate() {
+            let reference = match overrides.iter().find(|x| x.0 == *name) {
+                Some((_, configured)) => self.configured_type(configured).filter(|x| !self.returns_only(x)),
+                None => self.parameter_reference(krate, ty)
+            };
+            let Some(ty) = reference else {
+                let ty = self.type_key(krate, ty);
+                return Err(report::SkipReason::UnsupportedParameter { parameter: name.clone(), ty });
+            };
+
+            // Parameters bound by patterns such as `_` have no usable name, while raw identifiers like `r#in` keep theirs.
+            let bare = name.strip_prefix("r#").unwrap_or(name);
+            let name = if bare.chars().all(|x| x.is_alphanumeric() || x == '_') && bare != "_" { name.clone() } else { format!("arg{index}") };
+            params.push(ag::Parameter { name, ty });
+        }
+
+        let ret = match output {
+            Some(output) => {
+                // Views borrow from the returned value, which is kept by Rust, so they cannot be optional or shared.
+                let strategy = self.marshal_strategy(krate, output);
+                let kept = |x: &ag::TypeReference| matches!(x, ag::TypeReference::Named { .. }) && self.arc_view_argument(krate, output).is_none();
+                let reference = self.type_reference(krate, output).filter(|x| strategy.is_some_and(|strategy| strategy != Marshal::View || kept(x)));
+                let Some(ret) = reference else {
+                    let ty = self.type_key(krate, output);
+                    return Err(report::SkipReason::UnsupportedReturn { ty });
+                };
+                Some(ret)
+            },
+            None => None
+        };
+
+        Ok((params, ret))
+    }
+
+    /// Converts the type of a parameter to a reference usable by generated code, if it can be passed.
+    fn parameter_reference(&self, krate: usize, ty: &Type) -> Option<ag::TypeReference> {
+        match ty {
+            Type::BorrowedRef { is_mutable: false, type_, .. } if matches!(&**type_, Type::Primitive(x) if x == "str") => {
+                Some(ag::TypeReference::Primitive(ag::PrimitiveType::String))
+            },
+            // Objects borrowed by shared reference stay owned by their C# handles.
+            Type::BorrowedRef { is_mutable: false, type_, .. } if self.marshal_strategy(krate, type_) == Some(Marshal::Handle) => {
+                match self.type_reference(krate, type_)? {
+                    ag::TypeReference::Handle { class, .. } => Some(ag::TypeReference::Handle { class, mutable: false }),
+                    _ => None
+                }
+            },
+            // Objects borrowed by mutable reference, like the `&mut Ui` of a combo box, are borrowed from their C# handles.
+            Type::BorrowedRef { is_mutable: true, type_, .. } if self.marshal_strategy(krate, type_) == Some(Marshal::Handle) => {
+                match self.type_reference(krate, type_)? {
+                    ag::TypeReference::Handle { class, .. } => Some(ag::TypeReference::HandleMut { class }),
+                    _ => None
+                }
+            },
+            // Slices are pinned by C# for the duration of the call, and borrowed in place.
+            Type::BorrowedRef { is_mutable: false, type_, .. } if matches!(**type_, Type::Slice(_)) => {
+                self.type_reference(krate, ty).filter(Self::borrows_in_place)
+            },
+            Type::BorrowedRef { is_mutable: true, type_, .. } if !matches!(**type_, Type::Slice(_)) => {
+                let strategy = self.marshal_strategy(krate, type_);
+                let inner = self.type_reference(krate, type_).filter(|_| strategy.as_ref().is_some_and(Marshal::is_embeddable))?;
+                self.in_out(inner, strategy.as_ref()?)
+            },
+            // Objects passed by value are moved out of their C# handles.
+            _ => {
+                let strategy = self.marshal_strategy(krate, ty);
+                self.type_reference(krate, ty).filter(|_| matches!(strategy, Some(Marshal::Blittable | Marshal::ConvertOnCopy { .. } | Marshal::Handle)))
+            }
+        }
+    }
+
+    /// Wraps the target of a mutable reference as a value which C# passes by `ref`. Only strings, primitives
+    /// which cross the boundary unchanged, blittable types, and generated enums and structs which are copied may
+    /// be modified in place. Generated types are converted from their mirrors for the call, and written back afterwards.
+    fn in_out(&self, inner: ag::TypeReference, strategy: &Marshal) -> Option<ag::TypeReference> {
+        match &inner {
+            ag::TypeReference::Primitive(ag::PrimitiveType::Char | ag::PrimitiveType::FixedUsize | ag::PrimitiveType::FixedIsize) => None,
+            ag::TypeReference::Primitive(_) => Some(ag::TypeReference::InOut(Box::new(inner))),
+            ag::TypeReference::Named { .. } if *strategy == Marshal::Blittable || self.converts_in_place(&inner) => Some(ag::TypeReference::InOut(Box::new(inner))),
+            _ => None
+        }
+    }
+
+    /// Whether a borrowed slice can be passed from C# without copying it, since its elements have the same layout
+    /// on both sides. Unlike the other primitives, characters and fixed-width sizes are converted.
+    fn borrows_in_place(reference: &ag::TypeReference) -> bool {
+        match reference {
+            ag::TypeReference::Slice { element, ownership: ag::SliceOwnership::Borrowed } => !matches!(**element, ag::TypeReference::Primitive(ag::PrimitiveType::Char | ag::PrimitiveType::FixedUsize | ag::PrimitiveType::FixedIsize | ag::PrimitiveType::String)),
+            _ => false
+        }
+    }
+
+    /// Converts a type written in the configuration, such as `&str`, `&mut f32`, `&Context`, `&[u8]`, `Vec<u32>`,
+    /// `Option<Key>`, or the key of a known type.
+    fn configured_type(&self, ty: &str) -> Option<ag::TypeReference> {
+        if ty.trim() == "&str" {
+            return Some(ag::TypeReference::Primitive(ag::PrimitiveType::String));
+        }
+        else if let Some(element) = ty.trim().strip_prefix("&[").and_then(|x| x.strip_suffix(']')) {
+            let element = Self::normalize_type_key(element);
+            let element = match Self::primitive_type(&element) {
+                Some(primitive) => ag::TypeReference::Primitive(self.ffi_primitive(primitive)),
+                None => match self.known_types.get(&element)? {
+                    KnownType { strategy: Marshal::Blittable, reference: Some(reference @ ag::TypeReference::Named { .. }), .. } => reference.clone(),
+                    _ => return None
+                }
+            };
+            return Some(ag::TypeReference::Slice { element: Box::new(element), ownership: ag::SliceOwnership::Borrowed }).filter(Self::borrows_in_place);
+        }
+        else if let Some(inner) = ty.trim().strip_prefix("Option<").and_then(|x| x.strip_suffix('>')) {
+            // Only values which are copied may be optional, unlike strings, objects, and views.
+            return match self.configured_type(inner)? {
+                ag::TypeReference::Primitive(ag::PrimitiveType::String) => None,
+                inner @ (ag::TypeReference::Primitive(_) | ag::TypeReference::Named { .. }) if !self.returns_only(&inner) => Some(ag::TypeReference::Optional(Box::new(inner))),
+                _ => None
+            };
+        }
+        else if let Some(element) = ty.trim().strip_prefix("Vec<").and_then(|x| x.strip_suffix('>')) {
+            let element = Self::normalize_type_key(element);
+            return match Self::primitive_type(&element).filter(|x| *x != ag::PrimitiveType::String) {
+                Some(primitive) => Some(ag::TypeReference::Slice { element: Box::new(ag::TypeReference::Primitive(self.ffi_primitive(primitive))), ownership: ag::SliceOwnership::Owned }),
+                None => {
+                    let known_type = self.known_types.get(&element)?;
+                    let ownership = match known_type.strategy {
+                        Marshal::Blittable => ag::SliceOwnership::Owned,
+                        Marshal::View => ag::SliceOwnership::Frame,
+                        _ => return None
+                    };
+                    Some(ag::TypeReference::Slice { element: Box::new(known_type.reference.clone()?), ownership })
+                }
+            };
+        }
+        else if let Some(inner) = ty.trim().strip_prefix('&').filter(|x| !x.starts_with("mut ")) {
+            // Objects borrowed by shared reference stay owned by their C# handles.
+            return match self.known_types.get(&Self::normalize_type_key(inner))? {
+                KnownType { strategy: Marshal::Handle, reference: Some(ag::TypeReference::Handle { class, .. }), .. } => Some(ag::TypeReference::Handle { class: class.clone(), mutable: false }),
+                _ => None
+            };
+        }
+        else if let Some(inner) = ty.trim().strip_prefix("&mut ") {
+            let inner = Self::normalize_type_key(inner);
+            return match inner.as_str() {
+                "String" | "alloc::string::String" => Some(ag::TypeReference::InOut(Box::new(ag::TypeReference::Primitive(ag::PrimitiveType::String)))),
+                "StringBuffer" => Some(ag::TypeReference::StringBuffer),
+                _ => match Self::primitive_type(&inner).filter(|x| *x != ag::PrimitiveType::String) {
+                    Some(primitive) => self.in_out(ag::TypeReference::Primitive(self.ffi_primitive(primitive)), &Marshal::Blittable),
+                    None => match self.known_types.get(&inner)? {
+                        KnownType { strategy: Marshal::Handle, reference: Some(ag::TypeReference::Handle { class, .. }), .. } => Some(ag::TypeReference::HandleMut { class: class.clone() }),
+                        known_type => self.in_out(known_type.reference.clone()?, &known_type.strategy)
+                    }
+                }
+            };
+        }
+
+        let key = Self::normalize_type_key(ty);
+        match Self::primitive_type(&key) {
+            Some(ag::PrimitiveType::String) => None,
+            Some(primitive) => Some(ag::TypeReference::Primitive(self.ffi_primitive(primitive))),
+            None => self.known_types.get(&key)?.reference.clone()
+        }
+    }
+
+    /// Converts a return type written in the configuration, which may additionally be a `String` that C# takes ownership of.
+    fn configured_return(&self, ty: &str) -> Option<ag::TypeReference> {
+        match Self::normalize_type_key(ty).as_str() {
+            "String" | "alloc::string::String" => Some(ag::TypeReference::Primitive(ag::PrimitiveType::String)),
+            _ => self.configured_type(ty).filter(|x| !matches!(x, ag::TypeReference::InOut(_) | ag::TypeReference::HandleMut { .. } | ag::TypeReference::StringBuffer))
+        }
+    }
+
+    /// Whether a type may only be returned, since it borrows from a value kept by Rust: a view, or a vector of them.
+    fn returns_only(&self, reference: &ag::TypeReference) -> bool {
+        match reference {
+            ag::TypeReference::Slice { ownership, .. } => matches!(ownership, ag::SliceOwnership::Frame | ag::SliceOwnership::Converted),
+            ag::TypeReference::Named { rs_name, .. } => self.items.iter().any(|x| matches!(x, ag::Item::Struct { view: true, .. } | ag::Item::Union { view: true, .. }) && x.rs_name() == *rs_name),
+            _ => false
+        }
+    }
+
+    /// Whether a type is the mirror of a generated enum without data, or of a struct which is copied, which both
+    /// convert to and from their original types. Structs which leave fields to their defaults are not, since
+    /// writing them back would reset those fields.
+    fn converts_in_place(&self, reference: &ag::TypeReference) -> bool {
+        let ag::TypeReference::Named { rs_name, .. } = reference else {
+            return false;
+        };
+        self.items.iter().any(|x| x.rs_name() == *rs_name && match x {
+            ag::Item::Enum { .. } => true,
+            ag::Item::Struct { conversions: true, view: false, .. } => {
+                self.known_types.get(&format!("{}::{}", x.module(), x.name())).is_some_and(|x| matches!(x.strategy, Marshal::Blittable | Marshal::ConvertOnCopy { .. }))
+                    && !self.defaults.contains(&format!("{}::{}", x.module(), x.name()))
+            },
+            _ => false
+        })
+    }
+
+    /// Whether every type parameter of a function exists for the sake of its callbacks. A callback like
+    /// `impl FnOnce(&mut Ui) -> R` declares a synthetic parameter, and may return another parameter, which
+    /// the generated closures instantiate with `()`.
+    fn callback_generics(&self, krate: usize, generics: &Generics) -> bool {
+        let mut outputs = HashSet::new();
+        for param in &generics.params {
+            if let GenericParamDefKind::Type { bounds, is_synthetic: true, .. } = &param.kind {
+                match self.callback_signature(krate, bounds) {
+                    Some((_, Some(Type::Generic(output)))) => { outputs.insert(output); },
+                    Some(_) => {},
+                    None => return false
+                }
+            }
+        }
+
+        generics.where_predicates.is_empty() && generics.params.iter().all(|x| match &x.kind {
+            GenericParamDefKind::Lifetime { .. } | GenericParamDefKind::Type { is_synthetic: true, .. } => true,
+            GenericParamDefKind::Type { bounds, .. } => bounds.is_empty() && outputs.contains(&x.name),
+            GenericParamDefKind::Const { .. } => false
+        })
+    }
+
+    /// Gets the output of a function, discarding it if it refers to the output of a callback, like the
+    /// `InnerResponse<R>` returned by container methods. The callbacks return nothing, so neither does the function.
+    fn callback_output(generics: &Generics, output: &Option<Type>) -> Option<Type> {
+        let names = generics.params.iter()
+            .filter(|x| matches!(x.kind, GenericParamDefKind::Type { is_synthetic: false, .. }))
+            .map(|x| x.name.as_str())
+            .collect::<Vec<_>>();
+        output.clone().filter(|x| !Self::mentions_generic(x, &names))
+    }
+
+    /// Whether the type refers to any of the given type parameters.
+    fn mentions_generic(ty: &Type, names: &[&str]) -> bool {
+        match ty {
+            Type::Generic(name) => names.contains(&name.as_str()),
+            Type::ResolvedPath(path) => match path.args.as_deref() {
+                Some(GenericArgs::AngleBracketed { args, .. }) => args.iter().any(|x| matches!(x, GenericArg::Type(x) if Self::mentions_generic(x, names))),
+                _ => false
+            },
+            Type::BorrowedRef { type_, .. } | Type::RawPointer { type_, .. } | Type::Array { type_, .. } | Type::Slice(type_) => Self::mentions_generic(type_, names),
+            Type::Tuple(elements) => elements.iter().any(|x| Self::mentions_generic(x, names)),
+            _ => false
+        }
+    }
+
+    /// Whether the type borrows for any of the given lifetimes.
+    fn mentions_lifetime(ty: &Type, names: &[&str]) -> bool {
+        match ty {
+            Type::BorrowedRef { lifetime: Some(lifetime), .. } if names.contains(&lifetime.as_str()) => true,
+            Type::ResolvedPath(path) => match path.args.as_deref() {
+                Some(GenericArgs::AngleBracketed { args, .. }) => args.iter().any(|x| match x {
+                    GenericArg::Lifetime(lifetime) => names.contains(&lifetime.as_str()),
+                    GenericArg::Type(x) => Self::mentions_lifetime(x, names),
+                    _ => false
+                }),
+                _ => false
+            },
+            Type::BorrowedRef { type_, .. } | Type::RawPointer { type_, .. } | Type::Array { type_, .. } | Type::Slice(type_) => Self::mentions_lifetime(type_, names),
+            Type::Tuple(elements) => elements.iter().any(|x| Self::mentions_lifetime(x, names)),
+            _ => false
+        }
+    }
+
+    /// Replaces `Self` within a type with the type that an `impl` block is for, so that signatures like
+    /// `fn run(&self, run_ui: impl FnMut(&Self))` name the owner.
+    fn replace_self(ty: &Type, owner: &Type) -> Type {
+        let replace = |x: &Type| Self::replace_self(x, owner);
+        match ty {
+            Type::Generic(name) if name == "Self" => owner.clone(),
+            Type::ResolvedPath(path) => Type::ResolvedPath(Self::replace_self_in_path(path, owner)),
+            Type::BorrowedRef { lifetime, is_mutable, type_ } => Type::BorrowedRef { lifetime: lifetime.clone(), is_mutable: *is_mutable, type_: Box::new(replace(type_)) },
+            Type::RawPointer { is_mutable, type_ } => Type::RawPointer { is_mutable: *is_mutable, type_: Box::new(replace(type_)) },
+            Type::Array { type_, len } => Type::Array { type_: Box::new(replace(type_)), len: len.clone() },
+            Type::Slice(type_) => Type::Slice(Box::new(replace(type_))),
+            Type::Tuple(elements) => Type::Tuple(elements.iter().map(replace).collect()),
+            Type::ImplTrait(bounds) => Type::ImplTrait(bounds.iter().map(|x| match x {
+                GenericBound::TraitBound { trait_, generic_params, modifier } => GenericBound::TraitBound {
+                    trait_: Self::replace_self_in_path(trait_, owner),
+                    generic_params: generic_params.clone(),
+                    modifier: *modifier
+                },
+                other => other.clone()
+            }).collect()),
+            other => other.clone()
+        }
+    }
+
+    /// Replaces `Self` within the generic arguments of a path, such as the inputs of `FnMut(&Self)`.
+    fn replace_self_in_path(path: &rustdoc_types::Path, owner: &Type) -> rustdoc_types::Path {
+        let replace = |x: &Type| Self::replace_self(x, owner);
+        let args = path.args.as_deref().map(|args| Box::new(match args {
+            GenericArgs::AngleBracketed { args, constraints } => GenericArgs::AngleBracketed {
+                args: args.iter().map(|x| match x {
+                    GenericArg::Type(ty) => GenericArg::Type(replace(ty)),
+                    other => other.clone()
+                }).collect(),
+                constraints: constraints.clone()
+            },
+            GenericArgs::Parenthesized { inputs, output } => GenericArgs::Parenthesized {
+                inputs: inputs.iter().map(replace).collect(),
+                output: output.as_ref().map(replace)
+            }
+        }));
+        rustdoc_types::Path { args, ..path.clone() }
+    }
+
+    /// If the bounds are a single `FnOnce`, `FnMut`, or `Fn` trait, gets its inputs and output.
+    fn callback_signature<'a>(&self, krate: usize, bounds: &'a [GenericBound]) -> Option<(&'a [Type], Option<&'a Type>)> {
+        let mut traits = bounds.iter().filter_map(|x| match x {
+            GenericBound::TraitBound { trait_, .. } => Some(trait_),
+            _ => None
+        });
+        let (Some(trait_), None) = (traits.next(), traits.next()) else { return None };
+        let summary = self.crates[krate].paths.get(&trait_.id)?;
+        if !matches!(summary.path.iter().map(String::as_str).collect::<Vec<_>>()[..], ["core", "ops", "function", "FnOnce" | "FnMut" | "Fn"]) {
+            return None;
+        }
+
+        match trait_.args.as_deref()? {
+            GenericArgs::Parenthesized { inputs, output } => Some((inputs, output.as_ref())),
+            _ => None
+        }
+    }
+
+    /// Converts an `impl Fn(...)` parameter to the delegate through which C# provides it, returning the
+    /// name, parameters and return type of the delegate. Objects are passed to the callback by handle,
+    /// and other arguments must be blittable or convertible.
+    fn callback(&self, krate: usize, ty: &Type) -> Option<(String, Vec<ag::Parameter>, Option<ag::TypeReference>)> {
+        let Type::ImplTrait(bounds) = ty else { return None };
+        let (inputs, output) = self.callback_signature(krate, bounds)?;
+
+        let mut name = String::new();
+        let mut params = Vec::<ag::Parameter>::with_capacity(inputs.len());
+        for (index, input) in inputs.iter().enumerate() {
+            let (ty, cs_name) = match input {
+                // Objects are borrowed for the duration of the callback, and those borrowed by shared reference may
+                // only be used by methods which take `&self`.
+                Type::BorrowedRef { type_, .. } => {
+                    let known_type = self.known_types.get(&self.type_key(krate, type_)?).filter(|x| x.strategy == Marshal::Handle)?;
+                    (known_type.reference.clone()?, known_type.cs_name.clone())
+                },
+                _ => {
+                    let strategy = self.marshal_strategy(krate, input);
+                    match self.type_reference(krate, input).filter(|_| matches!(strategy, Some(Marshal::Blittable | Marshal::ConvertOnCopy { .. })))? {
+                        ag::TypeReference::Primitive(primitive) => (ag::TypeReference::Primitive(primitive.clone()), primitive.rs_name().to_case(Case::Pascal)),
+                        ag::TypeReference::Named { rs_name, cs_name } => (ag::TypeReference::Named { rs_name, cs_name: cs_name.clone() }, cs_name),
+                        _ => return None
+                    }
+                }
+            };
+
+            // Closure parameters are unnamed, so objects are named after their class.
+            let param_name = match ty {
+                ag::TypeReference::Handle { .. } => cs_name.to_case(Case::Snake),
+                _ => format!("arg{index}")
+            };
+            let param_name = if params.iter().any(|x| x.name == param_name) { format!("arg{index}") } else { param_name };
+            name += &cs_name;
+            params.push(ag::Parameter { name: param_name, ty });
+        }
+
+        let ret = match output {
+            None | Some(Type::Generic(_)) => None,
+            Some(Type::Tuple(elements)) if elements.is_empty() => None,
+            Some(output) => {
+                let strategy = self.marshal_strategy(krate, output);
+                let ret = self.type_reference(krate, output).filter(|_| strategy.is_some_and(|x| x.is_embeddable()))?;
+                name += "To";
+                name += &match &ret {
+                    ag::TypeReference::Primitive(primitive) => primitive.rs_name().to_case(Case::Pascal),
+                    ag::TypeReference::Named { cs_name, .. } => cs_name.clone(),
+                    _ => return None
+                };
+                Some(ret)
+            }
+        };
+
+        Some((name + "Callback", params, ret))
+    }
+
+    /// Adds the delegates of a function's callbacks, unless an identical callback has already declared them.
+    fn collect_callbacks(&mut self, callbacks: Vec<(String, Vec<ag::Parameter>, Option<ag::TypeReference>)>, module: String) {
+        for (name, params, ret) in callbacks {
+            if self.items.iter().any(|x| matches!(x, ag::Item::Delegate { .. }) && x.name() == name) {
+                continue;
+            }
+
+            self.items.push(ag::Item::Delegate {
+                name,
+                module: module.clone(),
+                params,
+                ret,
+                naming: self.naming.clone(),
+                deprecation: None,
+                origin: None,
+                docs: String::new()
+            });
+        }
+    }
+
+    /// Converts a type appearing within the given crate to a reference usable by generated code.
+    fn type_reference(&self, krate: usize, ty: &Type) -> Option<ag::TypeReference> {
+        if let Some(known_type) = self.known_instantiation(krate, ty) {
+            return known_type.reference.clone();
+        }
+
+        match ty {
+            Type::Primitive(name) => Some(ag::TypeReference::Primitive(self.ffi_primitive(Self::primitive_type(name)?))),
+            Type::Array { type_, len } => {
+                let element = self.type_reference(krate, type_)?;
+                if matches!(element, ag::TypeReference::Array { .. } | ag::TypeReference::Primitive(ag::PrimitiveType::Char | ag::PrimitiveType::String)) {
+                    return None;
+                }
+
+                Some(ag::TypeReference::Array { element: Box::new(element), len: len.parse().ok()? })
+            },
+            Type::BorrowedRef { type_, .. } if matches!(**type_, Type::Slice(_)) => {
+                let Type::Slice(element) = &**type_ else { unreachable!() };
+                self.slice_reference(krate, element, ag::SliceOwnership::Borrowed)
+            },
+            Type::ResolvedPath(_) if self.vec_argument(krate, ty).is_some() => self.slice_reference(krate, self.vec_argument(krate, ty)?, ag::SliceOwnership::Owned),
+            Type::ResolvedPath(_) if self.arc_view_argument(krate, ty).is_some() => self.type_reference(krate, self.arc_view_argument(krate, ty)?),
+            Type::ResolvedPath(_) => match self.option_argument(krate, ty) {
+                Some(inner) => {
+                    let inner = self.type_reference(krate, inner)?;
+                    if matches!(inner, ag::TypeReference::Array { .. } | ag::TypeReference::Primitive(ag::PrimitiveType::Char | ag::PrimitiveType::String)) {
+                        return None;
+                    }
+
+                    Some(ag::TypeReference::Optional(Box::new(inner)))
+                },
+                None => self.known_types.get(&self.type_key(krate, ty)?)?.reference.clone()
+            },
+            Type::RawPointer { is_mutable, type_ } => Some(ag::TypeReference::Pointer {
+                target: Box::new(self.type_reference(krate, type_)?),
+                mutable: *is_mutable
+            }),
+            Type::ImplTrait(_) => {
+                let (name, ..) = self.callback(krate, ty)?;
+                Some(ag::TypeReference::Delegate { rs_name: self.naming.type_prefix.clone() + &name, cs_name: name })
+            },
+            _ => None
+        }
+    }
+
+    /// Gets the arguments of all `#[repr(...)]` attributes on an item, such as `C` or `u8`.
+    fn repr_attributes(item: &Item) -> Vec<String> {
+        item.attrs.iter()
+            .filter_map(|x| x.strip_prefix("#[repr(")?.strip_suffix(")]"))
+            .flat_map(|x| x.split(','))
+            .map(|x| x.trim().to_string())
+            .collect()
+    }
+
+    /// Gets the packing of a struct from the arguments of its `#[repr(...)]` attributes. Returns
+    /// the offending argument if the layout cannot be reproduced in C#, since C# has no equivalent
+    /// of `align(N)`, or if packed structs have not been allowed.
+    fn struct_packing(&self, repr: &[String]) -> Result<Option<u32>, String> {
+        let mut packing = None;
+        for argument in repr {
+            if argument == "packed" {
+                packing = Some(1);
+            }
+            else if let Some(value) = argument.strip_prefix("packed(").and_then(|x| x.strip_suffix(')')) {
+                packing = Some(value.trim().parse().map_err(|_| argument.clone())?);
+            }
+            else if argument.starts_with("align(") {
+                return Err(argument.clone());
+            }
+
+            if packing.is_some() && !self.allow_packed_structs {
+                return Err(argument.clone());
+            }
+        }
+        Ok(packing)
+    }
+
+    /// Gets the shared primitive corresponding to a Rust primitive type name.
+    fn primitive_type(name: &str) -> Option<ag::PrimitiveType> {
+        Some(match name {
+            "bool" => ag::PrimitiveType::Bool,
+            "char" => ag::PrimitiveType::Char,
+            "u8" => ag::PrimitiveType::U8,
+            "u16" => ag::PrimitiveType::U16,
+            "u32" => ag::PrimitiveType::U32,
+            "u64" => ag::PrimitiveType::U64,
+            "usize" => ag::PrimitiveType::Usize,
+            "i8" => ag::PrimitiveType::I8,
+            "i16" => ag::PrimitiveType::I16,
+            "i32" => ag::PrimitiveType::I32,
+            "i64" => ag::PrimitiveType::I64,
+            "isize" => ag::PrimitiveType::Isize,
+            "f32" => ag::PrimitiveType::F32,
+            "f64" => ag::PrimitiveType::F64,
+            "str" => ag::PrimitiveType::String,
+            _ => return None
+        })
+    }
+
+    /// Gets the primitive which values of a primitive type cross the FFI boundary as. Pointer-sized
+    /// integers are widened to 64 bits if fixed-width sizes are enabled.
+    fn ffi_primitive(&self, primitive: ag::PrimitiveType) -> ag::PrimitiveType {
+        match primitive {
+            ag::PrimitiveType::Usize if self.fixed_width_sizes => ag::PrimitiveType::FixedUsize,
+            ag::PrimitiveType::Isize if self.fixed_width_sizes => ag::PrimitiveType::FixedIsize,
+            other => other
+        }
+    }
+
+    /// Determines how a type appearing within the given crate can be marshaled, if at all.
+    fn marshal_strategy(&self, krate: usize, ty: &Type) -> Option<Marshal> {
+        if let Some(known_type) = self.known_instantiation(krate, ty) {
+            return Some(known_type.strategy.clone());
+        }
+
+        match ty {
+            Type::Array { type_, .. } => self.marshal_strategy(krate, type_),
+            Type::RawPointer { .. } | Type::BorrowedRef { .. } | Type::ImplTrait(_) => self.type_reference(krate, ty).map(|_| Marshal::Blittable),
+            Type::ResolvedPath(_) if self.vec_argument(krate, ty).is_some() => self.type_reference(krate, ty).map(|_| Marshal::StringLike),
+            Type::ResolvedPath(_) if self.arc_view_argument(krate, ty).is_some() => Some(Marshal::View),
+            Type::Tuple(elements) => {
+                for element in elements {
+                    if self.marshal_strategy(krate, element) != Some(Marshal::Blittable) {
+                        return None;
+                    }
+                }
+                (!elements.is_empty()).then_some(Marshal::Blittable)
+            },
+            _ => match self.option_argument(krate, ty) {
+                Some(inner) => self.marshal_strategy(krate, inner),
+                None => self.known_types.get(&self.type_key(krate, ty)?).map(|x| x.strategy.clone())
+            }
+        }
+    }
+
+    /// Creates a reference to a slice with the given element type. Only slices of [`Marshal::Blittable`]
+    /// elements may be passed across the FFI boundary, except that vectors of views are kept for the frame.
+    fn slice_reference(&self, krate: usize, element: &Type, ownership: ag::SliceOwnership) -> Option<ag::TypeReference> {
+        let ownership = match self.marshal_strategy(krate, element) {
+            Some(Marshal::Blittable) => ownership,
+            Some(Marshal::View) if ownership == ag::SliceOwnership::Owned => ag::SliceOwnership::Frame,
+            _ => {
+                log::debug!("Slice element type {:?} is not blittable", self.type_key(krate, element));
+                return None;
+            }
+        };
+
+        let element = self.type_reference(krate, element)?;
+        match element {
+            ag::TypeReference::Primitive(_) | ag::TypeReference::Named { .. } => Some(ag::TypeReference::Slice { element: Box::new(element), ownership }),
+            _ => None
+        }
+    }
+
+    /// If the type is `Option<T>`, gets `T`.
+    fn option_argument<'a>(&self, krate: usize, ty: &'a Type) -> Option<&'a Type> {
+        self.generic_argument(krate, ty, &["core", "option", "Option"])
+    }
+
+    /// If the type is `Vec<T>`, gets `T`.
+    fn vec_argument<'a>(&self, krate: usize, ty: &'a Type) -> Option<&'a Type> {
+        self.generic_argument(krate, ty, &["alloc", "vec", "Vec"])
+    }
+
+    /// If the type is `Arc<T>` for a view `T`, gets `T`. Views only borrow from the original value, so they may
+    /// borrow through a shared pointer, like the images of texture updates.
+    fn arc_view_argument<'a>(&self, krate: usize, ty: &'a Type) -> Option<&'a Type> {
+        self.generic_argument(krate, ty, &["alloc", "sync", "Arc"]).filter(|x| self.marshal_strategy(krate, x) == Some(Marshal::View))
+    }
+
+    /// If the type is an instantiation of the generic type at `generic_path` with a single
+    /// type argument, gets that argument.
+    fn generic_argument<'a>(&self, krate: usize, ty: &'a Type, generic_path: &[&str]) -> Option<&'a Type> {
+        let Type::ResolvedPath(path) = ty else { return None };
+        let summary = self.crates[krate].paths.get(&path.id)?;
+        if summary.path != generic_path {
+            return None;
+        }
+
+        match path.args.as_deref()? {
+            GenericArgs::AngleBracketed { args, .. } => match args.as_slice() {
+                [GenericArg::Type(inner)] => Some(inner),
+                _ => None
+            },
+            _ => None
+        }
+    }
+
+    /// Gets the canonical [`Self::known_types`] key for a type appearing within the given crate.
+    /// Returns [`None`] for types that cannot be named in generated code.
+    fn type_key(&self, krate: usize, ty: &Type) -> Option<String> {
+        match ty {
+            Type::Primitive(name) => Some(name.clone()),
+            Type::ResolvedPath(path) => {
+                let arguments = match path.args.as_deref() {
+                    None => Vec::new(),
+                    Some(GenericArgs::AngleBracketed { args, .. }) => args.iter()
+                        .filter_map(|x| match x {
+                            GenericArg::Lifetime(_) => None,
+                            GenericArg::Type(ty) => Some(self.type_key(krate, ty)),
+                            _ => Some(None)
+                        })
+                        .collect::<Option<Vec<_>>>()?,
+                    Some(_) => return None
+                };
+
+                let name = match self.resolve(krate, path.id) {
+                    Some(id) => self.rust_name(id),
+                    None => self.crates[krate].paths.get(&path.id)?.path.join("::")
+                };
+
+                if arguments.is_empty() {
+                    Some(name)
+                }
+                else {
+                    Some(format!("{name}<{}>", arguments.join(", ")))
+                }
+            },
+            Type::Tuple(elements) => {
+                let keys = elements.iter().map(|x| self.type_key(krate, x)).collect::<Option<Vec<_>>>()?;
+                Some(format!("({})", keys.join(", ")))
+            },
+            Type::Array { type_, len } => Some(format!("[{}; {len}]", self.type_key(krate, type_)?)),
+            _ => None
+        }
+    }
+
+    /// Normalizes the whitespace of a type written in the configuration to match [`Self::type_key`],
+    /// so that `RangeInclusive< f32 >` and `RangeInclusive<f32>` are the same key.
+    fn normalize_type_key(ty: &str) -> String {
+        ty.split_whitespace().collect::<String>().replace(',', ", ").replace(';', "; ")
+    }
+
+    /// Whether a path has any generic type arguments, like `RangeInclusive<f32>`.
+    fn has_type_arguments(path: &rustdoc_types::Path) -> bool {
+        matches!(path.args.as_deref(), Some(GenericArgs::AngleBracketed { args, .. }) if args.iter().any(|x| matches!(x, GenericArg::Type(_))))
+    }
+
+    /// Gets the known type generated for an instantiation of a generic type, tuple, or array, if the type is one.
+    fn known_instantiation(&self, krate: usize, ty: &Type) -> Option<&KnownType> {
+        match ty {
+            Type::ResolvedPath(path) if Self::has_type_arguments(path) => {},
+            Type::Tuple(_) | Type::Array { .. } => {},
+            _ => return None
+        }
+
+        self.known_types.get(&self.type_key(krate, ty)?)
+    }
+
+    /// Gets the keys of the configured instantiations which appear within a type.
+    fn instantiation_keys(&self, krate: usize, ty: &Type, result: &mut Vec<String>) {
+        match ty {
+            Type::ResolvedPath(path) => {
+                if let Some(GenericArgs::AngleBracketed { args, .. }) = path.args.as_deref() {
+                    for arg in args {
+                        if let GenericArg::Type(ty) = arg {
+                            self.instantiation_keys(krate, ty, result);
+                        }
+                    }
+                }
+
+                result.extend(self.type_key(krate, ty).filter(|x| self.instantiations.contains_key(x)));
+            },
+            Type::Tuple(elements) => {
+                elements.iter().for_each(|x| self.instantiation_keys(krate, x, result));
+                result.extend(self.type_key(krate, ty).filter(|x| self.instantiations.contains_key(x)));
+            },
+            Type::Array { type_, .. } => {
+                self.instantiation_keys(krate, type_, result);
+                result.extend(self.type_key(krate, ty).filter(|x| self.instantiations.contains_key(x)));
+            },
+            Type::Slice(type_) | Type::RawPointer { type_, .. } | Type::BorrowedRef { type_, .. } => self.instantiation_keys(krate, type_, result),
+            _ => {}
+        }
+    }
+
+    /// Gets the first instantiation of a generic type within a type which has no known type. The arguments
+    /// of `Option<T>` and `Vec<T>` are searched instead, since those types are marshaled natively.
+    fn unknown_instantiation(&self, krate: usize, ty: &Type) -> Option<String> {
+        match ty {
+            Type::ResolvedPath(path) => {
+                if let Some(inner) = self.option_argument(krate, ty).or_else(|| self.vec_argument(krate, ty)) {
+                    return self.unknown_instantiation(krate, inner);
+                }
+
+                let key = self.type_key(krate, ty).filter(|_| Self::has_type_arguments(path))?;
+                (!self.known_types.contains_key(&key)).then_some(key)
+            },
+            Type::Tuple(elements) => elements.iter().find_map(|x| self.unknown_instantiation(krate, x)),
+            Type::Array { type_, .. } | Type::Slice(type_) | Type::RawPointer { type_, .. } | Type::BorrowedRef { type_, .. } => self.unknown_instantiation(krate, type_),
+            _ => None
+        }
+    }
+
+    /// Gets the name of the first variant carrying data, or [`None`] if the enum only has primitive variants.
+    fn payload_variant(&self, id: ItemId, x: &Enum) -> Result<Option<String>, BindgenError> {
+        for variant in &x.variants {
+            let variant = self.member(id, variant)?;
+            let ItemEnum::Variant(x) = &variant.inner else {
+                return Err(BindgenError::UnexpectedKind { path: self.rust_name(id), expected: "variant" });
+            };
+
+            if x.kind != VariantKind::Plain {
+                return Ok(Some(self.member_name(id, variant, "variant")?));
+            }
+        }
+    
+        Ok(None)
+    }
+    
+    /// Whether this is an item for which we will generate code.
+    fn item_relevant(x: &&Item) -> bool {
+        matches!(&x.inner, ItemEnum::Union(_)
+            | ItemEnum::Struct(_)
+            | ItemEnum::Enum(_)
+            | ItemEnum::Function(_)
+            | ItemEnum::TypeAlias(_)
+            | ItemEnum::Constant { .. }
+            | ItemEnum::AssocConst { .. }
+            | ItemEnum::Static(_)
+            | ItemEnum::ExternType
+            | ItemEnum::Macro(_)
+            | ItemEnum::ProcMacro(_))
+    }
+    
+    /// The canonical Rust paths of the built-in known types, in the order in which they are listed.
+    ///
+    /// ```
+    /// use egui_inspect::BindgenContext;
+    ///
+    /// let paths = BindgenContext::default_known_type_paths();
+    /// let unique = paths.iter().collect::<std::collections::HashSet<_>>();
+    /// assert_eq!(unique.len(), paths.len(), "{paths:?}");
+    /// assert!(paths.contains(&"ecolor::color32::Color32"));
+    /// ```
+    pub fn default_known_type_paths() -> Vec<&'static str> {
+        Self::default_known_type_entries().into_iter().map(|(path, _)| path).collect()
+    }
+
+    /// The types that are known before any items are processed, keyed by canonical Rust path.
+    /// Panics if the same path is listed twice, since one entry would silently replace the other.
+    fn default_known_types() -> HashMap<String, KnownType> {
+        let entries = Self::default_known_type_entries();
+        let mut result = HashMap::with_capacity(entries.len());
+        for (path, known_type) in entries {
+            assert!(result.insert(path.to_owned(), known_type).is_none(), "Known type {path} was listed twice");
+        }
+        result
+    }
+
+    /// The built-in known types, along with their canonical Rust paths.
+    fn default_known_type_entries() -> Vec<(&'static str, KnownType)> {
+        vec![
+            ("bool", KnownType::primitive(ag::PrimitiveType::Bool)),
+            ("char", KnownType { strategy: Marshal::ConvertOnCopy { into_fn: "VxChar::from".to_string(), from_fn: "char::from".to_string() }, ..KnownType::primitive(ag::PrimitiveType::Char) }),
+            ("u8", KnownType::primitive(ag::PrimitiveType::U8)),
+            ("u16", KnownType::primitive(ag::PrimitiveType::U16)),
+            ("u32", KnownType::primitive(ag::PrimitiveType::U32)),
+            ("u64", KnownType::primitive(ag::PrimitiveType::U64)),
+            ("i8", KnownType::primitive(ag::PrimitiveType::I8)),
+            ("i16", KnownType::primitive(ag::PrimitiveType::I16)),
+            ("i32", KnownType::primitive(ag::PrimitiveType::I32)),
+            ("i64", KnownType::primitive(ag::PrimitiveType::I64)),
+            ("usize", KnownType::primitive(ag::PrimitiveType::Usize)),
+            ("isize", KnownType::primitive(ag::PrimitiveType::Isize)),
+            ("f32", KnownType::primitive(ag::PrimitiveType::F32)),
+            ("f64", KnownType::primitive(ag::PrimitiveType::F64)),
+            ("alloc::string::String", KnownType { strategy: Marshal::StringLike, ..KnownType::primitive(ag::PrimitiveType::String) }),
+            // Both are `#[repr(C)]` pairs of `f32`, which matches the layout of `System.Numerics.Vector2`.
+            ("emath::pos2::Pos2", KnownType::named("System.Numerics.Vector2", "Pos2")),
+            ("emath::vec2::Vec2", KnownType::named("System.Numerics.Vector2", "Vec2")),
+            // Both are `#[repr(C)]` arrays of four channels, which match the generated C# support structs.
+            ("ecolor::color32::Color32", KnownType::named("Color32", "Color32")),
+            ("ecolor::rgba::Rgba", KnownType::named("Rgba", "Rgba")),
+            // Textures are passed as their kind and number, which the C# support struct can create from an engine's own handles.
+            ("epaint::TextureId", KnownType { strategy: Marshal::ConvertOnCopy { into_fn: "VxTextureId::from".to_string(), from_fn: "TextureId::from".to_string() }, ..KnownType::named("TextureId", "VxTextureId") })
+        ]
+    }
+
+    /// The C# names given to items before any configuration is applied, keyed by canonical Rust path.
+    fn default_renames() -> HashMap<String, String> {
+        // The fields of `Modifiers` would otherwise collide with its associated constants, like `Modifiers::ALT`.
+        let entries = [
+            ("egui::data::input::Modifiers::alt", "AltDown"),
+            ("egui::data::input::Modifiers::ctrl", "CtrlDown"),
+            ("egui::data::input::Modifiers::shift", "ShiftDown"),
+            ("egui::data::input::Modifiers::mac_cmd", "MacCmdDown"),
+            ("egui::data::input::Modifiers::command", "CommandDown")
+        ];
+        entries.into_iter().map(|(path, cs_name)| (path.to_owned(), cs_name.to_owned())).collect()
+    }
+
+    /// Gets the paths of the known types which do not exist in any loaded crate, in sorted order.
+    pub fn missing_known_types(&self) -> Vec<&str> {
+        let paths = self.crates.iter()
+            .flat_map(|x| x.paths.values().map(|x| x.path.join("::")))
+            .collect::<HashSet<_>>();
+        let mut missing = self.known_types.keys()
+            .filter(|x| x.contains("::") && !paths.contains(*x))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        missing.sort();
+        missing
+    }
+
+    /// Warns about built-in known types whose paths do not exist in any loaded crate, which
+    /// suggests that the table has drifted from the version of egui being bound.
+    fn validate_known_types(&self) {
+        for path in self.missing_known_types() {
+            log::warn!("Built-in known type `{path}` does not exist in the loaded crates");
+        }
+    }
+}
+
+/// The leading fields of a rustdoc JSON file, read before attempting a full parse.
+#[derive(serde::Deserialize)]
+struct FormatHeader {
+    /// The version of the rustdoc JSON format.
+    format_version: u32,
+    /// The version of the documented crate, if known.
+    crate_version: Option<String>
+}
+
+/// An error that occurred while loading rustdoc JSON.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The file could not be read.
+    Io(PathBuf, std::io::Error),
+    /// The file was not valid rustdoc JSON.
+    Parse(PathBuf, serde_json::Error),
+    /// The file was produced with a rustdoc format that this tool does not understand.
+    FormatVersion {
+        /// The file that was loaded.
+        path: PathBuf,
+        /// The format version recorded in the file.
+        found: u32,
+        /// The version of the documented crate, if known.
+        crate_version: Option<String>
+    }
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io(path, err) => write!(f, "Failed to read {}: {err}", path.display()),
+            LoadError::Parse(path, err) => write!(f, "Failed to parse rustdoc JSON from {}: {err}", path.display()),
+            LoadError::FormatVersion { path, found, crate_version } => {
+                write!(f, "{} was generated with rustdoc format {found}", path.display())?;
+                if let Some(version) = crate_version {
+                    write!(f, " (crate version {version})")?;
+                }
+                write!(f, ", but this tool supports format {FORMAT_VERSION}. Regenerate it with a nightly toolchain that emits format {FORMAT_VERSION}, or pass --force to try anyway.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// A problem with the rustdoc JSON of an item which prevents bindings from being generated for it.
+/// Unlike a [`report::SkipReason`], this means that the input is malformed rather than unsupported,
+/// so the item is recorded in the report's errors and generation continues without it.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "error", rename_all = "snake_case")]
+pub enum BindgenError {
+    /// The item, or one of its members, has no name.
+    MissingName {
+        /// The canonical path of the item.
+        path: String,
+        /// The kind of the nameless member, such as `variant`, or [`None`] if the item itself has no name.
+        member: Option<&'static str>
+    },
+    /// The item refers to a member which is not in the rustdoc index.
+    MissingItem {
+        /// The canonical path of the item.
+        path: String,
+        /// The ID of the missing member.
+        id: u32
+    },
+    /// A member of the item is not the kind of item which its parent requires.
+    UnexpectedKind {
+        /// The canonical path of the item.
+        path: String,
+        /// The kind of member that was expected, such as `variant` or `field`.
+        expected: &'static str
+    },
+    /// One of the enum's discriminants is not an integer.
+    MalformedDiscriminant {
+        /// The canonical path of the enum.
+        path: String,
+        /// The text of the discriminant.
+        value: String
+    }
+}
+
+impl BindgenError {
+    /// Gets the canonical path of the item which could not be generated.
+    pub fn path(&self) -> &str {
+        match self {
+            BindgenError::MissingName { path, .. }
+            | BindgenError::MissingItem { path, .. }
+            | BindgenError::UnexpectedKind { path, .. }
+            | BindgenError::MalformedDiscriminant { path, .. } => path
+        }
+    }
+}
+
+impl std::fmt::Display for BindgenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindgenError::MissingName { path, member: Some(member) } => write!(f, "`{path}` has a {member} without a name"),
+            BindgenError::MissingName { path, member: None } => write!(f, "`{path}` has no name"),
+            BindgenError::MissingItem { path, id } => write!(f, "`{path}` refers to item {id}, which is not in the rustdoc index"),
+            BindgenError::UnexpectedKind { path, expected } => write!(f, "`{path}` has a member which is not a {expected}"),
+            BindgenError::MalformedDiscriminant { path, value } => write!(f, "`{path}` has discriminant `{value}`, which is not an integer")
+        }
+    }
+}
+
+impl std::error::Error for BindgenError {}
+
+/// A [`BindgenError`] as it is deserialized, before the kinds of member which it names are checked.
+#[derive(serde::Deserialize)]
+#[serde(tag = "error", rename_all = "snake_case")]
+enum BindgenErrorData {
+    MissingName { path: String, member: Option<String> },
+    MissingItem { path: String, id: u32 },
+    UnexpectedKind { path: String, expected: String },
+    MalformedDiscriminant { path: String, value: String }
+}
+
+// The kinds of member are checked against those which errors can name, rather than borrowed from the input.
+impl<'de> serde::Deserialize<'de> for BindgenError {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let member_kind = |kind: String| ["variant", "field"].into_iter().find(|x| *x == kind)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown kind of member `{kind}`")));
+
+        Ok(match BindgenErrorData::deserialize(deserializer)? {
+            BindgenErrorData::MissingName { path, member } => BindgenError::MissingName { path, member: member.map(member_kind).transpose()? },
+            BindgenErrorData::MissingItem { path, id } => BindgenError::MissingItem { path, id },
+            BindgenErrorData::UnexpectedKind { path, expected } => BindgenError::UnexpectedKind { path, expected: member_kind(expected)? },
+            BindgenErrorData::MalformedDiscriminant { path, value } => BindgenError::MalformedDiscriminant { path, value }
+        })
+    }
+}
+
+/// A type whose marshaling is understood, either because it is built in or because bindings were generated for it.
+#[derive(Clone, Debug)]
+struct KnownType {
+    /// The name of the type in the public C# API.
+    pub cs_name: String,
+    /// The name of the type on the Rust side of the FFI boundary.
+    pub rs_name: String,
+    /// How values of the type cross the FFI boundary.
+    pub strategy: Marshal,
+    /// How generated code should refer to this type, if it can be referenced at all.
+    pub reference: Option<ag::TypeReference>
+}
+
+impl KnownType {
+    pub fn new(cs_name: impl Into<String>, rs_name: impl Into<String>, strategy: Marshal) -> Self {
+        Self { cs_name: cs_name.into(), rs_name: rs_name.into(), strategy, reference: None }
+    }
+
+    /// Creates a known type for an existing blittable type, which is referred to by the given names.
+    pub fn named(cs_name: impl Into<String>, rs_name: impl Into<String>) -> Self {
+        Self::from_reference(ag::TypeReference::Named { rs_name: rs_name.into(), cs_name: cs_name.into() })
+    }
+
+    /// Creates a known type that is marshaled as the given primitive.
+    pub fn primitive(primitive: ag::PrimitiveType) -> Self {
+        Self::from_reference(ag::TypeReference::Primitive(primitive))
+    }
+
+    /// Creates a known type that is marshaled in the same way as the given reference.
+    pub fn from_reference(reference: ag::TypeReference) -> Self {
+        Self {
+            cs_name: ag::DisplayCs(&reference, &ag::RenderContext::default()).to_string(),
+            rs_name: ag::DisplayRs(&reference, &ag::RenderContext::default()).to_string(),
+            strategy: Marshal::Blittable,
+            reference: Some(reference)
+        }
+    }
+
+    /// Creates a known type for a generated class, which is referred to by handle.
+    pub fn class(item: &ag::Item) -> Self {
+        let reference = ag::TypeReference::Handle { class: item.rs_class(), mutable: true };
+        Self { cs_name: item.cs_name(), rs_name: ag::DisplayRs(&reference, &ag::RenderContext::default()).to_string(), strategy: Marshal::Handle, reference: Some(reference) }
+    }
+
+    /// Creates a known type for a generated item.
+    pub fn item(item: &ag::Item, strategy: Marshal) -> Self {
+        let reference = ag::TypeReference::Named { rs_name: item.rs_name(), cs_name: item.cs_reference_name() };
+        Self { cs_name: item.cs_reference_name(), rs_name: item.rs_name(), strategy, reference: Some(reference) }
+    }
+}
+
+/// Describes how values of a type are passed across the FFI boundary.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Marshal {
+    /// The type has the same layout on both sides, and may be copied directly.
+    Blittable,
+    /// The type is copied into a generated mirror type, using the given conversion functions.
+    ConvertOnCopy {
+        /// The Rust function which converts the original type into the mirror type.
+        into_fn: String,
+        /// The Rust function which converts the mirror type back into the original type.
+        from_fn: String
+    },
+    /// The type lives on the Rust heap and is referred to by an opaque handle.
+    Handle,
+    /// The type owns a buffer, such as a string or vector, which must be freed explicitly.
+    StringLike,
+    /// The type holds vectors, so its mirror is a view which borrows from the original value. Views are only
+    /// returned, and Rust keeps the original value until it next returns one of the same type.
+    View
+}
+
+/// The fields of a variant, along with the strategy by which each is marshaled.
+type VariantFields = (Vec<ag::StructField>, Vec<Marshal>);
+
+impl Marshal {
+    /// Creates a strategy that converts to and from the mirror type generated for the given item.
+    pub fn convert(item: &ag::Item) -> Self {
+        Self::ConvertOnCopy {
+            into_fn: format!("{}::from", item.rs_name()),
+            from_fn: format!("{}::from", item.rs_type())
+        }
+    }
+
+    /// Whether values of this type may be embedded by value within generated structs. Views may only be
+    /// embedded within other views.
+    pub fn is_embeddable(&self) -> bool {
+        !matches!(self, Marshal::Handle | Marshal::View)
+    }
+}
+
+/// Describes the layout of the generated C# files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsFile {
+    /// The namespace containing every generated type.
+    pub namespace: String,
+    /// Whether to use a file-scoped namespace declaration rather than a block.
+    pub file_scoped: bool,
+    /// A hash of the rustdoc JSON from which the bindings were generated, to be recorded in the header.
+    pub source_hash: Option<u64>,
+    /// Whether to generate a class which verifies the sizes of generated structs.
+    pub layout_checks: bool,
+    /// The name of the native library which exports the Rust bindings.
+    pub library: String,
+    /// Whether to declare native functions with `[DllImport]` rather than `[LibraryImport]`.
+    pub dll_import: bool
+}
+
+impl CsFile {
+    /// Writes a C# file with the generated-code header, usings, and namespace, around the body written by the callback.
+    pub fn write(&self, out: &mut dyn std::io::Write, body: impl FnOnce(&mut ag::CodeWriter) -> std::fmt::Result) -> std::io::Result<()> {
+        let mut out = ag::IoWriter::new(out);
+        let mut w = ag::CodeWriter::new(&mut out);
+        let result = self.write_contents(&mut w, body);
+        out.finish(result)
+    }
+
+    /// Writes the header, usings, and namespace of a C# file around its body.
+    fn write_contents(&self, w: &mut ag::CodeWriter, body: impl FnOnce(&mut ag::CodeWriter) -> std::fmt::Result) -> std::fmt::Result {
+        w.write_str("// <auto-generated>\n")?;
+        match self.source_hash {
+            Some(hash) => writeln!(w, "//     Generated by {} {} from rustdoc JSON with hash {hash:016x}.", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))?,
+            None => writeln!(w, "//     Generated by {} {}.", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))?
+        }
+        w.write_str("//     Changes to this file will be lost when it is regenerated.\n")?;
+        w.write_str("// </auto-generated>\n\n")?;
+        w.write_str("using System;\nusing System.Runtime.InteropServices;\n\n")?;
+
+        if self.file_scoped {
+            write!(w, "namespace {};\n\n", self.namespace)?;
+            body(w)
+        }
+        else {
+            writeln!(w, "namespace {} {{", self.namespace)?;
+            w.push_indent();
+            body(w)?;
+            w.pop_indent()?;
+            w.write_str("}\n")
+        }
+    }
+}
+
+/// Writes the formatted C# bindings for the given items as a single file. The bindings are streamed
+/// line by line, so that they are never held in memory all at once.
+pub fn write_cs(out: &mut dyn std::io::Write, items: &[ag::Item], ctx: &ag::RenderContext, file: &CsFile) -> std::io::Result<()> {
+    file.write(out, |w| {
+        write_cs_support(w, items, ctx, file)?;
+        let items = items.iter().filter(|x| x.generates_cs()).collect::<Vec<_>>();
+        write_rendered(w, &items, ctx, |w, item| writeln!(w, "{}", ag::DisplayCs(item, ctx)))
+    })
+}
+
+/// Generates the formatted C# bindings for the given items.
+pub fn autogenerate_cs(items: &[ag::Item], ctx: &ag::RenderContext, file: &CsFile) -> String {
+    let mut result = Vec::new();
+    write_cs(&mut result, items, ctx, file).expect("Writing to a vector cannot fail");
+    String::from_utf8(result).expect("Bindings are valid UTF-8")
+}
+
+/// Generates the formatted C# bindings for the given items, with one file per type. Files are keyed by
+/// their path relative to the output directory, which mirrors the module that defines each type.
+/// Free constants, statics, and functions are grouped into one file per module.
+pub fn autogenerate_cs_files(items: &[ag::Item], ctx: &ag::RenderContext, file: &CsFile) -> BTreeMap<PathBuf, String> {
+    let mut files = BTreeMap::<PathBuf, Vec<&ag::Item>>::new();
+    for item in items.iter().filter(|x| x.generates_cs()) {
+        files.entry(cs_file_path(item)).or_default().push(item);
+    }
+
+    let mut support = String::new();
+    write_cs_support(&mut support, items, ctx, file).expect("Writing to a string cannot fail");
+    let mut result = BTreeMap::new();
+    if !support.is_empty() {
+        result.insert(PathBuf::from("VxSupport.cs"), write_cs_file(file, |w| w.write_str(&support)));
+    }
+
+    let render = |items: &[&ag::Item]| write_cs_file(file, |w| {
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                w.write_str("\n")?;
+            }
+            write!(w, "{}", ag::DisplayCs(*item, ctx))?;
+        }
+        Ok(())
+    });
+
+    #[cfg(feature = "parallel")]
+    if ctx.parallel {
+        use rayon::prelude::*;
+        let files = files.into_iter().collect::<Vec<_>>();
+        result.par_extend(files.into_par_iter().map(|(path, items)| (path, render(&items))));
+        return result;
+    }
+
+    result.extend(files.into_iter().map(|(path, items)| (path, render(&items))));
+    result
+}
+
+/// The number of items which are rendered at once before being written, which bounds the memory held by rendered text.
+#[cfg(feature = "parallel")]
+const RENDER_BATCH: usize = 256;
+
+/// Writes each of the items with the given function, in order. If the `parallel` feature is enabled and the context
+/// allows it, each batch of items is rendered on several threads before being written.
+#[cfg_attr(not(feature = "parallel"), allow(unused_variables))]
+fn write_rendered(w: &mut dyn std::fmt::Write, items: &[&ag::Item], ctx: &ag::RenderContext, render: impl Fn(&mut dyn std::fmt::Write, &ag::Item) -> std::fmt::Result + Sync) -> std::fmt::Result {
+    #[cfg(feature = "parallel")]
+    if ctx.parallel {
+        use rayon::prelude::*;
+        for batch in items.chunks(RENDER_BATCH) {
+            let rendered = batch.par_iter()
+                .map(|item| {
+                    let mut result = String::new();
+                    render(&mut result, item).map(|_| result)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            for text in rendered {
+                w.write_str(&text)?;
+            }
+        }
+        return Ok(());
+    }
+
+    for item in items {
+        render(w, item)?;
+    }
+    Ok(())
+}
+
+/// Writes a C# file into a string, around the body written by the callback.
+fn write_cs_file(file: &CsFile, body: impl FnOnce(&mut ag::CodeWriter) -> std::fmt::Result) -> String {
+    let mut result = Vec::new();
+    file.write(&mut result, body).expect("Writing to a vector cannot fail");
+    String::from_utf8(result).expect("Bindings are valid UTF-8")
+}
+
+/// Gets the C# class which declares an item, such as `Functions` for free functions, or the item itself for types.
+fn cs_declaring_class(item: &ag::Item) -> String {
+    match item {
+        ag::Item::Constant { owner: Some(owner), .. } => owner.clone(),
+        ag::Item::Constant { .. } => "Constants".to_string(),
+        ag::Item::Static { .. } => "Statics".to_string(),
+        ag::Item::Function { .. } => "Functions".to_string(),
+        _ => item.cs_name()
+    }
+}
+
+/// Gets the path of the file which an item is written to when the C# bindings are split, relative to the output
+/// directory. Each file is named for the class which declares the item, in a directory for its module.
+fn cs_file_path(item: &ag::Item) -> PathBuf {
+    item.module().split("::").collect::<PathBuf>().join(format!("{}.cs", cs_declaring_class(item).trim_start_matches('@')))
+}
+
+/// Gets every function exported from the Rust bindings for the given items, including those of the support code,
+/// in the order that C# declares them.
+fn exported_functions(items: &[ag::Item], ctx: &ag::RenderContext) -> Vec<ag::NativeFunction> {
+    let mut functions = Vec::new();
+    if uses_strings(items) {
+        functions.extend(ag::StringSupport.native_functions(ctx));
+    }
+    if has_shims(items) {
+        functions.extend(ag::ErrorSupport.native_functions(ctx));
+    }
+    if uses_string_buffers(items) {
+        functions.extend(ag::StringBufferSupport.native_functions(ctx));
+    }
+    if uses_type(items, |x| matches!(x, ag::TypeReference::Slice { .. })) {
+        functions.extend(ag::SliceSupport.native_functions(ctx));
+    }
+    functions.extend(owned_slice_elements(items).iter().map(|x| ag::SliceDrop(x, ctx).native_function()));
+    functions.extend(items.iter().flat_map(ag::Item::native_functions));
+    functions
+}
+
+/// Lists the generated items, the exported functions, and the skipped items. Items are given their files
+/// within the output directory if the C# bindings are split, or an empty path standing for the single output file.
+pub fn autogenerate_manifest(items: &[ag::Item], ctx: &ag::RenderContext, namespace: &str, split_files: bool, report: &report::Report) -> manifest::Manifest {
+    let mut result = manifest::Manifest::default();
+    for item in items {
+        let rust_path = item.origin().map_or_else(|| format!("{}::{}", item.module(), item.name()), |x| x.path.clone());
+        let file = match item {
+            _ if !item.generates_cs() => None,
+            _ if split_files => Some(cs_file_path(item)),
+            _ => Some(PathBuf::new())
+        };
+        let entry = |kind: &str, rust_path: String, cs_name: String| manifest::ManifestItem {
+            kind: kind.to_string(),
+            rust_path,
+            cs_name,
+            namespace: namespace.to_string(),
+            file: file.clone()
+        };
+
+        let (kind, cs_name) = match item {
+            ag::Item::Enum { .. } => ("enum", item.cs_name()),
+            ag::Item::Union { .. } => ("union", item.cs_name()),
+            ag::Item::Class { .. } => ("class", item.cs_name()),
+            ag::Item::Struct { .. } => ("struct", item.cs_name()),
+            ag::Item::Constant { .. } => ("constant", format!("{}.{}", cs_declaring_class(item), item.cs_name())),
+            ag::Item::Static { .. } => ("static", format!("{}.{}", cs_declaring_class(item), item.cs_name())),
+            ag::Item::Function { .. } => ("function", format!("{}.{}", cs_declaring_class(item), item.cs_name())),
+            ag::Item::Delegate { .. } => ("delegate", item.cs_name())
+        };
+        result.items.push(entry(kind, rust_path.clone(), cs_name));
+
+        // Members without an origin are named through their class.
+        if let ag::Item::Class { constructors, methods, .. } = item {
+            let class = item.cs_name();
+            for constructor in constructors {
+                let path = constructor.origin.as_ref().map_or_else(|| format!("{rust_path}::{}", constructor.name), |x| x.path.clone());
+                let name = if constructor.name == "new" { class.clone() } else { constructor.cs_name() };
+                result.items.push(entry("constructor", path, format!("{class}.{name}")));
+            }
+
+            for method in methods {
+                let path = method.origin.as_ref().map_or_else(|| format!("{rust_path}::{}", method.name), |x| x.path.clone());
+                let kind = if method.property { "property" } else { "method" };
+                result.items.push(entry(kind, path, format!("{class}.{}", method.cs_name())));
+            }
+        }
+    }
+
+    for function in exported_functions(items, ctx) {
+        let params = function.params.iter()
+            .map(|(name, ty)| format!("{}: {}", if name == "self" { "this" } else { name }, ag::DisplayRs(ty, ctx)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let ret = function.ret.as_ref().map_or(String::new(), |x| format!(" -> {}", ag::DisplayRs(x, ctx)));
+        result.symbols.push(manifest::ManifestSymbol { name: function.name, signature: format!("fn({params}){ret}") });
+    }
+
+    result.skipped = report.skipped.iter().map(manifest::ManifestSkip::from).collect();
+    result
+}
+
+/// Writes the C# support types required by the given items.
+fn write_cs_support(w: &mut dyn std::fmt::Write, items: &[ag::Item], ctx: &ag::RenderContext, file: &CsFile) -> std::fmt::Result {
+    if !ctx.class_tags.is_empty() {
+        writeln!(w, "{}", ag::DisplayCs(&ag::ObjectSupport, ctx))?;
+    }
+
+    if items.iter().any(|x| matches!(x, ag::Item::Class { safe_handle: false, .. })) {
+        writeln!(w, "{}", ag::CS_HANDLE_SUPPORT)?;
+    }
+
+    if uses_strings(items) {
+        writeln!(w, "{}", ag::DisplayCs(&ag::StringSupport, ctx))?;
+    }
+
+    if has_shims(items) {
+        writeln!(w, "{}", ag::DisplayCs(&ag::ErrorSupport, ctx))?;
+    }
+
+    if uses_string_buffers(items) {
+        writeln!(w, "{}", ag::DisplayCs(&ag::StringBufferSupport, ctx))?;
+    }
+
+    if uses_type(items, |x| matches!(x, ag::TypeReference::Optional(_))) {
+        writeln!(w, "{}", ag::CS_OPTION_SUPPORT)?;
+    }
+
+    if uses_type(items, |x| matches!(x, ag::TypeReference::Slice { .. })) {
+        writeln!(w, "{}", ag::DisplayCs(&ag::SliceSupport, ctx))?;
+    }
+
+    if uses_named_type(items, "Color32") {
+        writeln!(w, "{}", ag::CS_COLOR32_SUPPORT)?;
+    }
+
+    if uses_named_type(items, "Rgba") {
+        writeln!(w, "{}", ag::CS_RGBA_SUPPORT)?;
+    }
+
+    if uses_named_type(items, "VxTextureId") {
+        writeln!(w, "{}", ag::CS_TEXTURE_ID_SUPPORT)?;
+    }
+
+    let checks = layout::LayoutChecks::new(items, ctx.pointer_width);
+    if file.layout_checks && !checks.is_empty() {
+        writeln!(w, "{}", ag::DisplayCs(&checks, ctx))?;
+    }
+
+    let functions = exported_functions(items, ctx);
+    if !functions.is_empty() {
+        writeln!(w, "{}", ag::NativeMethods { ctx, library: &file.library, dll_import: file.dll_import, functions: &functions })?;
+    }
+
+    Ok(())
+}
+
+/// Gets the element type of every owned slice that the items refer to, each of which needs a destructor.
+fn owned_slice_elements(items: &[ag::Item]) -> Vec<ag::TypeReference> {
+    let mut result = Vec::new();
+    for item in items {
+        item.visit_types(&mut |x| if let ag::TypeReference::Slice { element, ownership: ag::SliceOwnership::Owned } = x && !result.contains(&**element) {
+            result.push((**element).clone());
+        });
+    }
+    result
+}
+
+/// Whether any of the items refer to a type matching the predicate.
+fn uses_type(items: &[ag::Item], mut predicate: impl FnMut(&ag::TypeReference) -> bool) -> bool {
+    let mut result = false;
+    for item in items {
+        item.visit_types(&mut |x| result |= predicate(x));
+    }
+    result
+}
+
+/// Whether any of the items have Rust shims, which report panics through the error support code.
+fn has_shims(items: &[ag::Item]) -> bool {
+    items.iter().any(|x| !x.native_functions().is_empty())
+}
+
+/// Whether the string support code is needed, either for strings which the items refer to or for the
+/// messages of panics caught by the shims.
+fn uses_strings(items: &[ag::Item]) -> bool {
+    has_shims(items) || uses_type(items, |x| matches!(x, ag::TypeReference::Primitive(ag::PrimitiveType::String)))
+}
+
+/// Whether any of the items take a string buffer, which requires its support code.
+fn uses_string_buffers(items: &[ag::Item]) -> bool {
+    uses_type(items, |x| matches!(x, ag::TypeReference::StringBuffer))
+}
+
+/// Whether any of the items refer to the type with the given Rust name, such as one backed by support code.
+fn uses_named_type(items: &[ag::Item], rs_name: &str) -> bool {
+    uses_type(items, |x| matches!(x, ag::TypeReference::Named { rs_name: name, .. } if name == rs_name))
+}
+
+/// Writes the formatted Rust bindings for the given items, optionally asserting the layout of each struct.
+pub fn write_rs(out: &mut dyn std::io::Write, items: &[ag::Item], ctx: &ag::RenderContext, layout_checks: bool) -> std::io::Result<()> {
+    let mut out = ag::IoWriter::new(out);
+    let result = write_rs_contents(&mut out, items, ctx, layout_checks);
+    out.finish(result)
+}
+
+/// Generates the formatted Rust bindings for the given items, optionally asserting the layout of each struct.
+pub fn autogenerate_rs(items: &[ag::Item], ctx: &ag::RenderContext, layout_checks: bool) -> String {
+    let mut result = Vec::new();
+    write_rs(&mut result, items, ctx, layout_checks).expect("Writing to a vector cannot fail");
+    String::from_utf8(result).expect("Bindings are valid UTF-8")
+}
+
+/// Writes the contents of the Rust bindings.
+fn write_rs_contents(w: &mut dyn std::fmt::Write, items: &[ag::Item], ctx: &ag::RenderContext, layout_checks: bool) -> std::fmt::Result {
+    w.write_str("//! Autogenerated FFI bindings for egui. Do not edit by hand.\n\n")?;
+    w.write_str("#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]\n\n")?;
+    w.write_str("use egui::*;\n\n")?;
+
+    if uses_strings(items) {
+        writeln!(w, "{}", ag::DisplayRs(&ag::StringSupport, ctx))?;
+    }
+
+    if has_shims(items) {
+        writeln!(w, "{}", ag::DisplayRs(&ag::ErrorSupport, ctx))?;
+    }
+
+    if uses_string_buffers(items) {
+        writeln!(w, "{}", ag::DisplayRs(&ag::StringBufferSupport, ctx))?;
+    }
+
+    if !ctx.class_tags.is_empty() {
+        writeln!(w, "{}", ag::DisplayRs(&ag::ObjectSupport, ctx))?;
+    }
+
+    if uses_type(items, |x| matches!(x, ag::TypeReference::Optional(_))) {
+        writeln!(w, "{}", ag::RS_OPTION_SUPPORT)?;
+    }
+
+    if uses_type(items, |x| matches!(x, ag::TypeReference::Slice { .. })) {
+        writeln!(w, "{}", ag::DisplayRs(&ag::SliceSupport, ctx))?;
+    }
+
+    if uses_type(items, |x| matches!(x, ag::TypeReference::Primitive(ag::PrimitiveType::FixedUsize | ag::PrimitiveType::FixedIsize))) {
+        writeln!(w, "{}", ag::RS_FIXED_SIZE_SUPPORT)?;
+    }
+
+    if uses_type(items, |x| matches!(x, ag::TypeReference::Primitive(ag::PrimitiveType::Char))) {
+        writeln!(w, "{}", ag::RS_CHAR_SUPPORT)?;
+    }
+
+    if uses_named_type(items, "VxTextureId") {
+        writeln!(w, "{}", ag::RS_TEXTURE_ID_SUPPORT)?;
+    }
+
+    if uses_type(items, |x| matches!(x, ag::TypeReference::InOut(inner) if matches!(&**inner, ag::TypeReference::Named { rs_name, .. } if ctx.mirrors.contains_key(rs_name)))) {
+        writeln!(w, "{}", ag::RS_MIRROR_MUT_SUPPORT)?;
+    }
+
+    for element in &owned_slice_elements(items) {
+        writeln!(w, "{}", ag::SliceDrop(element, ctx))?;
+    }
+
+    write_rendered(w, &items.iter().collect::<Vec<_>>(), ctx, |w, item| writeln!(w, "{}", ag::DisplayRs(item, ctx)))?;
+
+    let checks = layout::LayoutChecks::new(items, ctx.pointer_width);
+    if layout_checks && !checks.is_empty() {
+        write!(w, "{}", ag::DisplayRs(&checks, ctx))?;
+    }
+
+    Ok(())
+}
+
+/// Writes a C header declaring the types and functions of the Rust bindings, guarded by the given macro.
+pub fn write_h(out: &mut dyn std::io::Write, items: &[ag::Item], ctx: &ag::RenderContext, guard: &str) -> std::io::Result<()> {
+    let mut out = ag::IoWriter::new(out);
+    let result = write_h_contents(&mut out, items, ctx, guard);
+    out.finish(result)
+}
+
+/// Generates a C header declaring the types and functions of the Rust bindings, guarded by the given macro.
+pub fn autogenerate_h(items: &[ag::Item], ctx: &ag::RenderContext, guard: &str) -> String {
+    let mut result = Vec::new();
+    write_h(&mut result, items, ctx, guard).expect("Writing to a vector cannot fail");
+    String::from_utf8(result).expect("Bindings are valid UTF-8")
+}
+
+/// Writes the contents of a C header.
+fn write_h_contents(w: &mut dyn std::fmt::Write, items: &[ag::Item], ctx: &ag::RenderContext, guard: &str) -> std::fmt::Result {
+    w.write_str("/* Autogenerated FFI bindings for egui. Do not edit by hand. */\n\n")?;
+    write!(w, "#ifndef {guard}\n#define {guard}\n\n")?;
+    w.write_str("#include <stdbool.h>\n#include <stddef.h>\n#include <stdint.h>\n\n")?;
+    w.write_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n\n")?;
+
+    let functions = exported_functions(items, ctx);
+    let mut declarations = HeaderDeclarations {
+        items: items.iter().filter(|x| matches!(x, ag::Item::Enum { .. } | ag::Item::Union { .. } | ag::Item::Struct { .. } | ag::Item::Delegate { .. })).map(|x| (x.rs_name(), x)).collect(),
+        naming: &ctx.naming,
+        declared: HashSet::new(),
+        output: &mut *w
+    };
+
+    for item in items.iter().filter(|x| matches!(x, ag::Item::Enum { .. } | ag::Item::Union { .. } | ag::Item::Struct { .. } | ag::Item::Delegate { .. })) {
+        declarations.declare_item(item)?;
+    }
+
+    for function in &functions {
+        for ty in function.params.iter().map(|x| &x.1).chain(&function.ret) {
+            declarations.declare_type(ty)?;
+        }
+    }
+
+    for function in &functions {
+        writeln!(w, "{}", ag::DisplayHeader(function))?;
+    }
+    w.write_str("\n#ifdef __cplusplus\n}\n#endif\n\n")?;
+    writeln!(w, "#endif /* {guard} */")
+}
+
+/// Writes the type declarations of a C header, ordered so that each type is defined before it is used.
+struct HeaderDeclarations<'a> {
+    /// The generated enums, unions, structs, and delegates, keyed by the name of their Rust mirror.
+    items: HashMap<String, &'a ag::Item>,
+    /// The names given to the generated types and exported functions.
+    naming: &'a ag::NamingConfig,
+    /// The names of the types declared so far.
+    declared: HashSet<String>,
+    /// The destination of the declarations.
+    output: &'a mut dyn std::fmt::Write
+}
+
+impl HeaderDeclarations<'_> {
+    /// Declares an enum, union, struct, or delegate, after the types that it refers to.
+    fn declare_item(&mut self, item: &ag::Item) -> std::fmt::Result {
+        if !self.declared.insert(item.rs_name()) {
+            return Ok(());
+        }
+
+        match item {
+            ag::Item::Struct { fields, .. } => for field in fields {
+                self.declare_type(&field.ty)?;
+            },
+            ag::Item::Union { variants, .. } => for field in variants.iter().flat_map(|x| &x.fields) {
+                self.declare_type(&field.ty)?;
+            },
+            ag::Item::Delegate { params, ret, .. } => for ty in params.iter().map(|x| &x.ty).chain(ret) {
+                self.declare_type(ty)?;
+            },
+            _ => {}
+        }
+
+        writeln!(self.output, "{}", ag::DisplayHeader(item))
+    }
+
+    /// Declares the given type and any types that it depends upon, unless they are already declared.
+    fn declare_type(&mut self, ty: &ag::TypeReference) -> std::fmt::Result {
+        match ty {
+            ag::TypeReference::Primitive(ag::PrimitiveType::String) => {
+                if self.declared.insert("VxString".to_string()) {
+                    writeln!(self.output, "/// An owned UTF-8 string, which must be freed with `{}`.", self.naming.native_fn("string_drop"))?;
+                    self.output.write_str("typedef struct VxString {\n    uint8_t* ptr;\n    size_t len;\n    size_t capacity;\n} VxString;\n\n")?;
+                }
+            },
+            ag::TypeReference::Primitive(_) => {},
+            ag::TypeReference::Named { rs_name, .. } => match self.items.get(rs_name).copied() {
+                Some(item) => self.declare_item(item)?,
+                // Types provided by the user are only forward-declared, and must be defined before the header is included.
+                None => if self.declared.insert(rs_name.clone()) {
+                    self.output.write_str("/// Provided by the user, and must be defined before this header is included.\n")?;
+                    write!(self.output, "typedef struct {rs_name} {rs_name};\n\n")?;
+                }
+            },
+            ag::TypeReference::Array { element: inner, .. } | ag::TypeReference::Pointer { target: inner, .. } => self.declare_type(inner)?,
+            ag::TypeReference::InOut(inner) => self.declare_type(inner)?,
+            ag::TypeReference::Delegate { rs_name, .. } => {
+                if let Some(item) = self.items.get(rs_name).copied() {
+                    self.declare_item(item)?;
+                }
+            },
+            ag::TypeReference::Handle { .. } | ag::TypeReference::HandleMut { .. } => {
+                if self.declared.insert("VxObject".to_string()) {
+                    self.output.write_str("/// An opaque Rust object, which is only ever referred to by pointer.\n")?;
+                    self.output.write_str("typedef struct VxObject VxObject;\n\n")?;
+                }
+            },
+            ag::TypeReference::StringBuffer => {
+                if self.declared.insert("VxStringBuffer".to_string()) {
+                    self.output.write_str("/// A string owned by Rust, which is only ever referred to by pointer.\n")?;
+                    self.output.write_str("typedef struct VxStringBuffer VxStringBuffer;\n\n")?;
+                }
+            },
+            ag::TypeReference::Optional(inner) => {
+                self.declare_type(inner)?;
+                let name = format!("VxOption_{}", inner.c_generic_name());
+                if self.declared.insert(name.clone()) {
+                    write!(self.output, "typedef struct {name} {{\n    uint8_t has_value;\n    {} value;\n}} {name};\n\n", ag::DisplayHeader(&**inner))?;
+                }
+            },
+            ag::TypeReference::Slice { element, .. } => {
+                self.declare_type(element)?;
+                let name = format!("VxSlice_{}", element.c_generic_name());
+                if self.declared.insert(name.clone()) {
+                    write!(self.output, "typedef struct {name} {{\n    const {}* ptr;\n    size_t len;\n    size_t capacity;\n    uint64_t epoch;\n}} {name};\n\n", ag::DisplayHeader(&**element))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Configures and runs the whole binding generator: loading rustdoc JSON, collecting items,
+/// and rendering the C#, Rust, and optionally C bindings. Options set on the builder take
+/// precedence over those in its [`config::Config`].
+#[derive(Clone, Debug)]
+pub struct Bindgen {
+    /// The rustdoc JSON files to load. The bundled copy is used if this is empty.
+    inputs: Vec<PathBuf>,
+    /// Whether to load rustdoc JSON with an unsupported format version.
+    force: bool,
+    /// The configuration which the other options are merged over.
+    config: config::Config,
+    /// The paths of newtypes which should be generated as distinct C# structs.
+    keep_newtypes: Vec<String>,
+    /// Whether `#[doc(hidden)]` and non-public items should have bindings generated.
+    include_hidden: bool,
+    /// Whether to generate assertions that Rust and C# agree on the layout of each struct.
+    layout_checks: bool,
+    /// Whether to note the Rust item which each binding was generated from in a comment.
+    source_comments: bool,
+    /// Whether to generate one C# file per type.
+    split_files: bool,
+    /// Whether to render items on several threads, if the `parallel` feature is enabled.
+    parallel: bool,
+    /// The pointer width of the target in bits.
+    pointer_width: usize,
+    /// The include guard of the C header, if one should be generated.
+    header_guard: Option<String>,
+    /// The directory in which classified items are cached, if any.
+    cache_dir: Option<PathBuf>
+}
+
+impl Bindgen {
+    /// Creates a generator for the bundled rustdoc JSON with the default options.
+    pub fn new() -> Self {
+        Self {
+            inputs: Vec::new(),
+            force: false,
+            config: config::Config::default(),
+            keep_newtypes: Vec::new(),
+            include_hidden: false,
+            layout_checks: true,
+            source_comments: true,
+            split_files: false,
+            parallel: true,
+            pointer_width: 64,
+            header_guard: None,
+            cache_dir: None
+        }
+    }
+
+    /// Adds a rustdoc JSON file to generate bindings for, instead of the bundled copy.
+    pub fn input_json(mut self, path: impl Into<PathBuf>) -> Self {
+        self.inputs.push(path.into());
+        self
+    }
+
+    /// Sets whether to load rustdoc JSON produced with an unsupported format version.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Replaces the configuration, such as one loaded with [`config::Config::from_path`].
+    pub fn config(mut self, config: config::Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the namespace of the generated C# code.
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.config.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Sets whether the C# namespace is declared as file-scoped.
+    pub fn file_scoped_namespace(mut self, enabled: bool) -> Self {
+        self.config.file_scoped_namespace = Some(enabled);
+        self
+    }
+
+    /// Sets whether C# classes are owned by a generated `SafeHandle`.
+    pub fn safe_handles(mut self, enabled: bool) -> Self {
+        self.config.safe_handles = Some(enabled);
+        self
+    }
+
+    /// Sets whether `usize` and `isize` cross the FFI boundary as 64-bit integers, so that the layout of
+    /// the generated structs does not depend on the target's pointer width.
+    pub fn fixed_width_sizes(mut self, enabled: bool) -> Self {
+        self.config.fixed_width_sizes = Some(enabled);
+        self
+    }
+
+    /// Sets whether `bool` fields are declared as `[MarshalAs(UnmanagedType.U1)] bool`. By default, they are
+    /// stored as a `byte` behind a `bool` property instead, which keeps the struct blittable so that it is
+    /// one byte per field however it is marshaled.
+    pub fn marshal_bools(mut self, enabled: bool) -> Self {
+        self.config.marshal_bools = Some(enabled);
+        self
+    }
+
+    /// Sets whether `#[non_exhaustive]` enums are given an `Unknown` variant, which variants added to the
+    /// Rust enum after the bindings were generated are converted to. Otherwise, such variants panic.
+    pub fn unknown_variants(mut self, enabled: bool) -> Self {
+        self.config.unknown_variants = enabled;
+        self
+    }
+
+    /// Sets the pointer width of the target in bits, which determines the expected layout of structs
+    /// containing pointer-sized fields. Defaults to 64.
+    pub fn pointer_width(mut self, bits: usize) -> Self {
+        self.pointer_width = bits;
+        self
+    }
+
+    /// Sets the native library which C# loads the bindings from.
+    pub fn library(mut self, library: impl Into<String>) -> Self {
+        self.config.library = Some(library.into());
+        self
+    }
+
+    /// Sets whether native functions are declared with `[DllImport]` rather than `[LibraryImport]`.
+    pub fn dll_import(mut self, enabled: bool) -> Self {
+        self.config.dll_import = Some(enabled);
+        self
+    }
+
+    /// Sets whether colliding generated names are renamed apart with numeric suffixes, rather than reported.
+    pub fn disambiguate(mut self, enabled: bool) -> Self {
+        self.config.disambiguate = Some(enabled);
+        self
+    }
+
+    /// Sets the configuration of the build which the bindings are for, so that items disabled by a `#[cfg(...)]`
+    /// attribute are skipped.
+    pub fn target_cfg(mut self, target: cfg::TargetCfg) -> Self {
+        self.config.target_cfg = Some(target);
+        self
+    }
+
+    /// Declares how the type at the given canonical Rust path is marshaled.
+    pub fn known_type(mut self, path: impl Into<String>, known_type: config::KnownTypeConfig) -> Self {
+        self.config.known_types.insert(path.into(), known_type);
+        self
+    }
+
+    /// Generates a struct for an instantiation of a generic type, such as `core::ops::range::RangeInclusive<f32>`,
+    /// so that fields and parameters of that type can be marshaled.
+    pub fn instantiation(mut self, ty: impl Into<String>, instantiation: config::InstantiationConfig) -> Self {
+        self.config.instantiations.insert(ty.into(), instantiation);
+        self
+    }
+
+    /// Prevents bindings from being generated for the item at the given canonical Rust path, and its members.
+    /// The path may contain `*` wildcards, as described by [`config::path_matches`].
+    pub fn skip(mut self, path: impl Into<String>) -> Self {
+        self.config.skip.push(path.into());
+        self
+    }
+
+    /// Requires bindings to be generated for the item at the given canonical Rust path, so that generation
+    /// fails if it is skipped.
+    pub fn require(mut self, path: impl Into<String>) -> Self {
+        self.config.require.push(path.into());
+        self
+    }
+
+    /// Sets the C# name of the type, struct field, or method at the given canonical Rust path.
+    pub fn rename(mut self, path: impl Into<String>, cs_name: impl Into<String>) -> Self {
+        self.config.renames.insert(path.into(), cs_name.into());
+        self
+    }
+
+    /// Sets which methods and constructors to generate for the class at the given canonical Rust path. Methods
+    /// are matched by name across every inherent `impl` block of the class. Listed methods which cannot be
+    /// bound fail generation, while [`config::MethodSurface::All`] leaves them to the report.
+    pub fn class_methods(mut self, path: impl Into<String>, methods: config::MethodSurface) -> Self {
+        self.config.classes.entry(path.into()).or_default().methods = methods;
+        self
+    }
+
+    /// Generates a distinct C# struct for the newtype at the given path, rather than marshaling it as its inner type.
+    pub fn keep_newtype(mut self, path: impl Into<String>) -> Self {
+        self.keep_newtypes.push(path.into());
+        self
+    }
+
+    /// Sets whether `#[doc(hidden)]` and non-public items should have bindings generated.
+    pub fn include_hidden(mut self, include: bool) -> Self {
+        self.include_hidden = include;
+        self
+    }
+
+    /// Sets whether to generate assertions that Rust and C# agree on the layout of each struct.
+    pub fn layout_checks(mut self, enabled: bool) -> Self {
+        self.layout_checks = enabled;
+        self
+    }
+
+    /// Sets whether each generated item and exported function is preceded by a comment naming the Rust item,
+    /// and the file and line, which it was generated from.
+    pub fn source_comments(mut self, enabled: bool) -> Self {
+        self.source_comments = enabled;
+        self
+    }
+
+    /// Sets whether to render the generated items on several threads. This only has an effect if the `parallel`
+    /// feature is enabled, and the output is the same either way.
+    pub fn parallel(mut self, enabled: bool) -> Self {
+        self.parallel = enabled;
+        self
+    }
+
+    /// Sets whether to generate one C# file per type, in [`GeneratedOutput::cs_files`]. Each file is placed
+    /// in a directory for the module which publicly exports the type, following any `pub use` re-exports.
+    pub fn split_files(mut self, enabled: bool) -> Self {
+        self.split_files = enabled;
+        self
+    }
+
+    /// Generates a C header declaring the exported functions, with the given include guard.
+    pub fn header(mut self, guard: impl Into<String>) -> Self {
+        self.header_guard = Some(guard.into());
+        self
+    }
+
+    /// Caches the classified items in the given directory, keyed by the input, the version of this tool, and the
+    /// configuration. Reruns which only change how the items are written, such as the namespace, skip straight to
+    /// rendering. Entries which are corrupt or were written by another version are ignored.
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Runs the generator. Bindings are only returned if their names are unique and they are syntactically valid.
+    ///
+    /// The output is deterministic: the same inputs and options always produce byte-for-byte identical bindings,
+    /// regardless of hash map ordering or how many threads render the items.
+    pub fn generate(&self) -> Result<GeneratedOutput, GenerateError> {
+        let (classification, from_cache) = self.classify()?;
+        let cache::Classification { items, naming, source_hash, report, unbound_methods, missing_requirements } = classification;
+        if !unbound_methods.is_empty() {
+            return Err(GenerateError::UnboundMethods(unbound_methods));
+        }
+
+        if !missing_requirements.is_empty() {
+            return Err(GenerateError::MissingRequirements(missing_requirements));
+        }
+
+        let collisions = collisions::find_collisions(&items, &naming);
+        if !collisions.is_empty() {
+            return Err(GenerateError::Collisions(collisions));
+        }
+
+        let mut cs_file = CsFile {
+            namespace: self.config.namespace.clone().unwrap_or_else(|| "Vortex.Gui".to_string()),
+            file_scoped: self.config.file_scoped_namespace.unwrap_or(false),
+            source_hash: Some(source_hash),
+            layout_checks: self.layout_checks,
+            library: self.config.library.clone().unwrap_or_else(|| "egui_native".to_string()),
+            dll_import: self.config.dll_import.unwrap_or(false)
+        };
+
+        let render = ag::RenderContext {
+            pointer_width: self.pointer_width,
+            marshal_bools: self.config.marshal_bools.unwrap_or(false),
+            source_comments: self.source_comments,
+            parallel: self.parallel,
+            ..ag::RenderContext::new(&items, naming)
+        };
+        let rs = autogenerate_rs(&items, &render, self.layout_checks);
+        let (cs, cs_files) = if self.split_files {
+            // The hash is only recorded in the index, so that unchanged types keep identical files.
+            cs_file.source_hash = None;
+            (String::new(), autogenerate_cs_files(&items, &render, &cs_file))
+        }
+        else {
+            (autogenerate_cs(&items, &render, &cs_file), BTreeMap::new())
+        };
+        let h = self.header_guard.as_ref().map(|guard| autogenerate_h(&items, &render, guard));
+
+        // Malformed bindings are never returned, so that a broken emitter cannot overwrite good output.
+        let mut syntax_errors = validate::validate_items(&items, &render);
+        if let Err(message) = validate::validate_rs(&rs) {
+            syntax_errors.push(validate::SyntaxError { language: "Rust", source: "Rust bindings".to_string(), message });
+        }
+
+        if !self.split_files && let Err(message) = validate::validate_cs(&cs) {
+            syntax_errors.push(validate::SyntaxError { language: "C#", source: "C# bindings".to_string(), message });
+        }
+
+        for (path, contents) in &cs_files {
+            if let Err(message) = validate::validate_cs(contents) {
+                syntax_errors.push(validate::SyntaxError { language: "C#", source: path.display().to_string(), message });
+            }
+        }
+
+        if let Some(contents) = &h && let Err(message) = validate::validate_cs(contents) {
+            syntax_errors.push(validate::SyntaxError { language: "C", source: "C header".to_string(), message });
+        }
+
+        if !syntax_errors.is_empty() {
+            return Err(GenerateError::Syntax(syntax_errors));
+        }
+
+        let manifest = autogenerate_manifest(&items, &render, &cs_file.namespace, self.split_files, &report);
+        Ok(GeneratedOutput { cs, cs_files, rs, h, report, manifest, source_hash, from_cache })
+    }
+
+    /// Chooses the items to generate, loading them from the cache if they were stored by an identical run.
+    /// Returns whether the cache was used.
+    fn classify(&self) -> Result<(cache::Classification, bool), GenerateError> {
+        let Some(dir) = &self.cache_dir else {
+            return Ok((self.collect()?, false));
+        };
+
+        let key = self.cache_key()?;
+        if let Some(classification) = cache::load(dir, key) {
+            return Ok((classification, true));
+        }
+
+        let classification = self.collect()?;
+        if let Err(e) = cache::store(dir, key, &classification) {
+            log::warn!("Failed to cache classification in {}: {e}", dir.display());
+        }
+        Ok((classification, false))
+    }
+
+    /// Loads the rustdoc JSON and chooses the items to generate.
+    fn collect(&self) -> Result<cache::Classification, GenerateError> {
+        let mut ctx = if self.inputs.is_empty() {
+            BindgenContext::bundled()?
+        }
+        else {
+            BindgenContext::from_paths(&self.inputs, self.force)?
+        };
+
+        ctx.apply_config(&self.config);
+        for path in &self.keep_newtypes {
+            ctx.keep_newtype(path);
+        }
+        ctx.include_hidden(self.include_hidden);
+        ctx.safe_handles(self.config.safe_handles.unwrap_or(false));
+        ctx.disambiguate(self.config.disambiguate.unwrap_or(false));
+        ctx.fixed_width_sizes(self.config.fixed_width_sizes.unwrap_or(false));
+        if let Some(target) = &self.config.target_cfg {
+            ctx.target_cfg(target.clone());
+        }
+        ctx.collect();
+        Ok(cache::Classification::new(ctx))
+    }
+
+    /// Hashes everything which affects the choice of items: the version of this tool, the input, and the
+    /// options and configuration other than those which only affect rendering.
+    fn cache_key(&self) -> Result<u64, LoadError> {
+        let mut hash = fnv1a(FNV_OFFSET_BASIS, cache::VERSION.as_bytes());
+        if self.inputs.is_empty() {
+            hash = fnv1a(hash, BUNDLED_JSON.as_bytes());
+        }
+
+        for path in &self.inputs {
+            let json = std::fs::read(path).map_err(|e| LoadError::Io(path.clone(), e))?;
+            hash = fnv1a(hash, &json.len().to_le_bytes());
+            hash = fnv1a(hash, &json);
+        }
+
+        let options = format!("{:?}", (self.config.classification(), &self.keep_newtypes, self.include_hidden, self.force));
+        Ok(fnv1a(hash, options.as_bytes()))
+    }
+}
+
+impl Default for Bindgen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The bindings produced by [`Bindgen::generate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GeneratedOutput {
+    /// The C# bindings as a single file, or an empty string if they were split.
+    pub cs: String,
+    /// The C# bindings with one file per type, keyed by their path relative to the output directory,
+    /// or an empty map if they were not split.
+    pub cs_files: BTreeMap<PathBuf, String>,
+    /// The Rust functions which the C# bindings call.
+    pub rs: String,
+    /// The C header declaring the exported functions, if one was requested.
+    pub h: Option<String>,
+    /// Which items were generated, and why the others were skipped.
+    pub report: report::Report,
+    /// The generated items, the exported functions, and the skipped items.
+    pub manifest: manifest::Manifest,
+    /// A hash of the rustdoc JSON from which the bindings were generated.
+    pub source_hash: u64,
+    /// Whether the items were loaded from the cache set with [`Bindgen::cache_dir`], rather than classified again.
+    pub from_cache: bool
+}
+
+/// An error that prevented bindings from being generated.
+#[derive(Debug)]
+pub enum GenerateError {
+    /// The rustdoc JSON could not be loaded.
+    Load(LoadError),
+    /// Several sources generate the same name.
+    Collisions(Vec<collisions::Collision>),
+    /// Methods listed for a class in the configuration could not be generated.
+    UnboundMethods(Vec<report::UnboundMethod>),
+    /// Items which the configuration requires could not be generated.
+    MissingRequirements(Vec<report::MissingRequirement>),
+    /// The generated bindings were malformed.
+    Syntax(Vec<validate::SyntaxError>)
+}
+
+impl std::fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerateError::Load(err) => write!(f, "{err}"),
+            GenerateError::Collisions(collisions) => {
+                for collision in collisions {
+                    writeln!(f, "{collision}")?;
+                }
+                write!(f, "Rename the colliding items in the configuration, or enable disambiguation to add numeric suffixes")
+            },
+            GenerateError::UnboundMethods(methods) => {
+                for (i, method) in methods.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{method}")?;
+                }
+                Ok(())
+            },
+            GenerateError::MissingRequirements(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                Ok(())
+            },
+            GenerateError::Syntax(errors) => {
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "{error}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GenerateError::Load(err) => Some(err),
+            _ => None
+        }
+    }
+}
+
+impl From<LoadError> for GenerateError {
+    fn from(err: LoadError) -> Self {
+        GenerateError::Load(err)
+    }
+}
+
+/// The rustdoc JSON which is used if no input is given.
+const BUNDLED_JSON: &str = include_str!("egui.json");
+
+/// The initial state of an FNV-1a hash.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+/// Extends a 64-bit FNV-1a hash with the given bytes. This is used instead of [`std::hash::DefaultHasher`]
+/// because its output must be stable across Rust versions.
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
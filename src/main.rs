@@ -1,197 +1,562 @@
-#![allow(warnings)]
-
-#![feature(formatting_options)]
-
-use std::{collections::*, env::var};
-use rustdoc_types::*;
-
-/// Defines the C# and Rust members that will be autogenerated.
-mod ag;
+use std::collections::*;
+use std::path::{Path, PathBuf};
+use egui_inspect::*;
 
+/// Writes a file by first writing a temporary file alongside it and then renaming it into place,
+/// so that existing output is never left truncated. Parent directories are created as needed.
+fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent().filter(|x| !x.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
 
-pub struct BindgenContext {
-    /// The definitions to create in C# and Rust.
-    items: Vec<ag::Item>,
-    krate: Crate,
-    remaining_items: Vec<Id>,
-    result: String,
-    total_items: usize
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    std::fs::write(&temp, contents)?;
+    std::fs::rename(&temp, path)
 }
 
-impl BindgenContext {
-    pub fn new() -> Self {
-        let items = Vec::new();
-        let krate = serde_json::from_str::<Crate>(include_str!("egui.json")).expect("Failed to parse egui");
-        let mut remaining_items = krate.index.values().filter(Self::item_relevant).map(|x| x.id).collect::<Vec<_>>();
-        let total_items = remaining_items.len();
-        let result = String::new();
+/// The file which lists every file written by [`write_split`], relative to the output directory.
+const SPLIT_INDEX: &str = ".generated";
 
-        Self {
-            items,
-            krate,
-            remaining_items,
-            total_items,
-            result
+/// Writes a set of files into the output directory, leaving files with unchanged contents untouched.
+/// Files recorded in the directory's index by a previous run that are no longer generated are deleted,
+/// along with any directories that they leave empty.
+fn write_split(dir: &Path, files: &BTreeMap<PathBuf, String>, source_hash: u64) -> std::io::Result<()> {
+    for stale in stale_files(dir, files)? {
+        match std::fs::remove_file(dir.join(&stale)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+
+        let mut parent = stale.parent();
+        while let Some(x) = parent.filter(|x| !x.as_os_str().is_empty()) {
+            if std::fs::remove_dir(dir.join(x)).is_err() {
+                break;
+            }
+            parent = x.parent();
         }
-    }
-    
-    pub fn collect(&mut self) {
-        self.collect_primitive_enums();
-        self.items.sort();
     }
 
-    pub fn items(&self) -> &[ag::Item] {
-        &self.items
+    for (path, contents) in files {
+        write_if_changed(&dir.join(path), contents)?;
     }
 
-    fn collect_primitive_enums(&mut self) {
-        let mut remaining = self.remaining_items.clone();
-        remaining.retain(|x| {
-            let item = &self.krate.index[x];
-            match &item.inner {
-                ItemEnum::Enum(x) => !self.collect_primitive_enum(item.id),
-                _ => true
-            }
-        });
-        self.remaining_items = remaining;
-    }
-    
-    fn collect_primitive_enum(&mut self, id: Id) -> bool {
-        let ItemEnum::Enum(x) = &self.krate.index[&id].inner else { unreachable!() };
-        if self.is_primitive_enum(x) {
-            let enum_ty = &self.krate.index[&id];
-            
-            let variants = x.variants.iter().map(|id| {
-                let variant = &self.krate.index[id];
-                let ItemEnum::Variant(inner) = &variant.inner else { unreachable!() };
-                
-                ag::EnumVariant {
-                    docs: variant.docs.clone().unwrap_or_default(),
-                    name: variant.name.clone().unwrap_or_default(),
-                    index: inner.discriminant.as_ref().map(|x| str::parse(&x.value).expect("Failed to parse enum discriminant"))
-                }
-            }).collect();
+    write_if_changed(&dir.join(SPLIT_INDEX), &split_index(files, source_hash))
+}
 
-            self.items.push(ag::Item::Enum {
-                docs: enum_ty.docs.clone().unwrap_or_default(),
-                name: enum_ty.name.clone().unwrap_or_default(),
-                variants
-            });
+/// Lists the files recorded in the output directory's index by a previous run which are no longer generated.
+fn stale_files(dir: &Path, files: &BTreeMap<PathBuf, String>) -> std::io::Result<Vec<PathBuf>> {
+    let previous = match std::fs::read_to_string(dir.join(SPLIT_INDEX)) {
+        Ok(x) => x,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e)
+    };
 
-            true
-        }
-        else {
-            false
-        }
-    }
-    
-    /// Checks if the enum only has primitive variants.
-    fn is_primitive_enum(&self, x: &Enum) -> bool {
-        for variant in &x.variants {
-            let ItemEnum::Variant(x) = &self.krate.index[variant].inner else { unreachable!() };
-            if x.kind != VariantKind::Plain {
-                return false;
-            }
-        }
-    
-        true
-    }
-    
-    /// Whether this is an item for which we will generate code.
-    fn item_relevant(x: &&Item) -> bool {
-        match &x.inner {
-            ItemEnum::Union(_)
-            | ItemEnum::Struct(_)
-            | ItemEnum::Enum(_)
-            | ItemEnum::Function(_)
-            | ItemEnum::TypeAlias(_)
-            | ItemEnum::Constant { .. }
-            | ItemEnum::Static(_)
-            | ItemEnum::ExternType
-            | ItemEnum::Macro(_)
-            | ItemEnum::ProcMacro(_) => true,
-            _ => false
-        }
-    }
-    
-    /*
-    fn default_known_types() -> HashMap<String, KnownType> {
-        [
-            ("i32", KnownType::new("int", TypeKind::Copy)),
-            ("u32", KnownType::new("uint", TypeKind::Copy)),
-            ("f32", KnownType::new("float", TypeKind::Copy)),
-            ("f64", KnownType::new("double", TypeKind::Copy)),
-            ("egui::Pos2", KnownType::new("IVec2", TypeKind::Copy)),
-            ("egui::Vec2", KnownType::new("IVec2", TypeKind::Copy)),
-            ("egui::Vec2", KnownType::new("IVec2", TypeKind::Copy)),
-        ].into_iter().map(|(a, b)| (a.to_owned(), b)).collect()
-    } */
+    Ok(previous.lines().filter(|x| !x.starts_with('#')).map(PathBuf::from).filter(|x| !files.contains_key(x)).collect())
 }
 
-#[derive(Clone, Debug)]
-struct KnownType {
-    pub cs_name: String,
-    pub kind: TypeKind
+/// Creates the index which [`write_split`] writes alongside the given files.
+fn split_index(files: &BTreeMap<PathBuf, String>, source_hash: u64) -> String {
+    let mut index = format!("# Generated from rustdoc JSON with hash {source_hash:016x}\n");
+    for path in files.keys() {
+        index += &path.to_string_lossy().replace('\\', "/");
+        index += "\n";
+    }
+    index
 }
 
-impl KnownType {
-    pub fn new(cs_name: impl Into<String>, kind: TypeKind) -> Self {
-        Self { cs_name: cs_name.into(), kind }
+/// Compares the files that would be written against those on disk, printing a unified diff of each file
+/// that differs. Files which would be deleted count as differences. Returns whether every file is up to date.
+fn check_outputs(files: &[(PathBuf, &str)], stale: &[PathBuf], loose: bool) -> bool {
+    let mut up_to_date = true;
+    for (path, contents) in files {
+        match diff::check_file(path, contents, loose) {
+            Ok(None) => {},
+            Ok(Some(diff::Mismatch::Changed(diff))) => {
+                print!("{diff}");
+                up_to_date = false;
+            },
+            Ok(Some(diff::Mismatch::Missing)) => {
+                log::error!("{} is missing", path.display());
+                up_to_date = false;
+            },
+            Err(e) => {
+                log::error!("Failed to read {}: {e}", path.display());
+                up_to_date = false;
+            }
+        }
+    }
+
+    for path in stale.iter().filter(|x| x.exists()) {
+        log::error!("{} is no longer generated", path.display());
+        up_to_date = false;
     }
+    up_to_date
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-enum TypeKind {
-    Copy,
-    Opaque
+/// Writes a file with [`write_atomic`] unless it already has the given contents.
+fn write_if_changed(path: &Path, contents: &str) -> std::io::Result<()> {
+    if std::fs::read_to_string(path).is_ok_and(|x| x == contents) {
+        Ok(())
+    }
+    else {
+        write_atomic(path, contents)
+    }
 }
 
-/// Generates the formatted C# bindings for the given items.
-pub fn autogenerate_cs(items: &[ag::Item]) -> String {
-    let mut result = "namespace Vortex.Gui;\n\n".to_string();
+/// The usage printed by `--help`.
+const USAGE: &str = "\
+Generates C# bindings for egui, along with the Rust functions which they call.
 
-    for item in items {
-        result += &format!("{}\n", ag::DisplayCs(item));
-    }
+Usage: egui_inspect [OPTIONS] [INPUT]...
 
-    result
-}
+Inputs:
+  [INPUT]...                    The rustdoc JSON files to load, in addition to those given by --input
+      --input <PATH>            Loads a rustdoc JSON file. The bundled egui is used if none are given
+      --config <PATH>           Loads a configuration file
+      --force                   Loads rustdoc JSON with an unsupported format version
+      --cache-dir <DIR>         Caches the classified items in the given directory
+
+Items:
+      --keep-newtype <PATH>     Generates a distinct type for the newtype at the given path
+      --include-hidden          Generates bindings for hidden and private items
+      --target-cfg <CFG>        Sets a `--cfg` option of the target build, like `unix` or `target_arch = \"x86_64\"`
+      --features <LIST>         Sets the comma-separated features enabled for the target build
+      --disambiguate            Renames colliding generated names apart with numeric suffixes
 
-/// Generates the formatted Rust bindings for the given items.
-pub fn autogenerate_rs(items: &[ag::Item]) -> String {
-    let mut result = String::new();
+Output:
+      --output-cs <PATH>        Where to write the C# bindings, or their directory if split [default: Gui.g.cs]
+      --output-rs <PATH>        Where to write the Rust bindings [default: gui.rs]
+      --output-h <PATH>         Where to write the C header
+      --split-files             Writes each C# type to its own file
+      --namespace <NAME>        Sets the namespace of the C# bindings
+      --file-scoped-namespace   Declares the C# namespace as file-scoped
+      --library <NAME>          Sets the native library which C# loads the bindings from
+      --dll-import              Declares native functions with [DllImport]
+      --safe-handles            Owns C# classes with a SafeHandle
+      --fixed-width-sizes       Passes usize and isize as 64-bit integers
+      --pointer-width <BITS>    Sets the pointer width of the target: 16, 32, or 64
+      --no-layout-checks        Omits the assertions that Rust and C# agree on struct layouts
+      --no-source-comments      Omits the comments naming the Rust item of each binding
 
-    for item in items {
-        result += &format!("{}\n", ag::DisplayRs(item));
+Checks and reports:
+      --check                   Compares the output against the files on disk instead of writing it
+      --check-loose             Like --check, but ignores differences in whitespace
+      --min-coverage <FRACTION> Fails if less than the given fraction of items is generated
+      --verbose                 Lists every skipped item in the report
+      --report-json <PATH>      Writes the report as JSON
+      --manifest <PATH>         Writes the generated items and symbols as JSON
+  -h, --help                    Prints this message
+";
+
+/// The command-line options accepted by the tool.
+#[derive(Clone, Debug, Default)]
+struct Args {
+    /// The rustdoc JSON files to load. The bundled copy is used if this is empty.
+    inputs: Vec<PathBuf>,
+    /// The configuration file to load, if any.
+    config: Option<PathBuf>,
+    /// Whether to attempt parsing even if the format version does not match.
+    force: bool,
+    /// Newtypes that should not be unwrapped to their inner type.
+    keep_newtypes: Vec<String>,
+    /// Whether to generate bindings for hidden and private items.
+    include_hidden: bool,
+    /// The `--cfg` options set for the target build, such as `unix` or `target_arch = "x86_64"`.
+    target_cfgs: Vec<String>,
+    /// The features enabled for the target build, or [`None`] if they were not given.
+    features: Option<Vec<String>>,
+    /// Whether to list every skipped item in the report.
+    verbose: bool,
+    /// The least fraction of items which must be generated overall, if not the configured minimum.
+    min_coverage: Option<f64>,
+    /// Where to write the report as JSON, if anywhere.
+    report_json: Option<PathBuf>,
+    /// Where to write the manifest of generated items and symbols as JSON, if anywhere.
+    manifest: Option<PathBuf>,
+    /// The directory in which to cache the classified items, if any.
+    cache_dir: Option<PathBuf>,
+    /// Whether to compare the output against the files on disk instead of writing it.
+    check: bool,
+    /// Whether to ignore differences in whitespace when checking the output.
+    check_loose: bool,
+    /// Where to write the C# bindings. This is a directory if the output is split.
+    output_cs: Option<PathBuf>,
+    /// Whether to write each C# type to its own file.
+    split_files: bool,
+    /// The namespace of the C# bindings, if not the default.
+    namespace: Option<String>,
+    /// Whether to declare the C# namespace as file-scoped.
+    file_scoped_namespace: bool,
+    /// Whether to own C# classes with a `SafeHandle`.
+    safe_handles: bool,
+    /// Whether to rename colliding generated names apart.
+    disambiguate: bool,
+    /// The native library which C# loads the bindings from, if not the default.
+    library: Option<String>,
+    /// Whether to declare native functions with `[DllImport]`.
+    dll_import: bool,
+    /// Whether to pass `usize` and `isize` as 64-bit integers.
+    fixed_width_sizes: bool,
+    /// The pointer width of the target in bits, if not the default.
+    pointer_width: Option<usize>,
+    /// Where to write the Rust bindings.
+    output_rs: Option<PathBuf>,
+    /// Where to write the C header, if anywhere.
+    output_h: Option<PathBuf>,
+    /// Whether to generate assertions that Rust and C# agree on the layout of each struct.
+    layout_checks: bool,
+    /// Whether to note the Rust item which each binding was generated from in a comment.
+    source_comments: bool
+}
+
+impl Args {
+    /// Parses the arguments passed to this process.
+    pub fn parse() -> Self {
+        let mut result = Self { layout_checks: true, source_comments: true, ..Self::default() };
+        let mut args = std::env::args_os().skip(1);
+        while let Some(arg) = args.next() {
+            if arg == "--force" {
+                result.force = true;
+            }
+            else if arg == "--include-hidden" {
+                result.include_hidden = true;
+            }
+            else if arg == "--target-cfg" {
+                result.target_cfgs.push(Self::value(&mut args, "--target-cfg").to_string_lossy().into_owned());
+            }
+            else if arg == "--features" {
+                let features = Self::value(&mut args, "--features").to_string_lossy().into_owned();
+                result.features.get_or_insert_default().extend(features.split(',').map(str::trim).filter(|x| !x.is_empty()).map(str::to_string));
+            }
+            else if arg == "--config" {
+                result.config = Some(Self::value(&mut args, "--config").into());
+            }
+            else if arg == "--split-files" {
+                result.split_files = true;
+            }
+            else if arg == "--file-scoped-namespace" {
+                result.file_scoped_namespace = true;
+            }
+            else if arg == "--safe-handles" {
+                result.safe_handles = true;
+            }
+            else if arg == "--disambiguate" {
+                result.disambiguate = true;
+            }
+            else if arg == "--library" {
+                result.library = Some(Self::value(&mut args, "--library").to_string_lossy().into_owned());
+            }
+            else if arg == "--dll-import" {
+                result.dll_import = true;
+            }
+            else if arg == "--fixed-width-sizes" {
+                result.fixed_width_sizes = true;
+            }
+            else if arg == "--pointer-width" {
+                let width = Self::value(&mut args, "--pointer-width").to_string_lossy().into_owned();
+                match width.parse() {
+                    Ok(bits @ (16 | 32 | 64)) => result.pointer_width = Some(bits),
+                    _ => {
+                        log::error!("Invalid --pointer-width `{width}`, expected 16, 32, or 64");
+                        std::process::exit(1);
+                    }
+                }
+            }
+            else if arg == "--namespace" {
+                result.namespace = Some(Self::value(&mut args, "--namespace").to_string_lossy().into_owned());
+            }
+            else if arg == "--no-layout-checks" {
+                result.layout_checks = false;
+            }
+            else if arg == "--no-source-comments" {
+                result.source_comments = false;
+            }
+            else if arg == "--check" {
+                result.check = true;
+            }
+            else if arg == "--check-loose" {
+                result.check = true;
+                result.check_loose = true;
+            }
+            else if arg == "--verbose" {
+                result.verbose = true;
+            }
+            else if arg == "--min-coverage" {
+                let fraction = Self::value(&mut args, "--min-coverage").to_string_lossy().into_owned();
+                match fraction.parse() {
+                    Ok(x) if (0.0..=1.0).contains(&x) => result.min_coverage = Some(x),
+                    _ => {
+                        log::error!("Invalid --min-coverage `{fraction}`, expected a number from 0 to 1");
+                        std::process::exit(1);
+                    }
+                }
+            }
+            else if arg == "--report-json" {
+                result.report_json = Some(Self::value(&mut args, "--report-json").into());
+            }
+            else if arg == "--manifest" {
+                result.manifest = Some(Self::value(&mut args, "--manifest").into());
+            }
+            else if arg == "--cache-dir" {
+                result.cache_dir = Some(Self::value(&mut args, "--cache-dir").into());
+            }
+            else if arg == "--output-cs" {
+                result.output_cs = Some(Self::value(&mut args, "--output-cs").into());
+            }
+            else if arg == "--output-rs" {
+                result.output_rs = Some(Self::value(&mut args, "--output-rs").into());
+            }
+            else if arg == "--output-h" {
+                result.output_h = Some(Self::value(&mut args, "--output-h").into());
+            }
+            else if arg == "--input" {
+                result.inputs.push(Self::value(&mut args, "--input").into());
+            }
+            else if arg == "--keep-newtype" {
+                result.keep_newtypes.push(Self::value(&mut args, "--keep-newtype").to_string_lossy().into_owned());
+            }
+            else if arg == "--help" || arg == "-h" {
+                print!("{USAGE}");
+                std::process::exit(0);
+            }
+            else if arg.to_string_lossy().starts_with('-') {
+                log::error!("Unknown option `{}`; pass --help to list the options", arg.to_string_lossy());
+                std::process::exit(1);
+            }
+            else {
+                result.inputs.push(PathBuf::from(arg));
+            }
+        }
+        result
     }
 
-    result
+    /// Gets the value following a flag, exiting with an error if it is missing.
+    fn value(args: &mut impl Iterator<Item = std::ffi::OsString>, flag: &str) -> std::ffi::OsString {
+        args.next().unwrap_or_else(|| {
+            log::error!("Expected a value after {flag}");
+            std::process::exit(1);
+        })
+    }
 }
 
 pub fn main() {
-    let mut ctx = BindgenContext::new();
-    ctx.collect();
-
-    println!("{}", ag::DisplayRs(&ag::Item::Struct {
-        name: "Galley".to_string(),
-        fields: vec![
-            ag::StructField {
-                name: "hello".to_string(),
-                ty: ag::TypeReference::Primitive(ag::PrimitiveType::F64),
-                docs: "it's a field".to_string()
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let args = Args::parse();
+    let mut config = match &args.config {
+        Some(path) => config::Config::from_path(path).unwrap_or_else(|e| {
+            log::error!("{e}");
+            std::process::exit(1);
+        }),
+        None => config::Config::default()
+    };
+
+    // Options on the command line add to those in the configuration file, and enable filtering even if empty.
+    if !args.target_cfgs.is_empty() || args.features.is_some() {
+        let target = config.target_cfg.get_or_insert_default();
+        for option in &args.target_cfgs {
+            if let Err(e) = target.set(option) {
+                log::error!("Invalid --target-cfg `{option}`: {e}");
+                std::process::exit(1);
+            }
+        }
+        for feature in args.features.iter().flatten() {
+            target.enable_feature(feature);
+        }
+    }
+
+    let mut min_coverage = config.min_coverage.clone();
+    if let Some(fraction) = args.min_coverage {
+        min_coverage.insert(report::CoverageReport::OVERALL.to_string(), fraction);
+    }
+
+    let mut bindgen = Bindgen::new()
+        .config(config)
+        .force(args.force)
+        .include_hidden(args.include_hidden)
+        .layout_checks(args.layout_checks)
+        .source_comments(args.source_comments)
+        .split_files(args.split_files);
+    for path in &args.inputs {
+        bindgen = bindgen.input_json(path);
+    }
+    for path in &args.keep_newtypes {
+        bindgen = bindgen.keep_newtype(path);
+    }
+
+    // Flags on the command line take precedence over the configuration file.
+    if let Some(namespace) = &args.namespace {
+        bindgen = bindgen.namespace(namespace);
+    }
+    if let Some(library) = &args.library {
+        bindgen = bindgen.library(library);
+    }
+    if args.file_scoped_namespace {
+        bindgen = bindgen.file_scoped_namespace(true);
+    }
+    if args.safe_handles {
+        bindgen = bindgen.safe_handles(true);
+    }
+    if args.dll_import {
+        bindgen = bindgen.dll_import(true);
+    }
+    if args.disambiguate {
+        bindgen = bindgen.disambiguate(true);
+    }
+    if args.fixed_width_sizes {
+        bindgen = bindgen.fixed_width_sizes(true);
+    }
+    if let Some(bits) = args.pointer_width {
+        bindgen = bindgen.pointer_width(bits);
+    }
+    if let Some(dir) = &args.cache_dir {
+        bindgen = bindgen.cache_dir(dir);
+    }
+    if let Some(path) = &args.output_h {
+        let stem = path.file_stem().map_or("bindings".into(), |x| x.to_string_lossy());
+        let guard = stem.chars().map(|x| if x.is_ascii_alphanumeric() { x.to_ascii_uppercase() } else { '_' }).collect::<String>();
+        bindgen = bindgen.header(format!("{guard}_H"));
+    }
+
+    let output = bindgen.generate().unwrap_or_else(|e| {
+        match e {
+            GenerateError::Collisions(collisions) => {
+                for collision in &collisions {
+                    log::error!("{collision}");
+                }
+                log::error!("Rename the colliding items in the configuration, or pass --disambiguate to add numeric suffixes");
+            },
+            GenerateError::UnboundMethods(methods) => {
+                for method in &methods {
+                    log::error!("{method}");
+                }
+            },
+            GenerateError::MissingRequirements(items) => {
+                for item in &items {
+                    log::error!("{item}");
+                }
             },
-            ag::StructField {
-                name: "my_frien".to_string(),
-                ty: ag::TypeReference::Primitive(ag::PrimitiveType::Bool),
-                docs: "another one".to_string()
+            GenerateError::Syntax(errors) => {
+                for error in &errors {
+                    log::error!("{error}");
+                }
             },
-        ],
-        has_default: true,
-        docs: "It's a str".to_string()
-    }));
-    
-    std::fs::write("Gui.g.cs", autogenerate_cs(ctx.items())).expect("Failed to write C# bindings");
-    std::fs::write("gui.rs", autogenerate_rs(ctx.items())).expect("Failed to write Rust bindings");
-
-    println!("Generated {} / {} items", ctx.total_items - ctx.remaining_items.len(), ctx.total_items);
-}
\ No newline at end of file
+            e => log::error!("{e}")
+        }
+        std::process::exit(1);
+    });
+
+    let output_rs = args.output_rs.clone().unwrap_or_else(|| PathBuf::from("gui.rs"));
+    let output_cs = args.output_cs.clone().unwrap_or_else(|| PathBuf::from(if args.split_files { "Gui" } else { "Gui.g.cs" }));
+    if args.check {
+        // Default paths are only checked if a previous run wrote to them, so that checking one output does not
+        // require the others.
+        let mut files = Vec::new();
+        if args.output_rs.is_some() || output_rs.exists() {
+            files.push((output_rs, output.rs.as_str()));
+        }
+        if let (Some(path), Some(contents)) = (&args.output_h, &output.h) {
+            files.push((path.clone(), contents.as_str()));
+        }
+
+        let mut stale = Vec::new();
+        let index = split_index(&output.cs_files, output.source_hash);
+        let check_cs = args.output_cs.is_some() || output_cs.exists();
+        if check_cs && args.split_files {
+            files.extend(output.cs_files.iter().map(|(path, contents)| (output_cs.join(path), contents.as_str())));
+            files.push((output_cs.join(SPLIT_INDEX), index.as_str()));
+            stale = stale_files(&output_cs, &output.cs_files).unwrap_or_else(|e| {
+                log::error!("Failed to read {}: {e}", output_cs.join(SPLIT_INDEX).display());
+                std::process::exit(1);
+            });
+            stale.iter_mut().for_each(|x| *x = output_cs.join(&*x));
+        }
+        else if check_cs {
+            files.push((output_cs, output.cs.as_str()));
+        }
+
+        if files.is_empty() {
+            log::error!("There are no bindings to check; pass --output-cs or --output-rs to name them");
+            std::process::exit(1);
+        }
+
+        log::info!("{}", output.report);
+        if !check_outputs(&files, &stale, args.check_loose) {
+            log::error!("The generated bindings are out of date; run again without --check to regenerate them");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.verbose {
+        log::info!("{:#}", output.report);
+    }
+    else {
+        log::info!("{}", output.report);
+    }
+    log::info!("Coverage by kind:\n{}", output.report.coverage);
+
+    // Bindings are only written if they meet the minimum coverage.
+    let shortfalls = output.report.coverage.shortfalls(&min_coverage);
+    if !shortfalls.is_empty() {
+        for shortfall in &shortfalls {
+            log::error!("{shortfall}");
+        }
+
+        // The groups of skipped items which count against the unmet minimums, so that the largest gaps can be fixed first.
+        let gaps = output.report.skipped_groups().into_iter()
+            .filter(|(_, items)| !items[0].reason.is_intentional())
+            .filter(|((kind, _), _)| report::CoverageReport::KINDS.contains(kind)
+                && shortfalls.iter().any(|x| x.kind == report::CoverageReport::OVERALL || x.kind == *kind))
+            .take(10);
+        for ((kind, category), items) in gaps {
+            log::error!("    {} skipped: {category}", report::count(items.len(), kind));
+        }
+        std::process::exit(1);
+    }
+
+    let mut outputs = vec![(output_rs.clone(), write_atomic(&output_rs, &output.rs))];
+    if let (Some(path), Some(contents)) = (&args.output_h, &output.h) {
+        outputs.push((path.clone(), write_atomic(path, contents)));
+    }
+    if args.split_files {
+        outputs.push((output_cs.clone(), write_split(&output_cs, &output.cs_files, output.source_hash)));
+    }
+    else {
+        outputs.push((output_cs.clone(), write_atomic(&output_cs, &output.cs)));
+    }
+
+    for (path, result) in outputs {
+        if let Err(e) = result {
+            log::error!("Failed to write {}: {e}", path.display());
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(path) = &args.report_json {
+        let json = serde_json::to_string_pretty(&output.report).unwrap_or_else(|e| {
+            log::error!("Failed to serialize report: {e}");
+            std::process::exit(1);
+        });
+
+        if let Err(e) = std::fs::write(path, json) {
+            log::error!("Failed to write {}: {e}", path.display());
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(path) = &args.manifest {
+        let mut manifest = output.manifest;
+        manifest.resolve_files(&output_cs);
+        let json = serde_json::to_string_pretty(&manifest).unwrap_or_else(|e| {
+            log::error!("Failed to serialize manifest: {e}");
+            std::process::exit(1);
+        });
+
+        if let Err(e) = std::fs::write(path, json) {
+            log::error!("Failed to write {}: {e}", path.display());
+            std::process::exit(1);
+        }
+    }
+}
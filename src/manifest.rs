@@ -0,0 +1,72 @@
+use crate::report::*;
+use serde::*;
+use std::path::{Path, PathBuf};
+
+/// Lists everything that a run of the generator produced, so that build pipelines can find the generated
+/// API, and compare it between versions of the bound crates.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The generated types, functions, constants, and statics, each followed by the members of classes.
+    pub items: Vec<ManifestItem>,
+    /// The functions exported from the Rust bindings, including those of the support code, in the order that
+    /// C# declares them.
+    pub symbols: Vec<ManifestSymbol>,
+    /// The items and fields which were not generated, sorted by path.
+    pub skipped: Vec<ManifestSkip>
+}
+
+impl Manifest {
+    /// Resolves the file of each item against the path which the C# bindings were written to, which is a
+    /// directory if they were split, or a single file otherwise.
+    pub fn resolve_files(&mut self, output_cs: &Path) {
+        for file in self.items.iter_mut().filter_map(|x| x.file.as_mut()) {
+            *file = if file.as_os_str().is_empty() { output_cs.to_owned() } else { output_cs.join(&*file) };
+        }
+    }
+}
+
+/// An item, or member of a class, which was generated.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestItem {
+    /// The kind of item, such as `struct`, `class`, `method`, or `constructor`.
+    pub kind: String,
+    /// The canonical Rust path which the item was generated from, such as `egui::ui::Ui::button`.
+    pub rust_path: String,
+    /// The name of the item in C#. Members are qualified by their class, and free functions, constants,
+    /// and statics by the class which declares them, like `Functions.Lerp`.
+    pub cs_name: String,
+    /// The C# namespace which contains the item.
+    pub namespace: String,
+    /// The C# file which the item was written to. This is relative to the output directory if the bindings were
+    /// split into one file per type, or empty if they were written to a single file, until [`Manifest::resolve_files`]
+    /// is called. Items without C# bindings of their own, like external types, have no file.
+    pub file: Option<PathBuf>
+}
+
+/// A function exported from the Rust bindings.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestSymbol {
+    /// The exported name of the function, such as `vx_ui_label`.
+    pub name: String,
+    /// The parameters and return type with which the function is called across the FFI boundary, such as
+    /// `fn(this: *mut VxObject<Ui>, text: VxString)`. Return values wrapped to catch panics are listed unwrapped.
+    pub signature: String
+}
+
+/// An item or field for which no bindings were generated.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestSkip {
+    /// The canonical path of the item.
+    pub path: String,
+    /// The kind of item, such as `struct` or `function`.
+    pub kind: String,
+    /// Why the item was skipped.
+    #[serde(flatten)]
+    pub reason: SkipReason
+}
+
+impl From<&SkippedItem> for ManifestSkip {
+    fn from(value: &SkippedItem) -> Self {
+        Self { path: value.path.clone(), kind: value.kind.to_string(), reason: value.reason.clone() }
+    }
+}
@@ -0,0 +1,380 @@
+/// The prefix given to link destinations which refer to Rust items by canonical path.
+pub const RUST_LINK: &str = "rust:";
+/// The prefix given to link destinations which refer to generated C# types.
+pub const CS_LINK: &str = "cref:";
+
+/// Escapes the characters which have special meaning in XML, so that documentation text
+/// like `Vec<T>` or `a & b` does not produce malformed doc comments. Quotes are left alone,
+/// since documentation text is never placed inside an attribute.
+pub fn escape_xml_docs(docs: &str) -> String {
+    let mut result = String::with_capacity(docs.len());
+    for c in docs.chars() {
+        match c {
+            '&' => result += "&amp;",
+            '<' => result += "&lt;",
+            '>' => result += "&gt;",
+            other => result.push(other)
+        }
+    }
+    result
+}
+
+/// Rewrites the destination of every link outside of code blocks. The `resolve` function receives
+/// the key that rustdoc uses for the link (its destination, or its text if it has none) and
+/// returns the new destination, or [`None`] to leave the link unchanged.
+pub fn rewrite_links(markdown: &str, resolve: impl Fn(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(markdown.len());
+    let mut in_code = false;
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        }
+
+        if in_code || is_link_definition(line) {
+            result += line;
+        }
+        else {
+            let mut rest = line;
+            while let Some(start) = rest.find('[') {
+                result += &rest[..start];
+                match link_at(&rest[start..]) {
+                    Some(link) => {
+                        match resolve(link.destination.unwrap_or(link.text)) {
+                            Some(destination) => result += &format!("[{}]({destination})", link.text),
+                            None => result += &rest[start..start + link.len]
+                        }
+                        rest = &rest[start + link.len..];
+                    },
+                    None => {
+                        result.push('[');
+                        rest = &rest[start + 1..];
+                    }
+                }
+            }
+            result += rest;
+        }
+
+        result.push('\n');
+    }
+
+    result.truncate(result.trim_end().len());
+    result
+}
+
+/// Removes the destinations added by [`rewrite_links`], restoring the original intra-doc links.
+pub fn strip_link_destinations(markdown: &str) -> String {
+    let mut result = String::with_capacity(markdown.len());
+    let mut rest = markdown;
+    while let Some(start) = rest.find("](") {
+        let destination = &rest[start + 2..];
+        result += &rest[..=start];
+        if destination.starts_with(RUST_LINK) || destination.starts_with(CS_LINK) {
+            rest = destination.find(')').map_or("", |end| &destination[end + 1..]);
+        }
+        else {
+            result.push('(');
+            rest = destination;
+        }
+    }
+    result += rest;
+    result
+}
+
+/// The sections of a C# XML doc comment.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct XmlDocs {
+    /// The contents of the `<summary>` element, which is shown in tooltips.
+    pub summary: String,
+    /// The C# name and contents of the `<param>` element of each parameter.
+    pub params: Vec<(String, String)>,
+    /// The contents of the `<returns>` element, or [`None`] if the function returns nothing.
+    pub returns: Option<String>,
+    /// The contents of the `<exception>` element, which describes when the function panics.
+    pub exception: String,
+    /// The contents of the `<remarks>` element, which holds any further explanation.
+    pub remarks: String
+}
+
+/// Converts rustdoc markdown into the body of a C# XML doc comment. The first paragraph becomes the
+/// summary, and the rest of the blocks become the remarks. Inline code becomes `<c>`, resolved links
+/// become `<see cref="..."/>`, lists become `<list>`, and other blocks become `<para>`.
+/// Rust code blocks are dropped, since they would not compile as C#.
+pub fn to_xml_docs(markdown: &str) -> XmlDocs {
+    let mut blocks = parse_blocks(markdown);
+    let summary = match blocks.first() {
+        Some(Block::Paragraph(_)) => blocks_to_xml(blocks.drain(..1).collect()),
+        _ => String::new()
+    };
+
+    XmlDocs { summary, remarks: blocks_to_xml(blocks), ..XmlDocs::default() }
+}
+
+/// Converts the rustdoc markdown of a function into the body of a C# XML doc comment, as [`to_xml_docs`] does.
+/// Each parameter, given by its Rust and C# names, is described by its item in an `# Arguments` section, if any.
+/// A `# Returns` section describes the return value, and a `# Panics` section the exception thrown when the
+/// function panics. Other sections, such as `# Safety`, stay in the remarks.
+pub fn to_xml_function_docs(markdown: &str, params: &[(String, String)], returns: bool) -> XmlDocs {
+    let mut blocks = parse_blocks(markdown);
+    let summary = match blocks.first() {
+        Some(Block::Paragraph(_)) => blocks_to_xml(blocks.drain(..1).collect()),
+        _ => String::new()
+    };
+
+    let mut sections: [Vec<Block>; 4] = Default::default();
+    let mut current = 0;
+    for block in blocks {
+        if let Block::Heading(heading) = &block {
+            current = match heading.as_str() {
+                "Arguments" | "Parameters" => 1,
+                "Returns" => 2,
+                "Panics" => 3,
+                _ => 0
+            };
+
+            if current != 0 {
+                continue;
+            }
+        }
+        sections[current].push(block);
+    }
+
+    let [remarks, arguments, returned, panics] = sections;
+    let items = arguments.into_iter()
+        .flat_map(|x| match x { Block::List(items) => items, _ => Vec::new() })
+        .collect::<Vec<_>>();
+    let params = params.iter().map(|(rs_name, cs_name)| {
+        let description = items.iter().filter_map(|x| argument_item(x)).find(|x| x.0 == rs_name);
+        (cs_name.clone(), description.map_or(String::new(), |x| inline_to_xml(x.1)))
+    }).collect();
+
+    XmlDocs {
+        summary,
+        params,
+        returns: returns.then(|| blocks_to_xml(returned)),
+        exception: blocks_to_xml(panics),
+        remarks: blocks_to_xml(remarks)
+    }
+}
+
+/// Splits an item of an `# Arguments` list, like `` `text` - The text to show. ``, into the name of the
+/// parameter and its description.
+fn argument_item(item: &str) -> Option<(&str, &str)> {
+    let (name, rest) = match item.strip_prefix('`') {
+        Some(code) => code.split_once('`')?,
+        None => item.split_once([' ', ':'])?
+    };
+    Some((name.trim_end_matches(':'), rest.trim_start().trim_start_matches(['-', ':', '–', '—']).trim_start()))
+}
+
+/// Converts a sequence of blocks into XML. A lone paragraph is written as plain text.
+fn blocks_to_xml(blocks: Vec<Block>) -> String {
+    let single = blocks.len() == 1 && matches!(blocks[0], Block::Paragraph(_));
+    let mut result = Vec::new();
+    for block in blocks {
+        match block {
+            Block::Paragraph(text) if single => result.push(inline_to_xml(&text)),
+            Block::Paragraph(text) => result.push(format!("<para>{}</para>", inline_to_xml(&text))),
+            Block::Heading(text) => result.push(format!("<para><b>{}</b></para>", inline_to_xml(&text))),
+            Block::List(items) => {
+                result.push("<list type=\"bullet\">".to_string());
+                for item in items {
+                    result.push(format!("<item><description>{}</description></item>", inline_to_xml(&item)));
+                }
+                result.push("</list>".to_string());
+            },
+            Block::Code(code) => result.push(format!("<code>\n{}\n</code>", escape_xml_docs(&code)))
+        }
+    }
+    result.join("\n")
+}
+
+/// A top-level element of a markdown document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Block {
+    /// A run of text, with its lines joined by newlines.
+    Paragraph(String),
+    /// A section heading.
+    Heading(String),
+    /// A bulleted or numbered list, with the text of each item.
+    List(Vec<String>),
+    /// A fenced code block in a language other than Rust.
+    Code(String)
+}
+
+/// Splits markdown into blocks, dropping Rust code blocks, link definitions, and example headings.
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph = Vec::new();
+    let mut list = Vec::<String>::new();
+    let mut lines = markdown.lines();
+
+    let flush = |blocks: &mut Vec<Block>, paragraph: &mut Vec<&str>, list: &mut Vec<String>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(paragraph.join("\n")));
+            paragraph.clear();
+        }
+
+        if !list.is_empty() {
+            blocks.push(Block::List(std::mem::take(list)));
+        }
+    };
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if let Some(language) = trimmed.strip_prefix("```") {
+            flush(&mut blocks, &mut paragraph, &mut list);
+            let code = lines.by_ref().take_while(|x| !x.trim_start().starts_with("```")).collect::<Vec<_>>();
+            if !is_rust_language(language) {
+                blocks.push(Block::Code(code.join("\n")));
+            }
+        }
+        else if trimmed.is_empty() || is_link_definition(trimmed) {
+            flush(&mut blocks, &mut paragraph, &mut list);
+        }
+        else if let Some(heading) = trimmed.strip_prefix('#').map(|x| x.trim_start_matches('#').trim()) {
+            flush(&mut blocks, &mut paragraph, &mut list);
+            if !matches!(heading, "Example" | "Examples") {
+                blocks.push(Block::Heading(heading.to_string()));
+            }
+        }
+        else if let Some(item) = list_item(trimmed) {
+            if !paragraph.is_empty() {
+                flush(&mut blocks, &mut paragraph, &mut list);
+            }
+            list.push(item.to_string());
+        }
+        else if let Some(last) = list.last_mut().filter(|_| line.starts_with(' ')) {
+            *last += "\n";
+            *last += trimmed;
+        }
+        else {
+            if !list.is_empty() {
+                flush(&mut blocks, &mut paragraph, &mut list);
+            }
+            paragraph.push(trimmed.trim_end_matches('\\'));
+        }
+    }
+
+    flush(&mut blocks, &mut paragraph, &mut list);
+    blocks
+}
+
+/// Whether a fenced code block with the given info string contains Rust code. Rustdoc treats
+/// blocks without a language as Rust.
+fn is_rust_language(info: &str) -> bool {
+    let info = info.trim();
+    info.is_empty() || info.split(',').any(|x| matches!(x.trim(), "rust" | "ignore" | "no_run" | "should_panic" | "compile_fail" | "edition2015" | "edition2018" | "edition2021" | "edition2024"))
+}
+
+/// Whether a line defines the destination of a reference-style link, like `[Ui]: crate::Ui`.
+fn is_link_definition(line: &str) -> bool {
+    line.trim_start().starts_with('[') && line.contains("]: ")
+}
+
+/// Gets the text of a bulleted or numbered list item.
+fn list_item(line: &str) -> Option<&str> {
+    if let Some(item) = line.strip_prefix("* ").or_else(|| line.strip_prefix("- ")) {
+        return Some(item);
+    }
+
+    let digits = line.find(|x: char| !x.is_ascii_digit())?;
+    (digits > 0).then(|| line[digits..].strip_prefix(". "))?
+}
+
+/// A link found within markdown text.
+struct Link<'a> {
+    /// The text displayed for the link.
+    text: &'a str,
+    /// The destination of the link, if it was given inline.
+    destination: Option<&'a str>,
+    /// The length of the link syntax, in bytes.
+    len: usize
+}
+
+/// Parses the link starting at the beginning of the text, which must begin with `[`.
+fn link_at(text: &str) -> Option<Link<'_>> {
+    let close = text.find(']')?;
+    let link_text = &text[1..close];
+    if link_text.is_empty() || link_text.contains('[') {
+        return None;
+    }
+
+    let after = &text[close + 1..];
+    if let Some(destination) = after.strip_prefix('(') {
+        let end = destination.find(')')?;
+        Some(Link { text: link_text, destination: Some(&destination[..end]), len: close + end + 3 })
+    }
+    else if let Some(reference) = after.strip_prefix('[') {
+        let end = reference.find(']')?;
+        Some(Link { text: link_text, destination: None, len: close + end + 3 })
+    }
+    else {
+        Some(Link { text: link_text, destination: None, len: close + 1 })
+    }
+}
+
+/// Converts inline markdown (code spans, links, autolinks, and bold text) into XML.
+fn inline_to_xml(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(['`', '[', '*', '<']) {
+        result += &escape_xml_docs(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(code) = rest.strip_prefix('`') {
+            match code.find('`') {
+                Some(end) => {
+                    result += &format!("<c>{}</c>", escape_xml_docs(&code[..end]));
+                    rest = &code[end + 1..];
+                },
+                None => {
+                    result.push('`');
+                    rest = code;
+                }
+            }
+        }
+        else if let Some(bold) = rest.strip_prefix("**") {
+            match bold.find("**") {
+                Some(end) => {
+                    result += &format!("<b>{}</b>", inline_to_xml(&bold[..end]));
+                    rest = &bold[end + 2..];
+                },
+                None => {
+                    result += "**";
+                    rest = bold;
+                }
+            }
+        }
+        else if rest.starts_with('[') {
+            match link_at(rest) {
+                Some(link) => {
+                    match link.destination {
+                        Some(destination) if destination.starts_with(CS_LINK) => {
+                            result += &format!("<see cref=\"{}\"/>", &destination[CS_LINK.len()..]);
+                        },
+                        Some(destination) if destination.contains("://") => {
+                            result += &format!("<see href=\"{}\">{}</see>", escape_xml_docs(destination), inline_to_xml(link.text));
+                        },
+                        _ => result += &inline_to_xml(link.text)
+                    }
+                    rest = &rest[link.len..];
+                },
+                None => {
+                    result.push('[');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        else if let Some(url) = rest.strip_prefix('<').and_then(|x| Some(&x[..x.find('>')?])).filter(|x| x.contains("://")) {
+            result += &format!("<see href=\"{0}\">{0}</see>", escape_xml_docs(url));
+            rest = &rest[url.len() + 2..];
+        }
+        else {
+            result += &escape_xml_docs(&rest[..1]);
+            rest = &rest[1..];
+        }
+    }
+
+    result += &escape_xml_docs(rest);
+    result
+}
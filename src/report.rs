@@ -0,0 +1,439 @@
+use serde::*;
+use std::collections::*;
+use std::fmt::*;
+
+/// Explains why bindings were not generated for an item.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum SkipReason {
+    /// The struct has private or hidden fields.
+    StrippedFields,
+    /// The item has generic parameters.
+    Generic,
+    /// The struct has no fields.
+    UnitStruct,
+    /// One of the struct's fields cannot be copied across the FFI boundary.
+    NonCopyField {
+        /// The name of the field.
+        field: String,
+        /// The canonical path of the field's type, if it could be named.
+        ty: Option<String>
+    },
+    /// The field cannot be copied across the FFI boundary, so it is left out of the struct's mirror and set to
+    /// its default value when the struct is converted back.
+    Defaulted {
+        /// The canonical path of the field's type, if it could be named.
+        ty: Option<String>
+    },
+    /// The struct contains itself through a cycle of field types.
+    Cycle,
+    /// The struct is `#[non_exhaustive]`, so its fields are not a stable contract.
+    NonExhaustive,
+    /// The struct has a `#[repr(...)]` layout which the generated C# struct cannot reproduce,
+    /// or which has not been allowed by the configuration.
+    UnsupportedLayout {
+        /// The argument of the `#[repr(...)]` attribute, such as `packed` or `align(8)`.
+        repr: String
+    },
+    /// The type alias, constant, or static refers to a type that cannot be marshaled.
+    UnsupportedTarget {
+        /// The canonical path of the target type, if it could be named.
+        ty: Option<String>
+    },
+    /// One of the function's parameters cannot be passed across the FFI boundary.
+    UnsupportedParameter {
+        /// The name of the parameter.
+        parameter: String,
+        /// The canonical path of the parameter's type, if it could be named.
+        ty: Option<String>
+    },
+    /// The function's return type cannot be passed across the FFI boundary.
+    UnsupportedReturn {
+        /// The canonical path of the return type, if it could be named.
+        ty: Option<String>
+    },
+    /// The static is mutable, so it cannot be read safely.
+    MutableStatic,
+    /// The associated item belongs to a type for which no struct bindings were generated.
+    UnsupportedOwner {
+        /// The canonical path of the owning type, if it could be named.
+        owner: Option<String>
+    },
+    /// None of the data carried by the enum's variants can be copied across the FFI boundary.
+    PayloadEnum {
+        /// The name of the first variant whose data cannot be copied.
+        variant: String
+    },
+    /// One of the enum's discriminants does not fit in the underlying type.
+    InvalidDiscriminant {
+        /// The name of the variant.
+        variant: String,
+        /// The value of the discriminant.
+        value: String
+    },
+    /// The configuration requested that the item, or an item containing it, be skipped.
+    Configured,
+    /// The item, or an item containing it, is disabled for the target by a `#[cfg(...)]` attribute.
+    Cfg {
+        /// The unsatisfied predicate, such as `target_arch = "wasm32"`.
+        predicate: String
+    },
+    /// Bindings are not generated for this kind of item.
+    UnsupportedKind
+}
+
+impl SkipReason {
+    /// Whether the item was left out on purpose, by the configuration or for the target, rather than for being unsupported.
+    pub fn is_intentional(&self) -> bool {
+        matches!(self, SkipReason::Configured | SkipReason::Cfg { .. })
+    }
+
+    /// A short description of the reason, shared by all items skipped for it.
+    pub fn category(&self) -> &'static str {
+        match self {
+            SkipReason::StrippedFields => "has private fields",
+            SkipReason::Generic => "has generic parameters",
+            SkipReason::UnitStruct => "has no fields",
+            SkipReason::NonCopyField { .. } => "contains non-Copy field",
+            SkipReason::Defaulted { .. } => "left to its default value",
+            SkipReason::Cycle => "contains itself",
+            SkipReason::NonExhaustive => "is non-exhaustive",
+            SkipReason::UnsupportedLayout { .. } => "has an unsupported layout",
+            SkipReason::UnsupportedTarget { .. } => "refers to an unsupported type",
+            SkipReason::UnsupportedOwner { .. } => "belongs to an unsupported type",
+            SkipReason::MutableStatic => "is mutable",
+            SkipReason::UnsupportedParameter { .. } => "has an unsupported parameter",
+            SkipReason::UnsupportedReturn { .. } => "has an unsupported return type",
+            SkipReason::PayloadEnum { .. } => "has variants with unsupported data",
+            SkipReason::InvalidDiscriminant { .. } => "has an unrepresentable discriminant",
+            SkipReason::Configured => "skipped by configuration",
+            SkipReason::Cfg { .. } => "disabled for the target",
+            SkipReason::UnsupportedKind => "unsupported item kind"
+        }
+    }
+}
+
+impl Display for SkipReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SkipReason::NonCopyField { field, ty: Some(ty) } => write!(f, "field `{field}` has type `{ty}`"),
+            SkipReason::NonCopyField { field, ty: None } => write!(f, "field `{field}` has an unnameable type"),
+            SkipReason::Defaulted { ty: Some(ty) } => write!(f, "has type `{ty}`, so it is left to its default value"),
+            SkipReason::UnsupportedLayout { repr } => write!(f, "has layout `#[repr({repr})]`"),
+            SkipReason::UnsupportedTarget { ty: Some(ty) } => write!(f, "target type `{ty}` is unsupported"),
+            SkipReason::UnsupportedOwner { owner: Some(owner) } => write!(f, "owner `{owner}` has no struct bindings"),
+            SkipReason::UnsupportedParameter { parameter, ty: Some(ty) } => write!(f, "parameter `{parameter}` has type `{ty}`"),
+            SkipReason::UnsupportedParameter { parameter, ty: None } => write!(f, "parameter `{parameter}` has an unnameable type"),
+            SkipReason::UnsupportedReturn { ty: Some(ty) } => write!(f, "returns `{ty}`"),
+            SkipReason::PayloadEnum { variant } => write!(f, "variant `{variant}` carries data which cannot be copied"),
+            SkipReason::InvalidDiscriminant { variant, value } => write!(f, "variant `{variant}` has discriminant {value}"),
+            SkipReason::Cfg { predicate } => write!(f, "requires `cfg({predicate})`"),
+            other => f.write_str(other.category())
+        }
+    }
+}
+
+/// An item for which no bindings were generated.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SkippedItem {
+    /// The canonical path of the item.
+    pub path: String,
+    /// The kind of item, such as `struct` or `function`.
+    pub kind: &'static str,
+    /// Why the item was skipped.
+    #[serde(flatten)]
+    pub reason: SkipReason
+}
+
+/// A [`SkippedItem`] as it is deserialized, before its kind is checked.
+#[derive(Deserialize)]
+struct SkippedItemData {
+    path: String,
+    kind: String,
+    #[serde(flatten)]
+    reason: SkipReason
+}
+
+// The kind is checked against the known kinds of item, rather than borrowed from the input.
+impl<'de> Deserialize<'de> for SkippedItem {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let data = SkippedItemData::deserialize(deserializer)?;
+        let kind = static_kind(&data.kind).ok_or_else(|| de::Error::custom(format!("unknown kind of item `{}`", data.kind)))?;
+        Ok(Self { path: data.path, kind, reason: data.reason })
+    }
+}
+
+/// An instantiation of a generic type which struct fields use, but for which no struct is configured.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnknownInstantiation {
+    /// The instantiated type, such as `core::ops::range::RangeInclusive<usize>`.
+    pub ty: String,
+    /// The canonical paths of the fields which have the type, sorted.
+    pub fields: Vec<String>
+}
+
+/// A method which the configuration lists for a class, but for which no bindings could be generated.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnboundMethod {
+    /// The canonical path of the method.
+    pub path: String,
+    /// Why the method was skipped, or [`None`] if it does not exist.
+    pub reason: Option<SkipReason>
+}
+
+impl Display for UnboundMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match &self.reason {
+            Some(reason) => write!(f, "Listed method `{}` could not be generated: {reason}", self.path),
+            None => write!(f, "Listed method `{}` does not exist", self.path)
+        }
+    }
+}
+
+/// An item which the configuration requires, but for which no bindings were generated.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MissingRequirement {
+    /// The canonical path of the item.
+    pub path: String,
+    /// Why the item was skipped, or [`None`] if it does not exist in the loaded crates or is hidden.
+    pub reason: Option<SkipReason>
+}
+
+impl Display for MissingRequirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match &self.reason {
+            Some(reason) => write!(f, "Required item `{}` was not generated: {reason}", self.path),
+            None => write!(f, "Required item `{}` does not exist in the loaded crates, or is private or hidden", self.path)
+        }
+    }
+}
+
+/// Summarizes the results of binding generation.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    /// The number of items for which bindings were generated.
+    pub generated: usize,
+    /// The number of items that were considered.
+    pub total: usize,
+    /// The number of items that were excluded for being hidden or private.
+    pub hidden: usize,
+    /// The items that were considered but skipped, along with the fields left out of generated structs, sorted by path.
+    pub skipped: Vec<SkippedItem>,
+    /// The instantiations of generic types which prevented structs from being copied, sorted by type.
+    pub unknown_instantiations: Vec<UnknownInstantiation>,
+    /// The items whose rustdoc JSON was malformed, sorted by path.
+    pub errors: Vec<crate::BindgenError>,
+    /// How many of the considered items of each kind were generated.
+    pub coverage: CoverageReport
+}
+
+impl Report {
+    /// Groups the skipped items by kind and reason, with the largest groups first.
+    pub fn skipped_groups(&self) -> Vec<((&'static str, &'static str), Vec<&SkippedItem>)> {
+        let mut groups = BTreeMap::<_, Vec<&SkippedItem>>::new();
+        for item in &self.skipped {
+            groups.entry((item.kind, item.reason.category())).or_default().push(item);
+        }
+
+        let mut groups = groups.into_iter().collect::<Vec<_>>();
+        groups.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then(a.0.cmp(&b.0)));
+        groups
+    }
+}
+
+impl Display for Report {
+    /// Prints a summary of the skipped items, grouped by kind and reason, with the items skipped by the configuration
+    /// counted together. The alternate flag (`{:#}`) additionally lists every skipped item.
+    ///
+    /// ```
+    /// use egui_inspect::report::*;
+    ///
+    /// let skipped = |path: &str, kind, reason| SkippedItem { path: path.to_string(), kind, reason };
+    /// let report = Report {
+    ///     generated: 2,
+    ///     total: 5,
+    ///     skipped: vec![
+    ///         skipped("tiny::Shape", "enum", SkipReason::UnsupportedKind),
+    ///         skipped("tiny::Side", "enum", SkipReason::Configured),
+    ///         skipped("tiny::Rounding", "struct", SkipReason::Configured)
+    ///     ],
+    ///     ..Report::default()
+    /// };
+    /// assert_eq!(report.to_string(), "1 enum skipped: unsupported item kind\n\
+    ///     2 items skipped by configuration\n\
+    ///     Generated 2 / 5 items (0 hidden items skipped)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let groups = self.skipped_groups();
+        for ((kind, category), items) in groups.iter().filter(|(_, items)| items[0].reason != SkipReason::Configured) {
+            writeln!(f, "{} skipped: {category}", count(items.len(), kind))?;
+            if f.alternate() {
+                for item in items {
+                    writeln!(f, "    {} ({})", item.path, item.reason)?;
+                }
+            }
+        }
+
+        let configured = self.skipped.iter().filter(|x| x.reason == SkipReason::Configured).collect::<Vec<_>>();
+        if !configured.is_empty() {
+            writeln!(f, "{} skipped by configuration", count(configured.len(), "item"))?;
+            if f.alternate() {
+                for item in configured {
+                    writeln!(f, "    {} ({})", item.path, item.kind)?;
+                }
+            }
+        }
+
+        if !self.unknown_instantiations.is_empty() {
+            let verb = if self.unknown_instantiations.len() == 1 { "has" } else { "have" };
+            writeln!(f, "{} {verb} no configured struct", count(self.unknown_instantiations.len(), "generic instantiation"))?;
+            if f.alternate() {
+                for instantiation in &self.unknown_instantiations {
+                    writeln!(f, "    {} (used by {})", instantiation.ty, instantiation.fields.join(", "))?;
+                }
+            }
+        }
+
+        if !self.errors.is_empty() {
+            writeln!(f, "{} failed: malformed rustdoc JSON", count(self.errors.len(), "item"))?;
+            if f.alternate() {
+                for error in &self.errors {
+                    writeln!(f, "    {error}")?;
+                }
+            }
+        }
+
+        write!(f, "Generated {} / {} items ({} hidden items skipped)", self.generated, self.total, self.hidden)
+    }
+}
+
+/// The number of items which were generated, out of those that were considered.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Coverage {
+    /// The number of items for which bindings were generated.
+    pub generated: usize,
+    /// The number of items that were considered.
+    pub total: usize
+}
+
+impl Coverage {
+    /// Gets the fraction of items which were generated. This is one if no items were considered.
+    pub fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        }
+        else {
+            self.generated as f64 / self.total as f64
+        }
+    }
+}
+
+impl Display for Coverage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{:.1}% ({} / {})", 100.0 * self.ratio(), self.generated, self.total)
+    }
+}
+
+/// How many of the considered items of each kind were generated. Items which were skipped intentionally, by the
+/// configuration or for the target, are not counted, so that the coverage only reflects items which could not be bound.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverageReport {
+    /// The coverage of each kind of item, such as `struct`, `class`, or `method`.
+    #[serde(deserialize_with = "deserialize_kinds")]
+    pub kinds: BTreeMap<&'static str, Coverage>
+}
+
+impl CoverageReport {
+    /// The kinds of item which coverage is reported for. Structs generated as classes are counted as classes, and
+    /// functions in `impl` blocks as methods.
+    pub const KINDS: &[&str] = &["class", "constant", "enum", "extern type", "function", "item", "macro", "method", "static", "struct", "type alias", "union"];
+
+    /// The name with which a minimum coverage is given for all kinds of item together.
+    pub const OVERALL: &str = "overall";
+
+    /// Gets the coverage of all kinds of item together.
+    pub fn overall(&self) -> Coverage {
+        self.kinds.values().fold(Coverage::default(), |acc, x| Coverage { generated: acc.generated + x.generated, total: acc.total + x.total })
+    }
+
+    /// Compares the coverage against the given minimum fractions, keyed by kind of item or [`Self::OVERALL`],
+    /// returning those which are not met.
+    pub fn shortfalls(&self, minimums: &BTreeMap<String, f64>) -> Vec<CoverageShortfall> {
+        minimums.iter().filter_map(|(kind, &minimum)| {
+            let coverage = if kind == Self::OVERALL { self.overall() } else { self.kinds.get(kind.as_str()).copied().unwrap_or_default() };
+            (coverage.ratio() < minimum).then(|| CoverageShortfall { kind: kind.clone(), coverage, minimum })
+        }).collect()
+    }
+}
+
+impl Display for CoverageReport {
+    /// Prints a table of the coverage of each kind of item, followed by the overall coverage.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        writeln!(f, "{:<12} {:>9} {:>9} {:>9}", "Kind", "Generated", "Total", "Coverage")?;
+        for (kind, coverage) in self.kinds.iter().map(|(kind, x)| (*kind, *x)).chain([(Self::OVERALL, self.overall())]) {
+            write!(f, "{kind:<12} {:>9} {:>9} {:>8.1}%", coverage.generated, coverage.total, 100.0 * coverage.ratio())?;
+            if kind != Self::OVERALL {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A kind of item whose coverage is below the minimum which was required of it.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct CoverageShortfall {
+    /// The kind of item, or [`CoverageReport::OVERALL`] for all kinds together.
+    pub kind: String,
+    /// The coverage of the kind of item.
+    pub coverage: Coverage,
+    /// The minimum fraction of items which was required to be generated.
+    pub minimum: f64
+}
+
+impl Display for CoverageShortfall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let kind = if self.kind == CoverageReport::OVERALL { "all items".to_string() } else { plural(&self.kind) };
+        write!(f, "Coverage of {kind} is {}, below the minimum of {:.1}%", self.coverage, 100.0 * self.minimum)
+    }
+}
+
+/// Gets the plural of a kind of item, such as `classes` for `class`.
+pub fn plural(kind: &str) -> String {
+    if kind.ends_with('s') {
+        format!("{kind}es")
+    }
+    else {
+        format!("{kind}s")
+    }
+}
+
+/// Describes a number of items of the given kind, which is only pluralized if there is not exactly one.
+///
+/// ```
+/// use egui_inspect::report::count;
+///
+/// assert_eq!(count(1, "enum"), "1 enum");
+/// assert_eq!(count(0, "class"), "0 classes");
+/// assert_eq!(count(14, "struct"), "14 structs");
+/// ```
+pub fn count(count: usize, kind: &str) -> String {
+    if count == 1 {
+        format!("{count} {kind}")
+    }
+    else {
+        format!("{count} {}", plural(kind))
+    }
+}
+
+/// Gets the name of a kind of item, which is one of [`CoverageReport::KINDS`] or `field`, as a static string.
+fn static_kind(kind: &str) -> Option<&'static str> {
+    CoverageReport::KINDS.iter().chain(&["field"]).copied().find(|x| *x == kind)
+}
+
+/// Deserializes a map keyed by kinds of item.
+fn deserialize_kinds<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<BTreeMap<&'static str, Coverage>, D::Error> {
+    BTreeMap::<String, Coverage>::deserialize(deserializer)?.into_iter()
+        .map(|(kind, coverage)| static_kind(&kind).map(|x| (x, coverage)).ok_or_else(|| de::Error::custom(format!("unknown kind of item `{kind}`"))))
+        .collect()
+}
@@ -0,0 +1,17 @@
+/// A `char` which is passed across the FFI boundary as its `u32` codepoint.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct VxChar(pub u32);
+
+impl From<char> for VxChar {
+    fn from(value: char) -> Self {
+        Self(value as u32)
+    }
+}
+
+impl From<VxChar> for char {
+    fn from(value: VxChar) -> Self {
+        // Codepoints from C# are not guaranteed to be valid, so surrogates and out-of-range values are replaced.
+        char::from_u32(value.0).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+}
@@ -0,0 +1,54 @@
+/// An enum or struct which C# passes by `ref`. The value is converted from its mirror for the duration of
+/// a call, and written back when this is dropped, even if the call panics.
+pub struct VxMirrorMut<M: Copy + From<T>, T: From<M>> {
+    /// The mirror to write back to.
+    target: *mut M,
+    /// The value while it is in use.
+    value: std::mem::ManuallyDrop<T>
+}
+
+impl<M: Copy + From<T>, T: From<M>> VxMirrorMut<M, T> {
+    /// Converts the mirror which the pointer refers to.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must refer to a valid mirror, which is not used again until
+    /// this is dropped.
+    pub unsafe fn new(target: *mut M) -> Self {
+        Self { value: std::mem::ManuallyDrop::new(T::from(*target)), target }
+    }
+
+    /// Checks the discriminant which the pointer refers to with the given function, which panics if it is
+    /// invalid, and converts the mirror it returns.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must refer to an integer with the same layout as the mirror,
+    /// which is not used again until this is dropped.
+    pub unsafe fn checked<R: Copy>(target: *mut R, check: impl FnOnce(R) -> M) -> Self {
+        assert_eq!(std::mem::size_of::<R>(), std::mem::size_of::<M>(), "Mirror is not the size of its discriminant");
+        let mirror = check(*target);
+        Self { target: target.cast(), value: std::mem::ManuallyDrop::new(T::from(mirror)) }
+    }
+}
+
+impl<M: Copy + From<T>, T: From<M>> std::ops::Deref for VxMirrorMut<M, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<M: Copy + From<T>, T: From<M>> std::ops::DerefMut for VxMirrorMut<M, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<M: Copy + From<T>, T: From<M>> Drop for VxMirrorMut<M, T> {
+    fn drop(&mut self) {
+        let value = unsafe { std::mem::ManuallyDrop::take(&mut self.value) };
+        unsafe { *self.target = M::from(value) };
+    }
+}
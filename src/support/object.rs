@@ -0,0 +1,170 @@
+use std::cell::Cell;
+use std::ops::{Deref, DerefMut};
+
+/// A type whose objects are owned by C# through a handle. Each class has a distinct tag, which is stored
+/// with its objects so that a handle to the wrong class is detected before it is used.
+pub trait VxClass {
+    /// The tag stored with every object of this class.
+    const TAG: u32;
+}
+
+/// A Rust object which C# only ever refers to by pointer. The tag comes first, so it can be read before
+/// the pointer is known to refer to an object of this class. Objects are either owned by C#, or borrowed
+/// from Rust for the duration of a callback, possibly by shared reference.
+#[repr(C)]
+pub struct VxObject<T> {
+    /// The tag of the object's class.
+    tag: u32,
+    /// Whether the value is owned by this object, and freed along with it.
+    owned: bool,
+    /// Whether the value may be modified, which is false for values borrowed by shared reference.
+    mutable: bool,
+    /// The number of shared borrows of the value, or `-1` while it is mutably borrowed. This catches
+    /// callbacks which use an object that is already in use further up the stack.
+    borrows: Cell<isize>,
+    /// The value itself.
+    value: *mut T
+}
+
+impl<T: VxClass> VxObject<T> {
+    /// Creates an object which borrows the value, such as the argument of a callback. The object must not
+    /// outlive the borrow.
+    pub fn borrowed(value: impl VxBorrow<T>) -> Self {
+        let (value, mutable) = value.into_raw();
+        Self { tag: T::TAG, owned: false, mutable, borrows: Cell::new(0), value }
+    }
+
+    /// Panics unless the pointer refers to an object of this class.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must be null or refer to a live object of some class.
+    pub unsafe fn check(this: *const Self) {
+        assert!(!this.is_null(), "expected {} handle, got null", vx_class_name(T::TAG));
+        let tag = *this.cast::<u32>();
+        assert!(tag == T::TAG, "expected {} handle, got {}", vx_class_name(T::TAG), vx_class_name(tag));
+    }
+
+    /// Borrows the object, panicking unless the pointer refers to an object of this class which is not
+    /// mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must be null or refer to a live object of some class.
+    pub unsafe fn get<'a>(this: *const Self) -> VxRef<'a, T> {
+        Self::check(this);
+        let object = &*this;
+        assert!(object.borrows.get() >= 0, "{} is already in use", vx_class_name(T::TAG));
+        object.borrows.set(object.borrows.get() + 1);
+        VxRef { object }
+    }
+
+    /// Mutably borrows the object, panicking unless the pointer refers to an object of this class which
+    /// is not borrowed.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must be null or refer to a live object of some class.
+    pub unsafe fn get_mut<'a>(this: *mut Self) -> VxRefMut<'a, T> {
+        Self::check(this);
+        let object = &*this;
+        assert!(object.mutable, "{} is borrowed by shared reference, so it cannot be modified", vx_class_name(T::TAG));
+        assert!(object.borrows.get() == 0, "{} is already in use", vx_class_name(T::TAG));
+        object.borrows.set(-1);
+        VxRefMut { object }
+    }
+}
+
+/// A reference which a [`VxObject`] may borrow its value through.
+pub trait VxBorrow<T> {
+    /// Gets a pointer to the value, and whether it may be modified.
+    fn into_raw(self) -> (*mut T, bool);
+}
+
+impl<T> VxBorrow<T> for &mut T {
+    fn into_raw(self) -> (*mut T, bool) {
+        (self, true)
+    }
+}
+
+impl<T> VxBorrow<T> for &T {
+    fn into_raw(self) -> (*mut T, bool) {
+        (self as *const T as *mut T, false)
+    }
+}
+
+/// A shared borrow of the value of a [`VxObject`]. The field is named, so that it does not hide the
+/// fields of tuple structs.
+pub struct VxRef<'a, T> {
+    object: &'a VxObject<T>
+}
+
+impl<T> Deref for VxRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.object.value }
+    }
+}
+
+impl<T> Drop for VxRef<'_, T> {
+    fn drop(&mut self) {
+        self.object.borrows.set(self.object.borrows.get() - 1);
+    }
+}
+
+/// A mutable borrow of the value of a [`VxObject`].
+pub struct VxRefMut<'a, T> {
+    object: &'a VxObject<T>
+}
+
+impl<T> Deref for VxRefMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.object.value }
+    }
+}
+
+impl<T> DerefMut for VxRefMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.object.value }
+    }
+}
+
+impl<T> Drop for VxRefMut<'_, T> {
+    fn drop(&mut self) {
+        self.object.borrows.set(0);
+    }
+}
+
+/// Owns a value which was, or is about to be, handed to C#.
+pub struct VxHandle<T>(Box<T>);
+
+impl<T: VxClass> VxHandle<T> {
+    /// Moves the value to the heap, tagged with its class, and gives up ownership of it.
+    pub fn into_heap(value: T) -> *mut VxObject<T> {
+        let value = Box::into_raw(Box::new(value));
+        Box::into_raw(Box::new(VxObject { tag: T::TAG, owned: true, mutable: true, borrows: Cell::new(0), value }))
+    }
+
+    /// Takes back ownership of an object, panicking unless the pointer refers to an owned object of this
+    /// class which is not borrowed.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must be null or refer to a live object of some class, which
+    /// will not be used again.
+    pub unsafe fn from_heap(this: *mut VxObject<T>) -> Self {
+        VxObject::check(this);
+        assert!((*this).owned, "{} is borrowed, so it cannot be moved or freed", vx_class_name(T::TAG));
+        assert!((*this).borrows.get() == 0, "{} is already in use", vx_class_name(T::TAG));
+        let object = Box::from_raw(this);
+        Self(Box::from_raw(object.value))
+    }
+
+    /// Moves the value off the heap.
+    pub fn into_inner(self) -> T {
+        *self.0
+    }
+}
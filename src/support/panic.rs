@@ -0,0 +1,35 @@
+use std::cell::RefCell;
+use std::mem::MaybeUninit;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+thread_local! {
+    /// The message of the last panic caught on this thread, until it is taken by [`vx_take_error`].
+    static VX_LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Calls the function, catching any panic so that it does not unwind across the FFI boundary. The
+/// message of a caught panic is stored for [`vx_take_error`], and a zeroed value is returned instead,
+/// which the caller must not read.
+pub fn vx_catch<T>(f: impl FnOnce() -> T) -> MaybeUninit<T> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => MaybeUninit::new(value),
+        Err(payload) => {
+            let message = payload.downcast_ref::<&str>().map(|x| x.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Rust panicked with a non-string payload".to_string());
+            VX_LAST_ERROR.with(|x| *x.borrow_mut() = Some(message));
+            MaybeUninit::zeroed()
+        }
+    }
+}
+
+/// Calls the function, discarding any panic within it. Objects are freed by C# finalizers, which cannot
+/// throw, so panics while dropping them are not reported.
+pub fn vx_discard_panic(f: impl FnOnce()) {
+    let _ = catch_unwind(AssertUnwindSafe(f));
+}
+
+/// Takes the message of the last panic caught on this thread, if any.
+pub fn vx_take_error() -> Option<String> {
+    VX_LAST_ERROR.with(|x| x.borrow_mut().take())
+}
@@ -0,0 +1,112 @@
+// Paths are written in full, since this is included in the same module as the other support code, whose imports would conflict.
+
+/// A contiguous sequence of values which may be passed across the FFI boundary.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxSlice<T> {
+    /// A pointer to the first element.
+    pub ptr: *const T,
+    /// The number of elements.
+    pub len: usize,
+    /// The capacity of the allocation if the slice is owned, or zero if it is borrowed.
+    pub capacity: usize,
+    /// The epoch of the kept value which the slice borrows from, or zero if it does not borrow from one.
+    pub epoch: u64
+}
+
+impl<T> From<Vec<T>> for VxSlice<T> {
+    fn from(value: Vec<T>) -> Self {
+        let mut value = std::mem::ManuallyDrop::new(value);
+        Self { ptr: value.as_mut_ptr(), len: value.len(), capacity: value.capacity(), epoch: 0 }
+    }
+}
+
+impl<T> From<&[T]> for VxSlice<T> {
+    fn from(value: &[T]) -> Self {
+        Self { ptr: value.as_ptr(), len: value.len(), capacity: 0, epoch: 0 }
+    }
+}
+
+impl<T> VxSlice<T> {
+    /// Borrows the elements of a slice pinned by C#, as the original type which they mirror. The mirror must
+    /// have the same layout as the original type.
+    ///
+    /// # Safety
+    ///
+    /// The slice must point to `len` initialized elements, or be empty, and the elements must outlive the borrow.
+    pub unsafe fn as_slice<'a, U>(&self) -> &'a [U] {
+        assert!(std::mem::size_of::<T>() == std::mem::size_of::<U>() && std::mem::align_of::<T>() == std::mem::align_of::<U>(), "Mirror of {} has a different layout", std::any::type_name::<U>());
+        // Empty spans may be pinned to a null pointer, which a Rust slice cannot have.
+        if self.len == 0 {
+            &[]
+        }
+        else {
+            std::slice::from_raw_parts(self.ptr.cast(), self.len)
+        }
+    }
+}
+
+/// The epoch of the next value to be kept. Zero is never an epoch, so that it can mark slices which do not
+/// borrow from a kept value.
+static VX_FRAME_EPOCH: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+
+/// A value kept for C#, along with the copies made for its views.
+struct VxKept {
+    /// The epoch of the value.
+    epoch: u64,
+    /// The value itself.
+    value: Box<dyn std::any::Any>,
+    /// The elements of vectors which were converted for the views of the value.
+    copies: Vec<Box<dyn std::any::Any>>
+}
+
+thread_local! {
+    /// The values kept for C# on this thread, keyed by type.
+    static VX_FRAME_VALUES: std::cell::RefCell<std::collections::HashMap<std::any::TypeId, VxKept>> = Default::default();
+    /// The epoch of the value whose views are being created, and the copies made for them so far.
+    static VX_FRAME_VIEWING: std::cell::RefCell<(u64, Vec<Box<dyn std::any::Any>>)> = Default::default();
+}
+
+impl<T> VxSlice<T> {
+    /// Borrows the elements of a vector within the value being kept, as their mirror type. The mirror must have
+    /// the same layout as the original type, which holds for primitives and for `#[repr(C)]` structs of them.
+    pub fn frame<U>(value: &[U]) -> Self {
+        assert!(std::mem::size_of::<T>() == std::mem::size_of::<U>() && std::mem::align_of::<T>() == std::mem::align_of::<U>(), "Mirror of {} has a different layout", std::any::type_name::<U>());
+        Self { ptr: value.as_ptr().cast(), len: value.len(), capacity: 0, epoch: VX_FRAME_VIEWING.with(|x| x.borrow().0) }
+    }
+
+    /// Converts the elements of a vector within the value being kept, and keeps the copies along with it.
+    pub fn frame_map<U>(value: &[U], f: impl FnMut(&U) -> T) -> Self where T: 'static {
+        let copies = value.iter().map(f).collect::<Vec<_>>();
+        let result = Self { ptr: copies.as_ptr(), len: copies.len(), capacity: 0, epoch: VX_FRAME_VIEWING.with(|x| x.borrow().0) };
+        VX_FRAME_VIEWING.with(|x| x.borrow_mut().1.push(Box::new(copies)));
+        result
+    }
+}
+
+/// Keeps a value until the next one of the same type, and creates its views. Slices which borrow from a value
+/// which is no longer kept have been freed, so C# refuses to read them.
+fn vx_frame_keep_with<T: 'static, R>(value: T, view: impl FnOnce(&T) -> R) -> R {
+    let epoch = VX_FRAME_EPOCH.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let value = Box::new(value);
+    let outer = VX_FRAME_VIEWING.with(|x| std::mem::replace(&mut *x.borrow_mut(), (epoch, Vec::new())));
+    let result = view(&value);
+    let (_, copies) = VX_FRAME_VIEWING.with(|x| std::mem::replace(&mut *x.borrow_mut(), outer));
+    VX_FRAME_VALUES.with(|x| x.borrow_mut().insert(std::any::TypeId::of::<T>(), VxKept { epoch, value, copies }));
+    result
+}
+
+/// Keeps a value until the next one of the same type, and returns its view.
+pub fn vx_frame_keep<T: 'static, U: for<'a> From<&'a T>>(value: T) -> U {
+    vx_frame_keep_with(value, |x| U::from(x))
+}
+
+/// Keeps a vector until the next one with the same element type, and returns the views of its elements.
+pub fn vx_frame_store<T: 'static, U: for<'a> From<&'a T> + 'static>(value: Vec<T>) -> VxSlice<U> {
+    vx_frame_keep_with(value, |x| VxSlice::frame_map(x, |x| U::from(x)))
+}
+
+/// Whether the value with the given epoch is still kept.
+pub fn vx_frame_is_kept(epoch: u64) -> bool {
+    VX_FRAME_VALUES.with(|x| x.borrow().values().any(|x| x.epoch == epoch))
+}
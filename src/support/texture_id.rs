@@ -0,0 +1,27 @@
+/// A `TextureId`, which is passed across the FFI boundary as whether it was allocated by the user, and its number.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxTextureId {
+    /// Nonzero if the texture was allocated by the user, or zero if it is managed by egui.
+    pub user: u64,
+    /// The number of the texture.
+    pub id: u64
+}
+
+impl From<TextureId> for VxTextureId {
+    fn from(value: TextureId) -> Self {
+        match value {
+            TextureId::Managed(id) => Self { user: 0, id },
+            TextureId::User(id) => Self { user: 1, id }
+        }
+    }
+}
+
+impl From<VxTextureId> for TextureId {
+    fn from(value: VxTextureId) -> Self {
+        match value.user {
+            0 => TextureId::Managed(value.id),
+            _ => TextureId::User(value.id)
+        }
+    }
+}
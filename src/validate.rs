@@ -0,0 +1,104 @@
+use crate::ag::*;
+
+/// A syntax error found in the bindings generated for an item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// The language of the malformed bindings, such as `Rust` or `C#`.
+    pub language: &'static str,
+    /// The name of the item or file whose bindings are malformed.
+    pub source: String,
+    /// A description of the problem.
+    pub message: String
+}
+
+impl std::fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Generated {} for `{}` is malformed: {}", self.language, self.source, self.message)
+    }
+}
+
+/// Checks that the bindings generated for each item are syntactically valid. Rust is parsed in full,
+/// while C# is only checked for balanced delimiters and stray brackets.
+pub fn validate_items(items: &[Item], ctx: &RenderContext) -> Vec<SyntaxError> {
+    let mut result = Vec::new();
+    for item in items {
+        if let Err(message) = validate_rs(&DisplayRs(item, ctx).to_string()) {
+            result.push(SyntaxError { language: "Rust", source: item.name().to_string(), message });
+        }
+
+        if let Err(message) = validate_cs(&DisplayCs(item, ctx).to_string()) {
+            result.push(SyntaxError { language: "C#", source: item.name().to_string(), message });
+        }
+    }
+    result
+}
+
+/// Checks that the given source is a valid Rust file.
+pub fn validate_rs(source: &str) -> Result<(), String> {
+    syn::parse_file(source).map(|_| ()).map_err(|e| e.to_string())
+}
+
+/// Checks that the braces, brackets, and parentheses of the given C# or C source are balanced, and that
+/// no attribute is closed twice. Delimiters within comments, strings, and characters are ignored.
+pub fn validate_cs(source: &str) -> Result<(), String> {
+    for (number, line) in source.lines().enumerate() {
+        let code = cs_code(line);
+        if code.trim_start().starts_with('[') && code.trim_end().ends_with("]]") {
+            return Err(format!("line {} closes an attribute twice", number + 1));
+        }
+    }
+
+    let mut open = Vec::new();
+    for (number, line) in source.lines().enumerate() {
+        for c in cs_code(line).chars() {
+            match c {
+                '{' | '[' | '(' => open.push(c),
+                '}' | ']' | ')' => {
+                    let expected = match c {
+                        '}' => '{',
+                        ']' => '[',
+                        _ => '('
+                    };
+
+                    if open.pop() != Some(expected) {
+                        return Err(format!("line {} has an unmatched `{c}`", number + 1));
+                    }
+                },
+                _ => {}
+            }
+        }
+    }
+
+    match open.last() {
+        Some(c) => Err(format!("`{c}` is never closed")),
+        None => Ok(())
+    }
+}
+
+/// Gets the code on a line of C#, with comments removed and the contents of literals blanked out.
+fn cs_code(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+    let mut quote = None;
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(end) => {
+                if c == '\\' {
+                    chars.next();
+                }
+                else if c == end {
+                    quote = None;
+                    result.push(c);
+                }
+            },
+            None if c == '/' && chars.peek() == Some(&'/') => break,
+            None => {
+                if c == '"' || c == '\'' {
+                    quote = Some(c);
+                }
+                result.push(c);
+            }
+        }
+    }
+    result
+}
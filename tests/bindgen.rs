@@ -0,0 +1,334 @@
+//! Tests the options of the generator, and the files and reports which it produces.
+
+mod common;
+
+use common::{bindgen, config, fixture};
+use egui_inspect::config::{Config, ConfigError, KnownTypeConfig, MethodSurface};
+use egui_inspect::manifest::Manifest;
+use egui_inspect::report::SkipReason;
+use egui_inspect::{ag, autogenerate_cs, write_cs, Bindgen, BindgenContext, CsFile, GenerateError, GeneratedOutput, LoadError};
+use std::path::Path;
+
+#[test]
+fn config_from_path() {
+    let config = Config::from_path(fixture("handles.toml").as_ref()).unwrap();
+    assert_eq!(config.namespace.as_deref(), Some("Handles.Interop"));
+    assert_eq!((config.file_scoped_namespace, config.dll_import, config.marshal_bools), (Some(true), Some(true), Some(true)));
+    assert_eq!(config.library.as_deref(), Some("handles_native"));
+    assert_eq!((config.type_prefix.as_deref(), config.fn_prefix.as_deref()), (Some("Hx"), Some("hx_")));
+    assert_eq!(config.library_class_name.as_deref(), Some("Hx"));
+    assert!(config.target_cfg.is_some());
+    assert_eq!(config.min_coverage, [("class".to_string(), 1.0), ("overall".to_string(), 0.9)].into());
+    assert_eq!(config.skip, ["handles::Context::tessellate"]);
+    assert_eq!(config.require, ["handles::Context", "handles::Key"]);
+    assert_eq!(config.renames["handles::Ui::horizontal"], "Row");
+    assert_eq!(config.known_types["handles::Pos2"], KnownTypeConfig {
+        cs_name: "System.Numerics.Vector2".to_string(),
+        external: true,
+        ..KnownTypeConfig::default()
+    });
+    assert_eq!(config.instantiations["[usize; 2]"].fields, [("width".to_string(), "usize".to_string()), ("height".to_string(), "usize".to_string())]);
+    assert_eq!(config.methods["handles::FullOutput::take_textures_delta"].returns.as_deref(), Some("handles::TexturesDelta"));
+    assert_eq!(config.classes["handles::Ui"].methods, MethodSurface::Only(vec!["horizontal".to_string(), "vertical".to_string()]));
+
+    let error = Config::from_path("missing.toml".as_ref()).unwrap_err();
+    assert!(matches!(error, ConfigError::Io(..)));
+}
+
+#[test]
+fn write_cs_streams() -> Result<(), std::io::Error> {
+    /// Records the size of the output and of its largest write.
+    #[derive(Default)]
+    struct Sizes { total: usize, largest: usize }
+
+    impl std::io::Write for Sizes {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.total += buf.len();
+            self.largest = self.largest.max(buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut ctx = BindgenContext::from_paths(&[fixture("widgets.json")], false).unwrap();
+    ctx.collect();
+    let items = ctx.items().iter().cycle().take(ctx.items().len() * 500).cloned().collect::<Vec<_>>();
+    let render = ag::RenderContext::new(&items, ctx.naming().clone());
+    let file = CsFile {
+        namespace: "Gui".to_string(),
+        file_scoped: false,
+        source_hash: None,
+        layout_checks: false,
+        library: "egui_native".to_string(),
+        dll_import: false
+    };
+
+    let mut sizes = Sizes::default();
+    write_cs(&mut sizes, &items, &render, &file)?;
+    assert!(sizes.total > 1 << 20);
+    assert!(sizes.largest < 1 << 10);
+
+    let mut output = Vec::new();
+    write_cs(&mut output, &items, &render, &file)?;
+    assert_eq!(output.len(), sizes.total);
+    assert_eq!(String::from_utf8(output).unwrap(), autogenerate_cs(&items, &render, &file));
+    Ok(())
+}
+
+#[test]
+fn force() -> Result<(), GenerateError> {
+    let bindgen = bindgen("old_format.json");
+    let Err(GenerateError::Load(error)) = bindgen.clone().generate() else { panic!("the old format was loaded") };
+    assert!(matches!(&error, LoadError::FormatVersion { path, found: 37, crate_version: Some(version) } if path.ends_with("old_format.json") && version == "0.0.9"));
+    assert!(error.to_string().ends_with("old_format.json was generated with rustdoc format 37 (crate version 0.0.9), but this tool supports format 39. Regenerate it with a nightly toolchain that emits format 39, or pass --force to try anyway."));
+
+    let output = bindgen.force(true).generate()?;
+    assert!(output.cs.contains("public enum Direction {"));
+    Ok(())
+}
+
+#[test]
+fn config_sets_every_table() -> Result<(), GenerateError> {
+    let config = Config::from_path(fixture("handles.toml").as_ref()).unwrap();
+    let output = bindgen("handles.json").config(config).generate()?;
+
+    // `csharp` sets how the C# file is written, and `naming` the names of the generated bindings.
+    assert!(output.cs.contains("namespace Handles.Interop;\n"));
+    assert!(output.cs.contains("internal static unsafe partial class Hx {\n    private const string Library = \"handles_native\";"));
+    assert!(output.cs.contains("[DllImport(Library, CallingConvention = CallingConvention.Cdecl)]\n    internal static extern void hx_context_drop(VxObject* value);"));
+    assert!(output.cs.contains("[return: MarshalAs(UnmanagedType.U1)]"));
+    assert!(output.rs.contains("pub struct HxImageSize {"));
+
+    // `skip` and `classes` leave out methods, which `coverage` then does not count against the minimums.
+    assert!(!output.rs.contains("tessellate"));
+    assert!(output.cs.contains("public void Vertical(Action<Ui> addContents)"));
+    assert!(!output.cs.contains("public void Scope("));
+    assert!(output.report.coverage.shortfalls(&output.report.coverage.kinds.keys().map(|x| (x.to_string(), 1.0)).collect()).is_empty());
+
+    // `renames` changes C# names, while `known_types` replaces a struct by an existing C# type.
+    assert!(output.cs.contains("public void Row(Action<Ui> addContents)"));
+    assert!(output.cs.contains("public KeyModifiers Modifiers;"));
+    assert!(!output.cs.contains("struct Pos2"));
+    assert!(output.cs.contains("public System.Numerics.Vector2 Item0;"));
+
+    // `instantiations` generates a struct for an array, and `methods` binds a method with an explicit signature.
+    assert!(output.cs.contains("public ImageSize Size;"));
+    assert!(output.cs.contains("public TexturesDelta TakeTexturesDelta() =>"));
+
+    // `require` fails generation if a listed item is skipped.
+    let mut config = Config::from_path(fixture("handles.toml").as_ref()).unwrap();
+    config.skip.push("handles::Key".to_string());
+    let result = bindgen("handles.json")
+        .config(config)
+        .generate();
+    assert!(matches!(result, Err(GenerateError::MissingRequirements(x)) if x[0].path == "handles::Key"));
+    Ok(())
+}
+
+#[test]
+fn require() -> Result<(), GenerateError> {
+    let Err(GenerateError::MissingRequirements(missing)) = bindgen("panels.json")
+        .skip("panels::Win*")
+        .require("panels::Context")
+        .require("panels::Grid::new")
+        .require("panels::Missing")
+        .require("panels::Window")
+        .generate() else { panic!("expected missing requirements") };
+    assert_eq!(missing.len(), 3);
+    assert_eq!((missing[0].path.as_str(), &missing[0].reason), ("panels::Grid::new", &Some(SkipReason::Generic)));
+    assert_eq!((missing[1].path.as_str(), &missing[1].reason), ("panels::Missing", &None));
+    assert_eq!((missing[2].path.as_str(), &missing[2].reason), ("panels::Window", &Some(SkipReason::Configured)));
+
+    // Items skipped by the configuration are not counted against the coverage.
+    let output = bindgen("panels.json").skip("panels::Win*").require("panels::Context").generate()?;
+    assert!(!output.cs.contains("class Window"));
+    assert_eq!(output.report.total, 18);
+    assert_eq!(output.report.coverage.overall().total, 13);
+    assert_eq!(output.report.coverage.kinds["method"].generated, 7);
+    assert_eq!(output.report.coverage.kinds["method"].total, 9);
+    Ok(())
+}
+
+#[test]
+fn class_methods() -> Result<(), GenerateError> {
+    // `label` and `add_space` are declared in different `impl` blocks.
+    let only = |names: &[&str]| MethodSurface::Only(names.iter().map(|x| x.to_string()).collect());
+    let output = bindgen("surface.json")
+        .class_methods("surface::Ui", only(&["label", "add_space"]))
+        .generate()?;
+
+    assert!(output.cs.contains("public void Label(string text)"));
+    assert!(output.cs.contains("public void AddSpace(float amount)"));
+    assert!(!output.cs.contains("public void Separator()"));
+    assert!(!output.cs.contains("public Ui()"));
+    assert!(output.report.skipped.iter().any(|x| x.path == "surface::Ui::separator" && x.reason == SkipReason::Configured));
+
+    // Listing a method which cannot be bound, or which does not exist, is an error.
+    let Err(GenerateError::UnboundMethods(unbound)) = bindgen("surface.json")
+        .class_methods("surface::Ui", only(&["label", "add", "image"]))
+        .generate() else { panic!("expected unbound methods") };
+    assert_eq!(unbound.len(), 2);
+    assert_eq!((unbound[0].path.as_str(), &unbound[0].reason), ("surface::Ui::add", &Some(SkipReason::Generic)));
+    assert_eq!((unbound[1].path.as_str(), &unbound[1].reason), ("surface::Ui::image", &None));
+
+    // With every method requested, those which cannot be bound are only reported.
+    let config = config(r#"
+        [classes."surface::Ui"]
+        methods = "*"
+    "#);
+    let output = bindgen("surface.json").config(config).generate()?;
+    assert!(output.cs.contains("public void Separator()"));
+    assert!(output.cs.contains("public Ui()"));
+    assert!(output.report.skipped.iter().any(|x| x.path == "surface::Ui::add" && x.reason == SkipReason::Generic));
+    Ok(())
+}
+
+#[test]
+fn source_comments() -> Result<(), GenerateError> {
+    let bindgen = bindgen("function_docs.json");
+    let output = bindgen.generate()?;
+    assert!(output.cs.contains(concat!(
+        "        // generated from src/lib.rs:12 (function_docs::ScrollArea::scroll_to)\n",
+        "        /// <summary>\n",
+        "        /// Scrolls to the given offset.\n"
+    )));
+    assert!(output.rs.contains(concat!(
+        "// generated from src/lib.rs:12 (function_docs::ScrollArea::scroll_to)\n",
+        "/// Scrolls to the given offset.\n"
+    )));
+    assert!(output.cs.contains("    // generated from function_docs-0.1.0/src/lib.rs:4 (function_docs::ScrollArea)\n    /// <summary>\n"));
+    assert!(output.rs.contains("// generated from function_docs::lerp\n/// Interpolates between two values.\n"));
+
+    let output = bindgen.source_comments(false).generate()?;
+    assert!(!output.cs.contains("// generated from"));
+    assert!(!output.rs.contains("// generated from"));
+    Ok(())
+}
+
+#[test]
+fn parallel() -> Result<(), GenerateError> {
+    let bindgen = bindgen("handles.json")
+        .header("HANDLES_H");
+    let parallel = bindgen.clone().parallel(true).generate()?;
+    let serial = bindgen.clone().parallel(false).generate()?;
+    assert_eq!(parallel.cs, serial.cs);
+    assert_eq!(parallel.rs, serial.rs);
+    assert_eq!(parallel.h, serial.h);
+
+    let parallel = bindgen.clone().split_files(true).parallel(true).generate()?;
+    let serial = bindgen.split_files(true).parallel(false).generate()?;
+    assert_eq!(parallel.cs_files, serial.cs_files);
+    Ok(())
+}
+
+#[test]
+fn split_files() -> Result<(), GenerateError> {
+    // `Foo` is defined in the private `inner` module and re-exported with `pub use inner::Foo`,
+    // while `Circle` is re-exported from the private `shapes` module with `pub use shapes::*`.
+    let output = bindgen("reexport.json")
+        .split_files(true)
+        .generate()?;
+
+    assert!(output.cs_files.contains_key(Path::new("reexport/Foo.cs")));
+    assert!(output.cs_files.contains_key(Path::new("reexport/Circle.cs")));
+    assert_eq!(output.cs_files.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn cache_dir() -> Result<(), GenerateError> {
+    let dir = std::env::temp_dir().join(format!("egui_inspect_cache_doctest_{}", std::process::id()));
+    let bindgen = bindgen("widgets.json")
+        .cache_dir(&dir);
+
+    let first = bindgen.clone().generate()?;
+    let second = bindgen.clone().generate()?;
+    assert!(!first.from_cache && second.from_cache);
+    assert_eq!(GeneratedOutput { from_cache: false, ..second }, first);
+
+    // Only the rendering depends on the namespace, so the cached items are reused.
+    let renamed = bindgen.clone().namespace("Widgets").generate()?;
+    assert!(renamed.from_cache && renamed.cs.contains("namespace Widgets"));
+
+    // Skipping an item changes which items are classified, so the cache is not used.
+    let config = Config::parse("[skip]\npaths = [\"widgets::Rect\"]").unwrap();
+    let edited = bindgen.clone().config(config).generate()?;
+    assert!(!edited.from_cache && !edited.cs.contains("struct Rect"));
+
+    // Corrupt entries are ignored, and replaced by the next run.
+    for entry in std::fs::read_dir(&dir).unwrap() {
+        std::fs::write(entry.unwrap().path(), "{").unwrap();
+    }
+    let rebuilt = bindgen.clone().generate()?;
+    assert!(!rebuilt.from_cache && rebuilt.cs == first.cs);
+    assert!(bindgen.generate()?.from_cache);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+    Ok(())
+}
+
+#[test]
+fn generate_is_deterministic() -> Result<(), GenerateError> {
+    // The bundled egui exercises every kind of item.
+    let egui = Bindgen::new().header("EGUI_H");
+    let first = egui.clone().generate()?;
+    let second = egui.clone().generate()?;
+    let sequential = egui.parallel(false).generate()?;
+    for output in [&second, &sequential] {
+        assert!(output.cs.as_bytes() == first.cs.as_bytes());
+        assert!(output.rs.as_bytes() == first.rs.as_bytes());
+        assert!(output.h.as_deref().map(str::as_bytes) == first.h.as_deref().map(str::as_bytes));
+        assert_eq!(output.report.to_string(), first.report.to_string());
+    }
+
+    let split = bindgen("handles.json").split_files(true);
+    assert_eq!(split.clone().generate()?.cs_files, split.generate()?.cs_files);
+    Ok(())
+}
+
+#[test]
+fn manifest() -> Result<(), GenerateError> {
+    let output = bindgen("function_docs.json").generate()?;
+    let manifest: Manifest = serde_json::from_str(&serde_json::to_string(&output.manifest).unwrap()).unwrap();
+    assert_eq!(manifest, output.manifest);
+
+    let exported = output.rs.lines()
+        .filter_map(|x| x.split_once("extern \"C\" fn ").and_then(|(_, x)| x.split_once('(')))
+        .map(|(name, _)| name)
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(exported, manifest.symbols.iter().map(|x| x.name.as_str()).collect());
+    assert!(manifest.symbols.iter().any(|x| x.name == "vx_scroll_area_scroll_to"
+        && x.signature == "fn(this: *mut VxObject<ScrollArea>, offset: f32, animate: bool) -> bool"));
+    assert!(manifest.items.iter().any(|x| x.kind == "method" && x.cs_name == "ScrollArea.ScrollTo"));
+    Ok(())
+}
+
+#[test]
+fn coverage_shortfalls() -> Result<(), GenerateError> {
+    let minimums = config(r#"
+        [coverage]
+        overall = 0.8
+        method = 0.9
+    "#).min_coverage;
+
+    // The generic `Ui::add` cannot be bound, so only four of the five methods are generated.
+    let output = bindgen("surface.json").generate()?;
+    assert_eq!(output.report.coverage.kinds["method"].generated, 4);
+    assert_eq!(output.report.coverage.kinds["method"].total, 5);
+    let shortfalls = output.report.coverage.shortfalls(&minimums);
+    assert_eq!(shortfalls.len(), 1);
+    assert_eq!(shortfalls[0].to_string(), "Coverage of methods is 80.0% (4 / 5), below the minimum of 90.0%");
+
+    // Methods left out by the configuration are not counted against the coverage.
+    let config = config(r#"
+        [classes."surface::Ui"]
+        methods = ["new", "label", "separator", "add_space"]
+    "#);
+    let output = bindgen("surface.json").config(config).generate()?;
+    assert_eq!(output.report.coverage.kinds["method"].total, 4);
+    assert!(output.report.coverage.shortfalls(&minimums).is_empty());
+    Ok(())
+}
@@ -0,0 +1,160 @@
+//! Runs the command-line tool to test its options, and the files which it writes or leaves in place.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Creates an empty directory for the outputs of the test with the given name.
+fn out_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("egui_inspect_cli_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Gets the path of the fixture with the given file name.
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+/// Runs the tool in the given directory with the given arguments.
+fn run(dir: &Path, args: &[&std::ffi::OsStr]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_egui_inspect"))
+        .current_dir(dir)
+        .args(args)
+        .env("RUST_LOG", "error")
+        .output()
+        .expect("Failed to run egui_inspect")
+}
+
+#[test]
+fn positional_inputs() {
+    let dir = out_dir("positional_inputs");
+    let output = run(&dir, &[fixture("shapes.json").as_os_str(), "--output-cs".as_ref(), "Shapes.g.cs".as_ref(), "--output-rs".as_ref(), "shapes.rs".as_ref()]);
+    assert!(output.status.success());
+    assert!(std::fs::read_to_string(dir.join("Shapes.g.cs")).unwrap().contains("Size"));
+    assert!(dir.join("shapes.rs").exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn unknown_options_are_rejected() {
+    // Misspelled flags are not mistaken for input files.
+    let dir = out_dir("unknown_options_are_rejected");
+    let output = run(&dir, &[fixture("shapes.json").as_os_str(), "--output-c".as_ref(), "Shapes.g.cs".as_ref()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown option `--output-c`"));
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn help_prints_usage() {
+    let dir = out_dir("help_prints_usage");
+    let output = run(&dir, &["--help".as_ref()]);
+    assert!(output.status.success());
+    let usage = String::from_utf8_lossy(&output.stdout);
+    assert!(usage.starts_with("Generates C# bindings for egui"));
+    for flag in ["--input", "--output-cs", "--output-rs", "--output-h", "--check", "--min-coverage", "--split-files"] {
+        assert!(usage.contains(flag), "usage is missing {flag}");
+    }
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn check_only_compares_requested_outputs() {
+    let dir = out_dir("check_only_compares_requested_outputs");
+    let input = fixture("shapes.json");
+    assert!(run(&dir, &[input.as_os_str(), "--output-cs".as_ref(), "Shapes.g.cs".as_ref(), "--output-rs".as_ref(), "shapes.rs".as_ref()]).status.success());
+
+    // The default `gui.rs` was never written, so it is not reported as missing.
+    let output = run(&dir, &[input.as_os_str(), "--check".as_ref(), "--output-cs".as_ref(), "Shapes.g.cs".as_ref()]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(output.stdout.is_empty());
+
+    // Outputs at default paths are checked once they exist.
+    std::fs::write(dir.join("gui.rs"), "").unwrap();
+    let output = run(&dir, &[input.as_os_str(), "--check".as_ref(), "--output-cs".as_ref(), "Shapes.g.cs".as_ref()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("+++ gui.rs"));
+
+    // Requested outputs must exist, and nothing is written while checking.
+    let output = run(&dir, &[input.as_os_str(), "--check".as_ref(), "--output-rs".as_ref(), "other.rs".as_ref()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("other.rs is missing"));
+    assert!(!dir.join("other.rs").exists() && !dir.join("Gui.g.cs").exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn check_requires_an_output() {
+    let dir = out_dir("check_requires_an_output");
+    let output = run(&dir, &[fixture("shapes.json").as_os_str(), "--check".as_ref()]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("There are no bindings to check"));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn insufficient_coverage_writes_nothing() {
+    let dir = out_dir("insufficient_coverage_writes_nothing");
+    let args = [fixture("widgets.json").into_os_string(), "--output-cs".into(), "Widgets.g.cs".into(), "--output-rs".into(), "widgets.rs".into()];
+    let args = args.iter().map(|x| x.as_os_str()).collect::<Vec<_>>();
+
+    let output = run(&dir, &[&args[..], &["--min-coverage".as_ref(), "1".as_ref()]].concat());
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("below the minimum of 100.0%"));
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+
+    assert!(run(&dir, &[&args[..], &["--min-coverage".as_ref(), "0.5".as_ref()]].concat()).status.success());
+    assert!(dir.join("Widgets.g.cs").exists() && dir.join("widgets.rs").exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn split_files_follow_the_input() {
+    let dir = out_dir("split_files_follow_the_input");
+    let split = |input: &Path| run(&dir, &[input.as_os_str(), "--split-files".as_ref(), "--output-cs".as_ref(), "Gui".as_ref()]);
+    let files = || {
+        let mut files = Vec::new();
+        let mut dirs = vec![dir.join("Gui")];
+        while let Some(next) = dirs.pop() {
+            for entry in std::fs::read_dir(next).unwrap().map(Result::unwrap) {
+                if entry.file_type().unwrap().is_dir() {
+                    dirs.push(entry.path());
+                }
+                else {
+                    files.push(entry.path().strip_prefix(dir.join("Gui")).unwrap().to_string_lossy().replace('\\', "/"));
+                }
+            }
+        }
+        files.sort();
+        files
+    };
+
+    assert!(split(&fixture("shapes.json")).status.success());
+    assert_eq!(files(), [".generated", "VxSupport.cs", "shapes/Corner.cs", "shapes/Path.cs", "shapes/Size.cs"]);
+    let index = std::fs::read_to_string(dir.join("Gui/.generated")).unwrap();
+    assert_eq!(index.lines().skip(1).collect::<Vec<_>>(), ["VxSupport.cs", "shapes/Corner.cs", "shapes/Path.cs", "shapes/Size.cs"]);
+
+    // Removing `Path` from the input deletes its file, and drops it from the index.
+    let mut json = serde_json::from_str::<serde_json::Value>(&std::fs::read_to_string(fixture("shapes.json")).unwrap()).unwrap();
+    json["index"].as_object_mut().unwrap().remove("7");
+    json["paths"].as_object_mut().unwrap().remove("7");
+    json["index"]["0"]["inner"]["module"]["items"].as_array_mut().unwrap().retain(|x| x != 7);
+    let input = dir.join("shapes.json");
+    std::fs::write(&input, json.to_string()).unwrap();
+
+    assert!(split(&input).status.success());
+    assert_eq!(files(), [".generated", "VxSupport.cs", "shapes/Corner.cs", "shapes/Size.cs"]);
+    let next = std::fs::read_to_string(dir.join("Gui/.generated")).unwrap();
+    assert_eq!(next.lines().skip(1).collect::<Vec<_>>(), ["VxSupport.cs", "shapes/Corner.cs", "shapes/Size.cs"]);
+    assert_ne!(next.lines().next(), index.lines().next());
+
+    // Rerunning on the same input leaves every file untouched.
+    let modified = || files().iter().map(|x| std::fs::metadata(dir.join("Gui").join(x)).unwrap().modified().unwrap()).collect::<Vec<_>>();
+    let before = modified();
+    assert!(split(&input).status.success());
+    assert_eq!(modified(), before);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
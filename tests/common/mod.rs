@@ -0,0 +1,22 @@
+//! Helpers shared by the integration tests, which generate bindings for the fixtures in `tests/fixtures`.
+
+#![allow(dead_code)]
+
+use egui_inspect::Bindgen;
+use egui_inspect::config::Config;
+use std::path::PathBuf;
+
+/// Gets the path of the fixture with the given file name.
+pub fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+/// Creates a generator for the fixture with the given file name.
+pub fn bindgen(name: &str) -> Bindgen {
+    Bindgen::new().input_json(fixture(name))
+}
+
+/// Parses a configuration, panicking if it is invalid.
+pub fn config(text: &str) -> Config {
+    Config::parse(text).unwrap_or_else(|(line, message)| panic!("line {line}: {message}"))
+}
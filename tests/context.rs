@@ -0,0 +1,222 @@
+//! Tests the collection of items, and the checks run on them before they are rendered.
+
+mod common;
+
+use common::{bindgen, fixture};
+use egui_inspect::ag::{DisplayRs, Item, NamingConfig, RenderContext};
+use egui_inspect::collisions::{disambiguate, find_collisions, find_hidden_members, Collision};
+use egui_inspect::config::Config;
+use egui_inspect::layout::{Layout, LayoutChecks, LayoutModel};
+use egui_inspect::validate::{validate_cs, validate_items, validate_rs};
+use egui_inspect::{BindgenContext, GenerateError};
+
+#[test]
+fn collisions_are_found_in_every_namespace() {
+    let collect = |fixture: &str| {
+        let mut ctx = BindgenContext::from_paths(&[format!("{}/tests/fixtures/{fixture}", env!("CARGO_MANIFEST_DIR"))], false).unwrap();
+        ctx.collect();
+        ctx.items().to_vec()
+    };
+    let items = collect("widgets.json");
+    let naming = NamingConfig::default();
+    assert_eq!(find_collisions(&items, &naming), []);
+
+    // Two types with the same C# name.
+    let mut types = items.clone();
+    let Some(Item::Struct { rename, .. }) = types.iter_mut().find(|x| x.name() == "Rect") else { unreachable!() };
+    *rename = Some("Response".to_string());
+    assert_eq!(find_collisions(&types, &naming).iter().map(ToString::to_string).collect::<Vec<_>>(), [
+        "Generated C# type `Response` comes from both `widgets::Rect` and `widgets::Response`",
+        "Generated C# member `Response.Response` comes from both `widgets::Rect` and `widgets::Response`"
+    ]);
+
+    // A type which takes the name of a support type.
+    let Some(Item::Struct { rename, .. }) = types.iter_mut().find(|x| x.name() == "Rect") else { unreachable!() };
+    *rename = Some("VxHandle".to_string());
+    assert_eq!(find_collisions(&types, &naming), [Collision {
+        kind: "C# type",
+        name: "VxHandle".to_string(),
+        first: "generated support code".to_string(),
+        second: "widgets::Rect".to_string()
+    }]);
+
+    // Two members of the same C# type with the same name.
+    let mut members = items.clone();
+    let Some(Item::Struct { fields, .. }) = members.iter_mut().find(|x| x.name() == "Rect") else { unreachable!() };
+    fields[1].rename = Some("Min".to_string());
+    assert_eq!(find_collisions(&members, &naming).iter().map(ToString::to_string).collect::<Vec<_>>(), [
+        "Generated C# member `Rect.Min` comes from both `widgets::Rect::min` and `widgets::Rect::max`"
+    ]);
+
+    // A function whose exported symbol is also that of a method.
+    let mut symbols = items.clone();
+    let mut function = collect("tiny.json").into_iter().find(|x| x.name() == "area").unwrap();
+    let Item::Function { name, .. } = &mut function else { unreachable!() };
+    *name = "response_clicked".to_string();
+    symbols.push(function);
+    assert_eq!(find_collisions(&symbols, &naming).iter().map(ToString::to_string).collect::<Vec<_>>(), [
+        "Generated exported symbol `vx_response_clicked` comes from both `widgets::Response::clicked` and `tiny::response_clicked`"
+    ]);
+}
+
+#[test]
+fn members_hiding_object_members_are_found() {
+    let mut ctx = BindgenContext::from_paths(&[fixture("names.json")], false).unwrap();
+    ctx.collect();
+    assert_eq!(find_hidden_members(ctx.items(), &NamingConfig::default()).iter().map(ToString::to_string).collect::<Vec<_>>(), [
+        "Generated C# member `Galley.Equals` from `names::Galley::equals` hides a member of System.Object",
+        "Generated C# member `Galley.GetHashCode` from `names::Galley::get_hash_code` hides a member of System.Object",
+        "Generated C# member `Glyph.ToString` from `names::Glyph::to_string` hides a member of System.Object"
+    ]);
+
+    // Renamed members no longer hide anything.
+    let mut items = ctx.items().to_vec();
+    let Some(Item::Struct { fields, .. }) = items.iter_mut().find(|x| x.name() == "Glyph") else { unreachable!() };
+    fields[3].rename = Some("Offset".to_string());
+    assert_eq!(find_hidden_members(&items, &NamingConfig::default()).len(), 2);
+}
+
+#[test]
+fn disambiguate_renames_later_sources() {
+    let mut ctx = BindgenContext::from_paths(&[fixture("widgets.json")], false).unwrap();
+    ctx.collect();
+    let mut items = ctx.items().to_vec();
+    let Some(Item::Struct { fields, .. }) = items.iter_mut().find(|x| x.name() == "Rect") else { unreachable!() };
+    fields[1].rename = Some("Min".to_string());
+
+    disambiguate(&mut items, &NamingConfig::default());
+    assert_eq!(find_collisions(&items, &NamingConfig::default()), []);
+    let Some(Item::Struct { fields, .. }) = items.iter().find(|x| x.name() == "Rect") else { unreachable!() };
+    assert_eq!((fields[0].cs_name(), fields[1].cs_name()), ("Min".to_string(), "Min2".to_string()));
+}
+
+#[test]
+fn item_layouts() {
+    let mut ctx = BindgenContext::from_paths(&[fixture("handles.json")], false).unwrap();
+    ctx.collect();
+    let mut model = LayoutModel::new(ctx.items(), 8);
+    assert_eq!(model.item_layout("VxRect"), Some(Layout { size: 16, align: 4 }));
+    assert_eq!(model.item_layout("Color32"), Some(Layout { size: 4, align: 1 }));
+    // Two `Pos2` and a `Color32`, without padding.
+    assert_eq!(model.item_layout("VxVertex"), Some(Layout { size: 20, align: 4 }));
+    // Two filters, then an `Option<TextureFilter>` whose flag is padded to the alignment of the filter.
+    assert_eq!(model.item_layout("VxTextureOptions"), Some(Layout { size: 16, align: 4 }));
+    assert_eq!(model.item_layout("VxUi"), None);
+
+    // Packing lowers the alignment of the struct, but not of its fields' own layouts.
+    let mut items = ctx.items().to_vec();
+    let Some(Item::Struct { packing, .. }) = items.iter_mut().find(|x| x.name() == "TextureOptions") else { unreachable!() };
+    *packing = Some(1);
+    assert_eq!(LayoutModel::new(&items, 8).item_layout("VxTextureOptions"), Some(Layout { size: 16, align: 1 }));
+
+    let checks = DisplayRs(&LayoutChecks::new(&items, 64), &Default::default()).to_string();
+    assert!(checks.contains("const _: () = assert!(size_of::<VxTextureOptions>() == 16 && align_of::<VxTextureOptions>() == 1);"));
+    assert!(checks.contains("const _: () = assert!(size_of::<VxRect>() == 16 && align_of::<VxRect>() == 4);"));
+}
+
+#[test]
+fn collect() {
+    let mut ctx = BindgenContext::from_paths(&[fixture("widgets.json")], false).unwrap();
+    ctx.collect();
+
+    let names = ctx.items().iter().map(|x| x.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["Id", "Rect", "Response", "Ui"]);
+
+    let report = ctx.report();
+    assert_eq!((report.generated, report.total), (8, 12));
+    let skipped = report.skipped.iter().map(|x| x.path.as_str()).collect::<Vec<_>>();
+    assert_eq!(skipped, ["widgets::Ui::button", "widgets::Ui::checkbox", "widgets::Ui::label", "widgets::Ui::text_edit_singleline"]);
+}
+
+#[test]
+fn collect_walks_structs_in_dependency_order() {
+    // A single walk copies each struct even when the structs that it contains come after it in path order.
+    let mut ctx = BindgenContext::from_paths(&[fixture("chain.json")], false).unwrap();
+    ctx.collect();
+
+    let structs = ctx.items().iter().filter(|x| matches!(x, Item::Struct { .. })).map(|x| x.name()).collect::<Vec<_>>();
+    assert_eq!(structs, ["Frame", "Margin", "Side"]);
+    let report = ctx.report();
+    assert_eq!((report.generated, report.total), (3, 3));
+    assert!(report.skipped.is_empty());
+}
+
+#[test]
+fn items_are_classified_by_their_fields() {
+    let kinds = |config: &str| {
+        let mut ctx = BindgenContext::from_paths(&[fixture("fields.json")], false).unwrap();
+        ctx.apply_config(&Config::parse(config).unwrap());
+        ctx.collect();
+        ctx.items().iter().map(|x| (x.name().to_string(), matches!(x, Item::Struct { .. }))).collect::<Vec<_>>()
+    };
+
+    // `f32`, `u32`, a generated struct, and `[u32; 4]` fields are all copied. `Vec<u8>` is copied as a slice.
+    let copied = kinds("");
+    assert_eq!(copied, [("Buffer", true), ("Counter", true), ("Grid", true), ("Point", true), ("Sample", false), ("Segment", true)].map(|(x, y)| (x.to_string(), y)));
+
+    // The tuple field is copied once its type has a struct, whose key is written like the Rust type.
+    let copied = kinds("[instantiations.\"(f32,f32)\"]\nname = \"Pair\"\nfields = [\"first: f32\", \"second: f32\"]");
+    assert!(copied.contains(&("Sample".to_string(), true)));
+}
+
+#[test]
+fn missing_known_types() {
+    // Every built-in known type exists in the bundled egui.
+    assert_eq!(BindgenContext::bundled().unwrap().missing_known_types(), Vec::<&str>::new());
+
+    let ctx = BindgenContext::from_paths(&[fixture("tiny.json")], false).unwrap();
+    let expected = BindgenContext::default_known_type_paths().into_iter().filter(|x| x.contains("::")).collect::<Vec<_>>();
+    assert_eq!(ctx.missing_known_types().len(), expected.len());
+}
+
+#[test]
+fn every_fixture_renders_valid_syntax() {
+    for fixture in std::fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures")).unwrap() {
+        let path = fixture.unwrap().path();
+        if path.extension().is_some_and(|x| x == "json") {
+            let mut ctx = BindgenContext::from_paths(&[&path], true).unwrap();
+            ctx.collect();
+            let render = RenderContext::new(ctx.items(), ctx.naming().clone());
+            assert_eq!(validate_items(ctx.items(), &render), [], "{}", path.display());
+        }
+    }
+
+    // An item whose name breaks both renderings is reported once for each language.
+    let mut ctx = BindgenContext::from_paths(&[fixture("tiny.json")], false).unwrap();
+    ctx.collect();
+    let mut items = ctx.items().to_vec();
+    let Some(Item::Enum { name, .. }) = items.iter_mut().find(|x| x.name() == "Direction") else { unreachable!() };
+    *name = "Direction)".to_string();
+
+    let render = RenderContext::new(&items, ctx.naming().clone());
+    let errors = validate_items(&items, &render);
+    assert_eq!(errors.iter().map(|x| (x.language, x.source.as_str())).collect::<Vec<_>>(), [("Rust", "Direction)"), ("C#", "Direction)")]);
+    assert!(errors[1].message.contains("unmatched `)`"), "{}", errors[1].message);
+    assert!(errors[0].to_string().starts_with("Generated Rust for `Direction)` is malformed: "));
+}
+
+#[test]
+fn validate_rs_parses_the_whole_file() -> Result<(), GenerateError> {
+    let output = bindgen("handles.json").generate()?;
+    assert_eq!(validate_rs(&output.rs), Ok(()));
+
+    let truncated = &output.rs[..output.rs.rfind('}').unwrap()];
+    assert!(validate_rs(truncated).is_err());
+    assert!(validate_rs("pub struct VxSize { width: f32 height: f32 }").is_err());
+    Ok(())
+}
+
+#[test]
+fn validate_cs_checks_delimiters() -> Result<(), GenerateError> {
+    let output = bindgen("handles.json")
+        .header("HANDLES_H")
+        .generate()?;
+    assert_eq!(validate_cs(&output.cs), Ok(()));
+    assert_eq!(validate_cs(output.h.as_deref().unwrap()), Ok(()));
+
+    assert_eq!(validate_cs(&output.cs[..output.cs.rfind('}').unwrap()]), Err("`{` is never closed".to_string()));
+    assert_eq!(validate_cs("class Ui {\n    void Label(string text));\n}"), Err("line 2 has an unmatched `)`".to_string()));
+    assert_eq!(validate_cs("[Flags]]\nenum Edges { }"), Err("line 1 closes an attribute twice".to_string()));
+    assert_eq!(validate_cs("string Open = \"{ (\"; // ]\nchar Close = '}';"), Ok(()));
+    Ok(())
+}
@@ -0,0 +1,233 @@
+//! Runs the command-line tool on fixtures and compares the files that it writes against the golden
+//! copies in `tests/golden`. Set `UPDATE_GOLDEN=1` to overwrite the golden copies after an intended change.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Generates C#, Rust, and a C header for a fixture with the given extra arguments, and compares them
+/// against `tests/golden/<name>.g.cs`, `<name>.rs`, and `<name>.h`.
+fn check(name: &str, fixture: &str, args: &[&str]) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dir = std::env::temp_dir().join(format!("egui_inspect_golden_{name}_{}", std::process::id()));
+    let outputs = [format!("{name}.g.cs"), format!("{name}.rs"), format!("{name}.h")];
+    let paths = outputs.iter().map(|x| dir.join("out").join(x)).collect::<Vec<PathBuf>>();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_egui_inspect"))
+        .arg("--input").arg(root.join("tests/fixtures").join(fixture))
+        .arg("--output-cs").arg(&paths[0])
+        .arg("--output-rs").arg(&paths[1])
+        .arg("--output-h").arg(&paths[2])
+        .args(args)
+        .env("RUST_LOG", "error")
+        .status()
+        .expect("Failed to run egui_inspect");
+    assert!(status.success(), "egui_inspect failed on {fixture}");
+
+    let update = std::env::var_os("UPDATE_GOLDEN").is_some();
+    for (output, path) in outputs.iter().zip(&paths) {
+        let actual = std::fs::read_to_string(path).unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+        if output.ends_with(".cs") {
+            check_xml_docs(output, &actual);
+        }
+
+        let golden = root.join("tests/golden").join(output);
+        if update {
+            std::fs::write(&golden, &actual).unwrap();
+            continue;
+        }
+
+        let expected = std::fs::read_to_string(&golden).unwrap_or_else(|e| panic!("Failed to read {}: {e}", golden.display()));
+        if actual != expected {
+            let line = actual.lines().zip(expected.lines()).position(|(a, b)| a != b).unwrap_or(actual.lines().count().min(expected.lines().count()));
+            panic!("{output} differs from its golden copy from line {}; run with UPDATE_GOLDEN=1 to accept the changes", line + 1);
+        }
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Checks that each C# doc comment is well-formed XML: every tag is closed in order, and `<` and `&` only
+/// begin tags and entities.
+fn check_xml_docs(output: &str, cs: &str) {
+    let mut block = String::new();
+    for line in cs.lines().map(str::trim_start) {
+        if let Some(doc) = line.strip_prefix("///") {
+            block += doc;
+            block.push('\n');
+            continue;
+        }
+        else if block.is_empty() {
+            continue;
+        }
+
+        let mut open = Vec::new();
+        let mut rest = block.as_str();
+        while let Some(start) = rest.find(['<', '&']) {
+            rest = &rest[start..];
+            if rest.starts_with('&') {
+                let end = rest.find(';').unwrap_or_else(|| panic!("Unterminated entity in {output}:\n{block}"));
+                assert!(["&amp", "&lt", "&gt", "&quot", "&apos"].contains(&&rest[..end]), "Unknown entity `{}` in {output}:\n{block}", &rest[..=end]);
+                rest = &rest[end + 1..];
+                continue;
+            }
+
+            let end = rest.find('>').unwrap_or_else(|| panic!("Unterminated tag in {output}:\n{block}"));
+            let tag = &rest[1..end];
+            let name = tag.trim_start_matches('/').split([' ', '/']).next().unwrap();
+            assert!(!name.is_empty() && name.chars().all(|x| x.is_ascii_alphanumeric()), "Malformed tag `<{tag}>` in {output}:\n{block}");
+            if let Some(closed) = tag.strip_prefix('/') {
+                assert_eq!(open.pop(), Some(closed), "Mismatched `<{tag}>` in {output}:\n{block}");
+            }
+            else if !tag.ends_with('/') {
+                open.push(name);
+            }
+            rest = &rest[end + 1..];
+        }
+        assert!(open.is_empty(), "Unclosed {open:?} in {output}:\n{block}");
+        block.clear();
+    }
+}
+
+#[test]
+fn arrays() {
+    // Arrays of primitives become fixed buffers in C#, while arrays of structs are expanded into one field per element.
+    check("arrays", "arrays.json", &[]);
+}
+
+#[test]
+fn shapes() {
+    // One enum, one struct, and one class, so that the header holds a typedef of each kind of item and the drop
+    // function of the class.
+    check("shapes", "shapes.json", &[]);
+}
+
+#[test]
+fn widgets() {
+    check("widgets", "widgets.json", &[]);
+}
+
+#[test]
+fn widgets_safe_handles() {
+    // Only the C# classes differ from the plain rendering; the Rust side is the same in both modes.
+    check("widgets_safe_handles", "widgets.json", &["--safe-handles"]);
+}
+
+#[test]
+fn operators() {
+    // `Vec2` holds only numbers, so its operators are computed field-wise in C#. `Rect` holds structs, so its
+    // operators call Rust shims instead.
+    check("operators", "operators.json", &[]);
+}
+
+#[test]
+fn equality() {
+    // Arrays are compared and hashed element by element, since fixed buffers have no equality of their own.
+    check("equality", "equality.json", &[]);
+}
+
+#[test]
+fn deprecated() {
+    // The note of `Margin` spans several lines and contains a tab, which are escaped in the C# attribute.
+    check("deprecated", "deprecated.json", &[]);
+}
+
+#[test]
+fn names() {
+    // C# keywords are escaped with `@`, raw identifiers keep their `r#` only in Rust, and members named after those of
+    // `System.Object` are kept as they are.
+    check("names", "names.json", &[]);
+}
+
+#[test]
+fn constants() {
+    // Free constants are read once into the `Constants` class, while those associated with `Vec2` belong to its struct.
+    check("constants", "constants.json", &[]);
+}
+
+#[test]
+fn statics() {
+    // Statics are read again on every access. `FRAME_COUNT` is mutable, so it is skipped.
+    check("statics", "statics.json", &[]);
+}
+
+#[test]
+fn aliases() {
+    // `Px` is an alias of `f32`, so fields and parameters of that type are marshaled as floats.
+    check("aliases", "aliases.json", &[]);
+}
+
+#[test]
+fn clone() {
+    // Only `Style` implements `Clone`, so only it has a `Clone` method and a shim to call it.
+    check("clone", "clone.json", &[]);
+}
+
+#[test]
+fn defaults() {
+    // `Margin` and `Memory` implement `Default`, while `Side` and `Context` do not.
+    check("defaults", "defaults.json", &[]);
+}
+
+#[test]
+fn xml_docs() {
+    // `Vec<T>`, inline HTML, and `&` are escaped, so that each doc comment is valid XML.
+    check("xml_docs", "xml_docs.json", &[]);
+}
+
+#[test]
+fn markdown() {
+    // Code spans, lists, headings, and code blocks become XML doc elements, and links become `<see>` only if they
+    // point at a generated type. The Rust examples are left out of C#.
+    check("markdown", "markdown.json", &[]);
+}
+
+#[test]
+fn paragraphs() {
+    // Only the first paragraph is the summary, and the rest are remarks. `Max` begins with a heading, so all of its
+    // docs are remarks.
+    check("paragraphs", "paragraphs.json", &[]);
+}
+
+#[test]
+fn constructors() {
+    // `new` becomes the C# constructor, while `with_capacity` becomes a static factory.
+    check("constructors", "constructors.json", &[]);
+}
+
+#[test]
+fn modules() {
+    // `a::Id` and `b::Id` share a name, so the later one is numbered in C#, and imported under that name in Rust.
+    check("modules", "modules.json", &["--disambiguate"]);
+}
+
+#[test]
+fn tuples() {
+    // `Size` is a tuple struct whose fields are numbered, while the newtype `Id` is marshaled as its `u64` in C#,
+    // and converted through a transparent mirror in Rust.
+    check("tuples", "tuples.json", &[]);
+}
+
+#[test]
+fn tuples_keep_newtype() {
+    // Kept newtypes are generated like any other tuple struct.
+    check("tuples_keep_newtype", "tuples.json", &["--keep-newtype", "tuples::Id"]);
+}
+
+#[test]
+fn optional() {
+    // `Option<f32>` and `Option<Pos2>` fields become `VxOption<T>` on both sides, with a flag before the value.
+    check("optional", "optional.json", &[]);
+}
+
+#[test]
+fn strings() {
+    // The `String` field is a `VxString` behind a C# property, and `Free` passes the struct back to Rust to drop it.
+    check("strings", "strings.json", &[]);
+}
+
+#[test]
+fn layout() {
+    // A `bool` before an `f64` is padded to 16 bytes, unless the struct is packed into 9. C# stores the flag as a byte
+    // either way, since `bool` is not blittable.
+    check("layout", "layout.json", &["--config", "tests/fixtures/layout.toml"]);
+}
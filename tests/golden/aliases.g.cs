@@ -0,0 +1,158 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash 9548362828063596.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// An owned UTF-8 string which may be passed across the FFI boundary.
+    /// The string is allocated by Rust, and must be freed with <c>Vx.vx_string_drop</c>.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe struct VxString {
+        private byte* _ptr;
+        private nuint _len;
+        private nuint _capacity;
+
+        /// <summary>
+        /// Copies a managed string into a new Rust-allocated string.
+        /// </summary>
+        public static VxString FromString(string value) {
+            var bytes = System.Text.Encoding.UTF8.GetBytes(value);
+            fixed (byte* ptr = bytes) {
+                return Vx.vx_string_from_utf8(ptr, (nuint)bytes.Length);
+            }
+        }
+
+        /// <summary>
+        /// Copies a Rust-allocated string into a managed string, and then frees it.
+        /// </summary>
+        public static string Take(VxString value) {
+            try {
+                return value.ToString();
+            }
+            finally {
+                Free(value);
+            }
+        }
+
+        /// <summary>
+        /// Frees a Rust-allocated string.
+        /// </summary>
+        public static void Free(VxString value) => Vx.vx_string_drop(value);
+
+        /// <summary>
+        /// Whether this is a null string, which Rust returns in place of a string that does not exist.
+        /// </summary>
+        internal bool IsNull => _ptr == null;
+
+        /// <summary>
+        /// Copies the contents of this string into a managed string.
+        /// </summary>
+        public override string ToString() => System.Text.Encoding.UTF8.GetString(_ptr, checked((int)_len));
+    }
+
+    /// <summary>
+    /// A panic in the Rust code called by the bindings. Any Rust objects involved may be left in an
+    /// inconsistent state.
+    /// </summary>
+    public sealed class EguiException : Exception {
+        /// <summary>
+        /// Creates an exception with the message of the panic.
+        /// </summary>
+        public EguiException(string message) : base(message) { }
+
+        /// <summary>
+        /// Throws the panic caught during the last call into Rust on this thread, if there was one.
+        /// </summary>
+        internal static unsafe void ThrowIfPanicked() {
+            var message = Vx.vx_last_error_message();
+            if (!message.IsNull) {
+                throw new EguiException(VxString.Take(message));
+            }
+        }
+
+        /// <summary>
+        /// Returns the value from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static T Check<T>(T value) {
+            ThrowIfPanicked();
+            return value;
+        }
+
+        /// <summary>
+        /// Returns the pointer from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static unsafe void* Check(void* value) {
+            ThrowIfPanicked();
+            return value;
+        }
+    }
+
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Stroke>(4);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    /// <summary>
+    /// The functions exported by the native library.
+    /// </summary>
+    internal static unsafe partial class Vx {
+        private const string Library = "egui_native";
+
+        [LibraryImport(Library)]
+        internal static partial void vx_string_drop(VxString value);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_string_from_utf8(byte* ptr, nuint len);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_last_error_message();
+
+        [LibraryImport(Library)]
+        internal static partial float vx_scaled(float length, float pixelsPerPoint);
+    }
+
+    // generated from aliases::Stroke
+    /// <summary>
+    /// Describes the width of a line.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Stroke {
+        /// <summary>
+        /// The width of the line.
+        /// </summary>
+        public float Width;
+    }
+
+    /// <summary>
+    /// Gets the given length in physical pixels.
+    /// </summary>
+    public static unsafe partial class Functions {
+        // generated from aliases::scaled
+        /// <summary>
+        /// Gets the given length in physical pixels.
+        /// </summary>
+        /// <param name="length"></param>
+        /// <param name="pixelsPerPoint"></param>
+        /// <returns></returns>
+        public static float Scaled(float length, float pixelsPerPoint) => EguiException.Check(Vx.vx_scaled(length, pixelsPerPoint));
+    }
+}
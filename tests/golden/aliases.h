@@ -0,0 +1,36 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef ALIASES_H
+#define ALIASES_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// Describes the width of a line.
+typedef struct VxStroke {
+    /// The width of the line.
+    float width;
+} VxStroke;
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+float vx_scaled(float length, float pixelsPerPoint);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ALIASES_H */
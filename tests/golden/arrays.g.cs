@@ -0,0 +1,27 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash 60dbe914bc5385a0.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    // generated from arrays::Quad
+    /// <summary>
+    /// A colored rectangle between two corners.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Quad {
+        /// <summary>
+        /// The red, green, blue, and alpha channels.
+        /// </summary>
+        public fixed float Color[4];
+
+        /// <summary>
+        /// The top-left and bottom-right corners.
+        /// </summary>
+        public System.Numerics.Vector2 Corners0;
+        public System.Numerics.Vector2 Corners1;
+    }
+}
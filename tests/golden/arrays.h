@@ -0,0 +1,30 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef ARRAYS_H
+#define ARRAYS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// Provided by the user, and must be defined before this header is included.
+typedef struct Pos2 Pos2;
+
+/// A colored rectangle between two corners.
+typedef struct VxQuad {
+    /// The red, green, blue, and alpha channels.
+    float color[4];
+    /// The top-left and bottom-right corners.
+    Pos2 corners[2];
+} VxQuad;
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ARRAYS_H */
@@ -0,0 +1,36 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+// generated from arrays::Quad
+/// A colored rectangle between two corners.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxQuad {
+    /// The red, green, blue, and alpha channels.
+    pub color: [f32; 4],
+    /// The top-left and bottom-right corners.
+    pub corners: [Pos2; 2],
+}
+
+impl From<Quad> for VxQuad {
+    fn from(value: Quad) -> Self {
+        Self {
+            color: value.color.map(Into::into),
+            corners: value.corners.map(Into::into),
+        }
+    }
+}
+
+impl From<VxQuad> for Quad {
+    fn from(value: VxQuad) -> Self {
+        Self {
+            color: value.color.map(Into::into),
+            corners: value.corners.map(Into::into),
+        }
+    }
+}
+
+
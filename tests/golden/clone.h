@@ -0,0 +1,35 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef CLONE_H
+#define CLONE_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+/// An opaque Rust object, which is only ever referred to by pointer.
+typedef struct VxObject VxObject;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+void vx_context_drop(VxObject* value);
+void vx_style_drop(VxObject* value);
+VxObject* vx_style_clone(VxObject* self);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CLONE_H */
@@ -0,0 +1,363 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+/// An owned UTF-8 string which may be passed across the FFI boundary.
+/// The string must be freed with [`vx_string_drop`].
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxString {
+    /// A pointer to the first byte.
+    pub ptr: *mut u8,
+    /// The length of the string in bytes.
+    pub len: usize,
+    /// The capacity of the allocation.
+    pub capacity: usize
+}
+
+impl From<String> for VxString {
+    fn from(value: String) -> Self {
+        let mut value = std::mem::ManuallyDrop::new(value);
+        Self { ptr: value.as_mut_ptr(), len: value.len(), capacity: value.capacity() }
+    }
+}
+
+impl VxString {
+    /// Takes ownership of the string.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the string must have been allocated by Rust and not already freed.
+    pub unsafe fn into_string(self) -> String {
+        String::from_raw_parts(self.ptr, self.len, self.capacity)
+    }
+}
+
+/// A string which C# passes by `ref`. The string is taken for the duration of a call, and written back
+/// when this is dropped, even if the call panics.
+pub struct VxStringMut {
+    /// The string to write back to.
+    target: *mut VxString,
+    /// The string while it is in use.
+    value: String
+}
+
+impl VxStringMut {
+    /// Takes the string which the pointer refers to.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must refer to a string allocated by Rust, which is not
+    /// used again until this is dropped.
+    pub unsafe fn new(target: *mut VxString) -> Self {
+        Self { value: (*target).into_string(), target }
+    }
+}
+
+impl std::ops::Deref for VxStringMut {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.value
+    }
+}
+
+impl std::ops::DerefMut for VxStringMut {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.value
+    }
+}
+
+impl Drop for VxStringMut {
+    fn drop(&mut self) {
+        unsafe { *self.target = std::mem::take(&mut self.value).into() };
+    }
+}
+
+/// Frees the provided string.
+///
+/// # Safety
+///
+/// For this call to be sound, the string must have been allocated by Rust and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn vx_string_drop(value: VxString) {
+    drop(value.into_string());
+}
+
+/// Copies the provided UTF-8 bytes into a new string, replacing invalid sequences.
+///
+/// # Safety
+///
+/// For this call to be sound, the pointer must refer to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn vx_string_from_utf8(ptr: *const u8, len: usize) -> VxString {
+    String::from_utf8_lossy(std::slice::from_raw_parts(ptr, len)).into_owned().into()
+}
+
+use std::cell::RefCell;
+use std::mem::MaybeUninit;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+thread_local! {
+    /// The message of the last panic caught on this thread, until it is taken by [`vx_take_error`].
+    static VX_LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Calls the function, catching any panic so that it does not unwind across the FFI boundary. The
+/// message of a caught panic is stored for [`vx_take_error`], and a zeroed value is returned instead,
+/// which the caller must not read.
+pub fn vx_catch<T>(f: impl FnOnce() -> T) -> MaybeUninit<T> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => MaybeUninit::new(value),
+        Err(payload) => {
+            let message = payload.downcast_ref::<&str>().map(|x| x.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Rust panicked with a non-string payload".to_string());
+            VX_LAST_ERROR.with(|x| *x.borrow_mut() = Some(message));
+            MaybeUninit::zeroed()
+        }
+    }
+}
+
+/// Calls the function, discarding any panic within it. Objects are freed by C# finalizers, which cannot
+/// throw, so panics while dropping them are not reported.
+pub fn vx_discard_panic(f: impl FnOnce()) {
+    let _ = catch_unwind(AssertUnwindSafe(f));
+}
+
+/// Takes the message of the last panic caught on this thread, if any.
+pub fn vx_take_error() -> Option<String> {
+    VX_LAST_ERROR.with(|x| x.borrow_mut().take())
+}
+
+/// Takes the message of the last panic caught by a shim on this thread, or returns a null string if there was none.
+#[no_mangle]
+pub extern "C" fn vx_last_error_message() -> VxString {
+    match vx_take_error() {
+        Some(message) => message.into(),
+        None => VxString { ptr: std::ptr::null_mut(), len: 0, capacity: 0 }
+    }
+}
+
+use std::cell::Cell;
+use std::ops::{Deref, DerefMut};
+
+/// A type whose objects are owned by C# through a handle. Each class has a distinct tag, which is stored
+/// with its objects so that a handle to the wrong class is detected before it is used.
+pub trait VxClass {
+    /// The tag stored with every object of this class.
+    const TAG: u32;
+}
+
+/// A Rust object which C# only ever refers to by pointer. The tag comes first, so it can be read before
+/// the pointer is known to refer to an object of this class. Objects are either owned by C#, or borrowed
+/// from Rust for the duration of a callback, possibly by shared reference.
+#[repr(C)]
+pub struct VxObject<T> {
+    /// The tag of the object's class.
+    tag: u32,
+    /// Whether the value is owned by this object, and freed along with it.
+    owned: bool,
+    /// Whether the value may be modified, which is false for values borrowed by shared reference.
+    mutable: bool,
+    /// The number of shared borrows of the value, or `-1` while it is mutably borrowed. This catches
+    /// callbacks which use an object that is already in use further up the stack.
+    borrows: Cell<isize>,
+    /// The value itself.
+    value: *mut T
+}
+
+impl<T: VxClass> VxObject<T> {
+    /// Creates an object which borrows the value, such as the argument of a callback. The object must not
+    /// outlive the borrow.
+    pub fn borrowed(value: impl VxBorrow<T>) -> Self {
+        let (value, mutable) = value.into_raw();
+        Self { tag: T::TAG, owned: false, mutable, borrows: Cell::new(0), value }
+    }
+
+    /// Panics unless the pointer refers to an object of this class.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must be null or refer to a live object of some class.
+    pub unsafe fn check(this: *const Self) {
+        assert!(!this.is_null(), "expected {} handle, got null", vx_class_name(T::TAG));
+        let tag = *this.cast::<u32>();
+        assert!(tag == T::TAG, "expected {} handle, got {}", vx_class_name(T::TAG), vx_class_name(tag));
+    }
+
+    /// Borrows the object, panicking unless the pointer refers to an object of this class which is not
+    /// mutably borrowed.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must be null or refer to a live object of some class.
+    pub unsafe fn get<'a>(this: *const Self) -> VxRef<'a, T> {
+        Self::check(this);
+        let object = &*this;
+        assert!(object.borrows.get() >= 0, "{} is already in use", vx_class_name(T::TAG));
+        object.borrows.set(object.borrows.get() + 1);
+        VxRef { object }
+    }
+
+    /// Mutably borrows the object, panicking unless the pointer refers to an object of this class which
+    /// is not borrowed.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must be null or refer to a live object of some class.
+    pub unsafe fn get_mut<'a>(this: *mut Self) -> VxRefMut<'a, T> {
+        Self::check(this);
+        let object = &*this;
+        assert!(object.mutable, "{} is borrowed by shared reference, so it cannot be modified", vx_class_name(T::TAG));
+        assert!(object.borrows.get() == 0, "{} is already in use", vx_class_name(T::TAG));
+        object.borrows.set(-1);
+        VxRefMut { object }
+    }
+}
+
+/// A reference which a [`VxObject`] may borrow its value through.
+pub trait VxBorrow<T> {
+    /// Gets a pointer to the value, and whether it may be modified.
+    fn into_raw(self) -> (*mut T, bool);
+}
+
+impl<T> VxBorrow<T> for &mut T {
+    fn into_raw(self) -> (*mut T, bool) {
+        (self, true)
+    }
+}
+
+impl<T> VxBorrow<T> for &T {
+    fn into_raw(self) -> (*mut T, bool) {
+        (self as *const T as *mut T, false)
+    }
+}
+
+/// A shared borrow of the value of a [`VxObject`]. The field is named, so that it does not hide the
+/// fields of tuple structs.
+pub struct VxRef<'a, T> {
+    object: &'a VxObject<T>
+}
+
+impl<T> Deref for VxRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.object.value }
+    }
+}
+
+impl<T> Drop for VxRef<'_, T> {
+    fn drop(&mut self) {
+        self.object.borrows.set(self.object.borrows.get() - 1);
+    }
+}
+
+/// A mutable borrow of the value of a [`VxObject`].
+pub struct VxRefMut<'a, T> {
+    object: &'a VxObject<T>
+}
+
+impl<T> Deref for VxRefMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.object.value }
+    }
+}
+
+impl<T> DerefMut for VxRefMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.object.value }
+    }
+}
+
+impl<T> Drop for VxRefMut<'_, T> {
+    fn drop(&mut self) {
+        self.object.borrows.set(0);
+    }
+}
+
+/// Owns a value which was, or is about to be, handed to C#.
+pub struct VxHandle<T>(Box<T>);
+
+impl<T: VxClass> VxHandle<T> {
+    /// Moves the value to the heap, tagged with its class, and gives up ownership of it.
+    pub fn into_heap(value: T) -> *mut VxObject<T> {
+        let value = Box::into_raw(Box::new(value));
+        Box::into_raw(Box::new(VxObject { tag: T::TAG, owned: true, mutable: true, borrows: Cell::new(0), value }))
+    }
+
+    /// Takes back ownership of an object, panicking unless the pointer refers to an owned object of this
+    /// class which is not borrowed.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must be null or refer to a live object of some class, which
+    /// will not be used again.
+    pub unsafe fn from_heap(this: *mut VxObject<T>) -> Self {
+        VxObject::check(this);
+        assert!((*this).owned, "{} is borrowed, so it cannot be moved or freed", vx_class_name(T::TAG));
+        assert!((*this).borrows.get() == 0, "{} is already in use", vx_class_name(T::TAG));
+        let object = Box::from_raw(this);
+        Self(Box::from_raw(object.value))
+    }
+
+    /// Moves the value off the heap.
+    pub fn into_inner(self) -> T {
+        *self.0
+    }
+}
+
+impl VxClass for Context {
+    const TAG: u32 = 1;
+}
+
+impl VxClass for Style {
+    const TAG: u32 = 2;
+}
+
+/// Gets the name of the class with the given tag, for reporting mismatched handles.
+fn vx_class_name(tag: u32) -> &'static str {
+    match tag {
+        1 => "Context",
+        2 => "Style",
+        _ => "an unknown class"
+    }
+}
+
+// generated from clone::Context
+/// Frees the provided object.
+///
+/// # Safety
+///
+/// For this call to be sound, the pointer must be null or refer to a live object of a generated class.
+#[no_mangle]
+pub unsafe extern "C" fn vx_context_drop(value: *mut VxObject<Context>) {
+    vx_discard_panic(|| drop(VxHandle::from_heap(value)));
+}
+
+// generated from clone::Style
+/// Frees the provided object.
+///
+/// # Safety
+///
+/// For this call to be sound, the pointer must be null or refer to a live object of a generated class.
+#[no_mangle]
+pub unsafe extern "C" fn vx_style_drop(value: *mut VxObject<Style>) {
+    vx_discard_panic(|| drop(VxHandle::from_heap(value)));
+}
+
+/// Creates a new object with a copy of the provided object's contents.
+///
+/// # Safety
+///
+/// For this call to be sound, the pointer must be null or refer to a live object of a generated class.
+#[no_mangle]
+pub unsafe extern "C" fn vx_style_clone(this: *const VxObject<Style>) -> MaybeUninit<*mut VxObject<Style>> {
+    vx_catch(|| VxHandle::into_heap(VxObject::get(this).clone()))
+}
+
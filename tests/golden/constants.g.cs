@@ -0,0 +1,216 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash 8f2319bbdb70e43c.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// An owned UTF-8 string which may be passed across the FFI boundary.
+    /// The string is allocated by Rust, and must be freed with <c>Vx.vx_string_drop</c>.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe struct VxString {
+        private byte* _ptr;
+        private nuint _len;
+        private nuint _capacity;
+
+        /// <summary>
+        /// Copies a managed string into a new Rust-allocated string.
+        /// </summary>
+        public static VxString FromString(string value) {
+            var bytes = System.Text.Encoding.UTF8.GetBytes(value);
+            fixed (byte* ptr = bytes) {
+                return Vx.vx_string_from_utf8(ptr, (nuint)bytes.Length);
+            }
+        }
+
+        /// <summary>
+        /// Copies a Rust-allocated string into a managed string, and then frees it.
+        /// </summary>
+        public static string Take(VxString value) {
+            try {
+                return value.ToString();
+            }
+            finally {
+                Free(value);
+            }
+        }
+
+        /// <summary>
+        /// Frees a Rust-allocated string.
+        /// </summary>
+        public static void Free(VxString value) => Vx.vx_string_drop(value);
+
+        /// <summary>
+        /// Whether this is a null string, which Rust returns in place of a string that does not exist.
+        /// </summary>
+        internal bool IsNull => _ptr == null;
+
+        /// <summary>
+        /// Copies the contents of this string into a managed string.
+        /// </summary>
+        public override string ToString() => System.Text.Encoding.UTF8.GetString(_ptr, checked((int)_len));
+    }
+
+    /// <summary>
+    /// A panic in the Rust code called by the bindings. Any Rust objects involved may be left in an
+    /// inconsistent state.
+    /// </summary>
+    public sealed class EguiException : Exception {
+        /// <summary>
+        /// Creates an exception with the message of the panic.
+        /// </summary>
+        public EguiException(string message) : base(message) { }
+
+        /// <summary>
+        /// Throws the panic caught during the last call into Rust on this thread, if there was one.
+        /// </summary>
+        internal static unsafe void ThrowIfPanicked() {
+            var message = Vx.vx_last_error_message();
+            if (!message.IsNull) {
+                throw new EguiException(VxString.Take(message));
+            }
+        }
+
+        /// <summary>
+        /// Returns the value from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static T Check<T>(T value) {
+            ThrowIfPanicked();
+            return value;
+        }
+
+        /// <summary>
+        /// Returns the pointer from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static unsafe void* Check(void* value) {
+            ThrowIfPanicked();
+            return value;
+        }
+    }
+
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Vec2>(8);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    /// <summary>
+    /// The functions exported by the native library.
+    /// </summary>
+    internal static unsafe partial class Vx {
+        private const string Library = "egui_native";
+
+        [LibraryImport(Library)]
+        internal static partial void vx_string_drop(VxString value);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_string_from_utf8(byte* ptr, nuint len);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_last_error_message();
+
+        [LibraryImport(Library)]
+        internal static partial uint vx_const_max_textures();
+
+        [LibraryImport(Library)]
+        internal static partial Vec2 vx_const_origin();
+
+        [LibraryImport(Library)]
+        internal static partial Vec2 vx_const_vec_2_right();
+
+        [LibraryImport(Library)]
+        internal static partial float vx_const_spacing();
+
+        [LibraryImport(Library)]
+        internal static partial Vec2 vx_const_vec_2_zero();
+    }
+
+    /// <summary>
+    /// The most textures which may be allocated at once.
+    /// </summary>
+    public static partial class Constants {
+        // generated from constants::MAX_TEXTURES
+        /// <summary>
+        /// The most textures which may be allocated at once.
+        /// </summary>
+        public static readonly uint MaxTextures = EguiException.Check(Vx.vx_const_max_textures());
+    }
+
+    /// <summary>
+    /// The top-left corner of the screen.
+    /// </summary>
+    public static partial class Constants {
+        // generated from constants::ORIGIN
+        /// <summary>
+        /// The top-left corner of the screen.
+        /// </summary>
+        public static readonly Vec2 Origin = EguiException.Check(Vx.vx_const_origin());
+    }
+
+    /// <summary>
+    /// The unit vector pointing right.
+    /// </summary>
+    public unsafe partial struct Vec2 {
+        // generated from constants::Vec2::RIGHT
+        /// <summary>
+        /// The unit vector pointing right.
+        /// </summary>
+        public static readonly Vec2 Right = EguiException.Check(Vx.vx_const_vec_2_right());
+    }
+
+    /// <summary>
+    /// The space between widgets, in points.
+    /// </summary>
+    public static partial class Constants {
+        // generated from constants::SPACING
+        /// <summary>
+        /// The space between widgets, in points.
+        /// </summary>
+        public static readonly float Spacing = EguiException.Check(Vx.vx_const_spacing());
+    }
+
+    // generated from constants::Vec2
+    /// <summary>
+    /// A vector in 2D space.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Vec2 {
+        /// <summary>
+        /// The horizontal component.
+        /// </summary>
+        public float X;
+
+        /// <summary>
+        /// The vertical component.
+        /// </summary>
+        public float Y;
+    }
+
+    /// <summary>
+    /// The vector with both components zero.
+    /// </summary>
+    public unsafe partial struct Vec2 {
+        // generated from constants::Vec2::ZERO
+        /// <summary>
+        /// The vector with both components zero.
+        /// </summary>
+        public static readonly Vec2 Zero = EguiException.Check(Vx.vx_const_vec_2_zero());
+    }
+}
@@ -0,0 +1,42 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef CONSTANTS_H
+#define CONSTANTS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// A vector in 2D space.
+typedef struct VxVec2 {
+    /// The horizontal component.
+    float x;
+    /// The vertical component.
+    float y;
+} VxVec2;
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+uint32_t vx_const_max_textures(void);
+VxVec2 vx_const_origin(void);
+VxVec2 vx_const_vec_2_right(void);
+float vx_const_spacing(void);
+VxVec2 vx_const_vec_2_zero(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CONSTANTS_H */
@@ -0,0 +1,209 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+/// An owned UTF-8 string which may be passed across the FFI boundary.
+/// The string must be freed with [`vx_string_drop`].
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxString {
+    /// A pointer to the first byte.
+    pub ptr: *mut u8,
+    /// The length of the string in bytes.
+    pub len: usize,
+    /// The capacity of the allocation.
+    pub capacity: usize
+}
+
+impl From<String> for VxString {
+    fn from(value: String) -> Self {
+        let mut value = std::mem::ManuallyDrop::new(value);
+        Self { ptr: value.as_mut_ptr(), len: value.len(), capacity: value.capacity() }
+    }
+}
+
+impl VxString {
+    /// Takes ownership of the string.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the string must have been allocated by Rust and not already freed.
+    pub unsafe fn into_string(self) -> String {
+        String::from_raw_parts(self.ptr, self.len, self.capacity)
+    }
+}
+
+/// A string which C# passes by `ref`. The string is taken for the duration of a call, and written back
+/// when this is dropped, even if the call panics.
+pub struct VxStringMut {
+    /// The string to write back to.
+    target: *mut VxString,
+    /// The string while it is in use.
+    value: String
+}
+
+impl VxStringMut {
+    /// Takes the string which the pointer refers to.
+    ///
+    /// # Safety
+    ///
+    /// For this call to be sound, the pointer must refer to a string allocated by Rust, which is not
+    /// used again until this is dropped.
+    pub unsafe fn new(target: *mut VxString) -> Self {
+        Self { value: (*target).into_string(), target }
+    }
+}
+
+impl std::ops::Deref for VxStringMut {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        &self.value
+    }
+}
+
+impl std::ops::DerefMut for VxStringMut {
+    fn deref_mut(&mut self) -> &mut String {
+        &mut self.value
+    }
+}
+
+impl Drop for VxStringMut {
+    fn drop(&mut self) {
+        unsafe { *self.target = std::mem::take(&mut self.value).into() };
+    }
+}
+
+/// Frees the provided string.
+///
+/// # Safety
+///
+/// For this call to be sound, the string must have been allocated by Rust and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn vx_string_drop(value: VxString) {
+    drop(value.into_string());
+}
+
+/// Copies the provided UTF-8 bytes into a new string, replacing invalid sequences.
+///
+/// # Safety
+///
+/// For this call to be sound, the pointer must refer to `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn vx_string_from_utf8(ptr: *const u8, len: usize) -> VxString {
+    String::from_utf8_lossy(std::slice::from_raw_parts(ptr, len)).into_owned().into()
+}
+
+use std::cell::RefCell;
+use std::mem::MaybeUninit;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+thread_local! {
+    /// The message of the last panic caught on this thread, until it is taken by [`vx_take_error`].
+    static VX_LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Calls the function, catching any panic so that it does not unwind across the FFI boundary. The
+/// message of a caught panic is stored for [`vx_take_error`], and a zeroed value is returned instead,
+/// which the caller must not read.
+pub fn vx_catch<T>(f: impl FnOnce() -> T) -> MaybeUninit<T> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => MaybeUninit::new(value),
+        Err(payload) => {
+            let message = payload.downcast_ref::<&str>().map(|x| x.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Rust panicked with a non-string payload".to_string());
+            VX_LAST_ERROR.with(|x| *x.borrow_mut() = Some(message));
+            MaybeUninit::zeroed()
+        }
+    }
+}
+
+/// Calls the function, discarding any panic within it. Objects are freed by C# finalizers, which cannot
+/// throw, so panics while dropping them are not reported.
+pub fn vx_discard_panic(f: impl FnOnce()) {
+    let _ = catch_unwind(AssertUnwindSafe(f));
+}
+
+/// Takes the message of the last panic caught on this thread, if any.
+pub fn vx_take_error() -> Option<String> {
+    VX_LAST_ERROR.with(|x| x.borrow_mut().take())
+}
+
+/// Takes the message of the last panic caught by a shim on this thread, or returns a null string if there was none.
+#[no_mangle]
+pub extern "C" fn vx_last_error_message() -> VxString {
+    match vx_take_error() {
+        Some(message) => message.into(),
+        None => VxString { ptr: std::ptr::null_mut(), len: 0, capacity: 0 }
+    }
+}
+
+// generated from constants::MAX_TEXTURES
+/// The most textures which may be allocated at once.
+#[no_mangle]
+pub extern "C" fn vx_const_max_textures() -> MaybeUninit<u32> {
+    vx_catch(|| constants::MAX_TEXTURES.into())
+}
+
+// generated from constants::ORIGIN
+/// The top-left corner of the screen.
+#[no_mangle]
+pub extern "C" fn vx_const_origin() -> MaybeUninit<VxVec2> {
+    vx_catch(|| constants::ORIGIN.into())
+}
+
+// generated from constants::Vec2::RIGHT
+/// The unit vector pointing right.
+#[no_mangle]
+pub extern "C" fn vx_const_vec_2_right() -> MaybeUninit<VxVec2> {
+    vx_catch(|| constants::Vec2::RIGHT.into())
+}
+
+// generated from constants::SPACING
+/// The space between widgets, in points.
+#[no_mangle]
+pub extern "C" fn vx_const_spacing() -> MaybeUninit<f32> {
+    vx_catch(|| constants::SPACING.into())
+}
+
+// generated from constants::Vec2
+/// A vector in 2D space.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxVec2 {
+    /// The horizontal component.
+    pub x: f32,
+    /// The vertical component.
+    pub y: f32,
+}
+
+impl From<Vec2> for VxVec2 {
+    fn from(value: Vec2) -> Self {
+        Self {
+            x: value.x.into(),
+            y: value.y.into(),
+        }
+    }
+}
+
+impl From<VxVec2> for Vec2 {
+    fn from(value: VxVec2) -> Self {
+        Self {
+            x: value.x.into(),
+            y: value.y.into(),
+        }
+    }
+}
+
+
+// generated from constants::Vec2::ZERO
+/// The vector with both components zero.
+#[no_mangle]
+pub extern "C" fn vx_const_vec_2_zero() -> MaybeUninit<VxVec2> {
+    vx_catch(|| constants::Vec2::ZERO.into())
+}
+
+const _: () = assert!(size_of::<VxVec2>() == 8 && align_of::<VxVec2>() == 4);
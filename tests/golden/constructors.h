@@ -0,0 +1,36 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef CONSTRUCTORS_H
+#define CONSTRUCTORS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+/// An opaque Rust object, which is only ever referred to by pointer.
+typedef struct VxObject VxObject;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+void vx_mesh_drop(VxObject* value);
+VxObject* vx_mesh_new(void);
+VxObject* vx_mesh_with_capacity(uint32_t vertices);
+uint32_t vx_mesh_vertex_count(VxObject* self);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CONSTRUCTORS_H */
@@ -0,0 +1,50 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef DEFAULTS_H
+#define DEFAULTS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// The space around a frame.
+typedef struct VxMargin {
+    /// The space on the left.
+    float left;
+    /// The space on the right.
+    float right;
+} VxMargin;
+
+/// A side of a rectangle.
+typedef struct VxSide {
+    /// The width in points.
+    float width;
+} VxSide;
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+/// An opaque Rust object, which is only ever referred to by pointer.
+typedef struct VxObject VxObject;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+void vx_context_drop(VxObject* value);
+VxMargin vx_margin_default(void);
+void vx_memory_drop(VxObject* value);
+VxObject* vx_memory_default(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* DEFAULTS_H */
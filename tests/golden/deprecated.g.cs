@@ -0,0 +1,181 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash 26fe88acf2d55ab1.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// An owned UTF-8 string which may be passed across the FFI boundary.
+    /// The string is allocated by Rust, and must be freed with <c>Vx.vx_string_drop</c>.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe struct VxString {
+        private byte* _ptr;
+        private nuint _len;
+        private nuint _capacity;
+
+        /// <summary>
+        /// Copies a managed string into a new Rust-allocated string.
+        /// </summary>
+        public static VxString FromString(string value) {
+            var bytes = System.Text.Encoding.UTF8.GetBytes(value);
+            fixed (byte* ptr = bytes) {
+                return Vx.vx_string_from_utf8(ptr, (nuint)bytes.Length);
+            }
+        }
+
+        /// <summary>
+        /// Copies a Rust-allocated string into a managed string, and then frees it.
+        /// </summary>
+        public static string Take(VxString value) {
+            try {
+                return value.ToString();
+            }
+            finally {
+                Free(value);
+            }
+        }
+
+        /// <summary>
+        /// Frees a Rust-allocated string.
+        /// </summary>
+        public static void Free(VxString value) => Vx.vx_string_drop(value);
+
+        /// <summary>
+        /// Whether this is a null string, which Rust returns in place of a string that does not exist.
+        /// </summary>
+        internal bool IsNull => _ptr == null;
+
+        /// <summary>
+        /// Copies the contents of this string into a managed string.
+        /// </summary>
+        public override string ToString() => System.Text.Encoding.UTF8.GetString(_ptr, checked((int)_len));
+    }
+
+    /// <summary>
+    /// A panic in the Rust code called by the bindings. Any Rust objects involved may be left in an
+    /// inconsistent state.
+    /// </summary>
+    public sealed class EguiException : Exception {
+        /// <summary>
+        /// Creates an exception with the message of the panic.
+        /// </summary>
+        public EguiException(string message) : base(message) { }
+
+        /// <summary>
+        /// Throws the panic caught during the last call into Rust on this thread, if there was one.
+        /// </summary>
+        internal static unsafe void ThrowIfPanicked() {
+            var message = Vx.vx_last_error_message();
+            if (!message.IsNull) {
+                throw new EguiException(VxString.Take(message));
+            }
+        }
+
+        /// <summary>
+        /// Returns the value from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static T Check<T>(T value) {
+            ThrowIfPanicked();
+            return value;
+        }
+
+        /// <summary>
+        /// Returns the pointer from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static unsafe void* Check(void* value) {
+            ThrowIfPanicked();
+            return value;
+        }
+    }
+
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Margin>(8);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    /// <summary>
+    /// The functions exported by the native library.
+    /// </summary>
+    internal static unsafe partial class Vx {
+        private const string Library = "egui_native";
+
+        [LibraryImport(Library)]
+        internal static partial void vx_string_drop(VxString value);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_string_from_utf8(byte* ptr, nuint len);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_last_error_message();
+
+        [LibraryImport(Library)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        internal static partial bool vx_align_is_valid(long value);
+    }
+
+    // generated from deprecated::Align
+    /// <summary>
+    /// How to align text.
+    /// </summary>
+    public enum Align {
+        /// <summary>
+        /// Aligns to the left.
+        /// </summary>
+        Min,
+        /// <summary>
+        /// Aligns to the center.
+        /// </summary>
+        [Obsolete("Deprecated since 0.20.0: use `Align::Min` with a \"centered\" layout")]
+        Center,
+        /// <summary>
+        /// Aligns to the right.
+        /// </summary>
+        Max,
+    }
+
+    /// <summary>
+    /// Checks values of <see cref="Align"/> before they are passed to Rust.
+    /// </summary>
+    public static class AlignExtensions {
+        /// <summary>
+        /// Whether the value is a variant of the Rust enum. Passing any other value to Rust panics.
+        /// </summary>
+        public static bool IsDefined(this Align value) => Vx.vx_align_is_valid((long)value);
+    }
+
+    // generated from deprecated::Margin
+    /// <summary>
+    /// The space around a frame.
+    /// </summary>
+    [Obsolete("renamed to `Marginf`.\nThe old name will be removed in 0.30:\n\tsee the changelog.")]
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Margin {
+        /// <summary>
+        /// The space on the left.
+        /// </summary>
+        public float Left;
+
+        /// <summary>
+        /// The space on the right.
+        /// </summary>
+        public float Right;
+    }
+}
@@ -0,0 +1,66 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash 10038065fe573051.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Rounding>(8);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    // generated from equality::Rounding
+    /// <summary>
+    /// How rounded the corners of a rectangle are.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Rounding : IEquatable<Rounding> {
+        /// <summary>
+        /// The radius of every corner, in points.
+        /// </summary>
+        public float Radius;
+
+        /// <summary>
+        /// The radius of each corner, clockwise from the top left.
+        /// </summary>
+        public fixed byte Corners[4];
+
+        /// <inheritdoc/>
+        public bool Equals(Rounding other) => Radius.Equals(other.Radius) && Corners[0].Equals(other.Corners[0]) && Corners[1].Equals(other.Corners[1]) && Corners[2].Equals(other.Corners[2]) && Corners[3].Equals(other.Corners[3]);
+
+        /// <inheritdoc/>
+        public override bool Equals(object obj) => obj is Rounding other && Equals(other);
+
+        /// <inheritdoc/>
+        public override int GetHashCode() {
+            var hash = new HashCode();
+            hash.Add(Radius);
+            hash.Add(Corners[0]);
+            hash.Add(Corners[1]);
+            hash.Add(Corners[2]);
+            hash.Add(Corners[3]);
+            return hash.ToHashCode();
+        }
+
+        public static bool operator ==(Rounding left, Rounding right) => left.Equals(right);
+
+        public static bool operator !=(Rounding left, Rounding right) => !left.Equals(right);
+    }
+}
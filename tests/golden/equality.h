@@ -0,0 +1,27 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef EQUALITY_H
+#define EQUALITY_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// How rounded the corners of a rectangle are.
+typedef struct VxRounding {
+    /// The radius of every corner, in points.
+    float radius;
+    /// The radius of each corner, clockwise from the top left.
+    uint8_t corners[4];
+} VxRounding;
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* EQUALITY_H */
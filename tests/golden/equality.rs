@@ -0,0 +1,37 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+// generated from equality::Rounding
+/// How rounded the corners of a rectangle are.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxRounding {
+    /// The radius of every corner, in points.
+    pub radius: f32,
+    /// The radius of each corner, clockwise from the top left.
+    pub corners: [u8; 4],
+}
+
+impl From<Rounding> for VxRounding {
+    fn from(value: Rounding) -> Self {
+        Self {
+            radius: value.radius.into(),
+            corners: value.corners.map(Into::into),
+        }
+    }
+}
+
+impl From<VxRounding> for Rounding {
+    fn from(value: VxRounding) -> Self {
+        Self {
+            radius: value.radius.into(),
+            corners: value.corners.map(Into::into),
+        }
+    }
+}
+
+
+const _: () = assert!(size_of::<VxRounding>() == 8 && align_of::<VxRounding>() == 4);
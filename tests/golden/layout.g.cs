@@ -0,0 +1,73 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash 2736839cb2e9a89c.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<PackedSlider>(9);
+            Check<Slider>(16);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    // generated from layout::PackedSlider
+    /// <summary>
+    /// A slider whose value directly follows the flag.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential, Pack = 1)]
+    public unsafe partial struct PackedSlider {
+        private byte _enabled;
+
+        /// <summary>
+        /// Whether the slider can be dragged.
+        /// </summary>
+        public bool Enabled {
+            get => _enabled != 0;
+            set => _enabled = value ? (byte)1 : (byte)0;
+        }
+
+        /// <summary>
+        /// The value of the slider.
+        /// </summary>
+        public double Value;
+    }
+
+    // generated from layout::Slider
+    /// <summary>
+    /// A slider over a range of numbers.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Slider {
+        private byte _enabled;
+
+        /// <summary>
+        /// Whether the slider can be dragged.
+        /// </summary>
+        public bool Enabled {
+            get => _enabled != 0;
+            set => _enabled = value ? (byte)1 : (byte)0;
+        }
+
+        /// <summary>
+        /// The value of the slider.
+        /// </summary>
+        public double Value;
+    }
+}
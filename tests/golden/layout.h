@@ -0,0 +1,37 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef LAYOUT_H
+#define LAYOUT_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// A slider whose value directly follows the flag.
+#pragma pack(push, 1)
+typedef struct VxPackedSlider {
+    /// Whether the slider can be dragged.
+    bool enabled;
+    /// The value of the slider.
+    double value;
+} VxPackedSlider;
+#pragma pack(pop)
+
+/// A slider over a range of numbers.
+typedef struct VxSlider {
+    /// Whether the slider can be dragged.
+    bool enabled;
+    /// The value of the slider.
+    double value;
+} VxSlider;
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* LAYOUT_H */
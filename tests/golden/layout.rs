@@ -0,0 +1,68 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+// generated from layout::PackedSlider
+/// A slider whose value directly follows the flag.
+#[derive(Copy, Clone)]
+#[repr(C, packed)]
+pub struct VxPackedSlider {
+    /// Whether the slider can be dragged.
+    pub enabled: bool,
+    /// The value of the slider.
+    pub value: f64,
+}
+
+impl From<PackedSlider> for VxPackedSlider {
+    fn from(value: PackedSlider) -> Self {
+        Self {
+            enabled: value.enabled.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+impl From<VxPackedSlider> for PackedSlider {
+    fn from(value: VxPackedSlider) -> Self {
+        Self {
+            enabled: value.enabled.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+
+// generated from layout::Slider
+/// A slider over a range of numbers.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxSlider {
+    /// Whether the slider can be dragged.
+    pub enabled: bool,
+    /// The value of the slider.
+    pub value: f64,
+}
+
+impl From<Slider> for VxSlider {
+    fn from(value: Slider) -> Self {
+        Self {
+            enabled: value.enabled.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+impl From<VxSlider> for Slider {
+    fn from(value: VxSlider) -> Self {
+        Self {
+            enabled: value.enabled.into(),
+            value: value.value.into(),
+        }
+    }
+}
+
+
+const _: () = assert!(size_of::<VxPackedSlider>() == 9 && align_of::<VxPackedSlider>() == 1);
+const _: () = assert!(size_of::<VxSlider>() == 16 && align_of::<VxSlider>() == 8);
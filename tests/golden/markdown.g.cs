@@ -0,0 +1,66 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash f3bed41115874eed.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Frame>(4);
+            Check<Margin>(4);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    // generated from markdown::Frame
+    /// <summary>
+    /// Adds a rectangular background and border around a <c>Ui</c>, which holds a <c>String</c>.
+    /// </summary>
+    /// <remarks>
+    /// <para>Frames are drawn with:</para>
+    /// <list type="bullet">
+    /// <item><description>a <c>fill</c>, which may be transparent</description></item>
+    /// <item><description>a stroke, and</description></item>
+    /// <item><description>an inner <see cref="Margin"/>.</description></item>
+    /// </list>
+    /// <para><b>Layout</b></para>
+    /// <para>The margin is inside the stroke:</para>
+    /// <code>
+    /// stroke | margin | content
+    /// </code>
+    /// </remarks>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Frame {
+        /// <summary>
+        /// The margin within the frame, like <c>Margin::left</c>.
+        /// </summary>
+        public Margin InnerMargin;
+    }
+
+    // generated from markdown::Margin
+    /// <summary>
+    /// The space around a <see cref="Frame"/>, in points.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Margin {
+        /// <summary>
+        /// The space on the left.
+        /// </summary>
+        public float Left;
+    }
+}
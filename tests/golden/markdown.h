@@ -0,0 +1,49 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef MARKDOWN_H
+#define MARKDOWN_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// The space around a [`Frame`], in points.
+typedef struct VxMargin {
+    /// The space on the left.
+    float left;
+} VxMargin;
+
+/// Adds a rectangular background and border around a [`Ui`], which holds a [`String`].
+/// 
+/// Frames are drawn with:
+/// - a `fill`, which may be transparent
+/// - a [stroke](Stroke), and
+/// - an inner [`Margin`].
+/// 
+/// ## Layout
+/// The margin is inside the stroke:
+/// ```text
+/// stroke | margin | content
+/// ```
+/// 
+/// # Examples
+/// ```
+/// egui::Frame::new().show(ui, |ui| {
+///     ui.label("Framed");
+/// });
+/// ```
+typedef struct VxFrame {
+    /// The margin within the frame, like [`Margin::left`].
+    VxMargin inner_margin;
+} VxFrame;
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* MARKDOWN_H */
@@ -0,0 +1,78 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+// generated from markdown::Frame
+/// Adds a rectangular background and border around a [`Ui`], which holds a [`String`].
+/// 
+/// Frames are drawn with:
+/// - a `fill`, which may be transparent
+/// - a [stroke](Stroke), and
+/// - an inner [`Margin`].
+/// 
+/// ## Layout
+/// The margin is inside the stroke:
+/// ```text
+/// stroke | margin | content
+/// ```
+/// 
+/// # Examples
+/// ```
+/// egui::Frame::new().show(ui, |ui| {
+///     ui.label("Framed");
+/// });
+/// ```
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxFrame {
+    /// The margin within the frame, like [`Margin::left`].
+    pub inner_margin: VxMargin,
+}
+
+impl From<Frame> for VxFrame {
+    fn from(value: Frame) -> Self {
+        Self {
+            inner_margin: value.inner_margin.into(),
+        }
+    }
+}
+
+impl From<VxFrame> for Frame {
+    fn from(value: VxFrame) -> Self {
+        Self {
+            inner_margin: value.inner_margin.into(),
+        }
+    }
+}
+
+
+// generated from markdown::Margin
+/// The space around a [`Frame`], in points.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxMargin {
+    /// The space on the left.
+    pub left: f32,
+}
+
+impl From<Margin> for VxMargin {
+    fn from(value: Margin) -> Self {
+        Self {
+            left: value.left.into(),
+        }
+    }
+}
+
+impl From<VxMargin> for Margin {
+    fn from(value: VxMargin) -> Self {
+        Self {
+            left: value.left.into(),
+        }
+    }
+}
+
+
+const _: () = assert!(size_of::<VxFrame>() == 4 && align_of::<VxFrame>() == 4);
+const _: () = assert!(size_of::<VxMargin>() == 4 && align_of::<VxMargin>() == 4);
@@ -0,0 +1,76 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash d341364cbd7488ed.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Pair>(16);
+            Check<Id>(8);
+            Check<Id2>(8);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    // generated from modules::Pair
+    /// <summary>
+    /// A widget and the texture it draws.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Pair {
+        /// <summary>
+        /// The widget.
+        /// </summary>
+        public Id Widget;
+
+        /// <summary>
+        /// The texture.
+        /// </summary>
+        public Id2 Texture;
+    }
+
+    // generated from modules::a::Id
+    /// <summary>
+    /// Identifies a widget.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Id {
+        /// <summary>
+        /// The hash of the widget's path.
+        /// </summary>
+        public ulong Value;
+    }
+
+    // generated from modules::b::Id
+    /// <summary>
+    /// Identifies a texture.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Id2 {
+        /// <summary>
+        /// The index of the texture.
+        /// </summary>
+        public uint Index;
+
+        /// <summary>
+        /// How often the index has been reused.
+        /// </summary>
+        public uint Generation;
+    }
+}
@@ -0,0 +1,41 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef MODULES_H
+#define MODULES_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// Identifies a widget.
+typedef struct VxId {
+    /// The hash of the widget's path.
+    uint64_t value;
+} VxId;
+
+/// Identifies a texture.
+typedef struct VxId2 {
+    /// The index of the texture.
+    uint32_t index;
+    /// How often the index has been reused.
+    uint32_t generation;
+} VxId2;
+
+/// A widget and the texture it draws.
+typedef struct VxPair {
+    /// The widget.
+    VxId widget;
+    /// The texture.
+    VxId2 texture;
+} VxPair;
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* MODULES_H */
@@ -0,0 +1,96 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+// generated from modules::Pair
+/// A widget and the texture it draws.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxPair {
+    /// The widget.
+    pub widget: VxId,
+    /// The texture.
+    pub texture: VxId2,
+}
+
+impl From<Pair> for VxPair {
+    fn from(value: Pair) -> Self {
+        Self {
+            widget: value.widget.into(),
+            texture: value.texture.into(),
+        }
+    }
+}
+
+impl From<VxPair> for Pair {
+    fn from(value: VxPair) -> Self {
+        Self {
+            widget: value.widget.into(),
+            texture: value.texture.into(),
+        }
+    }
+}
+
+
+// generated from modules::a::Id
+/// Identifies a widget.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxId {
+    /// The hash of the widget's path.
+    pub value: u64,
+}
+
+impl From<Id> for VxId {
+    fn from(value: Id) -> Self {
+        Self {
+            value: value.value.into(),
+        }
+    }
+}
+
+impl From<VxId> for Id {
+    fn from(value: VxId) -> Self {
+        Self {
+            value: value.value.into(),
+        }
+    }
+}
+
+
+// generated from modules::b::Id
+use modules::b::Id as Id2;
+/// Identifies a texture.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxId2 {
+    /// The index of the texture.
+    pub index: u32,
+    /// How often the index has been reused.
+    pub generation: u32,
+}
+
+impl From<Id2> for VxId2 {
+    fn from(value: Id2) -> Self {
+        Self {
+            index: value.index.into(),
+            generation: value.generation.into(),
+        }
+    }
+}
+
+impl From<VxId2> for Id2 {
+    fn from(value: VxId2) -> Self {
+        Self {
+            index: value.index.into(),
+            generation: value.generation.into(),
+        }
+    }
+}
+
+
+const _: () = assert!(size_of::<VxPair>() == 16 && align_of::<VxPair>() == 8);
+const _: () = assert!(size_of::<VxId>() == 8 && align_of::<VxId>() == 8);
+const _: () = assert!(size_of::<VxId2>() == 8 && align_of::<VxId2>() == 4);
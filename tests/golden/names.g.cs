@@ -0,0 +1,362 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash 84f2824158bbf0f5.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// An opaque Rust object, which is only ever referred to by pointer.
+    /// </summary>
+    public struct VxObject { }
+
+    /// <summary>
+    /// Owns a heap-allocated Rust object, which is freed when the handle is disposed or finalized.
+    /// </summary>
+    public abstract unsafe class VxHandle : IDisposable {
+        private VxObject* _pointer;
+        private readonly bool _owned;
+        private bool _disposed;
+
+        /// <summary>
+        /// Takes ownership of the provided object, or borrows it for the duration of a callback if
+        /// <paramref name="owned"/> is false. Borrowed objects are never freed.
+        /// </summary>
+        protected VxHandle(VxObject* pointer, bool owned = true) {
+            _pointer = pointer;
+            _owned = owned;
+            if (!owned) {
+                GC.SuppressFinalize(this);
+            }
+        }
+
+        /// <summary>
+        /// Frees the object if the handle was never disposed.
+        /// </summary>
+        ~VxHandle() {
+            Dispose(false);
+        }
+
+        /// <summary>
+        /// Gets the owned object, throwing if it has already been freed or moved.
+        /// </summary>
+        internal VxObject* Pointer {
+            get {
+                ThrowIfDisposed();
+                return _pointer;
+            }
+        }
+
+        /// <summary>
+        /// Frees the object. Calling this more than once has no effect.
+        /// </summary>
+        public void Dispose() {
+            Dispose(true);
+            GC.SuppressFinalize(this);
+        }
+
+        /// <summary>
+        /// Gives up ownership of the object, which is being moved into Rust. The handle is
+        /// marked as disposed without freeing the object.
+        /// </summary>
+        internal VxObject* TakePointer() {
+            if (!_owned) {
+                throw new InvalidOperationException($"A borrowed {GetType().Name} cannot be moved into Rust.");
+            }
+
+            var pointer = Pointer;
+            _pointer = null;
+            _disposed = true;
+            GC.SuppressFinalize(this);
+            return pointer;
+        }
+
+        /// <summary>
+        /// Takes ownership of the object returned by a builder method, which consumed the previous one,
+        /// so that calls can be chained on the same handle.
+        /// </summary>
+        internal void ReplacePointer(VxObject* pointer) {
+            _pointer = pointer;
+            _disposed = false;
+            GC.ReRegisterForFinalize(this);
+        }
+
+        /// <summary>
+        /// Throws an exception if the object has already been freed or moved.
+        /// </summary>
+        protected void ThrowIfDisposed() {
+            if (_disposed) {
+                throw new ObjectDisposedException(GetType().Name);
+            }
+        }
+
+        /// <summary>
+        /// Frees the object, unless it has already been freed or moved or is only borrowed.
+        /// </summary>
+        protected virtual void Dispose(bool disposing) {
+            if (!_disposed) {
+                _disposed = true;
+                if (_owned) {
+                    Free(_pointer);
+                }
+                _pointer = null;
+            }
+        }
+
+        /// <summary>
+        /// Frees the provided object with the destructor for its type.
+        /// </summary>
+        protected abstract void Free(VxObject* pointer);
+    }
+
+    /// <summary>
+    /// An owned UTF-8 string which may be passed across the FFI boundary.
+    /// The string is allocated by Rust, and must be freed with <c>Vx.vx_string_drop</c>.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe struct VxString {
+        private byte* _ptr;
+        private nuint _len;
+        private nuint _capacity;
+
+        /// <summary>
+        /// Copies a managed string into a new Rust-allocated string.
+        /// </summary>
+        public static VxString FromString(string value) {
+            var bytes = System.Text.Encoding.UTF8.GetBytes(value);
+            fixed (byte* ptr = bytes) {
+                return Vx.vx_string_from_utf8(ptr, (nuint)bytes.Length);
+            }
+        }
+
+        /// <summary>
+        /// Copies a Rust-allocated string into a managed string, and then frees it.
+        /// </summary>
+        public static string Take(VxString value) {
+            try {
+                return value.ToString();
+            }
+            finally {
+                Free(value);
+            }
+        }
+
+        /// <summary>
+        /// Frees a Rust-allocated string.
+        /// </summary>
+        public static void Free(VxString value) => Vx.vx_string_drop(value);
+
+        /// <summary>
+        /// Whether this is a null string, which Rust returns in place of a string that does not exist.
+        /// </summary>
+        internal bool IsNull => _ptr == null;
+
+        /// <summary>
+        /// Copies the contents of this string into a managed string.
+        /// </summary>
+        public override string ToString() => System.Text.Encoding.UTF8.GetString(_ptr, checked((int)_len));
+    }
+
+    /// <summary>
+    /// A panic in the Rust code called by the bindings. Any Rust objects involved may be left in an
+    /// inconsistent state.
+    /// </summary>
+    public sealed class EguiException : Exception {
+        /// <summary>
+        /// Creates an exception with the message of the panic.
+        /// </summary>
+        public EguiException(string message) : base(message) { }
+
+        /// <summary>
+        /// Throws the panic caught during the last call into Rust on this thread, if there was one.
+        /// </summary>
+        internal static unsafe void ThrowIfPanicked() {
+            var message = Vx.vx_last_error_message();
+            if (!message.IsNull) {
+                throw new EguiException(VxString.Take(message));
+            }
+        }
+
+        /// <summary>
+        /// Returns the value from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static T Check<T>(T value) {
+            ThrowIfPanicked();
+            return value;
+        }
+
+        /// <summary>
+        /// Returns the pointer from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static unsafe void* Check(void* value) {
+            ThrowIfPanicked();
+            return value;
+        }
+    }
+
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Glyph>(16);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    /// <summary>
+    /// The functions exported by the native library.
+    /// </summary>
+    internal static unsafe partial class Vx {
+        private const string Library = "egui_native";
+
+        [LibraryImport(Library)]
+        internal static partial void vx_string_drop(VxString value);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_string_from_utf8(byte* ptr, nuint len);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_last_error_message();
+
+        [LibraryImport(Library)]
+        internal static partial void vx_galley_drop(VxObject* value);
+
+        [LibraryImport(Library)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        internal static partial bool vx_galley_equals(VxObject* self, VxObject* other);
+
+        [LibraryImport(Library)]
+        internal static partial ulong vx_galley_get_hash_code(VxObject* self);
+
+        [LibraryImport(Library)]
+        internal static partial Glyph vx_galley_glyph(VxObject* self, nuint @in, uint @object);
+
+        [LibraryImport(Library)]
+        internal static partial void vx_galley_override(VxObject* self, nuint @params, Glyph @ref);
+
+        [LibraryImport(Library)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        internal static partial bool vx_wrap_is_valid(long value);
+    }
+
+    // generated from names::Galley
+    /// <summary>
+    /// Text which has been laid out.
+    /// </summary>
+    public unsafe sealed class Galley : VxHandle {
+        /// <summary>
+        /// Takes ownership of the provided object, or borrows it for the duration of a callback.
+        /// </summary>
+        internal Galley(VxObject* pointer, bool owned = true) : base(pointer, owned) { }
+
+        /// <inheritdoc/>
+        protected override void Free(VxObject* pointer) {
+            Vx.vx_galley_drop(pointer);
+        }
+
+        // generated from names::Galley::equals
+        /// <summary>
+        /// Whether both galleys hold the same text.
+        /// </summary>
+        /// <param name="other"></param>
+        /// <returns></returns>
+        public bool Equals(Galley other) => EguiException.Check(Vx.vx_galley_equals(Pointer, (other ?? throw new ArgumentNullException(nameof(other))).Pointer));
+
+        // generated from names::Galley::get_hash_code
+        /// <summary>
+        /// Gets a hash of the text.
+        /// </summary>
+        /// <returns></returns>
+        public ulong GetHashCode() => EguiException.Check(Vx.vx_galley_get_hash_code(Pointer));
+
+        // generated from names::Galley::glyph
+        /// <summary>
+        /// Gets the glyph at the given index.
+        /// </summary>
+        /// <param name="in"></param>
+        /// <param name="object"></param>
+        /// <returns></returns>
+        public Glyph Glyph(nuint @in, uint @object) => EguiException.Check(Vx.vx_galley_glyph(Pointer, @in, @object));
+
+        // generated from names::Galley::r#override
+        /// <summary>
+        /// Replaces the glyph at the given index.
+        /// </summary>
+        /// <param name="params"></param>
+        /// <param name="ref"></param>
+        public void Override(nuint @params, Glyph @ref) { Vx.vx_galley_override(Pointer, @params, @ref); EguiException.ThrowIfPanicked(); }
+    }
+
+    // generated from names::Glyph
+    /// <summary>
+    /// A character laid out in a row of text.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Glyph {
+        /// <summary>
+        /// The distance from the top of the row to the baseline.
+        /// </summary>
+        public float Base;
+
+        /// <summary>
+        /// The kind of glyph.
+        /// </summary>
+        public uint Type;
+
+        private byte _checked;
+
+        /// <summary>
+        /// Whether the glyph has been checked for kerning.
+        /// </summary>
+        public bool Checked {
+            get => _checked != 0;
+            set => _checked = value ? (byte)1 : (byte)0;
+        }
+
+        /// <summary>
+        /// How far the glyph is from the start of the string.
+        /// </summary>
+        public float ToString;
+    }
+
+    // generated from names::Wrap
+    /// <summary>
+    /// How text wraps at the end of a row.
+    /// </summary>
+    public enum Wrap {
+        /// <summary>
+        /// Text does not wrap.
+        /// </summary>
+        None,
+        /// <summary>
+        /// Text wraps between words.
+        /// </summary>
+        Default,
+        /// <summary>
+        /// Text wraps anywhere.
+        /// </summary>
+        Checked,
+    }
+
+    /// <summary>
+    /// Checks values of <see cref="Wrap"/> before they are passed to Rust.
+    /// </summary>
+    public static class WrapExtensions {
+        /// <summary>
+        /// Whether the value is a variant of the Rust enum. Passing any other value to Rust panics.
+        /// </summary>
+        public static bool IsDefined(this Wrap value) => Vx.vx_wrap_is_valid((long)value);
+    }
+}
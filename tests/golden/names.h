@@ -0,0 +1,60 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef NAMES_H
+#define NAMES_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// A character laid out in a row of text.
+typedef struct VxGlyph {
+    /// The distance from the top of the row to the baseline.
+    float base;
+    /// The kind of glyph.
+    uint32_t r#type;
+    /// Whether the glyph has been checked for kerning.
+    bool checked;
+    /// How far the glyph is from the start of the string.
+    float to_string;
+} VxGlyph;
+
+/// How text wraps at the end of a row.
+typedef enum VxWrap {
+    /// Text does not wrap.
+    VxWrap_None,
+    /// Text wraps between words.
+    VxWrap_Default,
+    /// Text wraps anywhere.
+    VxWrap_Checked,
+} VxWrap;
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+/// An opaque Rust object, which is only ever referred to by pointer.
+typedef struct VxObject VxObject;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+void vx_galley_drop(VxObject* value);
+bool vx_galley_equals(VxObject* self, const VxObject* other);
+uint64_t vx_galley_get_hash_code(VxObject* self);
+VxGlyph vx_galley_glyph(VxObject* self, size_t in, uint32_t object);
+void vx_galley_override(VxObject* self, size_t params, VxGlyph ref);
+bool vx_wrap_is_valid(int64_t value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* NAMES_H */
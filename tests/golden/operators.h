@@ -0,0 +1,48 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef OPERATORS_H
+#define OPERATORS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// A vector in 2D space.
+typedef struct VxVec2 {
+    /// The horizontal component.
+    float x;
+    /// The vertical component.
+    float y;
+} VxVec2;
+
+/// A rectangle, given by two corners.
+typedef struct VxRect {
+    /// The top-left corner.
+    VxVec2 min;
+    /// The bottom-right corner.
+    VxVec2 max;
+} VxRect;
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+VxRect vx_rect_add(VxRect left, VxRect right);
+VxRect vx_rect_neg(VxRect value);
+VxRect vx_rect_mul(VxRect left, float right);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* OPERATORS_H */
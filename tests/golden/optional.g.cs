@@ -0,0 +1,90 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash 4f2cdecdc5dd421b.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// An optional value which may be passed across the FFI boundary.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public struct VxOption<T> where T : unmanaged {
+        private byte _hasValue;
+        private T _value;
+
+        /// <summary>
+        /// Creates an optional value that is present.
+        /// </summary>
+        public VxOption(T value) {
+            _hasValue = 1;
+            _value = value;
+        }
+
+        /// <summary>
+        /// Whether the value is present.
+        /// </summary>
+        public bool HasValue => _hasValue != 0;
+
+        /// <summary>
+        /// Gets the value, throwing if it is not present.
+        /// </summary>
+        public T Value => HasValue ? _value : throw new InvalidOperationException("Optional value was not present.");
+    }
+
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Popup>(20);
+            Check<Pos2>(8);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    // generated from optional::Popup
+    /// <summary>
+    /// A window which opens above other widgets.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Popup {
+        /// <summary>
+        /// The width of the popup, or <c>None</c> to fit its contents.
+        /// </summary>
+        public VxOption<float> Width;
+
+        /// <summary>
+        /// Where the popup is pinned, or <c>None</c> to place it below the pointer.
+        /// </summary>
+        public VxOption<Pos2> Anchor;
+    }
+
+    // generated from optional::Pos2
+    /// <summary>
+    /// A position on the screen.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Pos2 {
+        /// <summary>
+        /// The horizontal position.
+        /// </summary>
+        public float X;
+
+        /// <summary>
+        /// The vertical position.
+        /// </summary>
+        public float Y;
+    }
+}
@@ -0,0 +1,45 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef OPTIONAL_H
+#define OPTIONAL_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct VxOption_f32 {
+    uint8_t has_value;
+    float value;
+} VxOption_f32;
+
+/// A position on the screen.
+typedef struct VxPos2 {
+    /// The horizontal position.
+    float x;
+    /// The vertical position.
+    float y;
+} VxPos2;
+
+typedef struct VxOption_VxPos2 {
+    uint8_t has_value;
+    VxPos2 value;
+} VxOption_VxPos2;
+
+/// A window which opens above other widgets.
+typedef struct VxPopup {
+    /// The width of the popup, or `None` to fit its contents.
+    VxOption_f32 width;
+    /// Where the popup is pinned, or `None` to place it below the pointer.
+    VxOption_VxPos2 anchor;
+} VxPopup;
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* OPTIONAL_H */
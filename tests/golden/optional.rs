@@ -0,0 +1,94 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+/// An optional value which may be passed across the FFI boundary.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxOption<T: Copy> {
+    /// Whether the value is present. This is `0` or `1`.
+    pub has_value: u8,
+    /// The value, which is only initialized if `has_value` is set.
+    pub value: std::mem::MaybeUninit<T>
+}
+
+impl<T: Copy> From<Option<T>> for VxOption<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(x) => Self { has_value: 1, value: std::mem::MaybeUninit::new(x) },
+            None => Self { has_value: 0, value: std::mem::MaybeUninit::zeroed() }
+        }
+    }
+}
+
+impl<T: Copy> From<VxOption<T>> for Option<T> {
+    fn from(value: VxOption<T>) -> Self {
+        // Safety: the value is always initialized when the flag is set.
+        (value.has_value != 0).then(|| unsafe { value.value.assume_init() })
+    }
+}
+
+// generated from optional::Popup
+/// A window which opens above other widgets.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxPopup {
+    /// The width of the popup, or `None` to fit its contents.
+    pub width: VxOption<f32>,
+    /// Where the popup is pinned, or `None` to place it below the pointer.
+    pub anchor: VxOption<VxPos2>,
+}
+
+impl From<Popup> for VxPopup {
+    fn from(value: Popup) -> Self {
+        Self {
+            width: value.width.map(Into::into).into(),
+            anchor: value.anchor.map(Into::into).into(),
+        }
+    }
+}
+
+impl From<VxPopup> for Popup {
+    fn from(value: VxPopup) -> Self {
+        Self {
+            width: Option::<f32>::from(value.width).map(Into::into),
+            anchor: Option::<VxPos2>::from(value.anchor).map(Into::into),
+        }
+    }
+}
+
+
+// generated from optional::Pos2
+/// A position on the screen.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxPos2 {
+    /// The horizontal position.
+    pub x: f32,
+    /// The vertical position.
+    pub y: f32,
+}
+
+impl From<Pos2> for VxPos2 {
+    fn from(value: Pos2) -> Self {
+        Self {
+            x: value.x.into(),
+            y: value.y.into(),
+        }
+    }
+}
+
+impl From<VxPos2> for Pos2 {
+    fn from(value: VxPos2) -> Self {
+        Self {
+            x: value.x.into(),
+            y: value.y.into(),
+        }
+    }
+}
+
+
+const _: () = assert!(size_of::<VxPopup>() == 20 && align_of::<VxPopup>() == 4);
+const _: () = assert!(size_of::<VxPos2>() == 8 && align_of::<VxPos2>() == 4);
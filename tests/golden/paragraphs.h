@@ -0,0 +1,48 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef PARAGRAPHS_H
+#define PARAGRAPHS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// How to align text.
+typedef enum VxAlign {
+    /// Aligns to the left.
+    VxAlign_Min,
+    /// Aligns to the center.
+    /// 
+    /// With a second paragraph
+    /// which wraps onto another line.
+    /// 
+    /// And a third.
+    VxAlign_Center,
+    /// # Right
+    /// Aligns to the right.
+    /// 
+    /// Mirrored in right-to-left text.
+    VxAlign_Max,
+} VxAlign;
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+bool vx_align_is_valid(int64_t value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PARAGRAPHS_H */
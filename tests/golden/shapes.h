@@ -0,0 +1,50 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef SHAPES_H
+#define SHAPES_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// A corner of a shape.
+typedef enum VxCorner {
+    /// Drawn as a quarter circle.
+    VxCorner_Rounded,
+    /// Drawn as a right angle.
+    VxCorner_Square,
+} VxCorner;
+
+/// The extent of a shape.
+typedef struct VxSize {
+    /// The horizontal extent.
+    float width;
+    /// The vertical extent.
+    float height;
+} VxSize;
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+/// An opaque Rust object, which is only ever referred to by pointer.
+typedef struct VxObject VxObject;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+bool vx_corner_is_valid(int64_t value);
+void vx_path_drop(VxObject* value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SHAPES_H */
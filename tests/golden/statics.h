@@ -0,0 +1,39 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef STATICS_H
+#define STATICS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// The space around a frame.
+typedef struct VxMargin {
+    /// The space on the left.
+    float left;
+    /// The space on the right.
+    float right;
+} VxMargin;
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+VxMargin vx_static_default_margin(void);
+float vx_static_default_spacing(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* STATICS_H */
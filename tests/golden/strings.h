@@ -0,0 +1,38 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef STRINGS_H
+#define STRINGS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+/// Text drawn in a single style.
+typedef struct VxLabel {
+    /// The text to draw.
+    VxString text;
+    /// The height of the text, in points.
+    float size;
+} VxLabel;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+void vx_label_drop(VxLabel value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* STRINGS_H */
@@ -0,0 +1,41 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef TUPLES_H
+#define TUPLES_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// Identifies a widget by the hash of its path.
+typedef struct VxId {
+    /// The hash.
+    uint64_t item0;
+} VxId;
+
+/// A width and a height, in points.
+typedef struct VxSize {
+    /// The width.
+    float item0;
+    /// The height.
+    float item1;
+} VxSize;
+
+/// A widget and how much space it takes up.
+typedef struct VxWidget {
+    /// The widget.
+    VxId id;
+    /// Its size.
+    VxSize size;
+} VxWidget;
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* TUPLES_H */
@@ -0,0 +1,76 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash bd87009788e8891e.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Id>(8);
+            Check<Size>(8);
+            Check<Widget>(16);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    // generated from tuples::Id
+    /// <summary>
+    /// Identifies a widget by the hash of its path.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Id {
+        /// <summary>
+        /// The hash.
+        /// </summary>
+        public ulong Item0;
+    }
+
+    // generated from tuples::Size
+    /// <summary>
+    /// A width and a height, in points.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Size {
+        /// <summary>
+        /// The width.
+        /// </summary>
+        public float Item0;
+
+        /// <summary>
+        /// The height.
+        /// </summary>
+        public float Item1;
+    }
+
+    // generated from tuples::Widget
+    /// <summary>
+    /// A widget and how much space it takes up.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Widget {
+        /// <summary>
+        /// The widget.
+        /// </summary>
+        public Id Id;
+
+        /// <summary>
+        /// Its size.
+        /// </summary>
+        public Size Size;
+    }
+}
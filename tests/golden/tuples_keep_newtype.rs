@@ -0,0 +1,95 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+// generated from tuples::Id
+/// Identifies a widget by the hash of its path.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxId {
+    /// The hash.
+    pub item0: u64,
+}
+
+impl From<Id> for VxId {
+    fn from(value: Id) -> Self {
+        Self {
+            item0: value.0.into(),
+        }
+    }
+}
+
+impl From<VxId> for Id {
+    fn from(value: VxId) -> Self {
+        Self {
+            0: value.item0.into(),
+        }
+    }
+}
+
+
+// generated from tuples::Size
+/// A width and a height, in points.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxSize {
+    /// The width.
+    pub item0: f32,
+    /// The height.
+    pub item1: f32,
+}
+
+impl From<Size> for VxSize {
+    fn from(value: Size) -> Self {
+        Self {
+            item0: value.0.into(),
+            item1: value.1.into(),
+        }
+    }
+}
+
+impl From<VxSize> for Size {
+    fn from(value: VxSize) -> Self {
+        Self {
+            0: value.item0.into(),
+            1: value.item1.into(),
+        }
+    }
+}
+
+
+// generated from tuples::Widget
+/// A widget and how much space it takes up.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxWidget {
+    /// The widget.
+    pub id: VxId,
+    /// Its size.
+    pub size: VxSize,
+}
+
+impl From<Widget> for VxWidget {
+    fn from(value: Widget) -> Self {
+        Self {
+            id: value.id.into(),
+            size: value.size.into(),
+        }
+    }
+}
+
+impl From<VxWidget> for Widget {
+    fn from(value: VxWidget) -> Self {
+        Self {
+            id: value.id.into(),
+            size: value.size.into(),
+        }
+    }
+}
+
+
+const _: () = assert!(size_of::<VxId>() == 8 && align_of::<VxId>() == 8);
+const _: () = assert!(size_of::<VxSize>() == 8 && align_of::<VxSize>() == 4);
+const _: () = assert!(size_of::<VxWidget>() == 16 && align_of::<VxWidget>() == 8);
@@ -0,0 +1,50 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef WIDGETS_H
+#define WIDGETS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// Provided by the user, and must be defined before this header is included.
+typedef struct Vec2 Vec2;
+
+/// A rectangle on the screen.
+typedef struct VxRect {
+    /// The top-left corner.
+    Vec2 min;
+    /// The bottom-right corner.
+    Vec2 max;
+} VxRect;
+
+/// An owned UTF-8 string, which must be freed with `vx_string_drop`.
+typedef struct VxString {
+    uint8_t* ptr;
+    size_t len;
+    size_t capacity;
+} VxString;
+
+/// An opaque Rust object, which is only ever referred to by pointer.
+typedef struct VxObject VxObject;
+
+void vx_string_drop(VxString value);
+VxString vx_string_from_utf8(const uint8_t* ptr, size_t len);
+VxString vx_last_error_message(void);
+void vx_id_drop(VxObject* value);
+void vx_response_drop(VxObject* value);
+bool vx_response_clicked(VxObject* self);
+Vec2 vx_response_drag_delta(VxObject* self);
+bool vx_response_dragged(VxObject* self);
+bool vx_response_hovered(VxObject* self);
+void vx_ui_drop(VxObject* value);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* WIDGETS_H */
@@ -0,0 +1,391 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash 9b3bd75b7394c683.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// An opaque Rust object, which is only ever referred to by pointer.
+    /// </summary>
+    public struct VxObject { }
+
+    /// <summary>
+    /// An owned UTF-8 string which may be passed across the FFI boundary.
+    /// The string is allocated by Rust, and must be freed with <c>Vx.vx_string_drop</c>.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe struct VxString {
+        private byte* _ptr;
+        private nuint _len;
+        private nuint _capacity;
+
+        /// <summary>
+        /// Copies a managed string into a new Rust-allocated string.
+        /// </summary>
+        public static VxString FromString(string value) {
+            var bytes = System.Text.Encoding.UTF8.GetBytes(value);
+            fixed (byte* ptr = bytes) {
+                return Vx.vx_string_from_utf8(ptr, (nuint)bytes.Length);
+            }
+        }
+
+        /// <summary>
+        /// Copies a Rust-allocated string into a managed string, and then frees it.
+        /// </summary>
+        public static string Take(VxString value) {
+            try {
+                return value.ToString();
+            }
+            finally {
+                Free(value);
+            }
+        }
+
+        /// <summary>
+        /// Frees a Rust-allocated string.
+        /// </summary>
+        public static void Free(VxString value) => Vx.vx_string_drop(value);
+
+        /// <summary>
+        /// Whether this is a null string, which Rust returns in place of a string that does not exist.
+        /// </summary>
+        internal bool IsNull => _ptr == null;
+
+        /// <summary>
+        /// Copies the contents of this string into a managed string.
+        /// </summary>
+        public override string ToString() => System.Text.Encoding.UTF8.GetString(_ptr, checked((int)_len));
+    }
+
+    /// <summary>
+    /// A panic in the Rust code called by the bindings. Any Rust objects involved may be left in an
+    /// inconsistent state.
+    /// </summary>
+    public sealed class EguiException : Exception {
+        /// <summary>
+        /// Creates an exception with the message of the panic.
+        /// </summary>
+        public EguiException(string message) : base(message) { }
+
+        /// <summary>
+        /// Throws the panic caught during the last call into Rust on this thread, if there was one.
+        /// </summary>
+        internal static unsafe void ThrowIfPanicked() {
+            var message = Vx.vx_last_error_message();
+            if (!message.IsNull) {
+                throw new EguiException(VxString.Take(message));
+            }
+        }
+
+        /// <summary>
+        /// Returns the value from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static T Check<T>(T value) {
+            ThrowIfPanicked();
+            return value;
+        }
+
+        /// <summary>
+        /// Returns the pointer from a call into Rust, unless the call panicked.
+        /// </summary>
+        internal static unsafe void* Check(void* value) {
+            ThrowIfPanicked();
+            return value;
+        }
+    }
+
+    /// <summary>
+    /// The functions exported by the native library.
+    /// </summary>
+    internal static unsafe partial class Vx {
+        private const string Library = "egui_native";
+
+        [LibraryImport(Library)]
+        internal static partial void vx_string_drop(VxString value);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_string_from_utf8(byte* ptr, nuint len);
+
+        [LibraryImport(Library)]
+        internal static partial VxString vx_last_error_message();
+
+        [LibraryImport(Library)]
+        internal static partial void vx_id_drop(VxObject* value);
+
+        [LibraryImport(Library)]
+        internal static partial void vx_response_drop(VxObject* value);
+
+        [LibraryImport(Library)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        internal static partial bool vx_response_clicked(VxObject* self);
+
+        [LibraryImport(Library)]
+        internal static partial System.Numerics.Vector2 vx_response_drag_delta(VxObject* self);
+
+        [LibraryImport(Library)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        internal static partial bool vx_response_dragged(VxObject* self);
+
+        [LibraryImport(Library)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        internal static partial bool vx_response_hovered(VxObject* self);
+
+        [LibraryImport(Library)]
+        internal static partial void vx_ui_drop(VxObject* value);
+    }
+
+    // generated from widgets::Id
+    /// <summary>
+    /// Identifies a widget across frames.
+    /// </summary>
+    public unsafe sealed class Id : IDisposable {
+        /// <summary>
+        /// The handle which owns the object.
+        /// </summary>
+        internal IdHandle Handle;
+
+        /// <summary>
+        /// Takes ownership of the provided object, or borrows it for the duration of a callback.
+        /// </summary>
+        internal Id(VxObject* pointer, bool owned = true) {
+            Handle = new IdHandle(pointer, owned);
+        }
+
+        /// <summary>
+        /// Gets the owned object, throwing if it has already been freed or moved.
+        /// </summary>
+        internal VxObject* Pointer => Handle.IsClosed
+            ? throw new ObjectDisposedException(nameof(Id))
+            : (VxObject*)Handle.DangerousGetHandle();
+
+        /// <summary>
+        /// Gives up ownership of the object, which is being moved into Rust. The handle is closed without freeing the object.
+        /// </summary>
+        internal VxObject* TakePointer() {
+            var pointer = Pointer;
+            Handle.SetHandleAsInvalid();
+            return pointer;
+        }
+
+        /// <summary>
+        /// Takes ownership of the object returned by a builder method, which consumed the previous one.
+        /// </summary>
+        internal void ReplacePointer(VxObject* pointer) {
+            Handle = new IdHandle(pointer);
+        }
+
+        /// <summary>
+        /// Frees the object. Calling this more than once has no effect.
+        /// </summary>
+        public void Dispose() => Handle.Dispose();
+    }
+
+    /// <summary>
+    /// Owns a heap-allocated <c>Id</c>, freeing it when released.
+    /// </summary>
+    public unsafe sealed class IdHandle : Microsoft.Win32.SafeHandles.SafeHandleZeroOrMinusOneIsInvalid {
+        /// <summary>
+        /// Creates an invalid handle, to be filled in by marshaling.
+        /// </summary>
+        public IdHandle() : base(true) { }
+
+        /// <summary>
+        /// Takes ownership of the provided object, or borrows it for the duration of a callback.
+        /// </summary>
+        internal IdHandle(VxObject* pointer, bool owned = true) : base(owned) {
+            SetHandle((IntPtr)pointer);
+        }
+
+        /// <inheritdoc/>
+        protected override bool ReleaseHandle() {
+            Vx.vx_id_drop((VxObject*)handle);
+            return true;
+        }
+    }
+
+    // generated from widgets::Rect
+    /// <summary>
+    /// A rectangle on the screen.
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Rect {
+        /// <summary>
+        /// The top-left corner.
+        /// </summary>
+        public System.Numerics.Vector2 Min;
+
+        /// <summary>
+        /// The bottom-right corner.
+        /// </summary>
+        public System.Numerics.Vector2 Max;
+    }
+
+    // generated from widgets::Response
+    /// <summary>
+    /// The result of adding a widget.
+    /// </summary>
+    public unsafe sealed class Response : IDisposable {
+        /// <summary>
+        /// The handle which owns the object.
+        /// </summary>
+        internal ResponseHandle Handle;
+
+        /// <summary>
+        /// Takes ownership of the provided object, or borrows it for the duration of a callback.
+        /// </summary>
+        internal Response(VxObject* pointer, bool owned = true) {
+            Handle = new ResponseHandle(pointer, owned);
+        }
+
+        /// <summary>
+        /// Gets the owned object, throwing if it has already been freed or moved.
+        /// </summary>
+        internal VxObject* Pointer => Handle.IsClosed
+            ? throw new ObjectDisposedException(nameof(Response))
+            : (VxObject*)Handle.DangerousGetHandle();
+
+        /// <summary>
+        /// Gives up ownership of the object, which is being moved into Rust. The handle is closed without freeing the object.
+        /// </summary>
+        internal VxObject* TakePointer() {
+            var pointer = Pointer;
+            Handle.SetHandleAsInvalid();
+            return pointer;
+        }
+
+        /// <summary>
+        /// Takes ownership of the object returned by a builder method, which consumed the previous one.
+        /// </summary>
+        internal void ReplacePointer(VxObject* pointer) {
+            Handle = new ResponseHandle(pointer);
+        }
+
+        /// <summary>
+        /// Frees the object. Calling this more than once has no effect.
+        /// </summary>
+        public void Dispose() => Handle.Dispose();
+
+        // generated from widgets::Response::clicked
+        /// <summary>
+        /// Whether the widget was clicked.
+        /// </summary>
+        /// <returns></returns>
+        public bool Clicked() => EguiException.Check(Vx.vx_response_clicked(Pointer));
+
+        // generated from widgets::Response::drag_delta
+        /// <summary>
+        /// How far the widget was dragged this frame.
+        /// </summary>
+        /// <returns></returns>
+        public System.Numerics.Vector2 DragDelta() => EguiException.Check(Vx.vx_response_drag_delta(Pointer));
+
+        // generated from widgets::Response::dragged
+        /// <summary>
+        /// Whether the widget is being dragged.
+        /// </summary>
+        /// <returns></returns>
+        public bool Dragged() => EguiException.Check(Vx.vx_response_dragged(Pointer));
+
+        // generated from widgets::Response::hovered
+        /// <summary>
+        /// Whether the pointer is over the widget.
+        /// </summary>
+        /// <returns></returns>
+        public bool Hovered() => EguiException.Check(Vx.vx_response_hovered(Pointer));
+    }
+
+    /// <summary>
+    /// Owns a heap-allocated <c>Response</c>, freeing it when released.
+    /// </summary>
+    public unsafe sealed class ResponseHandle : Microsoft.Win32.SafeHandles.SafeHandleZeroOrMinusOneIsInvalid {
+        /// <summary>
+        /// Creates an invalid handle, to be filled in by marshaling.
+        /// </summary>
+        public ResponseHandle() : base(true) { }
+
+        /// <summary>
+        /// Takes ownership of the provided object, or borrows it for the duration of a callback.
+        /// </summary>
+        internal ResponseHandle(VxObject* pointer, bool owned = true) : base(owned) {
+            SetHandle((IntPtr)pointer);
+        }
+
+        /// <inheritdoc/>
+        protected override bool ReleaseHandle() {
+            Vx.vx_response_drop((VxObject*)handle);
+            return true;
+        }
+    }
+
+    // generated from widgets::Ui
+    /// <summary>
+    /// A region of the screen.
+    /// </summary>
+    public unsafe sealed class Ui : IDisposable {
+        /// <summary>
+        /// The handle which owns the object.
+        /// </summary>
+        internal UiHandle Handle;
+
+        /// <summary>
+        /// Takes ownership of the provided object, or borrows it for the duration of a callback.
+        /// </summary>
+        internal Ui(VxObject* pointer, bool owned = true) {
+            Handle = new UiHandle(pointer, owned);
+        }
+
+        /// <summary>
+        /// Gets the owned object, throwing if it has already been freed or moved.
+        /// </summary>
+        internal VxObject* Pointer => Handle.IsClosed
+            ? throw new ObjectDisposedException(nameof(Ui))
+            : (VxObject*)Handle.DangerousGetHandle();
+
+        /// <summary>
+        /// Gives up ownership of the object, which is being moved into Rust. The handle is closed without freeing the object.
+        /// </summary>
+        internal VxObject* TakePointer() {
+            var pointer = Pointer;
+            Handle.SetHandleAsInvalid();
+            return pointer;
+        }
+
+        /// <summary>
+        /// Takes ownership of the object returned by a builder method, which consumed the previous one.
+        /// </summary>
+        internal void ReplacePointer(VxObject* pointer) {
+            Handle = new UiHandle(pointer);
+        }
+
+        /// <summary>
+        /// Frees the object. Calling this more than once has no effect.
+        /// </summary>
+        public void Dispose() => Handle.Dispose();
+    }
+
+    /// <summary>
+    /// Owns a heap-allocated <c>Ui</c>, freeing it when released.
+    /// </summary>
+    public unsafe sealed class UiHandle : Microsoft.Win32.SafeHandles.SafeHandleZeroOrMinusOneIsInvalid {
+        /// <summary>
+        /// Creates an invalid handle, to be filled in by marshaling.
+        /// </summary>
+        public UiHandle() : base(true) { }
+
+        /// <summary>
+        /// Takes ownership of the provided object, or borrows it for the duration of a callback.
+        /// </summary>
+        internal UiHandle(VxObject* pointer, bool owned = true) : base(owned) {
+            SetHandle((IntPtr)pointer);
+        }
+
+        /// <inheritdoc/>
+        protected override bool ReleaseHandle() {
+            Vx.vx_ui_drop((VxObject*)handle);
+            return true;
+        }
+    }
+}
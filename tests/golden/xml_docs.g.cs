@@ -0,0 +1,48 @@
+// <auto-generated>
+//     Generated by egui_inspect 0.1.0 from rustdoc JSON with hash eaa0b45ecbdda81f.
+//     Changes to this file will be lost when it is regenerated.
+// </auto-generated>
+
+using System;
+using System.Runtime.InteropServices;
+
+namespace Vortex.Gui {
+    /// <summary>
+    /// Verifies that the generated structs have the same size as their Rust counterparts.
+    /// </summary>
+    public static class VxLayoutChecks {
+        /// <summary>
+        /// Throws an exception if the size of any generated struct differs from the size in Rust.
+        /// </summary>
+        public static void Verify() {
+            Check<Mesh>(8);
+        }
+
+        private static void Check<T>(int size) {
+            var actual = Marshal.SizeOf<T>();
+            if (actual != size) {
+                throw new InvalidOperationException($"{typeof(T).Name} has size {actual}, but the Rust struct has size {size}.");
+            }
+        }
+    }
+
+    // generated from xml_docs::Mesh
+    /// <summary>
+    /// Triangles which share a texture, like a <c>Vec&lt;Vertex&gt;</c> &amp; its <c>Vec&lt;u32&gt;</c> indices.
+    /// </summary>
+    /// <remarks>
+    /// The vertices are &lt;b&gt;not&lt;/b&gt; deduplicated, so a &lt;Vertex&gt; may appear twice when x &lt; y &amp;&amp; y &gt; z.
+    /// </remarks>
+    [StructLayout(LayoutKind.Sequential)]
+    public unsafe partial struct Mesh {
+        /// <summary>
+        /// Draws <c>a &lt; b</c> and then &lt;code&gt;b &gt; a&lt;/code&gt;.
+        /// </summary>
+        public uint Indices;
+
+        /// <summary>
+        /// Holds up to 2&lt;sup&gt;16&lt;/sup&gt; vertices &amp; no more, or u16::MAX&lt;br&gt; if needed.
+        /// </summary>
+        public uint Vertices;
+    }
+}
@@ -0,0 +1,29 @@
+/* Autogenerated FFI bindings for egui. Do not edit by hand. */
+
+#ifndef XML_DOCS_H
+#define XML_DOCS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/// Triangles which share a texture, like a `Vec<Vertex>` & its `Vec<u32>` indices.
+/// 
+/// The vertices are <b>not</b> deduplicated, so a <Vertex> may appear twice when x < y && y > z.
+typedef struct VxMesh {
+    /// Draws `a < b` and then <code>b > a</code>.
+    uint32_t indices;
+    /// Holds up to 2<sup>16</sup> vertices & no more, or u16::MAX<br> if needed.
+    uint32_t vertices;
+} VxMesh;
+
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* XML_DOCS_H */
@@ -0,0 +1,39 @@
+//! Autogenerated FFI bindings for egui. Do not edit by hand.
+
+#![allow(dead_code, non_snake_case, unused_imports, clippy::all)]
+
+use egui::*;
+
+// generated from xml_docs::Mesh
+/// Triangles which share a texture, like a `Vec<Vertex>` & its `Vec<u32>` indices.
+/// 
+/// The vertices are <b>not</b> deduplicated, so a <Vertex> may appear twice when x < y && y > z.
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VxMesh {
+    /// Draws `a < b` and then <code>b > a</code>.
+    pub indices: u32,
+    /// Holds up to 2<sup>16</sup> vertices & no more, or u16::MAX<br> if needed.
+    pub vertices: u32,
+}
+
+impl From<Mesh> for VxMesh {
+    fn from(value: Mesh) -> Self {
+        Self {
+            indices: value.indices.into(),
+            vertices: value.vertices.into(),
+        }
+    }
+}
+
+impl From<VxMesh> for Mesh {
+    fn from(value: VxMesh) -> Self {
+        Self {
+            indices: value.indices.into(),
+            vertices: value.vertices.into(),
+        }
+    }
+}
+
+
+const _: () = assert!(size_of::<VxMesh>() == 8 && align_of::<VxMesh>() == 4);
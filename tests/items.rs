@@ -0,0 +1,449 @@
+//! Tests how each kind of item is rendered, and which items are generated.
+
+mod common;
+
+use common::{bindgen, config};
+use egui_inspect::ag::{DisplayCs, DisplayHeader, DisplayRs, Item, NamingConfig, Parameter, RenderContext, TypeReference, CS_HANDLE_SUPPORT};
+use egui_inspect::cfg::TargetCfg;
+use egui_inspect::config::{Config, InstantiationConfig};
+use egui_inspect::{Bindgen, BindgenError, GenerateError};
+
+#[test]
+fn naming_prefixes_apply_to_every_binding() -> Result<(), GenerateError> {
+    // The same items are rendered under the default names and under configured ones.
+    // The same items are rendered under the default names and under configured ones.
+    for (type_prefix, fn_prefix, class) in [("Vx", "vx_", "Vx"), ("Gx", "gx_", "Native")] {
+        let config = Config::parse(&format!("[naming]\ntype_prefix = \"{type_prefix}\"\nfn_prefix = \"{fn_prefix}\"\nlibrary_class_name = \"{class}\"")).unwrap();
+        let generate = |fixture: &str| Bindgen::new()
+            .input_json(format!("{}/tests/fixtures/{fixture}", env!("CARGO_MANIFEST_DIR")))
+            .config(config.clone())
+            .header("NAMING_H")
+            .generate();
+
+        // A struct, and a class with a default value and a destructor.
+        let style = generate("style.json")?;
+        let h = style.h.unwrap();
+        assert!(style.rs.contains(&format!("pub struct {type_prefix}Stroke {{")));
+        assert!(h.contains(&format!("typedef struct {type_prefix}Stroke {{")));
+        assert!(style.rs.contains(&format!("pub extern \"C\" fn {fn_prefix}visuals_default()")));
+        assert!(style.rs.contains(&format!("pub unsafe extern \"C\" fn {fn_prefix}visuals_drop(")));
+        assert!(h.contains(&format!("VxObject* {fn_prefix}visuals_default(void);")));
+        assert!(h.contains(&format!("void {fn_prefix}visuals_drop(VxObject* value);")));
+        assert!(style.cs.contains(&format!("internal static unsafe partial class {class} {{")));
+        assert!(style.cs.contains(&format!("internal static partial VxObject* {fn_prefix}visuals_default();")));
+        assert!(style.cs.contains(&format!("public static Visuals Default => new Visuals((VxObject*)EguiException.Check({class}.{fn_prefix}visuals_default()));")));
+        assert!(style.cs.contains(&format!("{class}.{fn_prefix}visuals_drop(pointer);")));
+
+        // A class with a constructor.
+        let handles = generate("handles.json")?;
+        assert!(handles.rs.contains(&format!("pub extern \"C\" fn {fn_prefix}context_new()")));
+        assert!(handles.h.unwrap().contains(&format!("VxObject* {fn_prefix}context_new(void);")));
+        assert!(handles.cs.contains(&format!("public Context() : this((VxObject*)EguiException.Check({class}.{fn_prefix}context_new())) {{ }}")));
+
+        // Nothing exported or called keeps the default prefixes, besides the shared support types.
+        if type_prefix != "Vx" {
+            for output in [&style.rs, &handles.rs] {
+                assert!(!output.contains("extern \"C\" fn vx_") && !output.contains("VxStroke"));
+            }
+            for output in [&h, &style.cs, &handles.cs] {
+                assert!(!output.contains("vx_") && !output.contains("Vx.") && !output.contains("VxStroke"));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn enum_reprs_set_the_underlying_type() -> Result<(), GenerateError> {
+    let output = bindgen("reprs.json").generate()?;
+    assert!(output.cs.contains("    public enum Key : byte {"));
+    assert!(output.rs.contains("#[repr(u8)]\npub enum VxKey {"));
+    assert!(output.cs.contains("    public enum PointerButton : int {"));
+    assert!(output.rs.contains("#[repr(i32)]\npub enum VxPointerButton {"));
+
+    // Enums without a representation are given a `#[repr(C)]` mirror, which matches the C# default.
+    assert!(output.cs.contains("    public enum Side {"));
+    assert!(output.rs.contains("#[repr(C)]\npub enum VxSide {"));
+
+    // Structs embedding the enums are laid out with their underlying types.
+    assert!(output.cs.contains("            Check<KeyPress>(2);\n"));
+    assert!(output.rs.contains("const _: () = assert!(size_of::<VxKeyPress>() == 2 && align_of::<VxKeyPress>() == 1);"));
+    assert!(output.rs.contains("const _: () = assert!(size_of::<VxClick>() == 8 && align_of::<VxClick>() == 4);"));
+    Ok(())
+}
+
+#[test]
+fn flag_enums_allow_combinations() -> Result<(), GenerateError> {
+    // Combinations only exist in C#, since a Rust enum may only hold its declared values.
+    let output = bindgen("flags.json").generate()?;
+    assert!(output.cs.contains("[Flags]\n    public enum Edges : int {"));
+    assert!(output.cs.contains("        None = 0,"));
+    assert!(output.cs.contains("Vertical = 3,"));
+    assert!(output.cs.contains("    public enum Level {"));
+    assert_eq!(output.cs.matches("[Flags]").count(), 1);
+    assert!(!output.rs.contains("None = 0"));
+    Ok(())
+}
+
+#[test]
+fn data_enums_become_tagged_unions() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."handles::RawInput::push_event"]
+        params = ["event: handles::Event"]
+        call = "this.events.push(event)"
+    "#);
+    let output = bindgen("handles.json").config(config).generate()?;
+    assert!(output.rs.contains("#[repr(C, u32)]\npub enum VxEvent {"));
+    assert!(output.rs.contains("    Key {\n        /// The key, in the current keyboard layout.\n        key: VxKey,"));
+    assert!(output.rs.contains("Event::Key { key, physical_key, pressed, modifiers } => Self::Key { key: key.into(), physical_key: physical_key.map(Into::into).into(), pressed: pressed.into(), modifiers: modifiers.into() },"));
+    assert!(output.rs.contains("VxEvent::Text(item0) => Self::Text(unsafe { item0.into_string() }),"));
+    assert!(output.rs.contains("            _ => Self::Unknown,\n"));
+    assert!(output.rs.contains(concat!(
+        "pub unsafe extern \"C\" fn vx_raw_input_push_event(this: *mut VxObject<RawInput>, event: VxEvent) {\n",
+        "    vx_catch(|| { match (&mut *VxObject::get_mut(this), event.into()) { (this, event) => this.events.push(event) }; });"
+    )));
+    assert!(output.cs.contains("public void PushEvent(Event @event) { Vx.vx_raw_input_push_event(Pointer, @event); EguiException.ThrowIfPanicked(); }"));
+    assert!(output.cs.contains("    public static Event Key(Key key, VxOption<Key> physicalKey, bool pressed, Modifiers modifiers) => new Event { Tag = EventTag.Key, Payload = new EventPayload { Key = new EventKey(key, physicalKey, pressed, modifiers) } };"));
+    assert!(output.cs.contains("    public static Event Text(string item0) => new Event { Tag = EventTag.Text, Payload = new EventPayload { Text = new EventText(item0) } };"));
+    assert!(output.cs.contains("    public static Event PointerGone => new Event { Tag = EventTag.PointerGone };"));
+    assert!(output.cs.contains("public enum EventTag : uint {"));
+    assert!(output.cs.contains("        [FieldOffset(0)]\n        public EventPointerMoved PointerMoved;"));
+    assert!(output.cs.contains("            this = default;\n            _item0 = VxString.FromString(item0);\n"));
+    assert!(!output.cs.contains("EventScreenshot"));
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn classes_with_lifetimes_are_static() -> Result<(), GenerateError> {
+    // Builders like windows are often generic over a lifetime, and their consuming methods are chained on the same C# object.
+    let config = config(r#"
+        [renames]
+        "panels::Window" = "EguiWindow"
+
+        [methods."panels::Window::new"]
+        params = ["title: &str"]
+    "#);
+    let output = bindgen("panels.json").config(config).generate()?;
+
+    assert!(output.rs.contains("impl VxClass for Window<'static> {"));
+    assert!(output.rs.contains("VxHandle::into_heap(Window::new(unsafe { title.into_string() }.as_str()))"));
+    assert!(output.cs.contains("public EguiWindow(string title)"));
+    assert!(output.cs.contains("public EguiWindow Resizable(bool resizable) { ReplacePointer((VxObject*)EguiException.Check(Vx.vx_window_resizable(TakePointer(), resizable))); return this; }"));
+    assert!(output.cs.contains("public void Show(Context ctx, Action<Ui> addContents) {"));
+    assert!(output.cs.contains("Vx.vx_window_show(TakePointer(), (ctx ?? throw new ArgumentNullException(nameof(ctx))).Pointer,"));
+
+    // The flag would have to outlive the window, which C# cannot promise.
+    assert!(output.report.skipped.iter().any(|x| x.path == "panels::Window::open"));
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn delegates_render_in_every_language() {
+    let callback = Item::Delegate {
+        name: "UiCallback".to_string(),
+        module: "egui".to_string(),
+        params: vec![Parameter { name: "ui".to_string(), ty: TypeReference::Handle { class: "Ui".to_string(), mutable: true } }],
+        ret: None,
+        naming: NamingConfig::default(),
+        deprecation: None,
+        origin: None,
+        docs: "Adds the contents of a region.".to_string()
+    };
+    let ctx = RenderContext::new(std::slice::from_ref(&callback), NamingConfig::default());
+    assert_eq!(DisplayCs(&callback, &ctx).to_string(), concat!(
+        "/// <summary>\n",
+        "/// Adds the contents of a region.\n",
+        "/// </summary>\n",
+        "[UnmanagedFunctionPointer(CallingConvention.Cdecl)]\n",
+        "public unsafe delegate void UiCallback(VxObject* ui, void* userData);\n"
+    ));
+    assert_eq!(DisplayRs(&callback, &ctx).to_string(), concat!(
+        "/// Adds the contents of a region.\n",
+        "pub type VxUiCallback = unsafe extern \"C\" fn(ui: *mut VxObject<Ui>, user_data: *mut std::ffi::c_void);\n"
+    ));
+    assert_eq!(DisplayHeader(&callback).to_string(), concat!(
+        "/// Adds the contents of a region.\n",
+        "typedef void (*VxUiCallback)(VxObject* ui, void* user_data);\n"
+    ));
+
+    // Parameters of the delegate type are passed as a function pointer and user data.
+    let show = Item::Function {
+        name: "show".to_string(),
+        module: "egui".to_string(),
+        path: "egui::show".to_string(),
+        params: vec![Parameter { name: "add_contents".to_string(), ty: TypeReference::Delegate { rs_name: "VxUiCallback".to_string(), cs_name: "UiCallback".to_string() } }],
+        ret: None,
+        naming: NamingConfig::default(),
+        deprecation: None,
+        origin: None,
+        docs: String::new()
+    };
+    let rs = DisplayRs(&show, &ctx).to_string();
+    assert!(rs.contains("pub extern \"C\" fn vx_show(add_contents: VxUiCallback, add_contents_user_data: *mut std::ffi::c_void) {"));
+    assert!(rs.contains("egui::show(move |ui| unsafe { add_contents(&mut VxObject::borrowed(ui), add_contents_user_data) })"));
+    let cs = DisplayCs(&show, &ctx).to_string();
+    assert!(cs.contains("public static void Show(Action<Ui> addContents) {"));
+    assert!(cs.contains("Vx.vx_show(Marshal.GetFunctionPointerForDelegate(addContentsCallback), null);"));
+    assert_eq!(show.native_functions()[0].params[1].0, "addContentsUserData");
+}
+
+#[test]
+fn enum_discriminants() -> Result<(), GenerateError> {
+    let output = bindgen("discriminants.json").generate()?;
+    assert!(output.cs.contains("    public enum Offset {"));
+    assert!(output.cs.contains("        Before = -1,"));
+    assert!(output.rs.contains("#[repr(C)]\npub enum VxOffset {\n    /// Before the cursor.\n    Before = -1,"));
+
+    // `u32::MAX` widens an enum without a representation, but fits in a `#[repr(u32)]` enum.
+    assert!(output.cs.contains("    public enum Wide : long {"));
+    assert!(output.cs.contains("        Full = 4294967295,"));
+    assert!(output.rs.contains("#[repr(i64)]\npub enum VxWide {"));
+    assert!(output.cs.contains("    public enum Channel : uint {"));
+    assert!(output.cs.contains("        All = 4294967295,"));
+
+    // `u64::MAX` is passed to Rust as an `i64`, so it is checked as `-1`.
+    assert!(output.cs.contains("    public enum Huge : ulong {"));
+    assert!(output.cs.contains("        Max = 18446744073709551615,"));
+    assert!(output.rs.contains("#[repr(u64)]\npub enum VxHuge {"));
+    assert!(output.rs.contains("            0 => Ok(Self::Zero),\n            -1 => Ok(Self::Max),"));
+
+    assert!(output.cs.contains("    public enum Signed : sbyte {"));
+    assert_eq!((output.report.generated, output.report.total), (5, 5));
+    Ok(())
+}
+
+#[test]
+fn method_instantiations_are_overloads() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."widgets::Ui::slider"]
+        params = ["value: &mut T", "min: T", "max: T", "text: &str"]
+        returns = "widgets::Response"
+        call = "this.add(Slider::new(value, min..=max).text(text))"
+        instantiations = ["f32", "f64", "i32"]
+
+        [methods."widgets::Ui::drag_value"]
+        params = ["value: &mut T"]
+        returns = "widgets::Response"
+        call = "this.add(DragValue::new(value))"
+        instantiations = ["f32", "i32"]
+    "#);
+    let output = bindgen("widgets.json").config(config).generate()?;
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_ui_slider_f32(this: *mut VxObject<Ui>, value: *mut f32, min: f32, max: f32, text: VxString)"));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_ui_slider_f64(this: *mut VxObject<Ui>, value: *mut f64, min: f64, max: f64, text: VxString)"));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_ui_drag_value_i32(this: *mut VxObject<Ui>, value: *mut i32)"));
+    assert!(output.cs.contains("public Response Slider(ref float value, float min, float max, string text) {"));
+    assert!(output.cs.contains("public Response Slider(ref double value, double min, double max, string text) {"));
+    assert!(output.cs.contains("public Response Slider(ref int value, int min, int max, string text) {"));
+    assert!(output.cs.contains("var result = Vx.vx_ui_slider_i32(Pointer, &valueNative, min, max, VxString.FromString(text));"));
+    assert!(output.cs.contains("public Response DragValue(ref float value) {"));
+    Ok(())
+}
+
+#[test]
+fn property_methods() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."widgets::Response::clicked"]
+        property = true
+
+        [methods."widgets::Response::rect"]
+        returns = "widgets::Rect"
+        call = "this.rect"
+        property = true
+    "#);
+    let output = bindgen("widgets.json").config(config).generate()?;
+    assert!(output.cs.contains("public bool Clicked => EguiException.Check(Vx.vx_response_clicked(Pointer));"));
+    assert!(output.cs.contains("public bool Hovered() => EguiException.Check(Vx.vx_response_hovered(Pointer));"));
+    assert!(output.cs.contains("public Rect Rect => EguiException.Check(Vx.vx_response_rect(Pointer));"));
+    assert!(output.rs.contains(concat!(
+        "pub unsafe extern \"C\" fn vx_response_rect(this: *const VxObject<Response>) -> MaybeUninit<VxRect> {\n",
+        "    vx_catch(|| match &*VxObject::get(this) { this => this.rect }.into())\n"
+    )));
+    Ok(())
+}
+
+#[test]
+fn method_docs_describe_parameters() -> Result<(), GenerateError> {
+    let output = bindgen("function_docs.json").generate()?;
+    assert!(output.cs.contains(concat!(
+        "        /// <summary>\n",
+        "        /// Scrolls to the given offset.\n",
+        "        /// </summary>\n",
+        "        /// <param name=\"offset\">The distance from the top, in points.</param>\n",
+        "        /// <param name=\"animate\">Whether to scroll smoothly rather than jump, if <c>offset &lt; max</c>.</param>\n",
+        "        /// <returns>Whether the area moved.</returns>\n",
+        "        /// <exception cref=\"EguiException\">\n",
+        "        /// If <c>offset</c> is negative.\n",
+        "        /// </exception>\n",
+        "        /// <remarks>\n",
+        "        /// <para><b>Safety</b></para>\n",
+        "        /// <para>The area must not be shown while scrolling.</para>\n",
+        "        /// </remarks>\n",
+        "        public bool ScrollTo(float offset, bool animate)"
+    )));
+    assert!(output.rs.contains("/// * `offset` - The distance from the top, in points.\n"));
+
+    // Parameters without descriptions are still listed.
+    assert!(output.cs.contains(concat!(
+        "        /// <param name=\"from\"></param>\n",
+        "        /// <param name=\"to\"></param>\n",
+        "        /// <param name=\"t\"></param>\n",
+        "        /// <returns></returns>\n",
+        "        public static float Lerp(float from, float to, float t)"
+    )));
+    Ok(())
+}
+
+#[test]
+fn handle_classes_use_the_dispose_pattern() -> Result<(), GenerateError> {
+    let output = bindgen("widgets.json").generate()?;
+
+    // The base class is written in full within the namespace.
+    let indented = CS_HANDLE_SUPPORT.lines().map(|x| if x.is_empty() { "\n".to_string() } else { format!("    {x}\n") }).collect::<String>();
+    assert!(output.cs.contains(&indented));
+    for member in ["~VxHandle() {", "public void Dispose() {", "GC.SuppressFinalize(this);", "private bool _disposed;", "protected void ThrowIfDisposed() {", "internal VxObject* TakePointer() {"] {
+        assert!(CS_HANDLE_SUPPORT.contains(member), "{member}");
+    }
+
+    // Each class only supplies its constructor and destructor.
+    assert!(output.cs.contains(concat!(
+        "    // generated from widgets::Id\n",
+        "    /// <summary>\n",
+        "    /// Identifies a widget across frames.\n",
+        "    /// </summary>\n",
+        "    public unsafe sealed class Id : VxHandle {\n",
+        "        /// <summary>\n",
+        "        /// Takes ownership of the provided object, or borrows it for the duration of a callback.\n",
+        "        /// </summary>\n",
+        "        internal Id(VxObject* pointer, bool owned = true) : base(pointer, owned) { }\n",
+        "\n",
+        "        /// <inheritdoc/>\n",
+        "        protected override void Free(VxObject* pointer) {\n",
+        "            Vx.vx_id_drop(pointer);\n",
+        "        }\n",
+        "    }\n"
+    )));
+    Ok(())
+}
+
+#[test]
+fn private_structs_become_classes() -> Result<(), GenerateError> {
+    // The most important types of the bundled egui have private fields, so they are owned by C# through handles.
+    let output = Bindgen::new().generate()?;
+    for (class, symbol) in [("Context", "context"), ("Ui", "ui")] {
+        assert!(output.cs.contains(&format!("    public unsafe sealed class {class} : VxHandle {{\n")), "{class}");
+        assert!(!output.cs.contains(&format!("struct {class} {{")), "{class}");
+        assert!(output.rs.contains(&format!("impl VxClass for {class} {{\n")), "{class}");
+        assert!(output.rs.contains(&format!("pub unsafe extern \"C\" fn vx_{symbol}_drop(value: *mut VxObject<{class}>) {{\n")), "{class}");
+    }
+    Ok(())
+}
+
+#[test]
+fn cfg_disabled_items_are_skipped() -> Result<(), GenerateError> {
+    let mut target = TargetCfg::default();
+    target.set("target_arch = \"x86_64\"").unwrap();
+    target.enable_feature("persistence");
+
+    let output = bindgen("cfg.json")
+        .target_cfg(target)
+        .generate()?;
+
+    assert!(output.cs.contains("public enum Storage {"));
+    assert!(!output.cs.contains("WasmSize"));
+    assert_eq!(output.report.skipped[0].path, "tiny::WasmSize");
+    Ok(())
+}
+
+#[test]
+fn malformed_items_are_reported() -> Result<(), GenerateError> {
+    let output = bindgen("nameless_variant.json").generate()?;
+
+    assert_eq!(output.report.errors, [BindgenError::MissingName { path: "tiny::Direction".to_string(), member: Some("variant") }]);
+    assert!(!output.cs.contains("enum Direction"));
+    assert!(output.cs.contains("public static float Area(Size size)"));
+    Ok(())
+}
+
+#[test]
+fn unknown_variants() -> Result<(), GenerateError> {
+    let bindgen = bindgen("non_exhaustive.json");
+
+    let output = bindgen.clone().generate()?;
+    assert!(!output.cs.contains("Unknown"));
+    assert!(output.rs.contains("_ => panic!(\"`Theme` has a variant which did not exist when the bindings were generated\"),"));
+    assert!(output.cs.contains("public unsafe sealed class Margin : VxHandle {"));
+
+    let output = bindgen.unknown_variants(true).generate()?;
+    assert!(output.cs.contains("Unknown = 2,"));
+    assert!(output.rs.contains("_ => Self::Unknown,"));
+    Ok(())
+}
+
+#[test]
+fn generic_instantiations() -> Result<(), GenerateError> {
+    let range = InstantiationConfig {
+        name: "RangeInclusiveF32".to_string(),
+        fields: vec![("start".to_string(), "f32".to_string()), ("end".to_string(), "f32".to_string())]
+    };
+    let id = InstantiationConfig {
+        name: "OptionalId".to_string(),
+        fields: vec![("value".to_string(), "u32".to_string())]
+    };
+
+    let output = bindgen("generic.json")
+        .instantiation("core::ops::range::RangeInclusive<f32>", range)
+        .instantiation("core::option::Option<core::num::nonzero::NonZeroU32>", id)
+        .generate()?;
+
+    assert!(output.cs.contains("public unsafe partial struct RangeInclusiveF32 {"));
+    assert!(output.cs.contains("public RangeInclusiveF32 Range;"));
+    assert!(output.cs.contains("public OptionalId Id;"));
+    assert!(output.cs.contains("public static float Clamp(float value, RangeInclusiveF32 range)"));
+
+    // Instantiations which are not configured are reported, and the structs using them are not copied.
+    let unknown = &output.report.unknown_instantiations[0];
+    assert_eq!(unknown.ty, "core::ops::range::RangeInclusive<usize>");
+    assert_eq!(unknown.fields, ["generic::Page::items"]);
+    assert!(!output.cs.contains("public unsafe partial struct Page {"));
+    Ok(())
+}
+
+#[test]
+fn newtypes_are_unwrapped() -> Result<(), GenerateError> {
+    let bindgen = bindgen("newtypes.json");
+
+    // Tuple structs and `#[repr(transparent)]` structs with one field are marshaled as that field, so a
+    // struct containing them is still copied. Their Rust mirrors only convert, and are passed like the field.
+    let output = bindgen.clone().generate()?;
+    assert!(output.cs.contains("    public unsafe partial struct Hit {"));
+    assert!(output.cs.contains("        public ulong Id;\n"));
+    assert!(output.cs.contains("        public uint Handle;\n"));
+    assert!(output.rs.contains("pub struct VxHit {\n    /// The widget which was hit.\n    pub id: VxId,"));
+    assert!(output.rs.contains("#[repr(transparent)]\npub struct VxId {\n    pub item0: u64,\n}"));
+    assert!(!output.cs.contains("struct Id") && !output.cs.contains("struct Handle"));
+
+    let output = bindgen.keep_newtype("newtypes::Id").generate()?;
+    assert!(output.cs.contains("    public unsafe partial struct Id {"));
+    assert!(output.cs.contains("        public Id Id;\n"));
+    assert!(output.cs.contains("        public uint Handle;\n"));
+    Ok(())
+}
+
+#[test]
+fn hidden_items() -> Result<(), GenerateError> {
+    let bindgen = bindgen("hidden.json");
+
+    let output = bindgen.clone().generate()?;
+    assert!(output.cs.contains("struct Visible"));
+    assert!(!output.cs.contains("Internal") && !output.rs.contains("Internal"));
+    assert!(!output.cs.contains("Helper") && !output.rs.contains("Helper"));
+    assert_eq!((output.report.generated, output.report.total, output.report.hidden), (1, 1, 2));
+    assert!(output.report.to_string().ends_with("Generated 1 / 1 items (2 hidden items skipped)"));
+
+    let output = bindgen.include_hidden(true).generate()?;
+    assert!(output.cs.contains("struct Internal") && output.cs.contains("struct Helper"));
+    assert_eq!((output.report.generated, output.report.total, output.report.hidden), (3, 3, 0));
+    Ok(())
+}
@@ -0,0 +1,584 @@
+//! Tests how each kind of type crosses the boundary, in the Rust shims and the C# wrappers which call them.
+
+mod common;
+
+use common::{bindgen, config, fixture};
+use egui_inspect::ag::{DisplayCs, DisplayRs, Item, Parameter, RenderContext, StructField, TypeReference, RS_CHAR_SUPPORT, RS_MIRROR_MUT_SUPPORT, RS_OBJECT_SUPPORT, RS_PANIC_SUPPORT, RS_TEXTURE_ID_SUPPORT};
+use egui_inspect::{Bindgen, BindgenContext, GenerateError};
+
+#[test]
+fn named_structs_are_copied_by_value() -> Result<(), GenerateError> {
+    let bindgen = bindgen("chain.json");
+    let output = bindgen.clone().generate()?;
+    assert!(output.cs.contains("    public unsafe partial struct Frame {\n        /// <summary>\n        /// The space around the frame.\n        /// </summary>\n        public Margin Margin;\n    }"));
+    assert!(output.cs.contains("        public Side Left;\n"));
+    assert!(output.cs.contains("    public unsafe partial struct Side {\n        /// <summary>\n        /// The width in points.\n        /// </summary>\n        public float Width;\n    }"));
+    assert!(output.rs.contains("pub struct VxFrame {\n    /// The space around the frame.\n    pub margin: VxMargin,\n}"));
+    assert!(output.rs.contains("pub struct VxMargin {\n    /// The left side.\n    pub left: VxSide,"));
+    assert!(output.rs.contains("impl From<Frame> for VxFrame {\n    fn from(value: Frame) -> Self {\n        Self {\n            margin: value.margin.into(),"));
+    assert!(output.rs.contains("impl From<VxFrame> for Frame {\n    fn from(value: VxFrame) -> Self {\n        Self {\n            margin: value.margin.into(),"));
+
+    // Renaming the inner struct changes the field's C# type, but not its Rust mirror.
+    let output = bindgen.rename("chain::Margin", "Spacing").generate()?;
+    assert!(output.cs.contains("        public Spacing Margin;\n"));
+    assert!(output.rs.contains("    pub margin: VxMargin,\n"));
+    Ok(())
+}
+
+#[test]
+fn mutably_borrowed_objects_stay_owned_by_cs() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."select::ComboBox::from_label"]
+        params = ["label: &str"]
+
+        [methods."select::ComboBox::selected_text"]
+        params = ["selected_text: &str"]
+    "#);
+    let output = bindgen("select.json").config(config).generate()?;
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_combo_box_show_ui(this: *mut VxObject<ComboBox>, ui: *mut VxObject<Ui>, add_contents: VxUiCallback,"));
+    assert!(output.rs.contains("VxHandle::from_heap(this).into_inner().show_ui(&mut *VxObject::get_mut(ui), move |ui| unsafe {"));
+    assert!(output.cs.contains("public static ComboBox CreateFromLabel(string label) =>"));
+    assert!(output.cs.contains("public ComboBox SelectedText(string selectedText) {"));
+    assert!(output.cs.contains("public void ShowUi(Ui ui, Action<Ui> addContents) {"));
+    assert!(output.cs.contains("Vx.vx_combo_box_show_ui(TakePointer(), (ui ?? throw new ArgumentNullException(nameof(ui))).Pointer,"));
+
+    // Shims which take objects are unsafe, even if they are free functions.
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_theme_switch(ui: *mut VxObject<Ui>) {\n    vx_catch(|| { select::theme_switch(&mut *VxObject::get_mut(ui)); });"));
+    assert!(output.cs.contains("public static void ThemeSwitch(Ui ui) {"));
+
+    // The parameter itself is classified as a mutable borrow, rather than as an object moved out of C#.
+    let mut ctx = BindgenContext::from_paths(&[fixture("select.json")], false).unwrap();
+    ctx.collect();
+    let Some(Item::Class { methods, .. }) = ctx.items().iter().find(|x| x.name() == "ComboBox") else { unreachable!() };
+    let show_ui = methods.iter().find(|x| x.name == "show_ui").unwrap();
+    let ui = &show_ui.params[0];
+    assert_eq!(*ui, Parameter { name: "ui".to_string(), ty: TypeReference::HandleMut { class: "Ui".to_string() } });
+    assert_eq!(ui.rs_argument(&RenderContext::default()), "&mut *VxObject::get_mut(ui)");
+    assert_eq!(ui.ty.cs_to_native("ui"), "(ui ?? throw new ArgumentNullException(nameof(ui))).Pointer");
+    Ok(())
+}
+
+#[test]
+fn in_out_values_are_written_back() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."widgets::Ui::checkbox"]
+        params = ["text: &str"]
+
+        [methods."widgets::Ui::text_edit_singleline"]
+        params = ["text: &mut String"]
+
+        [methods."widgets::Ui::slider"]
+        params = ["value: &mut f32", "min: f32", "max: f32"]
+        returns = "widgets::Response"
+        call = "this.add(Slider::new(value, min..=max))"
+    "#);
+    let output = bindgen("widgets.json").config(config).generate()?;
+    assert!(output.rs.contains(concat!(
+        "pub unsafe extern \"C\" fn vx_ui_checkbox(this: *mut VxObject<Ui>, checked: *mut bool, text: VxString) -> MaybeUninit<*mut VxObject<Response>> {\n",
+        "    vx_catch(|| VxHandle::into_heap(VxObject::get_mut(this).checkbox(unsafe { &mut *checked }, unsafe { text.into_string() }.as_str())))\n"
+    )));
+    assert!(output.rs.contains("VxObject::get_mut(this).text_edit_singleline(&mut *unsafe { VxStringMut::new(text) })"));
+    assert!(output.rs.contains("match (&mut *VxObject::get_mut(this), unsafe { &mut *value }, min, max) { (this, value, min, max) => this.add(Slider::new(value, min..=max)) }"));
+    assert!(output.cs.contains(concat!(
+        "        public Response TextEditSingleline(ref string text) {\n",
+        "            var textNative = VxString.FromString(text);\n",
+        "            var result = Vx.vx_ui_text_edit_singleline(Pointer, &textNative);\n",
+        "            text = VxString.Take(textNative);\n",
+        "            return new Response((VxObject*)EguiException.Check(result));\n",
+        "        }\n"
+    )));
+    assert!(output.cs.contains("public Response Checkbox(ref bool @checked, string text) {"));
+    assert!(output.cs.contains("public Response Slider(ref float value, float min, float max) {"));
+    assert!(output.cs.contains("public bool Clicked() =>"));
+    Ok(())
+}
+
+#[test]
+fn closures_are_passed_as_callbacks() -> Result<(), GenerateError> {
+    let output = bindgen("handles.json").generate()?;
+    assert!(output.rs.contains("pub type VxUiCallback = unsafe extern \"C\" fn(ui: *mut VxObject<Ui>, user_data: *mut std::ffi::c_void);"));
+    assert!(output.rs.contains(concat!(
+        "pub unsafe extern \"C\" fn vx_ui_horizontal(this: *mut VxObject<Ui>, add_contents: VxUiCallback, add_contents_user_data: *mut std::ffi::c_void) {\n",
+        "    vx_catch(|| { VxObject::get_mut(this).horizontal(move |ui| unsafe { add_contents(&mut VxObject::borrowed(ui), add_contents_user_data) }); });\n"
+    )));
+    assert!(output.cs.contains("public unsafe delegate void UiCallback(VxObject* ui, void* userData);"));
+    assert!(output.cs.contains(concat!(
+        "        public void Vertical(Action<Ui> addContents) {\n",
+        "            System.Runtime.ExceptionServices.ExceptionDispatchInfo addContentsError = null;\n",
+        "            UiCallback addContentsCallback = (nativeUi, _) => {\n",
+        "                using var borrowedUi = new Ui(nativeUi, false);\n",
+        "                try {\n",
+        "                    addContents(borrowedUi);\n",
+        "                }\n",
+        "                catch (Exception exception) {\n",
+        "                    addContentsError ??= System.Runtime.ExceptionServices.ExceptionDispatchInfo.Capture(exception);\n",
+        "                }\n",
+        "            };\n",
+        "            Vx.vx_ui_vertical(Pointer, Marshal.GetFunctionPointerForDelegate(addContentsCallback), null);\n",
+        "            GC.KeepAlive(addContentsCallback);\n",
+        "            addContentsError?.Throw();\n",
+        "            EguiException.ThrowIfPanicked();\n",
+        "        }\n"
+    )));
+    assert!(output.cs.contains("public void Scope(Action<Ui> addContents) {"));
+    Ok(())
+}
+
+#[test]
+fn struct_conversions() -> Result<(), GenerateError> {
+    let output = bindgen("nested.json").generate()?;
+    assert!(output.rs.contains("impl From<Placement> for VxPlacement {"));
+    assert!(output.rs.contains("impl From<VxAnchor> for Anchor {"));
+    assert!(output.rs.contains("            anchor: value.anchor.into(),\n            size: value.size.map(Into::into),"));
+    assert!(output.rs.contains("            Align::Center => Self::Center,"));
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn views_borrow_vectors_for_the_frame() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."handles::FullOutput::tessellate"]
+        params = ["ctx: &handles::Context"]
+        returns = "Vec<handles::ClippedPrimitive>"
+        call = "ctx.tessellate(std::mem::take(&mut this.shapes), this.pixels_per_point)"
+    "#);
+    let output = bindgen("handles.json").config(config).generate()?;
+    assert!(output.rs.contains("const _: () = assert!(size_of::<VxVertex>() == 20 && align_of::<VxVertex>() == 4);"));
+    assert!(output.rs.contains("impl From<&Mesh> for VxMesh {"));
+    assert!(output.rs.contains("            indices: VxSlice::frame(value.indices.as_slice()),\n"));
+    assert!(output.rs.contains("            texture_id: value.texture_id.clone().into(),\n"));
+    assert!(!output.rs.contains("impl From<VxMesh> for Mesh"));
+    assert!(output.rs.contains("            Primitive::Mesh(item0) => Self::Mesh(<VxMesh as From<&Mesh>>::from(&(*item0))),\n"));
+    assert!(output.rs.contains(concat!(
+        "pub unsafe extern \"C\" fn vx_full_output_tessellate(this: *mut VxObject<FullOutput>, ctx: *const VxObject<Context>) -> MaybeUninit<VxSlice<VxClippedPrimitive>> {\n",
+        "    vx_catch(|| vx_frame_store(match (&mut *VxObject::get_mut(this), &*VxObject::get(ctx)) {"
+    )));
+    assert!(output.cs.contains("public VxSlice<ClippedPrimitive> Tessellate(Context ctx) =>"));
+    assert!(output.cs.contains("        public VxSlice<Vertex> Vertices;\n"));
+    assert!(output.cs.contains("if (_epoch != 0 && !Vx.vx_frame_kept(_epoch)) {"));
+    assert!(!output.cs.contains("public static Primitive Mesh("));
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn tuples_and_arrays_are_instantiated() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [instantiations."(handles::TextureId, handles::ImageDelta)"]
+        name = "TextureUpdate"
+        fields = ["id: handles::TextureId", "delta: handles::ImageDelta"]
+
+        [instantiations."[usize; 2]"]
+        name = "ImageSize"
+        fields = ["width: usize", "height: usize"]
+
+        [methods."handles::FullOutput::take_textures_delta"]
+        returns = "handles::TexturesDelta"
+        call = "std::mem::take(&mut this.textures_delta)"
+    "#);
+    let output = bindgen("handles.json").config(config).generate()?;
+    assert!(output.rs.contains("impl From<VxImageSize> for [usize; 2] {\n    fn from(value: VxImageSize) -> Self {\n        [value.width.into(), value.height.into()]\n"));
+    assert!(output.rs.contains("impl From<&(TextureId, ImageDelta)> for VxTextureUpdate {"));
+    assert!(output.rs.contains("            delta: <VxImageDelta as From<&ImageDelta>>::from(&value.1),\n"));
+    assert!(output.rs.contains("            set: VxSlice::frame_map(value.set.as_slice(), |x| <VxTextureUpdate as From<&(TextureId, ImageDelta)>>::from(x)),\n"));
+    assert!(output.rs.contains("            free: VxSlice::frame_map(value.free.as_slice(), |x| x.clone().into()),\n"));
+    assert!(output.rs.contains("            ImageData::Color(item0) => Self::Color(<VxColorImage as From<&ColorImage>>::from(&(*item0))),\n"));
+    assert!(output.rs.contains("            pos: value.pos.clone().map(Into::into).into(),\n"));
+    assert!(output.rs.contains("    vx_catch(|| vx_frame_keep(match &mut *VxObject::get_mut(this) { this => std::mem::take(&mut this.textures_delta) }))\n"));
+    assert!(output.cs.contains("public TexturesDelta TakeTexturesDelta() =>"));
+    assert!(output.cs.contains("        public VxSlice<float> Pixels;\n"));
+    assert!(output.cs.contains("        public VxOption<ImageSize> Pos;\n"));
+
+    // Unlike the other events, screenshots hold a view, so they are left out rather than making events a view.
+    assert!(output.rs.contains("impl From<Event> for VxEvent {"));
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn owned_strings_are_freed() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."handles::FullOutput::take_platform_output"]
+        returns = "handles::PlatformOutput"
+        call = "std::mem::take(&mut this.platform_output)"
+
+        [methods."handles::PlatformOutput::cursor_icon"]
+        returns = "handles::CursorIcon"
+        call = "this.cursor_icon"
+        property = true
+
+        [methods."handles::PlatformOutput::copied_text"]
+        returns = "String"
+        call = "this.copied_text.clone()"
+        property = true
+
+        [methods."handles::PlatformOutput::open_url"]
+        returns = "Option<handles::OpenUrl>"
+        call = "this.open_url.clone()"
+        property = true
+
+        [methods."handles::PlatformOutput::ime"]
+        returns = "Option<handles::IMEOutput>"
+        call = "this.ime"
+        property = true
+    "#);
+    let output = bindgen("handles.json").config(config).generate()?;
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_open_url_drop(value: VxOpenUrl) {\n    vx_catch(|| { drop(OpenUrl::from(value)); });\n}\n"));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_output_command_drop(value: VxOutputCommand) {"));
+    assert!(output.rs.contains("            url: unsafe { value.url.into_string() },\n"));
+    assert!(!output.rs.contains("fn vx_i_m_e_output_drop"));
+    assert!(output.rs.contains("    vx_catch(|| VxHandle::into_heap(match &mut *VxObject::get_mut(this) { this => std::mem::take(&mut this.platform_output) }))\n"));
+    assert!(output.rs.contains("    vx_catch(|| match &*VxObject::get(this) { this => this.copied_text.clone() }.into())\n"));
+    assert!(output.rs.contains("    vx_catch(|| match &*VxObject::get(this) { this => this.open_url.clone() }.map(Into::into).into())\n"));
+    assert!(output.cs.contains("public static void Free(OpenUrl value) { Vx.vx_open_url_drop(value); EguiException.ThrowIfPanicked(); }"));
+    assert!(output.cs.contains("public static void Free(OutputCommand value) { Vx.vx_output_command_drop(value); EguiException.ThrowIfPanicked(); }"));
+    assert!(output.cs.contains("public CursorIcon CursorIcon =>"));
+    assert!(output.cs.contains("public string CopiedText => VxString.Take("));
+    assert!(output.cs.contains("public VxOption<OpenUrl> OpenUrl =>"));
+    assert!(output.cs.contains("public VxOption<IMEOutput> Ime =>"));
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn uncopyable_fields_are_defaulted() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [defaults]
+        paths = ["style::Visuals"]
+
+        [methods."style::Context::get_visuals"]
+        returns = "style::Visuals"
+        call = "this.style().visuals.clone()"
+    "#);
+    let output = bindgen("style.json").config(config).generate()?;
+
+    // Every struct in the chain is copied, down to the colors.
+    assert!(output.cs.contains("public unsafe partial struct Visuals {"));
+    assert!(output.cs.contains("public Widgets Widgets;"));
+    assert!(output.cs.contains("public WidgetVisuals Hovered;"));
+    assert!(output.cs.contains("public Stroke FgStroke;"));
+    assert!(output.cs.contains("public Color32 Color;"));
+    assert!(!output.cs.contains("TextStyles"));
+    assert!(output.cs.contains("public void SetVisuals(Visuals visuals)"));
+    assert!(output.cs.contains("public Visuals GetVisuals()"));
+
+    // The map is filled from `Default` when the mirror is converted back.
+    assert!(output.rs.contains("            window_stroke: value.window_stroke.into(),\n            ..Default::default()\n        }\n"));
+    assert!(output.rs.contains(".set_visuals(visuals.into());"));
+    let field = output.report.skipped.iter().find(|x| x.path == "style::Visuals::text_styles").unwrap();
+    assert_eq!(field.reason.to_string(), "has type `alloc::collections::btree::map::BTreeMap<alloc::string::String, f32>`, so it is left to its default value");
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn enum_arguments_are_checked() -> Result<(), GenerateError> {
+    let output = bindgen("validation.json").generate()?;
+    assert!(output.rs.contains("pub extern \"C\" fn vx_verbose(level: i32) -> MaybeUninit<bool> {"));
+    assert!(output.rs.contains("verbose(VxLevel::try_from(level as i64).expect(\"Invalid discriminant for `VxLevel`\").into())"));
+    assert!(output.rs.contains("            5 => Ok(Self::High),\n            _ => Err(value)"));
+    assert!(output.rs.contains("pub extern \"C\" fn vx_level_is_valid(value: i64) -> bool {"));
+    assert!(output.cs.contains("public static bool IsDefined(this Level value) => Vx.vx_level_is_valid((long)value);"));
+    Ok(())
+}
+
+#[test]
+fn bound_arguments_keep_primitive_types() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."panels::ScrollArea::auto_shrink"]
+        params = ["auto_shrink: bool"]
+
+        [methods."panels::Grid::new"]
+        params = ["id_salt: &str"]
+    "#);
+    let output = bindgen("panels.json").config(config).generate()?;
+    assert!(output.rs.contains("VxHandle::from_heap(this).into_inner().auto_shrink(auto_shrink)"));
+    assert!(output.cs.contains("public ScrollArea AutoShrink(bool autoShrink) {"));
+    assert!(output.cs.contains("public ScrollArea MaxHeight(float maxHeight) {"));
+
+    // Grids are identified by a string, which they hash into an `Id`.
+    assert!(output.rs.contains("VxHandle::into_heap(Grid::new(unsafe { id_salt.into_string() }.as_str()))"));
+    assert!(output.cs.contains("public Grid(string idSalt)"));
+    assert!(output.cs.contains("public Grid Striped(bool striped) {"));
+    assert!(output.cs.contains("public void EndRow() {"));
+
+    // Containers show their contents through a callback, however their output wraps its result.
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_scroll_area_show(this: *mut VxObject<ScrollArea>, ui: *mut VxObject<Ui>, add_contents: VxUiCallback,"));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_grid_show(this: *mut VxObject<Grid>, ui: *mut VxObject<Ui>, add_contents: VxUiCallback,"));
+    assert_eq!(output.cs.matches("public void Show(Ui ui, Action<Ui> addContents) {").count(), 2);
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn color32_is_shared() -> Result<(), GenerateError> {
+    let output = bindgen("paint.json").generate()?;
+    assert!(output.cs.contains("public struct Color32 {"));
+    assert!(output.cs.contains("public unsafe partial struct Stroke {"));
+    assert!(output.cs.contains("public unsafe partial struct Shadow {"));
+    assert!(output.cs.contains("Check<Shadow>(8);"));
+    assert!(output.cs.contains("public static Rgba Linear(Color32 color) => EguiException.Check(Vx.vx_linear(color));"));
+    assert!(output.rs.contains("pub color: Color32,"));
+    Ok(())
+}
+
+#[test]
+fn texture_ids_and_byte_slices() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."textures::Ui::image"]
+        params = ["texture_id: epaint::TextureId", "size: emath::vec2::Vec2"]
+        returns = "textures::Response"
+        call = "this.image(load::SizedTexture { id: texture_id, size })"
+
+        [methods."textures::Context::load_texture"]
+        params = ["name: &str", "width: usize", "height: usize", "rgba: &[u8]"]
+        returns = "epaint::TextureId"
+        call = "this.tex_manager().write().alloc(name.to_owned(), ColorImage::from_rgba_unmultiplied([width, height], rgba).into(), Default::default())"
+    "#);
+    let output = bindgen("textures.json").config(config).generate()?;
+    assert!(output.cs.contains("public struct TextureId {"));
+    assert!(output.cs.contains("public static TextureId User(ulong id) => new TextureId(1, id);"));
+    assert!(output.cs.contains("public Response Image(TextureId textureId, System.Numerics.Vector2 size) =>"));
+    assert!(output.rs.contains("texture_id.into(), size.into()) { (this, texture_id, size) => this.image(load::SizedTexture { id: texture_id, size }) }"));
+    assert!(output.rs.contains(RS_TEXTURE_ID_SUPPORT));
+
+    // Byte slices are pinned by C# and borrowed in place.
+    assert!(output.cs.contains("public TextureId LoadTexture(string name, nuint width, nuint height, ReadOnlySpan<byte> rgba) {"));
+    assert!(output.cs.contains("fixed (byte* rgbaPtr = rgba) {"));
+    assert!(output.cs.contains("Vx.vx_context_load_texture(Pointer, VxString.FromString(name), width, height, new(rgbaPtr, rgba.Length))"));
+    assert!(output.rs.contains("rgba: VxSlice<u8>) -> MaybeUninit<VxTextureId> {"));
+    assert!(output.rs.contains("unsafe { rgba.as_slice::<u8>() }"));
+
+    // Slices in the original signatures are borrowed in the same way, unlike in constructors.
+    assert!(output.cs.contains("public void SetPixels(ReadOnlySpan<Color32> pixels) {"));
+    assert!(output.rs.contains("VxObject::get_mut(this).set_pixels(unsafe { pixels.as_slice::<Color32>() })"));
+    assert!(!output.cs.contains("FromPixels"));
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn string_buffers_are_not_converted_at_each_call() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."widgets::Ui::text_edit_singleline"]
+        params = ["text: &mut StringBuffer"]
+
+        [methods."widgets::Ui::text_edit_multiline"]
+        params = ["text: &mut StringBuffer"]
+        returns = "widgets::Response"
+        call = "this.text_edit_multiline(text)"
+    "#);
+    let output = bindgen("widgets.json").config(config).generate()?;
+
+    // The buffer is passed as the string it owns, and is not converted at each call.
+    assert!(output.rs.contains(concat!(
+        "pub unsafe extern \"C\" fn vx_ui_text_edit_singleline(this: *mut VxObject<Ui>, text: *mut VxStringBuffer) -> MaybeUninit<*mut VxObject<Response>> {\n",
+        "    vx_catch(|| VxHandle::into_heap(VxObject::get_mut(this).text_edit_singleline(unsafe { VxStringBuffer::get_mut(text) })))\n"
+    )));
+    assert!(output.rs.contains("match (&mut *VxObject::get_mut(this), unsafe { VxStringBuffer::get_mut(text) }) { (this, text) => this.text_edit_multiline(text) }"));
+    assert!(output.cs.contains("public Response TextEditSingleline(EguiStringBuffer text) => new Response((VxObject*)EguiException.Check(Vx.vx_ui_text_edit_singleline(Pointer, (text ?? throw new ArgumentNullException(nameof(text))).Pointer)));"));
+    assert!(output.cs.contains("public Response TextEditMultiline(EguiStringBuffer text) =>"));
+
+    // The buffer class converts its text only when it is read or written.
+    assert!(output.cs.contains("public unsafe sealed class EguiStringBuffer : IDisposable {"));
+    assert!(output.cs.contains("get => System.Text.Encoding.UTF8.GetString(Vx.vx_string_buffer_get(Pointer), checked((int)Vx.vx_string_buffer_len(Pointer)));"));
+    assert!(output.cs.contains("internal static partial VxStringBuffer* vx_string_buffer_new();"));
+    for symbol in ["new", "set", "get", "len", "drop"] {
+        assert!(output.rs.contains(&format!("pub unsafe extern \"C\" fn vx_string_buffer_{symbol}(")) || output.rs.contains(&format!("pub extern \"C\" fn vx_string_buffer_{symbol}(")));
+    }
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn objects_are_tagged_and_moved() -> Result<(), GenerateError> {
+    let output = bindgen("handles.json").generate()?;
+    assert!(output.rs.contains(RS_OBJECT_SUPPORT));
+    assert!(output.rs.contains("impl VxClass for Context {\n    const TAG: u32 = 1;\n}"));
+    assert!(output.rs.contains("impl VxClass for Ui {\n    const TAG: u32 = 7;\n}"));
+    assert!(output.rs.contains("        1 => \"Context\",\n        2 => \"FullOutput\",\n        3 => \"ImageDelta\",\n        4 => \"PlatformOutput\",\n"));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_context_drop(value: *mut VxObject<Context>) {\n    vx_discard_panic(|| drop(VxHandle::from_heap(value)));"));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_context_request_repaint(this: *const VxObject<Context>) {\n    vx_catch(|| { VxObject::get(this).request_repaint(); });"));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_ui_set_enabled(this: *mut VxObject<Ui>, enabled: bool) {\n    vx_catch(|| { VxObject::get_mut(this).set_enabled(enabled); });"));
+    assert!(!output.rs.contains("use crate::{VxHandle, VxObject};"));
+
+    // Frames are run with a callback which borrows the context, and objects passed by value are moved out of C#.
+    assert!(output.rs.contains(concat!(
+        "pub unsafe extern \"C\" fn vx_context_run(this: *const VxObject<Context>, new_input: *mut VxObject<RawInput>, run_ui: VxContextCallback, run_ui_user_data: *mut std::ffi::c_void) -> MaybeUninit<*mut VxObject<FullOutput>> {\n",
+        "    vx_catch(|| VxHandle::into_heap(VxObject::get(this).run(VxHandle::from_heap(new_input).into_inner(), move |context| unsafe { run_ui(&mut VxObject::borrowed(context), run_ui_user_data) })))\n"
+    )));
+    assert!(output.cs.contains("public FullOutput Run(RawInput newInput, Action<Context> runUi) {"));
+    assert!(output.cs.contains("public void BeginPass(RawInput newInput) { Vx.vx_context_begin_pass(Pointer, (newInput ?? throw new ArgumentNullException(nameof(newInput))).TakePointer()); EguiException.ThrowIfPanicked(); }"));
+    assert!(output.cs.contains("public FullOutput EndPass() => new FullOutput((VxObject*)EguiException.Check(Vx.vx_context_end_pass(Pointer)));"));
+
+    // Objects passed as arguments are moved and checked as well, with null references caught by C# before the call.
+    let ctx = Parameter { name: "ctx".to_string(), ty: TypeReference::Handle { class: "Context".to_string(), mutable: true } };
+    assert_eq!(ctx.rs_argument(&Default::default()), "VxHandle::from_heap(ctx).into_inner()");
+    assert_eq!(ctx.ty.cs_to_native("ctx"), "(ctx ?? throw new ArgumentNullException(nameof(ctx))).TakePointer()");
+
+    // Objects which cannot be modified are only borrowed for the call, and C# keeps them.
+    let ctx = Parameter { ty: TypeReference::Handle { class: "Context".to_string(), mutable: false }, ..ctx };
+    assert_eq!(ctx.rs_argument(&Default::default()), "&*VxObject::get(ctx)");
+    assert_eq!(ctx.ty.cs_to_native("ctx"), "(ctx ?? throw new ArgumentNullException(nameof(ctx))).Pointer");
+    Ok(())
+}
+
+#[test]
+fn handle_fields_hold_object_pointers() {
+    // Fields which refer to objects are raw pointers on both sides, and only Rust knows the class.
+    let ui = StructField { name: "ui".to_string(), rename: None, ty: TypeReference::Handle { class: "Ui".to_string(), mutable: true }, deprecation: None, docs: "The ui.".to_string() };
+    let ctx = RenderContext::default();
+    assert_eq!(DisplayRs(&ui, &ctx).to_string(), "/// The ui.\npub ui: *mut VxObject<Ui>,");
+    assert_eq!(DisplayCs(&ui, &ctx).to_string(), "/// <summary>\n/// The ui.\n/// </summary>\npublic VxObject* Ui;\n");
+
+    let ui = StructField { ty: TypeReference::Handle { class: "Ui".to_string(), mutable: false }, ..ui };
+    assert_eq!(DisplayRs(&ui, &ctx).to_string(), "/// The ui.\npub ui: *const VxObject<Ui>,");
+    assert_eq!(DisplayCs(&ui, &ctx).to_string(), "/// <summary>\n/// The ui.\n/// </summary>\npublic VxObject* Ui;\n");
+}
+
+#[test]
+fn panics_become_exceptions() -> Result<(), GenerateError> {
+    let output = bindgen("panics.json").generate()?;
+    assert!(output.rs.contains(RS_PANIC_SUPPORT));
+    assert!(output.rs.contains("pub extern \"C\" fn vx_divide(numerator: i32, denominator: i32) -> MaybeUninit<i32> {\n    vx_catch(|| panics::divide(numerator.into(), denominator.into()).into())\n}"));
+    assert!(output.rs.contains("pub extern \"C\" fn vx_check(value: f32) {\n    vx_catch(|| { panics::check(value.into()); });\n}"));
+    assert!(output.rs.contains("pub extern \"C\" fn vx_last_error_message() -> VxString {"));
+    assert!(output.cs.contains("public sealed class EguiException : Exception {"));
+    assert!(output.cs.contains("public static int Divide(int numerator, int denominator) => EguiException.Check(Vx.vx_divide(numerator, denominator));"));
+    assert!(output.cs.contains("public static void Check(float value) { Vx.vx_check(value); EguiException.ThrowIfPanicked(); }"));
+    Ok(())
+}
+
+#[test]
+fn fixed_width_sizes() -> Result<(), GenerateError> {
+    let bindgen = bindgen("sizes.json");
+
+    let native = bindgen.clone().pointer_width(32).generate()?;
+    assert!(native.cs.contains("public nuint Start;"));
+    assert!(native.cs.contains("public nint Offset;"));
+    assert!(native.cs.contains("if (IntPtr.Size == 4) Check<Span>(8);"));
+    assert!(native.rs.contains("#[cfg(target_pointer_width = \"32\")]\nconst _: () = assert!(size_of::<VxSpan>() == 8"));
+
+    let fixed = bindgen.pointer_width(32).fixed_width_sizes(true).generate()?;
+    assert!(fixed.cs.contains("public ulong Start;"));
+    assert!(fixed.cs.contains("public long Offset;"));
+    assert!(fixed.cs.contains("        Check<Span>(16);"));
+    assert!(fixed.rs.contains("pub start: VxUsize,"));
+    assert!(fixed.rs.contains("impl From<VxUsize> for usize {"));
+    Ok(())
+}
+
+#[test]
+fn marshal_bools() -> Result<(), GenerateError> {
+    let output = Bindgen::new().generate()?;
+    assert!(output.cs.contains("private byte _shiftDown;"));
+    assert!(output.cs.contains("Check<Modifiers>(5);"));
+    assert!(output.rs.contains("assert!(size_of::<VxModifiers>() == 5 && align_of::<VxModifiers>() == 1);"));
+
+    let output = Bindgen::new().marshal_bools(true).generate()?;
+    assert!(output.cs.contains("public bool ShiftDown;"));
+    assert!(!output.cs.contains("_shiftDown"));
+    assert!(output.cs.contains("Check<Modifiers>(5);"));
+    Ok(())
+}
+
+#[test]
+fn chars_are_marshaled_as_codepoints() -> Result<(), GenerateError> {
+    let output = bindgen("chars.json").generate()?;
+    assert!(output.rs.contains(RS_CHAR_SUPPORT));
+    assert!(output.rs.contains("pub symbol: VxChar,"));
+    assert!(output.cs.contains("private uint _symbol;"));
+    assert!(output.cs.contains("public static System.Text.Rune First(Glyph glyph) => new System.Text.Rune(EguiException.Check(Vx.vx_first(glyph)));"));
+    Ok(())
+}
+
+#[test]
+fn enums_and_structs_are_passed_by_ref() -> Result<(), GenerateError> {
+    let config = config(r#"
+        [methods."select::Ui::selectable_value"]
+        params = ["current_value: &mut T", "selected_value: T", "text: &str"]
+        returns = "select::Response"
+        call = "this.selectable_value(current_value, selected_value, text)"
+        instantiations = ["select::Theme"]
+
+        [methods."select::Ui::radio_value"]
+        params = ["current_value: &mut T", "selected_value: T", "text: &str"]
+        returns = "select::Response"
+        call = "this.radio_value(current_value, selected_value, text)"
+        instantiations = ["select::Theme"]
+    "#);
+    let output = bindgen("select.json").config(config).generate()?;
+    assert!(output.rs.contains(RS_MIRROR_MUT_SUPPORT));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_ui_selectable_value_theme(this: *mut VxObject<Ui>, current_value: *mut i32, selected_value: i32, text: VxString)"));
+    assert!(output.rs.contains("&mut *unsafe { VxMirrorMut::<_, Theme>::checked(current_value, |x| VxTheme::try_from(x as i64).expect(\"Invalid discriminant for `VxTheme`\")) }"));
+    assert!(output.cs.contains("public Response SelectableValue(ref Theme currentValue, Theme selectedValue, string text) {"));
+    assert!(output.cs.contains("var result = Vx.vx_ui_selectable_value_theme(Pointer, &currentValueNative, selectedValue, VxString.FromString(text));"));
+    assert!(output.cs.contains("public Response RadioValue(ref Theme currentValue, Theme selectedValue, string text) {"));
+    assert!(syn::parse_file(&output.rs).is_ok());
+
+    // Structs are copied through their mirrors, unless they are known types with the same layout in Rust.
+    let output = bindgen("colors.json").rename("colors::Ui::color_edit_button_srgba", "ColorEditButton").generate()?;
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_ui_color_edit_button_hsva(this: *mut VxObject<Ui>, hsva: *mut VxHsva)"));
+    assert!(output.rs.contains("VxObject::get_mut(this).color_edit_button_hsva(&mut *unsafe { VxMirrorMut::<_, Hsva>::new(hsva) })"));
+    assert!(output.rs.contains("colors::color_picker_hsva_2d(&mut *VxObject::get_mut(ui), &mut *unsafe { VxMirrorMut::<_, Hsva>::new(hsva) }, VxAlpha::try_from("));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_ui_color_edit_button(this: *mut VxObject<Ui>, srgba: *mut Color32)"));
+    assert!(output.rs.contains("VxObject::get_mut(this).color_edit_button_srgba(unsafe { &mut *srgba })"));
+    assert!(output.cs.contains("public Response ColorEditButton(ref Color32 srgba) {"));
+    assert!(output.cs.contains("public Response ColorEditButtonHsva(ref Hsva hsva) {"));
+    assert!(output.cs.contains("public static bool ColorPickerHsva2D(Ui ui, ref Hsva hsva, Alpha alpha) {"));
+    assert!(syn::parse_file(&output.rs).is_ok());
+    Ok(())
+}
+
+#[test]
+fn blittable_values_are_copied_directly() -> Result<(), GenerateError> {
+    // `Pos2` has the same layout on both sides, so C# passes it as is, and views borrow vectors of it.
+    let output = bindgen("strategies.json").generate()?;
+    assert!(output.cs.contains("public static Pos2 Offset(Pos2 pos, float by) => EguiException.Check(Vx.vx_offset(pos, by));"));
+    assert!(output.rs.contains("pub extern \"C\" fn vx_offset(pos: VxPos2, by: f32) -> MaybeUninit<VxPos2> {\n    vx_catch(|| strategies::offset(pos.into(), by.into()).into())\n}"));
+    assert!(output.rs.contains("vertices: VxSlice::frame(value.vertices.as_slice()),"));
+    Ok(())
+}
+
+#[test]
+fn converted_values_are_copied_through_mirrors() -> Result<(), GenerateError> {
+    // `Align` has no explicit repr, so Rust converts it to and from its mirror, and checks the discriminants it is given.
+    let output = bindgen("strategies.json").generate()?;
+    assert!(output.cs.contains("public static Align Flip(Align align) => EguiException.Check(Vx.vx_flip(align));"));
+    assert!(output.rs.contains("pub extern \"C\" fn vx_flip(align: i32) -> MaybeUninit<VxAlign> {\n    vx_catch(|| strategies::flip(VxAlign::try_from(align as i64).expect(\"Invalid discriminant for `VxAlign`\").into()).into())\n}"));
+    assert!(output.rs.contains("impl From<Align> for VxAlign {"));
+    assert!(output.rs.contains("impl From<VxAlign> for Align {"));
+    Ok(())
+}
+
+#[test]
+fn handles_are_passed_as_object_pointers() -> Result<(), GenerateError> {
+    // `Context` stays on the Rust heap, so only a pointer to it crosses the boundary.
+    let output = bindgen("strategies.json").generate()?;
+    assert!(output.cs.contains("public static float Scale(Context ctx) => EguiException.Check(Vx.vx_scale((ctx ?? throw new ArgumentNullException(nameof(ctx))).Pointer));"));
+    assert!(output.cs.contains("internal static partial float vx_scale(VxObject* ctx);"));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_scale(ctx: *const VxObject<Context>) -> MaybeUninit<f32> {\n    vx_catch(|| strategies::scale(&*VxObject::get(ctx)).into())\n}"));
+    Ok(())
+}
+
+#[test]
+fn strings_are_copied_and_freed_by_the_receiver() -> Result<(), GenerateError> {
+    // C# copies the argument into a buffer which Rust takes, and takes the returned buffer in turn.
+    let output = bindgen("strategies.json").generate()?;
+    assert!(output.cs.contains("public static string Shout(string text) => VxString.Take(EguiException.Check(Vx.vx_shout(VxString.FromString(text))));"));
+    assert!(output.rs.contains("pub extern \"C\" fn vx_shout(text: VxString) -> MaybeUninit<VxString> {\n    vx_catch(|| strategies::shout(unsafe { text.into_string() }.as_str()).into())\n}"));
+    Ok(())
+}
+
+#[test]
+fn views_are_kept_until_the_next_one() -> Result<(), GenerateError> {
+    // `Mesh` holds a vector, so Rust keeps the returned value and C# reads its vertices through a slice.
+    let output = bindgen("strategies.json").generate()?;
+    assert!(output.cs.contains("public static Mesh Tessellate(Context ctx) => EguiException.Check(Vx.vx_tessellate((ctx ?? throw new ArgumentNullException(nameof(ctx))).Pointer));"));
+    assert!(output.cs.contains("        public VxSlice<Pos2> Vertices;\n"));
+    assert!(output.rs.contains("pub unsafe extern \"C\" fn vx_tessellate(ctx: *const VxObject<Context>) -> MaybeUninit<VxMesh> {\n    vx_catch(|| vx_frame_keep(strategies::tessellate(&*VxObject::get(ctx))))\n}"));
+    assert!(output.rs.contains("impl From<&Mesh> for VxMesh {"));
+    assert!(!output.rs.contains("impl From<VxMesh> for Mesh {"));
+    Ok(())
+}
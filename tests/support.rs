@@ -0,0 +1,226 @@
+//! Runs the Rust support code which is copied into every generated file, against stand-ins for the egui types.
+
+// The generated files allow the same lints, since the support code is written for older editions too.
+#![allow(dead_code, unsafe_op_in_unsafe_fn)]
+
+mod texture_id {
+    #[derive(Debug, PartialEq)]
+    enum TextureId { Managed(u64), User(u64) }
+
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/support/texture_id.rs"));
+
+    #[test]
+    fn round_trips() {
+        assert_eq!(TextureId::from(VxTextureId::from(TextureId::Managed(0))), TextureId::Managed(0));
+        assert_eq!(TextureId::from(VxTextureId::from(TextureId::User(7))), TextureId::User(7));
+
+        // C# may set any nonzero kind for user textures.
+        assert_eq!(TextureId::from(VxTextureId { user: 2, id: 3 }), TextureId::User(3));
+    }
+}
+
+mod char {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/support/char.rs"));
+
+    #[test]
+    fn invalid_codepoints_are_replaced() {
+        for c in ['a', '🦀'] {
+            assert_eq!(char::from(VxChar::from(c)), c);
+        }
+        assert_eq!(VxChar::from('🦀').0, 0x1F980);
+        assert_eq!(char::from(VxChar(0xD800)), char::REPLACEMENT_CHARACTER);
+    }
+}
+
+mod mirror_mut {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/support/mirror_mut.rs"));
+
+    #[derive(Debug, PartialEq)]
+    enum Theme { Light, Dark }
+
+    #[derive(Copy, Clone)]
+    #[repr(C)]
+    enum VxTheme { Light, Dark }
+
+    impl From<Theme> for VxTheme {
+        fn from(value: Theme) -> Self {
+            match value { Theme::Light => Self::Light, Theme::Dark => Self::Dark }
+        }
+    }
+
+    impl From<VxTheme> for Theme {
+        fn from(value: VxTheme) -> Self {
+            match value { VxTheme::Light => Self::Light, VxTheme::Dark => Self::Dark }
+        }
+    }
+
+    #[derive(Copy, Clone)]
+    struct Hsva { h: f32 }
+
+    #[derive(Copy, Clone)]
+    struct VxHsva { h: f32 }
+
+    impl From<Hsva> for VxHsva {
+        fn from(value: Hsva) -> Self { Self { h: value.h } }
+    }
+
+    impl From<VxHsva> for Hsva {
+        fn from(value: VxHsva) -> Self { Self { h: value.h } }
+    }
+
+    #[test]
+    fn enums_are_checked_and_written_back() {
+        let check = |x: i32| match x { 0 => VxTheme::Light, 1 => VxTheme::Dark, _ => panic!("Invalid discriminant") };
+
+        let mut selected = 0i32;
+        {
+            let mut value = unsafe { VxMirrorMut::<_, Theme>::checked(&mut selected, check) };
+            assert_eq!(*value, Theme::Light);
+            *value = Theme::Dark;
+        }
+        assert_eq!(selected, 1);
+
+        selected = 2;
+        assert!(std::panic::catch_unwind(move || drop(unsafe { VxMirrorMut::<_, Theme>::checked(&mut selected, check) })).is_err());
+    }
+
+    #[test]
+    fn structs_are_written_back() {
+        let mut color = VxHsva { h: 0.25 };
+        unsafe { VxMirrorMut::<_, Hsva>::new(&mut color) }.h += 0.5;
+        assert_eq!(color.h, 0.75);
+    }
+}
+
+mod object {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/support/object.rs"));
+
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    struct Context(u32);
+    struct Ui;
+
+    impl VxClass for Context {
+        const TAG: u32 = 1;
+    }
+
+    impl VxClass for Ui {
+        const TAG: u32 = 2;
+    }
+
+    fn vx_class_name(tag: u32) -> &'static str {
+        match tag {
+            1 => "Context",
+            2 => "Ui",
+            _ => "an unknown class"
+        }
+    }
+
+    #[test]
+    fn handles_are_checked() {
+        std::panic::set_hook(Box::new(|_| {}));
+        let context = VxHandle::into_heap(Context(5));
+        unsafe {
+            VxObject::get_mut(context).0 += 1;
+            assert_eq!(VxObject::get(context).0, 6);
+
+            let error = catch_unwind(AssertUnwindSafe(|| VxObject::<Ui>::check(context.cast()))).unwrap_err();
+            assert_eq!(error.downcast_ref::<String>().unwrap(), "expected Ui handle, got Context");
+
+            let error = catch_unwind(|| VxObject::<Context>::check(std::ptr::null())).unwrap_err();
+            assert_eq!(error.downcast_ref::<String>().unwrap(), "expected Context handle, got null");
+
+            // Objects cannot be used mutably while they are in use further up the stack.
+            let outer = VxObject::get(context);
+            let error = catch_unwind(AssertUnwindSafe(|| { VxObject::get_mut(context); })).unwrap_err();
+            assert_eq!(error.downcast_ref::<String>().unwrap(), "Context is already in use");
+            assert_eq!(VxObject::get(context).0, outer.0);
+            drop(outer);
+
+            // Borrowed objects, like the arguments of callbacks, cannot be freed.
+            let mut value = Context(1);
+            let mut borrowed = VxObject::borrowed(&mut value);
+            VxObject::get_mut(&mut borrowed).0 += 1;
+            let error = catch_unwind(AssertUnwindSafe(|| { VxHandle::from_heap(&mut borrowed); })).unwrap_err();
+            assert_eq!(error.downcast_ref::<String>().unwrap(), "Context is borrowed, so it cannot be moved or freed");
+            assert_eq!(value.0, 2);
+
+            // Objects borrowed by shared reference cannot be modified.
+            let mut shared = VxObject::borrowed(&value);
+            assert_eq!(VxObject::get(&shared).0, 2);
+            let error = catch_unwind(AssertUnwindSafe(|| { VxObject::get_mut(&mut shared); })).unwrap_err();
+            assert_eq!(error.downcast_ref::<String>().unwrap(), "Context is borrowed by shared reference, so it cannot be modified");
+
+            assert_eq!(VxHandle::from_heap(context).into_inner().0, 6);
+        }
+    }
+}
+
+mod panic {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/support/panic.rs"));
+
+    #[test]
+    fn panics_are_caught_and_taken() {
+        std::panic::set_hook(Box::new(|_| {}));
+        let value = vx_catch(|| 5);
+        assert_eq!(unsafe { value.assume_init() }, 5);
+        assert_eq!(vx_take_error(), None);
+
+        vx_catch(|| -> u32 { panic!("Window is not open") });
+        assert_eq!(vx_take_error().as_deref(), Some("Window is not open"));
+        assert_eq!(vx_take_error(), None);
+
+        vx_catch(|| -> u32 { panic!("{} panels", 2) });
+        assert_eq!(vx_take_error().as_deref(), Some("2 panels"));
+
+        vx_discard_panic(|| panic!("Dropped twice"));
+        assert_eq!(vx_take_error(), None);
+    }
+}
+
+mod slice {
+    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/support/slice.rs"));
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum TextureId { User(u64) }
+
+    struct Mesh { indices: Vec<u32>, textures: Vec<TextureId> }
+
+    struct VxMesh { indices: VxSlice<u32>, textures: VxSlice<TextureId> }
+
+    impl From<&Mesh> for VxMesh {
+        fn from(value: &Mesh) -> Self {
+            Self { indices: VxSlice::frame(&value.indices), textures: VxSlice::frame_map(&value.textures, |x| *x) }
+        }
+    }
+
+    #[test]
+    fn frames_are_kept_until_replaced() {
+        let meshes = vx_frame_store::<Mesh, VxMesh>(vec![Mesh { indices: vec![0, 1, 2], textures: vec![TextureId::User(7)] }]);
+        assert!(vx_frame_is_kept(meshes.epoch));
+        let mesh = unsafe { &*meshes.ptr };
+        assert_eq!(mesh.indices.epoch, meshes.epoch);
+        assert_eq!(unsafe { std::slice::from_raw_parts(mesh.indices.ptr, mesh.indices.len) }, [0, 1, 2]);
+        assert_eq!(unsafe { *mesh.textures.ptr }, TextureId::User(7));
+
+        // Values of other types are kept alongside, while the next vector of meshes frees the earlier one.
+        let mesh = vx_frame_keep::<Mesh, VxMesh>(Mesh { indices: vec![3], textures: Vec::new() });
+        assert!(vx_frame_is_kept(meshes.epoch) && vx_frame_is_kept(mesh.indices.epoch));
+        let next = vx_frame_store::<Mesh, VxMesh>(Vec::new());
+        assert!(!vx_frame_is_kept(meshes.epoch) && vx_frame_is_kept(next.epoch));
+    }
+
+    #[test]
+    fn mirrors_are_borrowed_in_place_if_their_layout_matches() {
+        assert!(std::panic::catch_unwind(|| VxSlice::<u64>::frame(&[0u32])).is_err());
+    }
+
+    #[test]
+    fn pinned_slices_are_borrowed() {
+        // Slices pinned by C# are borrowed for the duration of a call, even if they are empty and null.
+        let pixels = [1u8, 2, 3];
+        assert_eq!(unsafe { VxSlice::from(&pixels[..]).as_slice::<u8>() }, [1, 2, 3]);
+        let empty = VxSlice::<u8> { ptr: std::ptr::null(), len: 0, capacity: 0, epoch: 0 };
+        assert!(unsafe { empty.as_slice::<u8>() }.is_empty());
+    }
+}